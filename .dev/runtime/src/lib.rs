@@ -68,6 +68,9 @@ pub type Nonce = u32;
 /// A hash of some data used by the chain.
 pub type Hash = sp_core::H256;
 
+/// Index of a poll of the infimum pallet.
+pub type PollId = u32;
+
 /// Opaque types. These are used by the CLI to instantiate machinery that don't need to know
 /// the specifics of the runtime. They can then be made to be agnostic over specific formats
 /// of data like extrinsics, allowing for them to continue syncing the network through upgrades
@@ -233,7 +236,7 @@ pub const EXISTENTIAL_DEPOSIT: u128 = 500;
 
 impl pallet_balances::Config for Runtime {
 	type MaxLocks = ConstU32<50>;
-	type MaxReserves = ();
+	type MaxReserves = ConstU32<50>;
 	type ReserveIdentifier = [u8; 8];
 	/// The type for recording an account's balance.
 	type Balance = Balance;
@@ -268,12 +271,52 @@ impl pallet_sudo::Config for Runtime {
 	type WeightInfo = pallet_sudo::weights::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+	pub InfimumOutcomeOrigin: frame_system::RawOrigin<AccountId> = frame_system::RawOrigin::Root;
+	pub InfimumMaxOptionCallWeight: Weight = Perbill::from_percent(10) * BlockWeights::get().max_block;
+	pub InfimumMaxInitializeWeight: Weight = Perbill::from_percent(10) * BlockWeights::get().max_block;
+	pub const InfimumFeeAllowanceReserveId: [u8; 8] = *b"infimfee";
+}
+
 impl pallet_infimum::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
+	type PollId = PollId;
 	type MaxCoordinatorPolls = ConstU32<1028>;
 	type MaxVoteOptions = ConstU32<32>;
+	type RuntimeCall = RuntimeCall;
+	type OutcomeOrigin = InfimumOutcomeOrigin;
+	type OptionCallOrigin = frame_system::EnsureNever<AccountId>;
+	type MaxOptionCallLen = ConstU32<4096>;
+	type MaxOptionCallWeight = InfimumMaxOptionCallWeight;
+	type MaxInitializeWeight = InfimumMaxInitializeWeight;
+	type MaxPruneKeys = ConstU32<1024>;
 	type MaxPollRegistrations = ConstU32<65536>;
 	type MaxPollInteractions = ConstU32<65536>;
+	type RegistrationTreeArity = ConstU32<2>;
+	type InteractionTreeArity = ConstU32<5>;
+	type EmitFullInteractionData = ConstBool<true>;
+	type StoreRegistrationLeaves = ConstBool<true>;
+	type ActivationTimeout = ConstU64<{ 7 * DAYS as u64 }>;
+	type MaxProofFailures = ConstU32<5>;
+	type ProofFailureCooldown = ConstU64<{ HOURS as u64 }>;
+	type PollCreationCooldown = ConstU64<0>;
+	type MaxInteractionsPerCall = ConstU32<32>;
+	type MaxProofsPerCall = ConstU32<16>;
+	type MaxKeeperQueueLen = ConstU32<64>;
+	type MaxPollClosuresPerBlock = ConstU32<64>;
+	type MaxScheduledPollsPerBlock = ConstU32<64>;
+	type MaxRegistrationsPerAccount = ConstU32<64>;
+	type OutcomeStagingTimeout = ConstU64<{ HOURS as u64 }>;
+	type Currency = Balances;
+	type FeeAllowanceReserveId = InfimumFeeAllowanceReserveId;
+	type ParticipantFee = ConstU128<{ 10 * EXISTENTIAL_DEPOSIT }>;
+	type WeightToFee = IdentityFee<Balance>;
+	type MaxSponsoredCalls = ConstU32<8>;
+	type OnFeeAllowanceCharged = ();
+	type SlashDestination = ();
+	type OnPollFinalized = ();
+	type CircuitOrigin = frame_system::EnsureRoot<AccountId>;
+	type WeightInfo = pallet_infimum::weights::SubstrateWeight<Runtime>;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -320,8 +363,24 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
+/// The storage migrations applied upon runtime upgrade, ahead of the hooks of each pallet.
+pub type Migrations = (
+	pallet_infimum::migrations::v1::MigrateToV1<Runtime>,
+	pallet_infimum::migrations::v2::MigrateToV2<Runtime>,
+	pallet_infimum::migrations::v3::MigrateToV3<Runtime>,
+	pallet_infimum::migrations::v4::MigrateToV4<Runtime>,
+	pallet_infimum::migrations::v5::MigrateToV5<Runtime>,
+	pallet_infimum::migrations::v6::MigrateToV6<Runtime>,
+	pallet_infimum::migrations::v7::MigrateToV7<Runtime>,
+	pallet_infimum::migrations::v8::MigrateToV8<Runtime>,
+	pallet_infimum::migrations::v9::MigrateToV9<Runtime>,
+	pallet_infimum::migrations::v10::MigrateToV10<Runtime>,
+	pallet_infimum::migrations::v11::MigrateToV11<Runtime>,
+);
+
 #[cfg(feature = "runtime-benchmarks")]
 #[macro_use]
 extern crate frame_benchmarking;
@@ -506,6 +565,100 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_infimum::runtime_api::InfimumApi<Block, AccountId, PollId> for Runtime {
+		fn interaction_batch_size(poll_id: PollId) -> Option<u32> {
+			Infimum::interaction_batch_size(poll_id)
+		}
+
+		fn tally_batch_size(poll_id: PollId) -> Option<u32> {
+			Infimum::tally_batch_size(poll_id)
+		}
+
+		fn replay_poll_outcome(poll_id: PollId) -> bool {
+			Infimum::replay_poll_outcome(poll_id)
+		}
+
+		fn active_polls(start: PollId, limit: u32) -> Vec<PollId> {
+			Infimum::active_polls(start, limit)
+		}
+
+		fn coordinator_public_key_at_poll_creation(poll_id: PollId) -> Option<Vec<u8>> {
+			Infimum::coordinator_public_key_at_poll_creation(poll_id)
+		}
+
+		fn poll_tree_depths(poll_id: PollId) -> Option<(u8, u8)> {
+			Infimum::poll_tree_depths(poll_id)
+		}
+
+		fn vote_option_count(poll_id: PollId) -> Option<u32> {
+			Infimum::vote_option_count(poll_id)
+		}
+
+		fn registration_tree_stats(poll_id: PollId) -> Option<pallet_infimum::MerkleTreeStats> {
+			Infimum::registration_tree_stats(poll_id)
+		}
+
+		fn interaction_tree_stats(poll_id: PollId) -> Option<pallet_infimum::MerkleTreeStats> {
+			Infimum::interaction_tree_stats(poll_id)
+		}
+
+		fn registration_leaves(poll_id: PollId, start: u32, count: u32) -> Vec<pallet_infimum::HashBytes> {
+			Infimum::registration_leaves(poll_id, start, count)
+		}
+
+		fn interaction_messages(poll_id: PollId, start: u32, count: u32) -> Vec<(pallet_infimum::PublicKey, pallet_infimum::PollInteractionData)> {
+			Infimum::interaction_messages(poll_id, start, count)
+		}
+
+		fn verify_registration_inclusion(poll_id: PollId, index: u32, leaf: pallet_infimum::HashBytes, path: Vec<Vec<pallet_infimum::HashBytes>>) -> bool {
+			Infimum::verify_registration_inclusion(poll_id, index, leaf, path)
+		}
+
+		fn participation_receipt(poll_id: PollId, who: AccountId) -> Option<Vec<u32>> {
+			Infimum::participation_receipt(poll_id, who)
+		}
+
+		fn participation_receipts(poll_id: PollId, page: u32, page_size: u32) -> Vec<(AccountId, Vec<u32>)> {
+			Infimum::participation_receipts(poll_id, page, page_size)
+		}
+
+		fn poll_config(poll_id: PollId) -> Option<pallet_infimum::SerializablePollConfig> {
+			Infimum::poll_config(poll_id, MILLISECS_PER_BLOCK)
+		}
+
+		fn allowed_actions(poll_id: PollId, who: Option<AccountId>) -> Option<pallet_infimum::AllowedActions> {
+			Infimum::allowed_actions(poll_id, who)
+		}
+
+		fn is_key_registered(poll_id: PollId, public_key: pallet_infimum::PublicKey) -> bool {
+			Infimum::is_key_registered(poll_id, public_key)
+		}
+
+		fn recent_polls(limit: u32) -> Vec<pallet_infimum::PollSummary<AccountId, PollId>> {
+			Infimum::recent_poll_summaries(limit)
+		}
+
+		fn get_coordinator_poll_ids(who: AccountId) -> Vec<PollId> {
+			Infimum::get_coordinator_poll_ids(who)
+		}
+
+		fn get_coordinator_poll_ids_paginated(who: AccountId, page: u32, page_size: u32) -> Vec<PollId> {
+			Infimum::get_coordinator_poll_ids_paginated(who, page, page_size)
+		}
+
+		fn coordinator_info(who: AccountId) -> Option<pallet_infimum::CoordinatorInfo<PollId>> {
+			Infimum::coordinator_info(who)
+		}
+
+		fn outcome_export(poll_id: PollId) -> Option<pallet_infimum::OutcomeExport<PollId>> {
+			Infimum::outcome_export(poll_id)
+		}
+
+		fn poll_resource_usage(poll_id: PollId) -> Weight {
+			Infimum::poll_resource_usage(poll_id)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (
@@ -2,9 +2,13 @@ import { ApiPromise } from '@polkadot/api';
 import { AddressOrPair } from '@polkadot/api/types';
 import { DispatchError } from '@polkadot/types/interfaces';
 import {
+    OutcomeHeader,
+    PollConfig,
+    PollInteractionCipher,
     PollOutcome,
     ProofData,
     PublicKey,
+    Signature,
     VerifyingKeys
 } from './interface';
 
@@ -20,6 +24,15 @@ export enum InfimumExtrinsic
     commitOutcome = 'commitOutcome',
     nullifyPoll = 'nullifyPoll',
     rotateKeys = 'rotateKeys',
+    activatePoll = 'activatePoll',
+    reapPoll = 'reapPoll',
+    replacePollVerifyKey = 'replacePollVerifyKey',
+    interactWithPollBatch = 'interactWithPollBatch',
+    signalIntent = 'signalIntent',
+    beginOutcome = 'beginOutcome',
+    submitOutcomeChunk = 'submitOutcomeChunk',
+    finalizeOutcome = 'finalizeOutcome',
+    commitTallyProofs = 'commitTallyProofs',
 }
 
 interface InfimumExtrinsicArgs
@@ -28,7 +41,9 @@ interface InfimumExtrinsicArgs
         // public_key: PublicKey
         PublicKey,
         // verify_key: VerifyingKeys
-        VerifyingKeys
+        VerifyingKeys,
+        // signature: Signature
+        Signature
     ];
     [InfimumExtrinsic.registerAsParticipant]: [
         // poll_id: PollId
@@ -41,29 +56,17 @@ interface InfimumExtrinsicArgs
         number,
         // public_key: PublicKey
         PublicKey,
-        // data: PollInteractionData
-        Array<Array<number>>
+        // cipher: PollInteractionCipher
+        PollInteractionCipher
     ];
     [InfimumExtrinsic.createPoll]: [
-        // signup_period: BlockNumber
-        number, 
-        // voting_period: BlockNumber
-        number, 
-        // registration_depth: u8
-        number, 
-        // interaction_depth: u8
-        number, 
-        // process_subtree_depth: u8
-        number, 
-        // tally_subtree_depth: u8
-        number, 
-        // vote_option_tree_depth: u8
-        number, 
-        // vote_options: vec::Vec<u128>
-        Array<number> 
+        // config: PollConfigOf
+        PollConfig
     ];
     [InfimumExtrinsic.mergePollState]: [];
     [InfimumExtrinsic.commitOutcome]: [
+        // proof_index: CommitmentIndex
+        number,
         // batches: ProofBatches
         Array<[ ProofData, Array<number> ]>,
         // outcome: Option<PollOutcome>
@@ -74,14 +77,65 @@ interface InfimumExtrinsicArgs
         // public_key: PublicKey
         PublicKey,
         // verify_key: VerifyingKeys
+        VerifyingKeys,
+        // signature: Signature
+        Signature
+    ];
+    [InfimumExtrinsic.activatePoll]: [
+        // poll_id: PollId
+        number
+    ];
+    [InfimumExtrinsic.reapPoll]: [
+        // poll_id: PollId
+        number
+    ];
+    [InfimumExtrinsic.replacePollVerifyKey]: [
+        // poll_id: PollId
+        number,
+        // verify_key: VerifyingKeys
         VerifyingKeys
     ];
+    [InfimumExtrinsic.interactWithPollBatch]: [
+        // poll_id: PollId
+        number,
+        // items: BoundedVec<(PublicKey, PollInteractionCipher), MaxInteractionsPerCall>
+        Array<[ PublicKey, PollInteractionCipher ]>
+    ];
+    [InfimumExtrinsic.signalIntent]: [
+        // poll_id: PollId
+        number
+    ];
+    [InfimumExtrinsic.beginOutcome]: [
+        // poll_id: PollId
+        number,
+        // header: OutcomeHeader
+        OutcomeHeader
+    ];
+    [InfimumExtrinsic.submitOutcomeChunk]: [
+        // poll_id: PollId
+        number,
+        // options: Vec<(u32, u128, Vec<Vec<HashBytes>>)>
+        Array<[ number, bigint, number[][][] ]>
+    ];
+    [InfimumExtrinsic.finalizeOutcome]: [
+        // poll_id: PollId
+        number
+    ];
+    [InfimumExtrinsic.commitTallyProofs]: [
+        // poll_id: PollId
+        number,
+        // proof_index: CommitmentIndex
+        number,
+        // batches: ProofBatches
+        Array<[ ProofData, Array<number> ]>
+    ];
 }
 
 enum InfimumDepositEvent
 {
     CoordinatorRegistered = 'CoordinatorRegistered',
     CoordinatorKeysChanged = 'CoordinatorKeysChanged',
+    CoordinatorVerifyKeyRotated = 'CoordinatorVerifyKeyRotated',
     ParticipantRegistered = 'ParticipantRegistered',
     PollCreated = 'PollCreated',
     PollInteraction = 'PollInteraction',
@@ -89,6 +143,16 @@ enum InfimumDepositEvent
     PollStateMerged = 'PollStateMerged',
     PollOutcome = 'PollOutcome',
     PollNullified = 'PollNullified',
+    PollActivated = 'PollActivated',
+    PollVotingPeriodStarted = 'PollVotingPeriodStarted',
+    PollReaped = 'PollReaped',
+    PollVerifyKeyReplaced = 'PollVerifyKeyReplaced',
+    PollMergeCompleted = 'PollMergeCompleted',
+    InteractionsBatchSubmitted = 'InteractionsBatchSubmitted',
+    IntentSignaled = 'IntentSignaled',
+    OutcomeStagingBegun = 'OutcomeStagingBegun',
+    OutcomeChunkAccepted = 'OutcomeChunkAccepted',
+    FeeAllowanceForfeited = 'FeeAllowanceForfeited',
 }
 
 type InfimumExtrinsicEvents = {
@@ -96,10 +160,19 @@ type InfimumExtrinsicEvents = {
     [InfimumExtrinsic.registerAsParticipant]: [InfimumDepositEvent.ParticipantRegistered],
     [InfimumExtrinsic.interactWithPoll]: [InfimumDepositEvent.PollInteraction],
     [InfimumExtrinsic.createPoll]: [InfimumDepositEvent.PollCreated],
-    [InfimumExtrinsic.mergePollState]: [InfimumDepositEvent.PollStateMerged],
+    [InfimumExtrinsic.mergePollState]: [InfimumDepositEvent.PollStateMerged, InfimumDepositEvent.PollMergeCompleted],
     [InfimumExtrinsic.commitOutcome]: [InfimumDepositEvent.PollCommitmentUpdated, InfimumDepositEvent.PollOutcome],
-    [InfimumExtrinsic.nullifyPoll]: [InfimumDepositEvent.PollNullified],
-    [InfimumExtrinsic.rotateKeys]: [InfimumDepositEvent.CoordinatorKeysChanged]
+    [InfimumExtrinsic.nullifyPoll]: [InfimumDepositEvent.PollNullified, InfimumDepositEvent.FeeAllowanceForfeited],
+    [InfimumExtrinsic.rotateKeys]: [InfimumDepositEvent.CoordinatorKeysChanged, InfimumDepositEvent.CoordinatorVerifyKeyRotated],
+    [InfimumExtrinsic.activatePoll]: [InfimumDepositEvent.PollActivated],
+    [InfimumExtrinsic.reapPoll]: [InfimumDepositEvent.PollReaped],
+    [InfimumExtrinsic.replacePollVerifyKey]: [InfimumDepositEvent.PollVerifyKeyReplaced],
+    [InfimumExtrinsic.interactWithPollBatch]: [InfimumDepositEvent.PollInteraction, InfimumDepositEvent.InteractionsBatchSubmitted],
+    [InfimumExtrinsic.signalIntent]: [InfimumDepositEvent.IntentSignaled],
+    [InfimumExtrinsic.beginOutcome]: [InfimumDepositEvent.OutcomeStagingBegun],
+    [InfimumExtrinsic.submitOutcomeChunk]: [InfimumDepositEvent.OutcomeChunkAccepted],
+    [InfimumExtrinsic.finalizeOutcome]: [InfimumDepositEvent.PollOutcome],
+    [InfimumExtrinsic.commitTallyProofs]: [InfimumDepositEvent.PollCommitmentUpdated]
 };
 
 interface InfimumDepositEventData
@@ -114,8 +187,15 @@ interface InfimumDepositEventData
         publicKey: any;
         verifyKey: any;
     };
+    [InfimumDepositEvent.CoordinatorVerifyKeyRotated]: {
+        pollId: string;
+        coordinator: string;
+        oldKeyHash: any;
+        newKeyHash: any;
+    };
     [InfimumDepositEvent.ParticipantRegistered]: {
         pollId: string;
+        who: string;
         count: string;
         block: string;
         publicKey: string;
@@ -123,13 +203,20 @@ interface InfimumDepositEventData
     [InfimumDepositEvent.PollCreated]: {
         pollId: string;
         coordinator: string;
+        coordinatorPublicKey: any;
         startsAt: string;
         endsAt: string;
+        maxRegistrations: string;
+        maxInteractions: string;
+        voteOptionCount: string;
+        feeAllowance: string;
+        configHash: any;
     };
     [InfimumDepositEvent.PollInteraction]: {
         pollId: string;
         count: string;
         publicKey: any;
+        leaf: any;
         data: any;
     };
     [InfimumDepositEvent.PollCommitmentUpdated]: {
@@ -144,10 +231,57 @@ interface InfimumDepositEventData
     [InfimumDepositEvent.PollOutcome]: {
         pollId: string;
         outcomeIndex: string;
+        weightConsumed: any;
     };
     [InfimumDepositEvent.PollNullified]: {
         pollId: string;
     };
+    [InfimumDepositEvent.PollActivated]: {
+        pollId: string;
+        startsAt: string;
+        endsAt: string;
+    };
+    [InfimumDepositEvent.PollVotingPeriodStarted]: {
+        pollId: string;
+        votingPeriodEnd: string;
+    };
+    [InfimumDepositEvent.PollReaped]: {
+        pollId: string;
+    };
+    [InfimumDepositEvent.PollVerifyKeyReplaced]: {
+        pollId: string;
+        verifyKey: any;
+    };
+    [InfimumDepositEvent.PollMergeCompleted]: {
+        pollId: string;
+        registrationRoot: any;
+        interactionRoot: any;
+        coordPubKeyHash: any;
+    };
+    [InfimumDepositEvent.InteractionsBatchSubmitted]: {
+        pollId: string;
+        firstIndex: string;
+        count: string;
+    };
+    [InfimumDepositEvent.IntentSignaled]: {
+        pollId: string;
+        who: string;
+        count: string;
+    };
+    [InfimumDepositEvent.OutcomeStagingBegun]: {
+        pollId: string;
+        expiresAt: string;
+    };
+    [InfimumDepositEvent.OutcomeChunkAccepted]: {
+        pollId: string;
+        optionsDone: string;
+        optionsTotal: string;
+    };
+    [InfimumDepositEvent.FeeAllowanceForfeited]: {
+        pollId: string;
+        coordinator: string;
+        amount: string;
+    };
 }
 
 export const extrinsic = (
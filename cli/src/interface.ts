@@ -9,6 +9,44 @@ export interface PublicKey
     y: Array<number>;
 }
 
+export type CoordKeyHash = 'Poseidon2Coords' | 'PoseidonPacked';
+
+export interface PollConfig
+{
+    signup_period: number;
+    voting_period: number;
+    registration_depth: number;
+    interaction_depth: number;
+    process_subtree_depth: number;
+    tally_subtree_depth: number;
+    vote_option_tree_depth: number;
+    vote_options: Array<number>;
+    fee_allowance: number;
+    reject_coordinator_key: boolean;
+    allow_early_interactions: boolean;
+    message_words: number;
+    pending: boolean;
+    collect_intents: boolean;
+    unique_keys: boolean;
+    compact_interactions: boolean;
+    coord_key_hash: CoordKeyHash;
+    circuit_id: number | null;
+    option_calls: Array<[number, string]>;
+}
+
+export interface PollInteractionCipher
+{
+    chunks: Array<Array<Array<number>>>;
+    words: number;
+}
+
+export interface Signature
+{
+    r8_x: Array<number>;
+    r8_y: Array<number>;
+    s: Array<number>;
+}
+
 export interface VerifyingKey
 {
     alpha_g1: Array<number>;
@@ -33,7 +71,7 @@ export interface ProofData
 
 export interface PollOutcome
 {
-    tallyResults: number[],
+    tallyResults: bigint[],
     tallyResultProofs: number[][][][],
     totalSpent: number[],
     totalSpentSalt: number[],
@@ -41,3 +79,13 @@ export interface PollOutcome
     newResultsCommitment: number[],
     spentVotesHash: number[]
 }
+
+export interface OutcomeHeader
+{
+    totalSpent: number[],
+    totalSpentSalt: number[],
+    tallyResultSalt: number[],
+    newResultsCommitment: number[],
+    spentVotesHash: number[],
+    optionCount: number
+}
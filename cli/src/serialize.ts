@@ -2,7 +2,9 @@ import inf from 'inf-lib';
 import path from 'path';
 import { readFileSync } from 'fs';
 import { Keypair, Message } from 'maci-domainobjs';
-import { ProofData, PublicKey, VerifyingKey, VerifyingKeys } from './interface';
+import { sign } from 'maci-crypto';
+import { blake2AsU8a, decodeAddress } from '@polkadot/util-crypto';
+import { PollInteractionCipher, ProofData, PublicKey, Signature, VerifyingKey, VerifyingKeys } from './interface';
 
 export const readJSON = (relPath: string) =>
 {
@@ -71,7 +73,37 @@ export const serializePublicKey = (keypair: Keypair): PublicKey =>
     };
 };
 
+// Signs the account id of `address`, proving possession of the private key of `keypair`.
+export const serializeProofOfPossession = (keypair: Keypair, address: string): Signature =>
+{
+    const hash = blake2AsU8a(decodeAddress(address), 256);
+    const message = BigInt('0x' + Buffer.from(hash).toString('hex'));
+    const { R8, S } = sign(keypair.privKey.rawPrivKey, message);
+    return {
+        r8_x: bnToBytes(R8[0]),
+        r8_y: bnToBytes(R8[1]),
+        s: bnToBytes(S)
+    };
+};
+
 export const serializeMessage = (message: Message): Array<Array<number>> =>
 {
     return message.data.map(value => padArrayStart(inf.bn_to_bytes_be({ value: value.toString() }).value, 0, 32));
 };
+
+// The number of words of an interaction message which are hashed together.
+const MESSAGE_CHUNK_WIDTH = 5;
+
+// Splits the words of a message into zero padded chunks, as hashed by the circuit.
+export const serializeCipher = (data: Array<Array<number>>): PollInteractionCipher =>
+{
+    const chunks: Array<Array<Array<number>>> = [];
+    for (let i = 0; i < data.length; i += MESSAGE_CHUNK_WIDTH)
+    {
+        const chunk = data.slice(i, i + MESSAGE_CHUNK_WIDTH);
+        while (chunk.length < MESSAGE_CHUNK_WIDTH) chunk.push(Array(32).fill(0));
+        chunks.push(chunk);
+    }
+
+    return { chunks, words: data.length };
+};
@@ -1,8 +1,8 @@
 import { Keypair, PrivKey } from 'maci-domainobjs';
 import { ApiPromise } from '@polkadot/api';
-import { serializePublicKey, loadVerifyingKeys } from '../serialize';
+import { serializePublicKey, serializeProofOfPossession, loadVerifyingKeys } from '../serialize';
 import { InfimumExtrinsic } from '../extrinsic';
-import { PollOutcome, ProofData, VerifyingKeys } from '../interface';
+import { CoordKeyHash, PollOutcome, ProofData, VerifyingKeys } from '../interface';
 import { User } from './user';
 
 export class Coordinator extends User
@@ -28,7 +28,8 @@ export class Coordinator extends User
             InfimumExtrinsic.registerAsCoordinator,
             [
                 serializePublicKey(this.palletKeypair),
-                this.verifyingKey
+                this.verifyingKey,
+                serializeProofOfPossession(this.palletKeypair, this.address())
             ]
         );
 
@@ -44,20 +45,44 @@ export class Coordinator extends User
         processSubtreeDepth: number,
         tallySubtreeDepth: number,
         voteOptionTreeDepth: number,
-        voteOptions: Array<number>
+        voteOptions: Array<number>,
+        feeAllowance: number = 0,
+        rejectCoordinatorKey: boolean = false,
+        allowEarlyInteractions: boolean = false,
+        messageWords: number = 10,
+        pending: boolean = false,
+        collectIntents: boolean = false,
+        uniqueKeys: boolean = false,
+        compactInteractions: boolean = false,
+        coordKeyHash: CoordKeyHash = 'Poseidon2Coords',
+        optionCalls: Array<[number, string]> = [],
+        circuitId: number | null = null
     )
     {
         const result = await this.sendExtrinsic(
             InfimumExtrinsic.createPoll,
             [
-                signupPeriod,
-                votingPeriod,
-                registrationDepth,
-                interactionDepth,
-                processSubtreeDepth,
-                tallySubtreeDepth,
-                voteOptionTreeDepth,
-                voteOptions
+                {
+                    signup_period: signupPeriod,
+                    voting_period: votingPeriod,
+                    registration_depth: registrationDepth,
+                    interaction_depth: interactionDepth,
+                    process_subtree_depth: processSubtreeDepth,
+                    tally_subtree_depth: tallySubtreeDepth,
+                    vote_option_tree_depth: voteOptionTreeDepth,
+                    vote_options: voteOptions,
+                    fee_allowance: feeAllowance,
+                    reject_coordinator_key: rejectCoordinatorKey,
+                    allow_early_interactions: allowEarlyInteractions,
+                    message_words: messageWords,
+                    pending,
+                    collect_intents: collectIntents,
+                    unique_keys: uniqueKeys,
+                    compact_interactions: compactInteractions,
+                    coord_key_hash: coordKeyHash,
+                    circuit_id: circuitId,
+                    option_calls: optionCalls
+                }
             ]
         );
 
@@ -65,6 +90,14 @@ export class Coordinator extends User
         return result.depositEvents.at(0)!.data;
     }
 
+    async activatePoll(pollId: number)
+    {
+        const result = await this.sendExtrinsic(InfimumExtrinsic.activatePoll, [ pollId ]);
+
+        if (result.error) throw new Error(result.error);
+        return result.depositEvents.at(0)!.data;
+    }
+
     async mergePollState()
     {
         const result = await this.sendExtrinsic(InfimumExtrinsic.mergePollState, []);
@@ -74,6 +107,7 @@ export class Coordinator extends User
     }
 
     async commitOutcome(
+        proofIndex: number,
         batches: Array<[ ProofData, Array<number> ]>,
         outcome?: PollOutcome
     )
@@ -81,6 +115,7 @@ export class Coordinator extends User
         const result = await this.sendExtrinsic(
             InfimumExtrinsic.commitOutcome,
             [
+                proofIndex,
                 batches,
                 outcome
             ]
@@ -98,6 +133,25 @@ export class Coordinator extends User
         return result.depositEvents.at(0)!.data;
     }
 
+    async replacePollVerifyKey(
+        pollId: number,
+        verifyingKey: VerifyingKeys
+    )
+    {
+        const result = await this.sendExtrinsic(
+            InfimumExtrinsic.replacePollVerifyKey,
+            [
+                pollId,
+                verifyingKey
+            ]
+        );
+
+        this.verifyingKey = verifyingKey;
+
+        if (result.error) throw new Error(result.error);
+        return result.depositEvents.at(0)!.data;
+    }
+
     async rotateKeys(
         privateKey: string,
         verifyingKey: VerifyingKeys
@@ -108,7 +162,8 @@ export class Coordinator extends User
             InfimumExtrinsic.rotateKeys,
             [
                 serializePublicKey(keypair),
-                verifyingKey
+                verifyingKey,
+                serializeProofOfPossession(keypair, this.address())
             ]
         );
 
@@ -1,5 +1,5 @@
 import { ApiPromise } from '@polkadot/api';
-import { serializePublicKey } from '../serialize';
+import { serializeCipher, serializePublicKey } from '../serialize';
 import { InfimumExtrinsic } from '../extrinsic';
 import { User } from './user';
 import { Keypair } from 'maci-domainobjs';
@@ -29,6 +29,14 @@ export class Participant extends User
         return result.depositEvents.at(0)!.data;
     }
 
+    async signalIntent(pollId: number)
+    {
+        const result = await this.sendExtrinsic(InfimumExtrinsic.signalIntent, [ pollId ]);
+
+        if (result.error) throw new Error(result.error);
+        return result.depositEvents.at(0)!.data;
+    }
+
     async interactWithPoll(
         pollId: number,
         keypair: Keypair,
@@ -40,7 +48,7 @@ export class Participant extends User
             [
                 pollId,
                 serializePublicKey(keypair),
-                data
+                serializeCipher(data)
             ]
         );
 
@@ -1,7 +1,8 @@
 import { Keypair, PrivKey } from 'maci-domainobjs';
 import { ApiPromise, Keyring } from '@polkadot/api';
 import { NetworkKeyring } from '../interface';
-import { extrinsic } from '../extrinsic';
+import { extrinsic, InfimumExtrinsic } from '../extrinsic';
+import { serializeCipher, serializePublicKey } from '../serialize';
 
 export class User
 {
@@ -32,4 +33,29 @@ export class User
     
     keypair() { return this.palletKeypair; };
     address() { return this.networkKeyring.address; }
+
+    async reapPoll(pollId: number)
+    {
+        const result = await this.sendExtrinsic(InfimumExtrinsic.reapPoll, [ pollId ]);
+
+        if (result.error) throw new Error(result.error);
+        return result.depositEvents.at(0)!.data;
+    }
+
+    async interactWithPollBatch(
+        pollId: number,
+        items: Array<[ Keypair, Array<Array<number>> ]>
+    )
+    {
+        const result = await this.sendExtrinsic(
+            InfimumExtrinsic.interactWithPollBatch,
+            [
+                pollId,
+                items.map(([ keypair, data ]) => [ serializePublicKey(keypair), serializeCipher(data) ])
+            ]
+        );
+
+        if (result.error) throw new Error(result.error);
+        return result.depositEvents.at(-1)!.data;
+    }
 }
@@ -139,7 +139,7 @@ export const getPollResults = (poll: Poll) =>
             BigInt(asHex(tally!.newSpentVoiceCreditSubtotalSalt as BigNumberish)),
         )
     );
-    const tallyResults = poll.tallyResult.map(x => Number(x));
+    const tallyResults = poll.tallyResult.map(x => BigInt(x));
     const voteOptionIndices = tallyResults.map((_, i) => i);
     const totalSpentSalt = bnToBytes(tally.newSpentVoiceCreditSubtotalSalt);
     const tallyResultSalt = bnToBytes(tally.newResultsRootSalt);
@@ -0,0 +1,134 @@
+//! RPC interface for the infimum pallet.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{
+    core::{DeserializeOwned, RpcResult},
+    proc_macros::rpc,
+    types::error::{CallError, ErrorObject}
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+pub use pallet_infimum::runtime_api::InfimumApi as InfimumRuntimeApi;
+use pallet_infimum::{AllowedActions, HashBytes, SerializablePollConfig};
+
+#[cfg(feature = "maci")]
+pub mod maci;
+
+/// The error code returned when a runtime API call fails.
+const RUNTIME_ERROR: i32 = 1;
+
+#[rpc(client, server)]
+pub trait InfimumApi<BlockHash, AccountId, PollId>
+{
+    /// Returns the configuration of the poll in a human readable form, or `null` if the poll does not exist.
+    #[method(name = "infimum_getPollConfig")]
+    fn poll_config(&self, poll_id: PollId, at: Option<BlockHash>) -> RpcResult<Option<SerializablePollConfig>>;
+
+    /// Returns at most `count` registration leaves of the poll, starting from the registration index `start`.
+    #[method(name = "infimum_registrationLeaves")]
+    fn registration_leaves(
+        &self,
+        poll_id: PollId,
+        start: u32,
+        count: u32,
+        at: Option<BlockHash>
+    ) -> RpcResult<Vec<HashBytes>>;
+
+    /// Returns the actions which the poll accepts at the given block, or `null` if the poll does not exist. Actions
+    /// which depend on the caller are only permitted if `who` is given.
+    #[method(name = "infimum_allowedActions")]
+    fn allowed_actions(
+        &self,
+        poll_id: PollId,
+        who: Option<AccountId>,
+        at: Option<BlockHash>
+    ) -> RpcResult<Option<AllowedActions>>;
+}
+
+/// Provides the RPC methods of the infimum pallet.
+pub struct Infimum<C, Block>
+{
+    client: Arc<C>,
+    _marker: PhantomData<Block>
+}
+
+impl<C, Block> Infimum<C, Block>
+{
+    pub fn new(client: Arc<C>) -> Self
+    {
+        Self { client, _marker: Default::default() }
+    }
+}
+
+impl<C, Block, AccountId, PollId> InfimumApiServer<<Block as BlockT>::Hash, AccountId, PollId> for Infimum<C, Block>
+where
+    Block: BlockT,
+    AccountId: Codec + DeserializeOwned + Send + Sync + 'static,
+    PollId: Codec + DeserializeOwned + Send + Sync + 'static,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: InfimumRuntimeApi<Block, AccountId, PollId>
+{
+    fn poll_config(
+        &self,
+        poll_id: PollId,
+        at: Option<<Block as BlockT>::Hash>
+    ) -> RpcResult<Option<SerializablePollConfig>>
+    {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.poll_config(at, poll_id).map_err(|error| {
+            CallError::Custom(ErrorObject::owned(
+                RUNTIME_ERROR,
+                "Unable to query the poll configuration.",
+                Some(format!("{:?}", error))
+            ))
+            .into()
+        })
+    }
+
+    fn registration_leaves(
+        &self,
+        poll_id: PollId,
+        start: u32,
+        count: u32,
+        at: Option<<Block as BlockT>::Hash>
+    ) -> RpcResult<Vec<HashBytes>>
+    {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.registration_leaves(at, poll_id, start, count).map_err(|error| {
+            CallError::Custom(ErrorObject::owned(
+                RUNTIME_ERROR,
+                "Unable to query the registration leaves.",
+                Some(format!("{:?}", error))
+            ))
+            .into()
+        })
+    }
+
+    fn allowed_actions(
+        &self,
+        poll_id: PollId,
+        who: Option<AccountId>,
+        at: Option<<Block as BlockT>::Hash>
+    ) -> RpcResult<Option<AllowedActions>>
+    {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.allowed_actions(at, poll_id, who).map_err(|error| {
+            CallError::Custom(ErrorObject::owned(
+                RUNTIME_ERROR,
+                "Unable to query the allowed actions.",
+                Some(format!("{:?}", error))
+            ))
+            .into()
+        })
+    }
+}
@@ -0,0 +1,229 @@
+//! Export of the outcome of a fulfilled poll in the schema of the `tally.json` files of the MACI tooling.
+
+use std::marker::PhantomData;
+
+use codec::Codec;
+use jsonrpsee::{
+    core::{DeserializeOwned, RpcResult},
+    proc_macros::rpc,
+    types::error::{CallError, ErrorObject}
+};
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+use pallet_infimum::{HashBytes, OutcomeExport};
+
+use crate::{Infimum, InfimumRuntimeApi, RUNTIME_ERROR};
+
+#[rpc(client, server)]
+pub trait InfimumMaciApi<BlockHash, AccountId, PollId>
+{
+    /// Returns the outcome of a fulfilled poll in the schema of a MACI `tally.json`, or `null` if the poll was not
+    /// fulfilled with an outcome.
+    #[method(name = "infimum_exportMaciTally")]
+    fn export_maci_tally(&self, poll_id: PollId, at: Option<BlockHash>) -> RpcResult<Option<MaciTally>>;
+}
+
+/// The outcome of a poll in the schema of a MACI `tally.json`. Field elements are encoded as strings: tallies and
+/// credits in decimal, and salts, commitments and hashes in hexadecimal with a `0x` prefix and without leading
+/// zeroes, as the MACI tooling formats them.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MaciTally
+{
+    /// The poll id, in decimal.
+    pub poll_id: String,
+
+    /// The final commitment of the tallying circuit.
+    pub new_tally_commitment: String,
+
+    /// The tally results, their salt, and their salted commitment.
+    pub results: MaciResults,
+
+    /// The total spent voice credits, their salt, and their salted commitment.
+    pub total_spent_voice_credits: MaciSpentVoiceCredits,
+
+    /// The final commitment of the message processing circuit, which the MACI schema does not carry.
+    pub process_commitment: String,
+
+    /// The root of the registration tree, which the MACI schema does not carry.
+    pub state_root: String,
+
+    /// The root of the interaction tree, which the MACI schema does not carry.
+    pub message_root: String
+}
+
+/// The `results` of a MACI `tally.json`.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct MaciResults
+{
+    /// The tally result of each vote option, in decimal.
+    pub tally: Vec<String>,
+
+    /// The salt of the tally results.
+    pub salt: String,
+
+    /// The salted commitment of the tally results.
+    pub commitment: String
+}
+
+/// The `totalSpentVoiceCredits` of a MACI `tally.json`.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct MaciSpentVoiceCredits
+{
+    /// The total spent voice credits, in decimal.
+    pub spent: String,
+
+    /// The salt of the spent voice credits.
+    pub salt: String,
+
+    /// The salted commitment of the spent voice credits.
+    pub commitment: String
+}
+
+impl<PollId: ToString> From<&OutcomeExport<PollId>> for MaciTally
+{
+    fn from(export: &OutcomeExport<PollId>) -> Self
+    {
+        let header = &export.header;
+
+        MaciTally {
+            poll_id: export.poll_id.to_string(),
+            new_tally_commitment: hex_string(&export.tally_commitment),
+            results: MaciResults {
+                tally: export.tally_results.iter().map(|tally| tally.to_string()).collect(),
+                salt: hex_string(&header.tally_result_salt),
+                commitment: hex_string(&header.new_results_commitment)
+            },
+            total_spent_voice_credits: MaciSpentVoiceCredits {
+                spent: decimal_string(&header.total_spent),
+                salt: hex_string(&header.total_spent_salt),
+                commitment: hex_string(&header.spent_votes_hash)
+            },
+            process_commitment: hex_string(&export.process_commitment),
+            state_root: hex_string(&export.registration_root),
+            message_root: hex_string(&export.interaction_root)
+        }
+    }
+}
+
+/// Encode a big-endian field element in hexadecimal with a `0x` prefix and without leading zeroes.
+pub fn hex_string(bytes: &HashBytes) -> String
+{
+    let digits: String = bytes.iter().map(|byte| format!("{:02x}", byte)).collect();
+    let digits = digits.trim_start_matches('0');
+
+    format!("0x{}", if digits.is_empty() { "0" } else { digits })
+}
+
+/// Encode a big-endian field element in decimal.
+pub fn decimal_string(bytes: &HashBytes) -> String
+{
+    let mut value = bytes.to_vec();
+    let mut digits = Vec::new();
+
+    // Divide the big-endian value by ten until it is zero, collecting the remainders as the digits.
+    while value.iter().any(|byte| *byte != 0)
+    {
+        let mut remainder = 0u16;
+        for byte in value.iter_mut()
+        {
+            let current = (remainder << 8) | u16::from(*byte);
+            *byte = (current / 10) as u8;
+            remainder = current % 10;
+        }
+        digits.push(char::from(b'0' + remainder as u8));
+    }
+
+    if digits.is_empty() { return "0".into(); }
+
+    digits.iter().rev().collect()
+}
+
+impl<C, Block, AccountId, PollId> InfimumMaciApiServer<<Block as BlockT>::Hash, AccountId, PollId> for Infimum<C, Block>
+where
+    Block: BlockT,
+    AccountId: Codec + DeserializeOwned + Send + Sync + 'static,
+    PollId: Codec + DeserializeOwned + ToString + Send + Sync + 'static,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: InfimumRuntimeApi<Block, AccountId, PollId>
+{
+    fn export_maci_tally(
+        &self,
+        poll_id: PollId,
+        at: Option<<Block as BlockT>::Hash>
+    ) -> RpcResult<Option<MaciTally>>
+    {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let export = api.outcome_export(at, poll_id).map_err(|error| {
+            CallError::Custom(ErrorObject::owned(
+                RUNTIME_ERROR,
+                "Unable to query the poll outcome.",
+                Some(format!("{:?}", error))
+            ))
+        })?;
+
+        Ok(export.as_ref().map(MaciTally::from))
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use pallet_infimum::OutcomeHeader;
+    use super::*;
+
+    fn bytes(value: u128) -> HashBytes
+    {
+        let mut bytes = [0u8; 32];
+        bytes[16..].copy_from_slice(&value.to_be_bytes());
+        bytes
+    }
+
+    /// Field elements should be formatted as the MACI tooling formats them.
+    #[test]
+    fn field_element_strings()
+    {
+        assert_eq!(hex_string(&[0u8; 32]), "0x0");
+        assert_eq!(hex_string(&bytes(0x0abc)), "0xabc");
+        assert_eq!(hex_string(&[0xffu8; 32]), format!("0x{}", "ff".repeat(32)));
+
+        assert_eq!(decimal_string(&[0u8; 32]), "0");
+        assert_eq!(decimal_string(&bytes(1_000_000_007)), "1000000007");
+        assert_eq!(decimal_string(&bytes(u128::MAX)), u128::MAX.to_string());
+
+        let mut large = [0u8; 32];
+        large[15] = 1;
+        assert_eq!(decimal_string(&large), "340282366920938463463374607431768211456");
+    }
+
+    /// The export of an outcome should match the committed `tally.json` fixture.
+    #[test]
+    fn maci_tally_fixture()
+    {
+        let export = OutcomeExport {
+            poll_id: 3u32,
+            outcome_index: 1,
+            header: OutcomeHeader {
+                total_spent: bytes(97),
+                total_spent_salt: bytes(0x5a17),
+                tally_result_salt: bytes(0x7a11),
+                new_results_commitment: bytes(0xc0ffee),
+                spent_votes_hash: bytes(0xbeef),
+                option_count: 3
+            },
+            tally_results: vec![ 4, 9, 0 ],
+            process_commitment: bytes(0x1234),
+            tally_commitment: bytes(0xabcdef),
+            registration_root: bytes(0x5747e),
+            interaction_root: bytes(0x3e55a6e)
+        };
+
+        let expected: serde_json::Value = serde_json::from_str(include_str!("fixtures/tally.json")).unwrap();
+        assert_eq!(serde_json::to_value(MaciTally::from(&export)).unwrap(), expected);
+    }
+}
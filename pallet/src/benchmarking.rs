@@ -0,0 +1,564 @@
+//! Benchmarks for `pallet_infimum`.
+//!
+//! The poll state trees are filled to their configured maxima through a benchmark-only backdoor, such
+//! that the worst case of each extrinsic is measured without hashing every leaf during setup.
+
+use super::*;
+use crate::Pallet as Infimum;
+use crate::hash::{Poseidon, PoseidonHasher};
+use ark_bn254::{g1, g2, Fr, G1Affine, G2Affine};
+use ark_ff::{BigInteger, PrimeField};
+use ark_serialize::CanonicalSerialize;
+use codec::Encode;
+use frame_benchmarking::v2::*;
+use frame_support::{traits::{Get, Hooks}, BoundedVec};
+use frame_system::RawOrigin;
+use sp_runtime::traits::{SaturatedConversion, Zero};
+use sp_std::vec;
+
+const SIGNUP_PERIOD: BlockNumber = 10;
+const VOTING_PERIOD: BlockNumber = 10;
+
+/// The precomputed leaf with which the state trees are filled.
+const BENCHMARK_LEAF: HashBytes = [1u8; 32];
+
+const COORDINATOR_PK: PublicKey = PublicKey {
+    x: [ 47, 251, 84, 72, 124, 5, 250, 184, 108, 105, 233, 65, 173, 6, 148, 178, 97, 59, 169, 24, 37, 253, 56, 60, 116, 29, 135, 209, 153, 55, 138, 1 ],
+    y: [ 0, 208, 187, 24, 225, 152, 32, 253, 150, 2, 56, 22, 114, 192, 50, 57, 6, 172, 129, 198, 198, 135, 128, 22, 90, 189, 179, 218, 81, 142, 132, 50 ]
+};
+
+const PARTICIPANT_PK: PublicKey = PublicKey {
+    x: [ 37, 65, 89, 247, 81, 66, 57, 66, 160, 59, 9, 185, 3, 52, 188, 122, 132, 221, 26, 200, 129, 243, 234, 120, 128, 23, 19, 96, 94, 154, 207, 196 ],
+    y: [ 38, 38, 57, 70, 162, 8, 198, 245, 211, 231, 101, 158, 63, 226, 172, 117, 156, 26, 3, 50, 0, 241, 20, 66, 227, 150, 160, 78, 249, 106, 140, 69 ]
+};
+
+const SHARED_PK: PublicKey = PublicKey {
+    x: [ 40, 162, 73, 223, 129, 218, 20, 106, 227, 221, 21, 198, 229, 247, 95, 63, 67, 107, 48, 80, 66, 13, 114, 203, 227, 83, 110, 211, 1, 230, 208, 15 ],
+    y: [ 16, 186, 146, 190, 25, 247, 51, 27, 61, 209, 71, 23, 169, 166, 156, 229, 156, 148, 80, 67, 232, 167, 99, 179, 33, 97, 164, 231, 182, 54, 24, 193 ]
+};
+
+/// The largest depth of a tree of the given arity whose capacity does not exceed `max`.
+fn max_depth(arity: u32, max: u32) -> u8
+{
+    let mut depth = 0u8;
+    while arity.checked_pow(u32::from(depth) + 1).map_or(false, |capacity| capacity <= max) { depth += 1; }
+
+    depth
+}
+
+fn set_block_number<T: frame_system::Config>(n: BlockNumber)
+{
+    frame_system::Pallet::<T>::set_block_number(n.saturated_into());
+}
+
+fn poseidon(inputs: &[HashBytes]) -> HashBytes
+{
+    let mut hasher = Poseidon::<Fr>::new_circom(inputs.len()).expect("arities of at most five are supported; qed");
+    let inputs: vec::Vec<Fr> = inputs
+        .iter()
+        .map(|bytes| Fr::from_be_bytes_mod_order(bytes))
+        .collect();
+
+    let bytes = hasher
+        .hash(&inputs)
+        .expect("input length matches the arity of the hasher; qed")
+        .into_bigint()
+        .to_bytes_be();
+
+    let mut hash = [0u8; 32];
+    hash[..bytes.len()].copy_from_slice(&bytes);
+    hash
+}
+
+fn serialize_point<P: CanonicalSerialize>(point: P) -> vec::Vec<u8>
+{
+    let mut bytes = vec::Vec::new();
+    point.serialize_uncompressed(&mut bytes).expect("serialization into a vector is infallible; qed");
+    bytes
+}
+
+/// A well-formed verify key and proof consisting of the curve generators. The proof does not attest to any
+/// inputs, but is verified in full all the same.
+fn generator_proof(nr_inputs: usize) -> (VerifyKey, ProofData)
+{
+    let g1 = serialize_point(G1Affine::new_unchecked(g1::G1_GENERATOR_X, g1::G1_GENERATOR_Y));
+    let g2 = serialize_point(G2Affine::new_unchecked(g2::G2_GENERATOR_X, g2::G2_GENERATOR_Y));
+
+    let verify_key = VerifyKey {
+        alpha_g1: g1.clone(),
+        beta_g2: g2.clone(),
+        gamma_g2: g2.clone(),
+        delta_g2: g2.clone(),
+        gamma_abc_g1: vec::Vec::from_iter((0..=nr_inputs).map(|_| g1.clone()))
+    };
+    let proof = ProofData {
+        pi_a: g1.clone(),
+        pi_b: g2,
+        pi_c: g1
+    };
+
+    (verify_key, proof)
+}
+
+/// Well-formed verifying keys of the sizes of both circuits.
+fn generator_keys() -> VerifyingKeys
+{
+    VerifyingKeys {
+        process: generator_proof(PROCESS_PUBLIC_INPUTS).0,
+        tally: generator_proof(TALLY_PUBLIC_INPUTS).0
+    }
+}
+
+/// Well-formed verifying keys holding `points` points in the `gamma_abc_g1` of both keys, of which each holds at most
+/// `MAX_PUBLIC_INPUTS + 1`. The points are split as between the standard circuits where possible, such that the keys
+/// are accepted by `register_circuit` iff they hold the points of both circuits.
+fn generator_keys_of(points: usize) -> VerifyingKeys
+{
+    let tally = (points / 2).min(TALLY_PUBLIC_INPUTS + 1).max(points.saturating_sub(MAX_PUBLIC_INPUTS + 1));
+
+    VerifyingKeys {
+        process: generator_proof(points - tally - 1).0,
+        tally: generator_proof(tally - 1).0
+    }
+}
+
+/// A public key derived from the account, alongside its proof of possession signed over the account.
+fn sign_proof_of_possession<AccountId: Encode>(who: &AccountId) -> (PublicKey, Signature)
+{
+    crate::crypto::sign(&sp_io::hashing::blake2_256(&who.encode()), crate::crypto::proof_of_possession_message(who))
+}
+
+/// Register `coordinator` and create a poll with the given depths.
+fn create_poll<T: Config<I>, I: 'static>(
+    coordinator: &T::AccountId,
+    registration_depth: u8,
+    interaction_depth: u8,
+    vote_options: u32,
+    vote_option_tree_depth: u8
+) -> Result<T::PollId, BenchmarkError>
+{
+    let empty_key = VerifyKey {
+        alpha_g1: vec::Vec::new(),
+        beta_g2: vec::Vec::new(),
+        gamma_g2: vec::Vec::new(),
+        delta_g2: vec::Vec::new(),
+        gamma_abc_g1: vec::Vec::new()
+    };
+
+    Coordinators::<T, I>::insert(coordinator, Coordinator {
+        public_key: COORDINATOR_PK,
+        verify_key: VerifyingKeys { process: empty_key.clone(), tally: empty_key },
+        last_poll: None
+    });
+
+    let vote_options: VoteOptions<T, I> = (0..u128::from(vote_options))
+        .collect::<vec::Vec<u128>>()
+        .try_into()
+        .map_err(|_| BenchmarkError::Stop("vote options exceed `MaxVoteOptions`"))?;
+
+    let poll_id = NextPollId::<T, I>::get();
+    Infimum::<T, I>::create_poll(RawOrigin::Signed(coordinator.clone()).into(), PollConfigOf {
+        signup_period: SIGNUP_PERIOD,
+        voting_period: VOTING_PERIOD,
+        registration_depth,
+        interaction_depth,
+        process_subtree_depth: 1,
+        tally_subtree_depth: 1,
+        vote_option_tree_depth,
+        vote_options,
+        fee_allowance: Zero::zero(),
+        reject_coordinator_key: false,
+        allow_early_interactions: false,
+        message_words: MAX_MESSAGE_WORDS as u8,
+        pending: false,
+        collect_intents: false,
+        unique_keys: false,
+        compact_interactions: false,
+        coord_key_hash: CoordKeyHash::Poseidon2Coords,
+        circuit_id: None,
+        option_calls: Default::default()
+    })?;
+
+    Ok(poll_id)
+}
+
+/// Fill the state trees of a poll with the given number of registrations and interactions.
+fn fill_poll<T: Config<I>, I: 'static>(
+    poll_id: T::PollId,
+    registrations: u32,
+    interactions: u32
+) -> Result<(), BenchmarkError>
+{
+    let Some(mut poll) = Infimum::<T, I>::get_poll_with_frontiers(poll_id) else { Err(BenchmarkError::Stop("poll does not exist"))? };
+
+    poll.state.registrations = poll.state.registrations.fill_for_benchmarks(registrations, BENCHMARK_LEAF);
+    poll.state.interactions = poll.state.interactions.fill_for_benchmarks(interactions, BENCHMARK_LEAF);
+    Infimum::<T, I>::insert_poll_with_frontiers(poll_id, poll);
+
+    Ok(())
+}
+
+#[instance_benchmarks]
+mod benchmarks
+{
+    use super::*;
+
+    /// Register a coordinator, checking the subgroup membership of their public key, the points of both verifying
+    /// keys and the proof of possession.
+    #[benchmark]
+    fn register_as_coordinator() -> Result<(), BenchmarkError>
+    {
+        let caller: T::AccountId = whitelisted_caller();
+        let (public_key, signature) = sign_proof_of_possession(&caller);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), public_key, generator_keys(), signature);
+
+        let Some(coordinator) = Infimum::<T, I>::coordinators(&caller) else { Err(BenchmarkError::Stop("coordinator is not registered"))? };
+        assert_eq!(coordinator.public_key, public_key);
+
+        Ok(())
+    }
+
+    /// Rotate both keys of a coordinator whose poll awaits processing, such that the previous and new verifying keys
+    /// are hashed to alert the provers of the poll.
+    #[benchmark]
+    fn rotate_keys() -> Result<(), BenchmarkError>
+    {
+        let caller: T::AccountId = whitelisted_caller();
+        set_block_number::<T>(1);
+
+        create_poll::<T, I>(&caller, 2, 1, 2, 1)?;
+        Coordinators::<T, I>::mutate(&caller, |coordinator| {
+            if let Some(coordinator) = coordinator { coordinator.verify_key = generator_keys(); }
+        });
+        set_block_number::<T>(2 + SIGNUP_PERIOD + VOTING_PERIOD);
+
+        let (public_key, signature) = sign_proof_of_possession(&caller);
+        let mut verify_key = generator_keys();
+        verify_key.process.alpha_g1 = serialize_point(-G1Affine::new_unchecked(g1::G1_GENERATOR_X, g1::G1_GENERATOR_Y));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), public_key, verify_key.clone(), signature);
+
+        let Some(coordinator) = Infimum::<T, I>::coordinators(&caller) else { Err(BenchmarkError::Stop("coordinator is not registered"))? };
+        assert_eq!(coordinator.verify_key, verify_key);
+
+        Ok(())
+    }
+
+    /// Register in a poll whose registration tree has room for a single registration.
+    #[benchmark]
+    fn register_as_participant() -> Result<(), BenchmarkError>
+    {
+        let coordinator: T::AccountId = account("coordinator", 0, 0);
+        let caller: T::AccountId = whitelisted_caller();
+        set_block_number::<T>(1);
+
+        let registration_depth = max_depth(T::RegistrationTreeArity::get(), T::MaxPollRegistrations::get());
+        let max_registrations = T::RegistrationTreeArity::get().pow(registration_depth.into());
+        let poll_id = create_poll::<T, I>(&coordinator, registration_depth, 1, 2, 1)?;
+
+        // The zero leaf occupies the first position of the registration tree.
+        fill_poll::<T, I>(poll_id, max_registrations - 2, 0)?;
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), poll_id, PARTICIPANT_PK);
+
+        let Some(poll) = Infimum::<T, I>::polls(poll_id) else { Err(BenchmarkError::Stop("poll does not exist"))? };
+        assert_eq!(poll.state.registrations.count, max_registrations - 1);
+
+        Ok(())
+    }
+
+    /// Interact with a poll whose interaction tree of depth `d` has room for a single interaction, such that the
+    /// insertion completes the tree and hashes once at every level, on top of the hashes of the interaction leaf.
+    #[benchmark]
+    fn interact_with_poll(
+        d: Linear<1, { u32::from(max_depth(T::InteractionTreeArity::get(), T::MaxPollInteractions::get())) }>
+    ) -> Result<(), BenchmarkError>
+    {
+        let coordinator: T::AccountId = account("coordinator", 0, 0);
+        let caller: T::AccountId = whitelisted_caller();
+        set_block_number::<T>(1);
+
+        let interaction_depth = d as u8;
+        let max_interactions = T::InteractionTreeArity::get().pow(d);
+        let poll_id = create_poll::<T, I>(&coordinator, 1, interaction_depth, 2, 1)?;
+        fill_poll::<T, I>(poll_id, 1, max_interactions - 1)?;
+
+        let data: PollInteractionData = vec::Vec::from([BENCHMARK_LEAF; MAX_MESSAGE_WORDS as usize])
+            .try_into()
+            .map_err(|_| BenchmarkError::Stop("message exceeds `MAX_MESSAGE_WORDS`"))?;
+
+        set_block_number::<T>(1 + SIGNUP_PERIOD);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), poll_id, SHARED_PK, data.into());
+
+        let Some(poll) = Infimum::<T, I>::polls(poll_id) else { Err(BenchmarkError::Stop("poll does not exist"))? };
+        assert_eq!(poll.state.interactions.count, max_interactions);
+
+        Ok(())
+    }
+
+    /// Interact with a poll whose interaction tree has room for exactly `n` interactions.
+    #[benchmark]
+    fn interact_with_poll_batch(n: Linear<1, { T::MaxInteractionsPerCall::get() }>) -> Result<(), BenchmarkError>
+    {
+        let coordinator: T::AccountId = account("coordinator", 0, 0);
+        let caller: T::AccountId = whitelisted_caller();
+        set_block_number::<T>(1);
+
+        let interaction_depth = max_depth(T::InteractionTreeArity::get(), T::MaxPollInteractions::get());
+        let max_interactions = T::InteractionTreeArity::get().pow(interaction_depth.into());
+        let poll_id = create_poll::<T, I>(&coordinator, 1, interaction_depth, 2, 1)?;
+        fill_poll::<T, I>(poll_id, 1, max_interactions.saturating_sub(n))?;
+
+        let data: PollInteractionData = vec::Vec::from([BENCHMARK_LEAF; MAX_MESSAGE_WORDS as usize])
+            .try_into()
+            .map_err(|_| BenchmarkError::Stop("message exceeds `MAX_MESSAGE_WORDS`"))?;
+        let items: BoundedVec<(PublicKey, PollInteractionCipher), T::MaxInteractionsPerCall> = (0..n)
+            .map(|_| (SHARED_PK, data.clone().into()))
+            .collect::<vec::Vec<_>>()
+            .try_into()
+            .map_err(|_| BenchmarkError::Stop("batch exceeds `MaxInteractionsPerCall`"))?;
+
+        set_block_number::<T>(1 + SIGNUP_PERIOD);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller), poll_id, items);
+
+        let Some(poll) = Infimum::<T, I>::polls(poll_id) else { Err(BenchmarkError::Stop("poll does not exist"))? };
+        assert_eq!(poll.state.interactions.count, max_interactions);
+
+        Ok(())
+    }
+
+    /// Read and store a poll with the deepest interaction tree and the most vote options as a whole, as every
+    /// registration and interaction did before the state trees and phase cache of a poll were stored apart from it.
+    /// Not charged by any call: its proof size, measured with `--pov-mode Measured`, is the baseline against which
+    /// the bytes read and written by `register_as_participant` and `interact_with_poll` are compared.
+    #[benchmark]
+    fn read_write_poll() -> Result<(), BenchmarkError>
+    {
+        let coordinator: T::AccountId = account("coordinator", 0, 0);
+        set_block_number::<T>(1);
+
+        let interaction_depth = max_depth(T::InteractionTreeArity::get(), T::MaxPollInteractions::get());
+        let max_interactions = T::InteractionTreeArity::get().pow(interaction_depth.into());
+        let poll_id = create_poll::<T, I>(&coordinator, 1, interaction_depth, T::MaxVoteOptions::get(), MAX_PATH_DEPTH)?;
+        fill_poll::<T, I>(poll_id, 1, max_interactions - 1)?;
+
+        #[block]
+        {
+            if let Some(poll) = Infimum::<T, I>::get_poll_with_frontiers(poll_id)
+            {
+                Infimum::<T, I>::insert_poll_with_frontiers(poll_id, poll);
+            }
+        }
+
+        // An interaction reads and writes the phase cache and the interaction tree with its frontier instead.
+        let Some(poll) = Infimum::<T, I>::get_poll_with_frontiers(poll_id) else { Err(BenchmarkError::Stop("poll does not exist"))? };
+        let per_call = PollPhases::<T, I>::get(poll_id).encoded_size() +
+            PollInteractionTree::<T, I>::get(poll_id).encoded_size() +
+            PollInteractionFrontier::<T, I>::get(poll_id).encoded_size();
+        assert!(per_call < poll.encoded_size());
+
+        Ok(())
+    }
+
+    /// Merge the fullest registration tree which is not yet merged, i.e. one leaf short of capacity.
+    #[benchmark]
+    fn merge_registrations() -> Result<(), BenchmarkError>
+    {
+        let coordinator: T::AccountId = account("coordinator", 0, 0);
+        set_block_number::<T>(1);
+
+        let registration_depth = max_depth(T::RegistrationTreeArity::get(), T::MaxPollRegistrations::get());
+        let max_registrations = T::RegistrationTreeArity::get().pow(registration_depth.into());
+        let poll_id = create_poll::<T, I>(&coordinator, registration_depth, 1, 2, 1)?;
+        fill_poll::<T, I>(poll_id, max_registrations - 2, 0)?;
+
+        set_block_number::<T>(1 + SIGNUP_PERIOD);
+
+        #[block]
+        {
+            Infimum::<T, I>::merge_poll_state(RawOrigin::Signed(coordinator.clone()).into())?;
+        }
+
+        let Some(poll) = Infimum::<T, I>::polls(poll_id) else { Err(BenchmarkError::Stop("poll does not exist"))? };
+        assert!(poll.state.registrations.root.is_some());
+
+        Ok(())
+    }
+
+    /// Merge the fullest interaction tree which is not yet merged, i.e. one leaf short of capacity.
+    #[benchmark]
+    fn merge_interactions() -> Result<(), BenchmarkError>
+    {
+        let coordinator: T::AccountId = account("coordinator", 0, 0);
+        set_block_number::<T>(1);
+
+        let interaction_depth = max_depth(T::InteractionTreeArity::get(), T::MaxPollInteractions::get());
+        let max_interactions = T::InteractionTreeArity::get().pow(interaction_depth.into());
+        let poll_id = create_poll::<T, I>(&coordinator, 2, interaction_depth, 2, 1)?;
+        fill_poll::<T, I>(poll_id, 1, max_interactions - 1)?;
+
+        // The registration tree is merged first, once the closure of the poll sealed it.
+        set_block_number::<T>(2 + SIGNUP_PERIOD + VOTING_PERIOD);
+        Infimum::<T, I>::on_initialize((2 + SIGNUP_PERIOD + VOTING_PERIOD).saturated_into());
+        Infimum::<T, I>::merge_poll_state(RawOrigin::Signed(coordinator.clone()).into())?;
+
+        #[block]
+        {
+            Infimum::<T, I>::merge_poll_state(RawOrigin::Signed(coordinator.clone()).into())?;
+        }
+
+        let Some(poll) = Infimum::<T, I>::polls(poll_id) else { Err(BenchmarkError::Stop("poll does not exist"))? };
+        assert!(poll.state.interactions.root.is_some());
+
+        Ok(())
+    }
+
+    /// Verify a single proof against the circuit with the most public inputs.
+    #[benchmark]
+    fn verify_proof()
+    {
+        let (verify_key, proof) = generator_proof(PROCESS_PUBLIC_INPUTS);
+        let public_inputs: vec::Vec<Fr> = (0..PROCESS_PUBLIC_INPUTS as u64).map(Fr::from).collect();
+        let verified;
+
+        #[block]
+        {
+            verified = crate::pallet::verify_proof(verify_key, public_inputs, proof);
+        }
+
+        assert!(!verified);
+    }
+
+    /// Verify `n` tally proofs one after another, the baseline against which `verify_proofs_batched` is compared.
+    #[benchmark]
+    fn verify_proofs_sequential(n: Linear<1, { T::MaxProofsPerCall::get() }>)
+    {
+        let (verify_key, proof) = generator_proof(TALLY_PUBLIC_INPUTS);
+        let public_inputs: vec::Vec<Fr> = (0..TALLY_PUBLIC_INPUTS as u64).map(Fr::from).collect();
+        let mut verified = true;
+
+        #[block]
+        {
+            for _ in 0..n
+            {
+                verified &= crate::pallet::verify_proof(verify_key.clone(), public_inputs.clone(), proof.clone());
+            }
+        }
+
+        assert!(!verified);
+    }
+
+    /// Verify `n` tally proofs at once with a single multi-pairing.
+    #[benchmark]
+    fn verify_proofs_batched(n: Linear<1, { T::MaxProofsPerCall::get() }>)
+    {
+        let (verify_key, proof) = generator_proof(TALLY_PUBLIC_INPUTS);
+        let public_inputs: vec::Vec<Fr> = (0..TALLY_PUBLIC_INPUTS as u64).map(Fr::from).collect();
+        let statements = vec::Vec::from_iter((0..n).map(|_| (public_inputs.clone(), proof.clone())));
+        let verified;
+
+        #[block]
+        {
+            verified = crate::pallet::verify_proofs_batched(verify_key, statements);
+        }
+
+        assert!(!verified);
+    }
+
+    /// Commit the outcome of a fully proven poll with `o` vote options.
+    #[benchmark]
+    fn commit_outcome(o: Linear<2, { T::MaxVoteOptions::get() }>) -> Result<(), BenchmarkError>
+    {
+        let coordinator: T::AccountId = whitelisted_caller();
+        set_block_number::<T>(1);
+
+        // Each tally result is verified against a path of the maximum depth, such that the weight bounds any poll.
+        let depth = MAX_PATH_DEPTH;
+        let poll_id = create_poll::<T, I>(&coordinator, 2, 1, o, depth)?;
+
+        // Every tally result is zero, such that the vote option tree is the zero tree and each tally path
+        // consists of the zero subtree roots at each level.
+        let mut zeroes = vec::Vec::from([[0u8; 32]]);
+        for level in 0..usize::from(depth) { zeroes.push(poseidon(&[zeroes[level]; 5])); }
+        let root = zeroes[usize::from(depth)];
+        let tally_path: vec::Vec<vec::Vec<HashBytes>> = zeroes[..usize::from(depth)]
+            .iter()
+            .map(|zero| vec::Vec::from([*zero; 4]))
+            .collect();
+
+        let new_results_commitment = poseidon(&[root, [0u8; 32]]);
+        let spent_votes_hash = poseidon(&[[0u8; 32], [0u8; 32]]);
+        let tally_commitment = poseidon(&[new_results_commitment, spent_votes_hash]);
+
+        let Some(mut poll) = Infimum::<T, I>::polls(poll_id) else { Err(BenchmarkError::Stop("poll does not exist"))? };
+        poll.state.registrations.root = Some(BENCHMARK_LEAF);
+        poll.state.interactions.root = Some(BENCHMARK_LEAF);
+        poll.state.sealed = true;
+        poll.state.commitment.process = ProofChain { completed: 1, expected: 1, current: BENCHMARK_LEAF };
+        poll.state.commitment.tally = ProofChain { completed: 1, expected: 1, current: tally_commitment };
+        Infimum::<T, I>::insert_poll_with_frontiers(poll_id, poll);
+
+        let outcome = PollOutcome {
+            tally_results: vec::Vec::from_iter((0..o).map(|_| 0)),
+            tally_result_proofs: vec::Vec::from_iter((0..o).map(|_| tally_path.clone())),
+            total_spent: [0u8; 32],
+            total_spent_salt: [0u8; 32],
+            tally_result_salt: [0u8; 32],
+            new_results_commitment,
+            spent_votes_hash
+        };
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(coordinator), 0, BoundedVec::default(), Some(outcome));
+
+        let Some(poll) = Infimum::<T, I>::polls(poll_id) else { Err(BenchmarkError::Stop("poll does not exist"))? };
+        assert_eq!(poll.state.outcome, Some(NO_VOTES));
+
+        Ok(())
+    }
+
+    /// Register a circuit whose verifying keys hold `p` points in their `gamma_abc_g1`, every one of which is
+    /// deserialized. Only keys of the sizes of the standard circuits are accepted, after their points were
+    /// deserialized all the same.
+    #[benchmark]
+    fn register_circuit(p: Linear<2, { 2 * (MAX_PUBLIC_INPUTS as u32 + 1) }>) -> Result<(), BenchmarkError>
+    {
+        let origin = T::CircuitOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let verify_key = generator_keys_of(p as usize);
+        let result;
+
+        #[block]
+        {
+            result = Infimum::<T, I>::register_circuit(origin, 0, verify_key);
+        }
+
+        assert_eq!(result.is_ok(), p as usize == PROCESS_PUBLIC_INPUTS + TALLY_PUBLIC_INPUTS + 2);
+
+        Ok(())
+    }
+
+    /// Remove a circuit which no unfinished poll references.
+    #[benchmark]
+    fn remove_circuit() -> Result<(), BenchmarkError>
+    {
+        let origin = T::CircuitOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        CircuitRegistry::<T, I>::insert(0, generator_keys());
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, 0);
+
+        assert!(!CircuitRegistry::<T, I>::contains_key(0));
+
+        Ok(())
+    }
+
+    impl_benchmark_test_suite!(Infimum, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,219 @@
+use codec::Encode;
+use sp_std::vec;
+use ark_bn254::{Fr};
+use ark_ff::{BigInteger, Field, MontFp, PrimeField, Zero, One};
+use crate::hash::{Poseidon, PoseidonHasher};
+use crate::poll::{HashBytes, PublicKey, Signature};
+
+/// The `a` coefficient of the Baby Jubjub curve.
+const COEFF_A: Fr = MontFp!("168700");
+
+/// The `d` coefficient of the Baby Jubjub curve.
+const COEFF_D: Fr = MontFp!("168696");
+
+/// The generator of the prime order subgroup of the Baby Jubjub curve.
+const BASE8: BabyJubPoint = BabyJubPoint {
+    x: MontFp!("5299619240641551281634865583518297030282874472190772894086521144482721001553"),
+    y: MontFp!("16950150798460657717958625567821834550301663161624707787222815936182638968203")
+};
+
+/// The order of the prime order subgroup of the Baby Jubjub curve, represented as a (big-endian) byte array.
+const SUBGROUP_ORDER: HashBytes = [
+    6, 12, 137, 206, 92, 38, 52, 5, 55, 10, 8, 182, 208, 48, 43, 11,
+    171, 62, 237, 184, 57, 32, 238, 10, 103, 114, 151, 220, 57, 33, 38, 241
+];
+
+/// The scalar field of the prime order subgroup of the Baby Jubjub curve, in which private keys, nonces and
+/// signature scalars are reduced. The derived implementations reference the features of `ark-ff`, hence the lints
+/// are relaxed for them.
+#[cfg(any(test, feature = "runtime-benchmarks"))]
+#[allow(unexpected_cfgs, non_local_definitions)]
+mod subgroup_scalar
+{
+    #[derive(ark_ff::MontConfig)]
+    #[modulus = "2736030358979909402780800718157159386076813972158567259200215660948447373041"]
+    #[generator = "31"]
+    pub struct SubgroupScalarConfig;
+
+    pub type SubgroupScalar = ark_ff::Fp256<ark_ff::MontBackend<SubgroupScalarConfig, 4>>;
+}
+
+#[cfg(any(test, feature = "runtime-benchmarks"))]
+use subgroup_scalar::SubgroupScalar;
+
+/// The reasons for which a public key may be rejected.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PublicKeyError
+{
+    /// The coordinates are not canonical field elements, or do not lie on the curve.
+    Malformed,
+
+    /// The point does not belong to the prime order subgroup.
+    NotInSubgroup
+}
+
+/// An affine point on the Baby Jubjub twisted Edwards curve.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BabyJubPoint
+{
+    pub x: Fr,
+    pub y: Fr
+}
+
+impl BabyJubPoint
+{
+    /// The neutral element of the curve.
+    pub fn identity() -> Self
+    {
+        BabyJubPoint { x: Fr::zero(), y: Fr::one() }
+    }
+
+    /// Returns the point encoded by the (big-endian) coordinates of the public key,
+    /// iff the coordinates are canonical field elements which lie on the curve.
+    pub fn from_public_key(public_key: &PublicKey) -> Option<Self>
+    {
+        let x = fr_from_canonical_bytes(&public_key.x)?;
+        let y = fr_from_canonical_bytes(&public_key.y)?;
+        let point = BabyJubPoint { x, y };
+
+        if point.is_on_curve() { Some(point) } else { None }
+    }
+
+    /// Returns true iff the point satisfies `a * x^2 + y^2 = 1 + d * x^2 * y^2`.
+    pub fn is_on_curve(&self) -> bool
+    {
+        let x2 = self.x.square();
+        let y2 = self.y.square();
+        COEFF_A * x2 + y2 == Fr::one() + COEFF_D * x2 * y2
+    }
+
+    /// Returns true iff the point belongs to the prime order subgroup, i.e. `l * P` is the identity.
+    pub fn is_in_subgroup(&self) -> bool
+    {
+        self.mul(&SUBGROUP_ORDER) == Self::identity()
+    }
+
+    /// Twisted Edwards point addition. The formula is complete since `d` is not a square.
+    pub fn add(&self, other: &Self) -> Self
+    {
+        let x1x2 = self.x * other.x;
+        let y1y2 = self.y * other.y;
+        let dxy = COEFF_D * x1x2 * y1y2;
+
+        let x = (self.x * other.y + self.y * other.x) * (Fr::one() + dxy).inverse().unwrap_or_default();
+        let y = (y1y2 - COEFF_A * x1x2) * (Fr::one() - dxy).inverse().unwrap_or_default();
+
+        BabyJubPoint { x, y }
+    }
+
+    /// Multiply the point by a scalar represented as a (big-endian) byte array.
+    pub fn mul(&self, scalar: &[u8]) -> Self
+    {
+        let mut result = Self::identity();
+        for byte in scalar.iter()
+        {
+            for bit in (0..8).rev()
+            {
+                result = result.add(&result);
+                if (byte >> bit) & 1 == 1 { result = result.add(self); }
+            }
+        }
+        result
+    }
+}
+
+/// Ensure that the public key encodes a point in the prime order subgroup of the curve.
+/// Points of small order would otherwise leak information about, or break, the shared keys derived in the circuits.
+pub fn validate_public_key(public_key: &PublicKey) -> Result<BabyJubPoint, PublicKeyError>
+{
+    let point = BabyJubPoint::from_public_key(public_key).ok_or(PublicKeyError::Malformed)?;
+    if !point.is_in_subgroup() { return Err(PublicKeyError::NotInSubgroup); }
+
+    Ok(point)
+}
+
+/// Verify an EdDSA (Baby Jubjub, Poseidon) signature of `message` made with the private key
+/// corresponding to `public_key`. Compatible with the `verifyPoseidon` scheme of circomlib.
+pub fn verify_signature(
+    public_key: &PublicKey,
+    message: Fr,
+    signature: &Signature
+) -> bool
+{
+    // Reject non-canonical signature scalars to prevent malleability.
+    if signature.s >= SUBGROUP_ORDER { return false; }
+
+    let Some(a) = BabyJubPoint::from_public_key(public_key) else { return false; };
+    let Some(r8) = BabyJubPoint::from_public_key(&PublicKey { x: signature.r8_x, y: signature.r8_y }) else { return false; };
+
+    let Some(mut hasher) = Poseidon::<Fr>::new_circom(5).ok() else { return false; };
+    let inputs: vec::Vec<Fr> = vec::Vec::from([ r8.x, r8.y, a.x, a.y, message ]);
+    let Some(hm) = hasher.hash(&inputs).ok() else { return false; };
+
+    // Check that `S * B8 == R8 + 8 * hm * A`.
+    let left = BASE8.mul(&signature.s);
+    let right = a.mul(&hm.into_bigint().to_bytes_be()).mul(&[8u8]);
+
+    left == r8.add(&right)
+}
+
+/// Sign `message` with the private key `secret`, returning the public key alongside the signature, which
+/// `verify_signature` accepts. The nonce is derived from the private key and the message. Signatures are produced
+/// off-chain by coordinators, such that the signer only serves the tests and benchmarks.
+#[cfg(any(test, feature = "runtime-benchmarks"))]
+pub fn sign(
+    secret: &HashBytes,
+    message: Fr
+) -> (PublicKey, Signature)
+{
+    let secret = SubgroupScalar::from_be_bytes_mod_order(secret);
+    let nonce = SubgroupScalar::from_be_bytes_mod_order(&sp_io::hashing::blake2_256(
+        &[to_hash_bytes(secret), to_hash_bytes(message)].concat()
+    ));
+
+    let a = BASE8.mul(&to_hash_bytes(secret));
+    let r8 = BASE8.mul(&to_hash_bytes(nonce));
+
+    // `S = r + 8 * hm * s`, such that `S * B8 == R8 + 8 * hm * A`.
+    let mut hasher = Poseidon::<Fr>::new_circom(5).expect("the arity is supported; qed");
+    let hm = hasher.hash(&[ r8.x, r8.y, a.x, a.y, message ]).expect("the number of inputs matches the arity; qed");
+    let s = nonce + SubgroupScalar::from(8u64) * SubgroupScalar::from_be_bytes_mod_order(&to_hash_bytes(hm)) * secret;
+
+    (
+        PublicKey { x: to_hash_bytes(a.x), y: to_hash_bytes(a.y) },
+        Signature { r8_x: to_hash_bytes(r8.x), r8_y: to_hash_bytes(r8.y), s: to_hash_bytes(s) }
+    )
+}
+
+/// The (big-endian) bytes of the canonical representation of a field element.
+#[cfg(any(test, feature = "runtime-benchmarks"))]
+fn to_hash_bytes<F: PrimeField>(element: F) -> HashBytes
+{
+    let mut bytes = [0u8; 32];
+    let element_bytes = element.into_bigint().to_bytes_be();
+    bytes[32 - element_bytes.len()..].copy_from_slice(&element_bytes);
+    bytes
+}
+
+/// The message which a coordinator signs in order to prove possession of their private key.
+pub fn proof_of_possession_message<AccountId: Encode>(who: &AccountId) -> Fr
+{
+    Fr::from_be_bytes_mod_order(&sp_io::hashing::blake2_256(&who.encode()))
+}
+
+/// Returns true iff the (big-endian) bytes are strictly less than the BN254 scalar field modulus.
+pub fn is_valid_fr_element(bytes: &HashBytes) -> bool
+{
+    fr_from_canonical_bytes(bytes).is_some()
+}
+
+/// Returns the field element represented by the (big-endian) bytes, iff they are strictly less than the modulus.
+fn fr_from_canonical_bytes(bytes: &HashBytes) -> Option<Fr>
+{
+    let element = Fr::from_be_bytes_mod_order(bytes);
+    let mut canonical = [0u8; 32];
+    let element_bytes = element.into_bigint().to_bytes_be();
+    canonical[32 - element_bytes.len()..].copy_from_slice(&element_bytes);
+
+    if canonical == *bytes { Some(element) } else { None }
+}
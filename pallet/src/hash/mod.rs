@@ -1,4 +1,50 @@
+use sp_std::vec;
+use ark_bn254::Fr;
+use ark_ff::{BigInteger, PrimeField};
+
+use crate::poll::HashBytes;
+
 pub mod parameters;
 pub mod poseidon;
 
 pub use poseidon::*;
+
+/// Computes the circom compatible Poseidon hash of two big-endian field elements.
+pub fn poseidon2(left: HashBytes, right: HashBytes) -> Option<HashBytes>
+{
+    poseidon_hash(&[ left, right ])
+}
+
+/// Computes the circom compatible Poseidon hash of three big-endian field elements.
+pub fn poseidon3(a: HashBytes, b: HashBytes, c: HashBytes) -> Option<HashBytes>
+{
+    poseidon_hash(&[ a, b, c ])
+}
+
+/// Computes the circom compatible Poseidon hash of four big-endian field elements.
+pub fn poseidon4(inputs: [HashBytes; 4]) -> Option<HashBytes>
+{
+    poseidon_hash(&inputs)
+}
+
+/// Computes the circom compatible Poseidon hash of five big-endian field elements.
+pub fn poseidon5(inputs: [HashBytes; 5]) -> Option<HashBytes>
+{
+    poseidon_hash(&inputs)
+}
+
+/// Reduces each input into the scalar field, hashes them, and returns the big-endian bytes of the digest.
+pub(crate) fn poseidon_hash(inputs: &[HashBytes]) -> Option<HashBytes>
+{
+    let mut hasher = Poseidon::<Fr>::new_circom(inputs.len()).ok()?;
+    let inputs: vec::Vec<Fr> = inputs
+        .iter()
+        .map(|bytes| Fr::from_be_bytes_mod_order(bytes))
+        .collect();
+
+    let bytes = hasher.hash(&inputs).ok()?.into_bigint().to_bytes_be();
+    let mut hash = [0u8; HASH_LEN];
+    hash[..bytes.len()].copy_from_slice(&bytes);
+
+    Some(hash)
+}
@@ -28,6 +28,9 @@ pub enum PoseidonError
     U64ToU8,
     BytesToBigInt,
     InvalidWidthCircom { width: usize, max_limit: usize },
+    /// There are no circom compatible parameters for the number of inputs. This indicates a
+    /// programming error, rather than a runtime condition.
+    UnsupportedArity { nr_inputs: usize, max_limit: usize },
 }
 
 /// Parameters for the Poseidon hash algorithm.
@@ -299,6 +302,12 @@ where
     Ok(element)
 }
 
+/// Returns true iff there are circom compatible parameters for hashing `nr_inputs` inputs.
+pub fn is_supported_circom_arity(nr_inputs: usize) -> bool
+{
+    nr_inputs > 0 && nr_inputs < MAX_X5_LEN
+}
+
 impl<F: PrimeField> Poseidon<F>
 {
     pub fn new_circom(nr_inputs: usize) -> Result<Poseidon<Fr>, PoseidonError>
@@ -311,14 +320,15 @@ impl<F: PrimeField> Poseidon<F>
         domain_tag: Fr,
     ) -> Result<Poseidon<Fr>, PoseidonError>
     {
-        let width = nr_inputs + 1;
-        if width > MAX_X5_LEN {
-            return Err(PoseidonError::InvalidWidthCircom {
-                width,
-                max_limit: MAX_X5_LEN,
+        if !is_supported_circom_arity(nr_inputs) {
+            return Err(PoseidonError::UnsupportedArity {
+                nr_inputs,
+                max_limit: MAX_X5_LEN - 1,
             });
         }
 
+        let width = nr_inputs + 1;
+
         let params = crate::hash::parameters::get_poseidon_parameters::<Fr>(
             width.try_into().map_err(|_| PoseidonError::U64ToU8)?,
         )?;
@@ -3,13 +3,18 @@
 pub use pallet::*;
 use sp_std::vec;
 use sp_runtime::traits::SaturatedConversion;
+#[cfg(any(feature = "try-runtime", test))]
+use sp_runtime::TryRuntimeError;
 
 use ark_bn254::{
     Bn254,
     Fr,
     G1Affine, 
+    G1Projective,
     G2Affine
 };
+use ark_ec::{pairing::Pairing, CurveGroup};
+use ark_ff::{BigInteger, Field, PrimeField};
 use ark_serialize::{CanonicalDeserialize};
 use ark_crypto_primitives::snark::SNARK;
 use ark_groth16::{
@@ -18,41 +23,84 @@ use ark_groth16::{
     data_structures::VerifyingKey
 };
 
+pub mod crypto;
 pub mod hash;
+pub mod migrations;
 pub mod poll;
+pub mod runtime_api;
+pub mod weights;
 
 pub use poll::*;
+pub use weights::WeightInfo;
+use poll::state::PollStateTree;
 
 #[cfg(test)]
 mod mock;
 
+#[cfg(test)]
+mod mock_instances;
+
 #[cfg(test)]
 mod tests;
 
-// #[cfg(feature = "runtime-benchmarks")]
-// pub mod benchmarking;
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
 
 #[frame_support::pallet]
 pub mod pallet 
 {
 	use super::*;
 	use frame_support::pallet_prelude::*;
+	use frame_support::dispatch::{DispatchErrorWithPostInfo, GetDispatchInfo, PostDispatchInfo};
+	use frame_support::weights::{WeightMeter, WeightToFee};
+	use codec::DecodeLimit;
 	use frame_system::pallet_prelude::*;
+	use frame_support::traits::{Currency, Imbalance, NamedReservableCurrency, OnUnbalanced, WithdrawReasons};
+	use sp_runtime::traits::{AtLeast32BitUnsigned, CheckedSub, Dispatchable, One, Zero};
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(11);
+
+	/// The maximum number of poll ids returned by a single call to `active_polls`.
+	pub const MAX_ACTIVE_POLLS_PAGE: u32 = 256;
+
+	/// The maximum number of poll ids examined by a single call to `active_polls`.
+	pub const MAX_ACTIVE_POLLS_SCAN: u32 = 1024;
+
+	/// The maximum number of poll ids returned by a single call to `get_coordinator_poll_ids_paginated`.
+	pub const MAX_COORDINATOR_POLL_IDS_PAGE: u32 = 256;
+
+	/// The maximum number of leaves returned by a single call to `registration_leaves`.
+	pub const MAX_REGISTRATION_LEAVES_PAGE: u32 = 256;
+
+	/// The maximum number of messages returned by a single call to `interaction_messages`.
+	pub const MAX_INTERACTION_MESSAGES_PAGE: u32 = 256;
 
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+	/// The maximum number of receipts returned by a single call to `participation_receipts`.
+	pub const MAX_PARTICIPATION_RECEIPTS_PAGE: u32 = 256;
+
+	/// The number of most recently created poll ids retained in `RecentPolls`.
+	pub const RECENT_POLLS_CAPACITY: u32 = 64;
+
+	/// The maximum nesting depth of the calls attached to vote options.
+	pub const MAX_OPTION_CALL_DEPTH: u32 = 64;
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
-	#[pallet::without_storage_info]
-	pub struct Pallet<T>(_);
+	pub struct Pallet<T, I = ()>(_);
 
 	#[pallet::config]
-	pub trait Config: frame_system::Config
+	pub trait Config<I: 'static = ()>: frame_system::Config
 	{
 		/// The overarching event type.
-		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		type RuntimeEvent: From<Event<Self, I>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
-		/// The maximum number of polls a given coordinator may create.
+		/// The type with which polls are indexed, e.g. `u32`, or `u64` for chains which expect more than `u32::MAX`
+		/// polls over their lifetime. Every poll keyed map is keyed by this type, so it may not be changed on a live
+		/// chain without migrating them.
+		type PollId: Parameter + Member + MaxEncodedLen + AtLeast32BitUnsigned + Copy + Default;
+
+		/// The maximum number of poll ids of a given coordinator retained in `CoordinatorPollIds`. Upon the creation of
+		/// a poll, the oldest poll ids beyond it are archived into `CoordinatorHistory`.
 		#[pallet::constant]
 		type MaxCoordinatorPolls: Get<u32>;
 
@@ -60,6 +108,40 @@ pub mod pallet
 		#[pallet::constant]
 		type MaxVoteOptions: Get<u32>;
 
+		/// The overarching call type, of which the calls attached to vote options are decoded.
+		type RuntimeCall: Parameter
+			+ Dispatchable<RuntimeOrigin = <Self as frame_system::Config>::RuntimeOrigin, PostInfo = PostDispatchInfo>
+			+ GetDispatchInfo
+			+ IsType<<Self as frame_system::Config>::RuntimeCall>;
+
+		/// The origin with which the call attached to the winning vote option of a poll is dispatched.
+		type OutcomeOrigin: Get<frame_system::RawOrigin<Self::AccountId>>;
+
+		/// The origin which may create polls that attach calls to their vote options, resolving to the account of the
+		/// coordinator, e.g. `EnsureSignedBy` a set of accounts approved by governance. The coordinator of a poll
+		/// supplies the keys against which its outcome is verified, and thereby picks the call dispatched with
+		/// `OutcomeOrigin`, such that it must be as trusted as `OutcomeOrigin` itself.
+		type OptionCallOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
+
+		/// The maximum encoded length of the call attached to a vote option.
+		#[pallet::constant]
+		type MaxOptionCallLen: Get<u32>;
+
+		/// The maximum weight of the call attached to a vote option, which is charged to the call that verifies
+		/// the outcome of the poll.
+		#[pallet::constant]
+		type MaxOptionCallWeight: Get<Weight>;
+
+		/// The maximum weight of the poll transitions applied in `on_initialize`. Transitions beyond it are deferred
+		/// to the following blocks, in order.
+		#[pallet::constant]
+		type MaxInitializeWeight: Get<Weight>;
+
+		/// The maximum number of keys of the per-poll data of fulfilled polls which are removed per block. The data
+		/// is pruned in `on_idle`, within the weight which remains in the block.
+		#[pallet::constant]
+		type MaxPruneKeys: Get<u32>;
+
 		/// The maximum allowable number of registrations.
 		#[pallet::constant]
 		type MaxPollRegistrations: Get<u32>;
@@ -67,11 +149,124 @@ pub mod pallet
 		/// The maximum allowable number of poll interactions.
 		#[pallet::constant]
 		type MaxPollInteractions: Get<u32>;
+
+		/// The arity of the registration tree of newly created polls.
+		#[pallet::constant]
+		type RegistrationTreeArity: Get<u32>;
+
+		/// The arity of the interaction tree of newly created polls.
+		#[pallet::constant]
+		type InteractionTreeArity: Get<u32>;
+
+		/// Whether interaction data is deposited in full with the `PollInteraction` event. If false,
+		/// the data is instead written to `Messages` and only its hash is deposited.
+		#[pallet::constant]
+		type EmitFullInteractionData: Get<bool>;
+
+		/// Whether the registration leaves of each poll are written to `RegistrationLeaves`, such that a
+		/// coordinator may rebuild the registration tree from current state rather than from past events.
+		#[pallet::constant]
+		type StoreRegistrationLeaves: Get<bool>;
+
+		/// The number of blocks after its creation within which a pending poll must be activated by its
+		/// coordinator, after which anyone may reap it.
+		#[pallet::constant]
+		type ActivationTimeout: Get<BlockNumber>;
+
+		/// The number of proofs of a poll which may fail to verify before further proofs are throttled.
+		#[pallet::constant]
+		type MaxProofFailures: Get<u32>;
+
+		/// The number of blocks which must pass after a failed proof before another proof may be submitted, once a
+		/// poll exceeded `MaxProofFailures`.
+		#[pallet::constant]
+		type ProofFailureCooldown: Get<BlockNumber>;
+
+		/// The number of blocks which must pass after a coordinator created a poll before they may create another.
+		/// Zero permits a coordinator to create a poll as soon as their previous poll is finished.
+		#[pallet::constant]
+		type PollCreationCooldown: Get<BlockNumber>;
+
+		/// The maximum number of interactions which may be submitted in a single batch.
+		#[pallet::constant]
+		type MaxInteractionsPerCall: Get<u32>;
+
+		/// The maximum number of proofs which may be submitted in a single call to `commit_outcome` or
+		/// `commit_tally_proofs`.
+		#[pallet::constant]
+		type MaxProofsPerCall: Get<u32>;
+
+		/// The maximum number of poll ids held by each of the keeper queues `NeedsMerge`, `NeedsNullificationCheck`
+		/// and `Reapable`. Poll ids enqueued beyond it are held in `KeeperBacklog` until the queue has room.
+		#[pallet::constant]
+		type MaxKeeperQueueLen: Get<u32>;
+
+		/// The maximum number of polls whose voting period ends at the same block, such that sealing them is bounded.
+		/// Creating or activating a poll which would close at a full block is rejected.
+		#[pallet::constant]
+		type MaxPollClosuresPerBlock: Get<u32>;
+
+		/// The maximum number of polls whose voting period commences at the same block, and likewise of pending polls
+		/// whose activation timeout elapses at the same block, such that announcing or expiring them is bounded.
+		/// Creating or activating a poll which would be scheduled at a full block is rejected.
+		#[pallet::constant]
+		type MaxScheduledPollsPerBlock: Get<u32>;
+
+		/// The maximum number of registrations which a single account may submit to a poll, e.g. as a relayer,
+		/// i.e. the bound of each of its `RegistrationReceipts`.
+		#[pallet::constant]
+		type MaxRegistrationsPerAccount: Get<u32>;
+
+		/// The number of blocks after `begin_outcome` within which a staged outcome must be finalized, after which
+		/// it is abandoned and must be staged anew.
+		#[pallet::constant]
+		type OutcomeStagingTimeout: Get<BlockNumber>;
+
+		/// The currency in which fee allowances are reserved.
+		type Currency: NamedReservableCurrency<Self::AccountId, ReserveIdentifier = [u8; 8]>;
+
+		/// The named reserve in which coordinators hold the fee allowances of their polls, apart from their other
+		/// reserves. The currency must permit at least one named reserve per account.
+		#[pallet::constant]
+		type FeeAllowanceReserveId: Get<[u8; 8]>;
+
+		/// The base fee drawn from the fee allowance of a poll for each registration or interaction, i.e. the
+		/// inclusion and length fee of the call, on top of the fee for its actual weight.
+		#[pallet::constant]
+		type ParticipantFee: Get<BalanceOf<Self, I>>;
+
+		/// Converts the actual weight of a registration or interaction into the fee drawn for it from the fee
+		/// allowance of its poll, e.g. the `WeightToFee` of the transaction payment pallet.
+		type WeightToFee: WeightToFee<Balance = BalanceOf<Self, I>>;
+
+		/// The number of registrations and interactions of each account in a poll whose fees are drawn from the fee
+		/// allowance of the poll. Further calls of the account are paid by the account, such that the allowance
+		/// cannot be spent on filling the poll from a single account.
+		#[pallet::constant]
+		type MaxSponsoredCalls: Get<u32>;
+
+		/// The handler for the fees drawn from fee allowances, e.g. the same handler used by the transaction payment pallet.
+		type OnFeeAllowanceCharged: OnUnbalanced<NegativeImbalanceOf<Self, I>>;
+
+		/// The destination of fee allowances forfeited by coordinators, e.g. a treasury or a rewards pot. Use `()` to
+		/// burn them.
+		type SlashDestination: OnUnbalanced<NegativeImbalanceOf<Self, I>>;
+
+		/// The handler for polls whose outcome was verified, e.g. to act on the winning vote option. Use `()` to
+		/// ignore finalized polls.
+		type OnPollFinalized: OnPollFinalized<Self::PollId>;
+
+		/// The origin which may register and remove the standard circuits of `CircuitRegistry`, e.g. root or a
+		/// governance collective.
+		type CircuitOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Weight information for the extrinsics of this pallet.
+		type WeightInfo: WeightInfo;
 	}
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
-	pub enum Event<T: Config> 
+	pub enum Event<T: Config<I>, I: 'static = ()>
 	{
 		/// A new coordinator was registered.
 		CoordinatorRegistered {
@@ -93,10 +288,25 @@ pub mod pallet
 			verify_key: VerifyingKeys
 		},
 
+		/// A coordinator rotated their verifying keys while one of their polls was unfinished. The poll continues to
+		/// be proven against its snapshotted keys.
+		CoordinatorVerifyKeyRotated {
+			/// The index of the unfinished poll.
+			poll_id: T::PollId,
+			/// The coordinator.
+			coordinator: T::AccountId,
+			/// The Poseidon hash of the previous verifying keys.
+			old_key_hash: HashBytes,
+			/// The Poseidon hash of the new verifying keys.
+			new_key_hash: HashBytes
+		},
+
 		/// A participant registered to vote in a poll.
 		ParticipantRegistered { 
 			/// The index of the poll registered in.
-			poll_id: PollId,
+			poll_id: T::PollId,
+			/// The account which submitted the registration, e.g. a relayer.
+			who: T::AccountId,
 			/// The current registration count.
 			count: u32,
 			/// The block number of the registration.
@@ -108,39 +318,126 @@ pub mod pallet
 		/// A new poll was created.
 		PollCreated {
 			/// The poll index.
-			poll_id: PollId,
+			poll_id: T::PollId,
 			/// The poll coordinator.
 			coordinator: T::AccountId,
+			/// The public key of the coordinator, with which participants encrypt their interactions.
+			coordinator_public_key: PublicKey,
+			/// The block number the poll signup period ends and voting commences. Provisional if the poll is pending.
+			starts_at: BlockNumber,
+			/// The block number the voting period commences. Provisional if the poll is pending.
+			ends_at: BlockNumber,
+			/// The maximum number of participants permitted.
+			max_registrations: u32,
+			/// The maximum number of interactions permitted.
+			max_interactions: u32,
+			/// The number of vote options of the poll.
+			vote_option_count: u32,
+			/// The amount reserved from the coordinator in order to pay the fees of participants.
+			fee_allowance: BalanceOf<T, I>,
+			/// The Poseidon commitment to every parameter of the poll configuration, as computed by
+			/// `PollConfigOf::config_hash`, against which participants may check the advertised configuration.
+			config_hash: HashBytes
+		},
+
+		/// A pending poll was activated by its coordinator, and its signup period commenced.
+		PollActivated {
+			/// The poll index.
+			poll_id: T::PollId,
 			/// The block number the poll signup period ends and voting commences.
 			starts_at: BlockNumber,
 			/// The block number the voting period commences.
 			ends_at: BlockNumber
 		},
 
+		/// The signup period of a poll ended and its voting period commenced, i.e. the poll accepts interactions.
+		PollVotingPeriodStarted {
+			/// The poll index.
+			poll_id: T::PollId,
+			/// The last block of the voting period.
+			voting_period_end: BlockNumber
+		},
+
+		/// The voting period of a poll ended.
+		PollClosed {
+			/// The poll index.
+			poll_id: T::PollId,
+			/// The number of participant registrations.
+			registrations: u32,
+			/// The number of poll interactions.
+			interactions: u32
+		},
+
 		/// Poll was interacted with.
 		PollInteraction {
 			/// The index of the poll interacted with.
-			poll_id: PollId,
+			poll_id: T::PollId,
 			/// The current interaction count.
 			count: u32,
 			/// Ephemeral public key used to encrypt the message.
 			public_key: PublicKey,
+			/// The interaction tree leaf computed from the public key and interaction data.
+			leaf: HashBytes,
 			/// Interaction data.
 			data: PollInteractionData
 		},
 
+		/// Poll was interacted with, and the interaction data was written to storage.
+		PollInteractionStored {
+			/// The index of the poll interacted with.
+			poll_id: T::PollId,
+			/// The index of the interaction leaf.
+			index: u32,
+			/// Ephemeral public key used to encrypt the message.
+			public_key: PublicKey,
+			/// The interaction tree leaf computed from the public key and interaction data.
+			leaf: HashBytes,
+			/// The blake2 hash of the public key and interaction data.
+			hash: HashBytes
+		},
+
 		/// Poll state was partially processed.
 		PollCommitmentUpdated {
 			/// The poll index.
-			poll_id: PollId,
+			poll_id: T::PollId,
 			/// The new commitment value.
 			commitment: Commitment
 		},
 
+		/// A proof failed to verify. The proofs preceding it in the same call were retained.
+		ProofRejected {
+			/// The poll index.
+			poll_id: T::PollId,
+			/// The circuit which the proof was verified against.
+			kind: ProofKind,
+			/// The number of proofs of the poll which failed to verify, including this one.
+			attempt: u32
+		},
+
+		/// A message processing proof was verified.
+		ProcessProgress {
+			/// The poll index.
+			poll_id: T::PollId,
+			/// The number of verified message processing proofs.
+			batches_done: CommitmentIndex,
+			/// The expected number of message processing proofs.
+			batches_total: CommitmentIndex
+		},
+
+		/// A tally proof was verified.
+		TallyProgress {
+			/// The poll index.
+			poll_id: T::PollId,
+			/// The number of verified tally proofs.
+			batches_done: CommitmentIndex,
+			/// The expected number of tally proofs.
+			batches_total: CommitmentIndex
+		},
+
 		/// Poll state tree root was computed. 
 		PollStateMerged {
 			/// The poll index.
-			poll_id: PollId,
+			poll_id: T::PollId,
 			/// The poll registrations tree root.
 			registration_root: Option<HashBytes>,
 			/// The poll interactions tree root.
@@ -150,20 +447,132 @@ pub mod pallet
 		/// Poll result was verified.
 		PollOutcome {
 			/// The poll index.
-			poll_id: PollId,
+			poll_id: T::PollId,
+			/// The outcome index of the poll, i.e. the vote option with the largest tally. Ties are won by
+			/// the lowest option index, and a poll without votes concludes with `NO_VOTES`.
+			outcome_index: u32,
+			/// The weight consumed by every successful extrinsic scoped to the poll, including the one which
+			/// finalized it.
+			weight_consumed: Weight
+		},
+
+		/// The call attached to the winning vote option of a poll was dispatched.
+		OutcomeExecuted {
+			/// The poll index.
+			poll_id: T::PollId,
+			/// The outcome index of the poll.
+			outcome_index: OutcomeIndex
+		},
+
+		/// The call attached to the winning vote option of a poll failed to decode or dispatch. Its effects were
+		/// reverted, while the outcome of the poll stands.
+		OutcomeExecutionFailed {
+			/// The poll index.
+			poll_id: T::PollId,
 			/// The outcome index of the poll.
-			outcome_index: u32
+			outcome_index: OutcomeIndex,
+			/// The error with which the call failed.
+			error: DispatchError
 		},
 
 		/// Empty and expired poll was nullified.
 		PollNullified {
 			/// The poll index.
-			poll_id: PollId
+			poll_id: T::PollId
+		},
+
+		/// Pending poll which was not activated before the activation timeout was nullified.
+		PollReaped {
+			/// The poll index.
+			poll_id: T::PollId
+		},
+
+		/// The coordinator replaced the verifying keys against which the proofs of a poll are verified.
+		PollVerifyKeyReplaced {
+			/// The poll index.
+			poll_id: T::PollId,
+			/// The new verifying keys.
+			verify_key: VerifyingKeys
+		},
+
+		/// A batch of interactions was submitted to a poll.
+		InteractionsBatchSubmitted {
+			/// The poll index.
+			poll_id: T::PollId,
+			/// The index of the first interaction of the batch.
+			first_index: u32,
+			/// The number of interactions in the batch.
+			count: u32
+		},
+
+		/// The roots of both poll state trees were computed, i.e. the poll is ready for proving.
+		PollMergeCompleted {
+			/// The poll index.
+			poll_id: T::PollId,
+			/// The poll registrations tree root.
+			registration_root: HashBytes,
+			/// The poll interactions tree root.
+			interaction_root: HashBytes,
+			/// The hash of the coordinator public key which the message processing circuit takes as a public
+			/// input, such that the coordinator may compare it against their own before proving.
+			coord_pub_key_hash: HashBytes
+		},
+
+		/// A participant signalled their non-binding intent to vote in a poll.
+		IntentSignaled {
+			/// The poll index.
+			poll_id: T::PollId,
+			/// The participant.
+			who: T::AccountId,
+			/// The number of intents signalled in the poll.
+			count: u32
+		},
+
+		/// The coordinator began staging the outcome of a poll across multiple extrinsics.
+		OutcomeStagingBegun {
+			/// The poll index.
+			poll_id: T::PollId,
+			/// The block after which the staged outcome is abandoned.
+			expires_at: BlockNumber
+		},
+
+		/// A chunk of the tally results of a staged outcome was verified.
+		OutcomeChunkAccepted {
+			/// The poll index.
+			poll_id: T::PollId,
+			/// The number of tally results verified so far.
+			options_done: u32,
+			/// The number of vote options of the poll.
+			options_total: u32
+		},
+
+		/// The remaining fee allowance of a poll was forfeited by its coordinator, and routed to `SlashDestination`.
+		FeeAllowanceForfeited {
+			/// The poll index.
+			poll_id: T::PollId,
+			/// The coordinator of the poll.
+			coordinator: T::AccountId,
+			/// The forfeited amount.
+			amount: BalanceOf<T, I>
+		},
+
+		/// The verifying keys of a standard circuit were registered.
+		CircuitRegistered {
+			/// The circuit id.
+			circuit_id: CircuitId,
+			/// The verifying keys of the message processing and tally circuits.
+			verify_key: VerifyingKeys
+		},
+
+		/// A standard circuit was removed from the registry.
+		CircuitRemoved {
+			/// The circuit id.
+			circuit_id: CircuitId
 		}
 	}
 
 	#[pallet::error]
-	pub enum Error<T>
+	pub enum Error<T, I = ()>
 	{
 		/// Coordinator is already registered.
 		CoordinatorAlreadyRegistered,
@@ -171,7 +580,8 @@ pub mod pallet
 		/// Coordinator role not found.
 		CoordinatorNotRegistered,
 
-		/// Coordinator poll limit reached.
+		/// Coordinator poll limit reached. No longer returned, as a coordinator may only have a single unfinished
+		/// poll; retained such that the indices of the subsequent errors are unchanged.
 		CoordinatorPollLimitReached,
 
 		/// Maximum number of participants have registered.
@@ -210,13 +620,14 @@ pub mod pallet
 		/// Poll state trees have not yet been merged.
 		PollStateNotMerged,
 
-		/// Poll state tree merge operation failed.
+		/// Poll state tree merge operation failed. No longer returned, as the failures of the state trees map onto
+		/// dedicated errors; retained such that the indices of the subsequent errors are unchanged.
 		PollMergeFailed { reason: u8 },
 
-		/// Poll registration failed.
+		/// Poll registration failed. No longer returned, as `PollMergeFailed`.
 		PollRegistrationFailed { reason: u8 },
 
-		/// Poll interaction failed.
+		/// Poll interaction failed. No longer returned, as `PollMergeFailed`.
 		PollInteractionFailed { reason: u8 },
 
 		/// The key(s) provided are malformed.
@@ -226,558 +637,3839 @@ pub mod pallet
 		MalformedProof,
 
 		/// The extrinsic arguments are insufficient.
-		MalformedInput
+		MalformedInput,
+
+		/// The signature does not prove possession of the private key of the public key.
+		ProofOfPossessionInvalid,
+
+		/// The public key is not a point in the prime order subgroup of the curve.
+		PublicKeyNotInSubgroup,
+
+		/// The commitment is not an element of the BN254 scalar field.
+		InvalidCommitment,
+
+		/// The fee allowance of the poll is insufficient to pay for the call.
+		FeeAllowanceExhausted,
+
+		/// Reserving the amount would reduce the free balance below the existential deposit.
+		InsufficientFreeBalance,
+
+		/// The interaction was encrypted with the public key of the coordinator.
+		InteractionKeyIsCoordinatorKey,
+
+		/// The interaction data does not consist of the number of words expected by the poll.
+		MessageLengthMismatch,
+
+		/// Poll awaits activation by its coordinator.
+		PollPending,
+
+		/// Poll is not pending, e.g. it was already activated or reaped.
+		PollNotPending,
+
+		/// The caller is not the coordinator of the poll.
+		NotPollCoordinator,
+
+		/// The activation timeout of the pending poll has not yet elapsed.
+		PollActivationNotExpired,
+
+		/// A proof of the poll was already verified.
+		ProvingAlreadyStarted,
+
+		/// The voting period of the poll has not yet been closed, such that its deadline may still change.
+		PollNotSealed,
+
+		/// A verifying key does not have the size expected for the public inputs of its circuit.
+		VerifyKeySizeMismatch,
+
+		/// The poll does not collect intents to vote.
+		PollIntentsNotCollected,
+
+		/// The caller has already signalled their intent to vote in the poll.
+		IntentAlreadySignaled,
+
+		/// The submitted proofs were already verified with identical commitments. No fee is charged.
+		ProofAlreadyAccepted,
+
+		/// The submitted proofs do not start at the next proof of the poll.
+		ProofIndexMismatch,
+
+		/// A submitted proof was already verified with a different commitment.
+		ConflictingProofCommitment,
+
+		/// The poll exceeded `MaxProofFailures`, and `ProofFailureCooldown` has not passed since its last failure.
+		ProofCooldownActive,
+
+		/// A call attached to a vote option does not decode, or names an unknown or duplicate option.
+		OptionCallInvalid,
+
+		/// A call attached to a vote option exceeds `MaxOptionCallWeight`.
+		OptionCallTooHeavy,
+
+		/// A public key was registered twice in a poll created with `unique_keys`.
+		KeyAlreadyRegistered,
+
+		/// The state tree to be merged, or inserted into, already has a root.
+		StateTreeAlreadyMerged,
+
+		/// A hash failed while inserting into or merging a state tree, which is not expected to succeed upon retrying.
+		StateTreeHashFailed,
+
+		/// The stored frontier of the state tree to be merged does not describe a tree of its arity and depth.
+		StateTreeMalformed,
+
+		/// The state tree holds as many leaves as its depth permits.
+		StateTreeFull,
+
+		/// Merging a state tree did not produce its root.
+		StateTreeMergeFailed,
+
+		/// `PollCreationCooldown` has not passed since the coordinator last created a poll.
+		CreationCooldownActive,
+
+		/// The number of interactions processed, or registrations tallied, per proof is zero or does not fit in a `u32`.
+		InvalidBatchSize,
+
+		/// Every id of the `PollId` type of the runtime was assigned to a poll.
+		PollIdOverflow,
+
+		/// The public key of the coordinator which the poll circuits are parameterized with has a coordinate which
+		/// is not a canonical field element, e.g. as it was registered before keys were validated.
+		CoordinatorKeyMalformed,
+
+		/// The poll has no staged outcome, or its staged outcome was abandoned.
+		OutcomeNotStaged,
+
+		/// The staged outcome was not finalized within `OutcomeStagingTimeout` blocks.
+		OutcomeStagingExpired,
+
+		/// A tally result of the chunk does not follow the staged results, or does not match the tally commitment.
+		InvalidOutcomeChunk,
+
+		/// The tally result of some vote option has not been staged.
+		OutcomeIncomplete,
+
+		/// The staged total number of votes cast does not match the tally commitment.
+		OutcomeVerificationFailed,
+
+		/// Not every proof of the poll has been verified.
+		PollNotProven,
+
+		/// Tally proofs were submitted before every process proof of the poll was verified.
+		ProcessProofsIncomplete,
+
+		/// The circuit is not registered in `CircuitRegistry`.
+		CircuitNotFound,
+
+		/// A circuit is already registered under the circuit id.
+		CircuitAlreadyRegistered,
+
+		/// The account has submitted `MaxRegistrationsPerAccount` registrations to the poll.
+		TooManyRegistrations,
+
+		/// The voting period of the poll would end at a block at which `MaxPollClosuresPerBlock` polls already close.
+		PollClosuresFull,
+
+		/// The circuit is referenced by an unfinished poll.
+		CircuitInUse,
+
+		/// The poll was already nullified.
+		PollAlreadyNullified,
+
+		/// The voting period of the poll would commence, or its activation timeout elapse, at a block at which
+		/// `MaxScheduledPollsPerBlock` polls already do.
+		PollScheduleFull
+	}
+
+	impl<T, I> From<MerkleTreeError> for Error<T, I>
+	{
+		fn from(error: MerkleTreeError) -> Self
+		{
+			match error
+			{
+				MerkleTreeError::TreeAlreadyFull => Error::<T, I>::StateTreeFull,
+				MerkleTreeError::TreeAlreadyMerged => Error::<T, I>::StateTreeAlreadyMerged,
+				MerkleTreeError::HashFailed => Error::<T, I>::StateTreeHashFailed,
+				MerkleTreeError::MergeFailed => Error::<T, I>::StateTreeMergeFailed,
+				MerkleTreeError::MalformedFrontier => Error::<T, I>::StateTreeMalformed,
+				MerkleTreeError::InvalidBatchSize => Error::<T, I>::InvalidBatchSize
+			}
+		}
 	}
 
-	/// Map of ids to polls.
+	/// Map of ids to polls. A stored poll embeds only the shape of its state trees, which are stored in
+	/// `PollRegistrationTree` and `PollInteractionTree`, such that the poll is only rewritten when its metadata
+	/// changes; use `polls` or `get_poll` to read the poll alongside its trees.
 	#[pallet::storage]
-	#[pallet::getter(fn polls)]
-	pub type Polls<T: Config> = CountedStorageMap<
+	pub type Polls<T: Config<I>, I: 'static = ()> = CountedStorageMap<
 		_,
 		Twox64Concat,
-		PollId,
-		Poll<T>
+		T::PollId,
+		Poll<T, I>
+	>;
+
+	/// The id of the next poll to be created. Polls are never removed, so this equals the number of polls, but is
+	/// not bounded by the `u32` counter of `Polls`.
+	#[pallet::storage]
+	#[pallet::getter(fn next_poll_id)]
+	pub type NextPollId<T: Config<I>, I: 'static = ()> = StorageValue<
+		_,
+		T::PollId,
+		ValueQuery
 	>;
 
 	/// Map of coordinators to their keys.
 	#[pallet::storage]
 	#[pallet::getter(fn coordinators)]
-	pub type Coordinators<T: Config> = CountedStorageMap<
+	pub type Coordinators<T: Config<I>, I: 'static = ()> = CountedStorageMap<
 		_, 
 		Blake2_128Concat, 
 		T::AccountId,
-		Coordinator
+		Coordinator<T::PollId>
 	>;
 
-	/// Map of coordinators to the poll Ids they manage.
+	/// Map of coordinators to the poll Ids they manage, sorted in ascending order. The order is not a recency
+	/// signal beyond that of the ids themselves; consumers must consult the polls for their state.
 	#[pallet::storage]
 	#[pallet::getter(fn poll_ids)]
-	pub type CoordinatorPollIds<T: Config> = StorageMap<
+	#[pallet::unbounded]
+	pub type CoordinatorPollIds<T: Config<I>, I: 'static = ()> = StorageMap<
 		_,
 		Blake2_128Concat,
 		T::AccountId,
-		vec::Vec<PollId>,
+		vec::Vec<T::PollId>,
 		ValueQuery
 	>;
 
-	#[pallet::call]
-	impl<T: Config> Pallet<T> 
-	{
-		/// Register the caller as a coordinator, granting the ability to create polls.
-		///
-		/// - `public_key`: The public key of the coordinator.
-		/// - `verify_key`: The verification key of the coordinator.
-		///
-		/// Emits `CoordinatorRegistered`.
-		#[pallet::call_index(0)]
-		#[pallet::weight(T::DbWeight::get().reads_writes(1, 1))]
-		pub fn register_as_coordinator(
-			origin: OriginFor<T>,
-			public_key: PublicKey,
-			verify_key: VerifyingKeys
-		) -> DispatchResult
-		{
-			// Check that the extrinsic was signed and get the signer.
-			let sender = ensure_signed(origin)?;
+	/// Map of coordinators to finished poll ids which were archived from `CoordinatorPollIds` once they exceeded
+	/// `MaxCoordinatorPolls`, sorted in ascending order.
+	#[pallet::storage]
+	#[pallet::getter(fn poll_history)]
+	#[pallet::unbounded]
+	pub type CoordinatorHistory<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		vec::Vec<T::PollId>,
+		ValueQuery
+	>;
 
-			// Ensure the verification keys can be serialized as affine points.
-			ensure!(serialize_vkey(verify_key.process.clone()).is_some(), Error::<T>::MalformedKeys);
-			ensure!(serialize_vkey(verify_key.tally.clone()).is_some(), Error::<T>::MalformedKeys);
+	/// The ids of the most recently created polls, oldest first. Once full, the oldest id is evicted whenever a
+	/// poll is created, such that front-ends may list recent polls without iterating `Polls`.
+	#[pallet::storage]
+	#[pallet::getter(fn recent_polls)]
+	pub type RecentPolls<T: Config<I>, I: 'static = ()> = StorageValue<
+		_,
+		BoundedVec<T::PollId, ConstU32<RECENT_POLLS_CAPACITY>>,
+		ValueQuery
+	>;
 
-			// A coordinator may only be registered once.
-			ensure!(
-				!Coordinators::<T>::contains_key(&sender), 
-				Error::<T>::CoordinatorAlreadyRegistered
-			);
+	/// The ids of the polls of which either state tree may be merged, oldest first, such that keepers need not scan
+	/// `Polls` to find work. Drained by `merge_poll_state` once neither tree may be merged.
+	#[pallet::storage]
+	#[pallet::getter(fn needs_merge)]
+	pub type NeedsMerge<T: Config<I>, I: 'static = ()> = StorageValue<
+		_,
+		BoundedVec<T::PollId, T::MaxKeeperQueueLen>,
+		ValueQuery
+	>;
 
-			// Store the coordinator keys.
-			Coordinators::<T>::insert(&sender, Coordinator {
-				last_poll: None,
-				public_key,
-				verify_key: verify_key.clone()
-			});
+	/// The ids of the polls which ended their signup period without registrations, or their voting period without
+	/// interactions, oldest first. Drained by `nullify_poll`, or once the poll is otherwise concluded.
+	#[pallet::storage]
+	#[pallet::getter(fn needs_nullification_check)]
+	pub type NeedsNullificationCheck<T: Config<I>, I: 'static = ()> = StorageValue<
+		_,
+		BoundedVec<T::PollId, T::MaxKeeperQueueLen>,
+		ValueQuery
+	>;
 
-			// Emit a registration event.
-			Self::deposit_event(Event::CoordinatorRegistered {
-				who: sender,
-				public_key,
-				verify_key
-			});
+	/// The ids of the pending polls whose activation timeout elapsed, oldest first. Drained by `reap_poll`, or by
+	/// `activate_poll` should the coordinator activate the poll before it is reaped.
+	#[pallet::storage]
+	#[pallet::getter(fn reapable)]
+	pub type Reapable<T: Config<I>, I: 'static = ()> = StorageValue<
+		_,
+		BoundedVec<T::PollId, T::MaxKeeperQueueLen>,
+		ValueQuery
+	>;
 
-			Ok(())
-		}
+	/// Set of the poll ids which were enqueued for a keeper action while its queue was full. An entry is moved into
+	/// the queue whenever an entry of the queue is drained.
+	#[pallet::storage]
+	#[pallet::getter(fn keeper_backlog)]
+	pub type KeeperBacklog<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		KeeperAction,
+		Twox64Concat,
+		T::PollId,
+		(),
+		OptionQuery
+	>;
 
-		/// Permits a coordinator to rotate their public and verification keys.
-		/// Rejected if an extant poll is ongoing or awaiting processing.
-		///
+	/// Map of poll ids to the tally results and commitments of the outcome which was verified when the poll was
+	/// fulfilled. The vote option tree paths of the tally results are not retained.
+	#[pallet::storage]
+	#[pallet::getter(fn poll_outcomes)]
+	pub type PollOutcomes<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		T::PollId,
+		OutcomeRecord<T, I>
+	>;
+
+	/// Map of poll ids to the public key of the coordinator at the time the poll was created, i.e. the key
+	/// with which the poll circuits are parameterized regardless of later key rotations.
+	#[pallet::storage]
+	#[pallet::getter(fn poll_coordinator_pub_key)]
+	pub type PollCoordinatorPubKey<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		T::PollId,
+		PublicKey
+	>;
+
+	/// Map of poll ids to the verifying keys of the coordinator at the time the poll was created, against which
+	/// the proofs of the poll are verified regardless of later key rotations.
+	#[pallet::storage]
+	#[pallet::getter(fn poll_verify_key)]
+	pub type PollVerifyKey<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		T::PollId,
+		VerifyingKeys
+	>;
+
+	/// Map of circuit ids to the verifying keys of standard circuits, populated by `CircuitOrigin`, such that polls
+	/// may reference the keys of a circuit rather than each storing a copy of the keys of their coordinator.
+	#[pallet::storage]
+	#[pallet::getter(fn circuit)]
+	pub type CircuitRegistry<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		CircuitId,
+		VerifyingKeys
+	>;
+
+	/// Map of circuit ids to the number of unfinished polls which reference the circuit, which may not be removed
+	/// while any does. A poll releases its reference once it is fulfilled, or its verifying keys are replaced.
+	#[pallet::storage]
+	#[pallet::getter(fn circuit_references)]
+	pub type CircuitReferences<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		CircuitId,
+		u32,
+		ValueQuery
+	>;
+
+	/// Map of poll ids to the circuit whose registered verifying keys the proofs of the poll are verified against.
+	/// Polls which reference a circuit have no `PollVerifyKey`. Removed once the poll is finished.
+	#[pallet::storage]
+	#[pallet::getter(fn poll_circuit)]
+	pub type PollCircuit<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		T::PollId,
+		CircuitId
+	>;
+
+	/// Map of poll ids to the convention with which the public key of the coordinator is hashed into the public
+	/// inputs of the message processing circuit. Polls created before the convention was configurable use
+	/// `Poseidon2Coords`.
+	#[pallet::storage]
+	#[pallet::getter(fn poll_coord_key_hash)]
+	pub type PollCoordKeyHash<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		T::PollId,
+		CoordKeyHash,
+		ValueQuery
+	>;
+
+	/// Map of poll ids to the remaining fee allowance reserved from the coordinator. Only populated for polls
+	/// whose participants do not pay their own fees, and returned to the coordinator once the poll is fulfilled.
+	#[pallet::storage]
+	#[pallet::getter(fn fee_allowances)]
+	pub type FeeAllowances<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		T::PollId,
+		BalanceOf<T, I>
+	>;
+
+	/// Map of (poll id, account) to the number of calls of the account whose fees were drawn from the fee allowance
+	/// of the poll, at most `MaxSponsoredCalls`. Pruned in `on_idle` once the poll is fulfilled.
+	#[pallet::storage]
+	#[pallet::getter(fn sponsored_calls)]
+	pub type SponsoredCalls<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::PollId,
+		Blake2_128Concat,
+		T::AccountId,
+		u32,
+		ValueQuery
+	>;
+
+	/// Map of poll ids to the arity of their registration tree.
+	#[pallet::storage]
+	#[pallet::getter(fn poll_registration_tree_arity)]
+	pub type PollRegistrationTreeArity<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		T::PollId,
+		u32
+	>;
+
+	/// Map of poll ids to the arity of their interaction tree.
+	#[pallet::storage]
+	#[pallet::getter(fn poll_interaction_tree_arity)]
+	pub type PollInteractionTreeArity<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		T::PollId,
+		u32
+	>;
+
+	/// Map of poll ids to the depth of their registration tree, such that circuit inputs may be constructed
+	/// without decoding the poll.
+	#[pallet::storage]
+	#[pallet::getter(fn poll_registration_tree_depth)]
+	pub type PollRegistrationTreeDepth<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		T::PollId,
+		u8
+	>;
+
+	/// Map of poll ids to the depth of their interaction tree, such that circuit inputs may be constructed
+	/// without decoding the poll.
+	#[pallet::storage]
+	#[pallet::getter(fn poll_interaction_tree_depth)]
+	pub type PollInteractionTreeDepth<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		T::PollId,
+		u8
+	>;
+
+	/// Map of poll ids to their number of vote options, such that it may be read without decoding the poll. Written
+	/// once upon creation, as the vote options of a poll are immutable.
+	#[pallet::storage]
+	#[pallet::getter(fn poll_vote_option_count)]
+	pub type PollVoteOptionCount<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		T::PollId,
+		u32
+	>;
+
+	/// Map of poll ids to the block at which their registration period ends, i.e. the first block at which
+	/// registrations are rejected, such that eligibility to register may be checked with a single read. Updated
+	/// when a pending poll is activated.
+	#[pallet::storage]
+	#[pallet::getter(fn poll_registration_period_end)]
+	pub type PollRegistrationPeriodEnd<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		T::PollId,
+		BlockNumber
+	>;
+
+	/// Map of poll ids to the last block of their voting period, such that whether a poll is over may be
+	/// determined without recomputing its periods. Updated when a pending poll is activated.
+	#[pallet::storage]
+	#[pallet::getter(fn poll_voting_period_end)]
+	pub type PollVotingPeriodEnd<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		T::PollId,
+		BlockNumber
+	>;
+
+	/// Map of block numbers to the ids of polls whose voting period ends at that block, of at most
+	/// `MaxPollClosuresPerBlock` ids.
+	#[pallet::storage]
+	#[pallet::getter(fn poll_closures)]
+	pub type PollClosures<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumber,
+		BoundedVec<T::PollId, T::MaxPollClosuresPerBlock>,
+		ValueQuery
+	>;
+
+	/// Map of block numbers to the ids of polls whose voting period commences at that block, i.e. the first block
+	/// after their signup period, of at most `MaxScheduledPollsPerBlock` ids.
+	#[pallet::storage]
+	#[pallet::getter(fn poll_voting_starts)]
+	pub type PollVotingStarts<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumber,
+		BoundedVec<T::PollId, T::MaxScheduledPollsPerBlock>,
+		ValueQuery
+	>;
+
+	/// Map of block numbers to the ids of pending polls whose activation timeout elapses at that block, after which
+	/// they are enqueued in `Reapable` unless they were activated, of at most `MaxScheduledPollsPerBlock` ids.
+	#[pallet::storage]
+	#[pallet::getter(fn poll_activation_expiries)]
+	pub type PollActivationExpiries<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumber,
+		BoundedVec<T::PollId, T::MaxScheduledPollsPerBlock>,
+		ValueQuery
+	>;
+
+	/// The earliest block whose scheduled poll transitions were deferred by `on_initialize` for want of weight, if
+	/// any. The transitions of every block from it onwards are applied before those of the current block.
+	#[pallet::storage]
+	#[pallet::getter(fn transitions_deferred_from)]
+	pub type TransitionsDeferredFrom<T: Config<I>, I: 'static = ()> = StorageValue<
+		_,
+		BlockNumber
+	>;
+
+	/// Map of the fulfilled polls whose per-poll data awaits pruning to the progress of their pruning. Drained by
+	/// `on_idle`, which removes at most `MaxPruneKeys` keys per block.
+	#[pallet::storage]
+	#[pallet::getter(fn poll_prune_cursor)]
+	pub type PollPruneCursors<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		T::PollId,
+		PruneCursor
+	>;
+
+	/// Set of the poll ids for which `PollVotingPeriodStarted` was emitted, such that it is emitted once per poll.
+	/// Pruned once the poll is fulfilled.
+	#[pallet::storage]
+	#[pallet::getter(fn poll_voting_started_emitted)]
+	pub type PollVotingStartedEmitted<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		T::PollId,
+		()
+	>;
+
+	/// Map of (poll id, interaction index) to interaction data. Only populated when
+	/// `EmitFullInteractionData` is false, and pruned once the poll is fulfilled.
+	#[pallet::storage]
+	#[pallet::getter(fn messages)]
+	pub type Messages<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::PollId,
+		Twox64Concat,
+		u32,
+		(PublicKey, PollInteractionData)
+	>;
+
+	/// Map of (poll id, registration index) to the registration leaf, where the index is `count - 1` of the
+	/// corresponding `ParticipantRegistered` event. Only populated when `StoreRegistrationLeaves` is true, and
+	/// pruned once the poll is fulfilled.
+	#[pallet::storage]
+	#[pallet::getter(fn registration_leaf)]
+	pub type RegistrationLeaves<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::PollId,
+		Twox64Concat,
+		u32,
+		HashBytes
+	>;
+
+	/// Map of (poll id, interaction index) to the interaction leaf, where the index is `count - 1` of the
+	/// corresponding `PollInteraction` event. Only populated for polls created with `compact_interactions`, whose
+	/// interaction data is emitted rather than stored, and pruned once the poll is fulfilled.
+	#[pallet::storage]
+	#[pallet::getter(fn interaction_leaf)]
+	pub type InteractionLeaves<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::PollId,
+		Twox64Concat,
+		u32,
+		HashBytes
+	>;
+
+	/// Map of (poll id, proof index) to the commitment attested to by the verified proof, where the index counts
+	/// the proofs of both circuits in order. Used to recognise resubmitted proofs, and pruned once the poll is
+	/// fulfilled.
+	#[pallet::storage]
+	#[pallet::getter(fn proof_commitment)]
+	pub type ProofCommitments<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::PollId,
+		Twox64Concat,
+		CommitmentIndex,
+		CommitmentData
+	>;
+
+	/// Map of poll ids to the frontier of their registration tree, i.e. the `(depth, hash)` pairs of its unmerged
+	/// subtree roots. Stored apart from the poll, such that reading the poll does not decode the frontier. Removed
+	/// once the tree is merged.
+	#[pallet::storage]
+	#[pallet::getter(fn poll_registration_frontier)]
+	#[pallet::unbounded]
+	pub type PollRegistrationFrontier<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		T::PollId,
+		vec::Vec<(u8, HashBytes)>
+	>;
+
+	/// Map of poll ids to the frontier of their interaction tree, stored as in `PollRegistrationFrontier`.
+	#[pallet::storage]
+	#[pallet::getter(fn poll_interaction_frontier)]
+	#[pallet::unbounded]
+	pub type PollInteractionFrontier<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		T::PollId,
+		vec::Vec<(u8, HashBytes)>
+	>;
+
+	/// Map of poll ids to their registration tree, without its frontier. Stored apart from the poll, such that
+	/// registering reads and writes the tree alone rather than the whole poll.
+	#[pallet::storage]
+	#[pallet::getter(fn poll_registration_tree)]
+	#[pallet::unbounded]
+	pub type PollRegistrationTree<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		T::PollId,
+		PollStateTree
+	>;
+
+	/// Map of poll ids to their interaction tree, stored as in `PollRegistrationTree`.
+	#[pallet::storage]
+	#[pallet::getter(fn poll_interaction_tree)]
+	#[pallet::unbounded]
+	pub type PollInteractionTree<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		T::PollId,
+		PollStateTree
+	>;
+
+	/// Map of poll ids to the metadata against which registrations and interactions are checked, such that the
+	/// hot extrinsics do not decode the poll. Rewritten whenever the poll is stored.
+	#[pallet::storage]
+	#[pallet::getter(fn poll_phase)]
+	pub type PollPhases<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		T::PollId,
+		PollPhaseCache<T::AccountId>
+	>;
+
+	/// Map of coordinators to their track record, i.e. the number of polls they created, finalized and abandoned,
+	/// and the number of participants of the finalized polls.
+	#[pallet::storage]
+	#[pallet::getter(fn coordinator_stats)]
+	pub type CoordinatorStats<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		CoordinatorRecord,
+		ValueQuery
+	>;
+
+	/// Map of coordinators to the block at which they last created a poll, from which `PollCreationCooldown` is
+	/// measured.
+	#[pallet::storage]
+	#[pallet::getter(fn last_poll_creation)]
+	pub type CoordinatorLastCreation<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BlockNumber
+	>;
+
+	/// Map of poll ids to the proofs of the poll which failed to verify. Pruned once the poll is fulfilled.
+	#[pallet::storage]
+	#[pallet::getter(fn failed_proof_attempts)]
+	pub type FailedProofAttempts<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		T::PollId,
+		ProofFailures,
+		ValueQuery
+	>;
+
+	/// Map of poll ids to the number of intents to vote signalled in the poll. Only populated for polls created
+	/// with `collect_intents`.
+	#[pallet::storage]
+	#[pallet::getter(fn poll_intents)]
+	pub type PollIntents<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		T::PollId,
+		u32
+	>;
+
+	/// Set of (poll id, account) pairs which have signalled their intent to vote, such that each account may
+	/// only signal once per poll. Pruned once the poll is fulfilled.
+	#[pallet::storage]
+	pub type IntentSignals<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::PollId,
+		Blake2_128Concat,
+		T::AccountId,
+		()
+	>;
+
+	/// Map of (poll id, account) to the registration indices, i.e. `count - 1` of each `ParticipantRegistered`
+	/// event, of the registrations submitted by the account, from which rewards may be claimed once the poll is
+	/// finalized. Retained once the poll is fulfilled, and pruned in `on_idle` if it is nullified or reaped.
+	#[pallet::storage]
+	#[pallet::getter(fn registration_receipts)]
+	pub type RegistrationReceipts<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::PollId,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<u32, T::MaxRegistrationsPerAccount>,
+		ValueQuery
+	>;
+
+	/// Set of poll ids which were created with `unique_keys`, such that the public keys of their registrations
+	/// are indexed in `RegisteredKeys`. Pruned once the poll is fulfilled.
+	#[pallet::storage]
+	pub type UniqueKeyPolls<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		T::PollId,
+		()
+	>;
+
+	/// Set of poll ids which were created with `compact_interactions`, such that only the leaves of their
+	/// interactions are stored, in `InteractionLeaves`. Pruned once the poll is fulfilled.
+	#[pallet::storage]
+	pub type CompactInteractionPolls<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		T::PollId,
+		()
+	>;
+
+	/// Set of (poll id, public key) pairs of the registrations of polls created with `unique_keys`. Pruned once
+	/// the poll is fulfilled.
+	#[pallet::storage]
+	pub type RegisteredKeys<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::PollId,
+		Blake2_128Concat,
+		PublicKey,
+		()
+	>;
+
+	/// Map of (poll id, vote option index) to the encoded call attached to the option. Pruned once the poll is
+	/// fulfilled, after the call attached to the winning option is dispatched.
+	#[pallet::storage]
+	#[pallet::getter(fn poll_option_call)]
+	pub type PollOptionCalls<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::PollId,
+		Twox64Concat,
+		OutcomeIndex,
+		OptionCall<T, I>
+	>;
+
+	/// Map of poll ids to the outcome which their coordinator is staging across multiple extrinsics. Pruned once the
+	/// poll is fulfilled.
+	#[pallet::storage]
+	#[pallet::getter(fn staged_outcome)]
+	pub type StagedOutcomes<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		T::PollId,
+		StagedOutcome<T, I>
+	>;
+
+	/// Map of poll ids to the accumulated actual weight of every successful extrinsic scoped to the poll, for
+	/// coordinators to bill the resources which their polls consumed. Retained once the poll is fulfilled.
+	#[pallet::storage]
+	#[pallet::getter(fn poll_resource_usage)]
+	pub type PollResourceUsage<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		T::PollId,
+		Weight,
+		ValueQuery
+	>;
+
+	#[pallet::hooks]
+	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I>
+	{
+		/// Announces the polls whose voting period commences, and seals the polls whose voting period ended. Either
+		/// transition, and the expiry of the activation timeout of a pending poll, enqueues the poll for the keeper
+		/// action it permits. Coordinator registration is permissionless, such that any number of polls may be
+		/// scheduled for a block; the transitions are therefore applied under a `WeightMeter` of
+		/// `MaxInitializeWeight`, and those which exceed it are deferred to the following blocks.
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight
+		{
+			let now = n.saturated_into::<u64>();
+			let cursor_weight = T::DbWeight::get().reads_writes(1, 1);
+			let mut meter = WeightMeter::with_limit(T::MaxInitializeWeight::get().saturating_sub(cursor_weight));
+
+			// Resume from the earliest block whose transitions were deferred, before those of the current block.
+			let deferred_from = TransitionsDeferredFrom::<T, I>::get();
+			for block in deferred_from.unwrap_or(now)..=now
+			{
+				if !Self::apply_transitions(block, &mut meter)
+				{
+					TransitionsDeferredFrom::<T, I>::put(block);
+					return cursor_weight.saturating_add(meter.consumed());
+				}
+			}
+
+			if deferred_from.is_some() { TransitionsDeferredFrom::<T, I>::kill(); }
+
+			cursor_weight.saturating_add(meter.consumed())
+		}
+
+		/// Prunes the per-poll data of fulfilled polls one poll at a time, removing at most `MaxPruneKeys` keys and
+		/// consuming at most the remaining weight of the block. Pruning which exceeds either resumes in the
+		/// following blocks.
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight
+		{
+			let db = T::DbWeight::get();
+			let key_weight = db.reads_writes(1, 1);
+			let mut meter = WeightMeter::with_limit(remaining_weight);
+			let mut budget = T::MaxPruneKeys::get();
+
+			while budget > 0
+			{
+				// Reading the next poll and its cursor, and writing back or removing the cursor.
+				if meter.try_consume(db.reads_writes(2, 1)).is_err() { break; }
+				let Some((poll_id, mut progress)) = PollPruneCursors::<T, I>::iter().next() else { break; };
+
+				// Removing a key reads and writes it.
+				let limit = meter.remaining()
+					.checked_div_per_component(&key_weight)
+					.map_or(budget, |keys| keys.min(budget.into()) as u32);
+				let (removed, pruned) = Self::prune_poll(poll_id, &mut progress, limit);
+				meter.consume(key_weight.saturating_mul(removed.into()));
+				budget = budget.saturating_sub(removed);
+
+				if !pruned
+				{
+					PollPruneCursors::<T, I>::insert(poll_id, progress);
+					break;
+				}
+				PollPruneCursors::<T, I>::remove(poll_id);
+			}
+
+			meter.consumed()
+		}
+
+		fn integrity_test()
+		{
+			// Polls require at least two vote options.
+			assert!(
+				T::MaxVoteOptions::get() > 1,
+				"`MaxVoteOptions` must permit at least two vote options"
+			);
+
+			// Zero hashes are only precomputed for binary and quinary trees.
+			assert!(
+				matches!(T::RegistrationTreeArity::get(), 2 | 5),
+				"`RegistrationTreeArity` must be either 2 or 5"
+			);
+			assert!(
+				matches!(T::InteractionTreeArity::get(), 2 | 5),
+				"`InteractionTreeArity` must be either 2 or 5"
+			);
+
+			// The registration tree always contains the zero leaf, so a poll requires at least two leaves.
+			assert!(
+				T::MaxPollRegistrations::get() >= T::RegistrationTreeArity::get(),
+				"`MaxPollRegistrations` must permit at least one complete subtree of registrations"
+			);
+
+			// The interaction tree requires at least one complete batch of leaves.
+			assert!(
+				T::MaxPollInteractions::get() >= T::InteractionTreeArity::get(),
+				"`MaxPollInteractions` must permit at least one complete batch of interactions"
+			);
+
+			// Should a single block not fit within the limit, its transitions would be deferred indefinitely.
+			assert!(
+				Self::min_initialize_weight().all_lte(T::MaxInitializeWeight::get()),
+				"`MaxInitializeWeight` must permit reading the schedules of a block and applying one transition"
+			);
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: BlockNumberFor<T>) -> Result<(), TryRuntimeError>
+		{
+			Self::do_try_state()
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config<I>, I: 'static> Pallet<T, I>
+	{
+		/// Register the caller as a coordinator, granting the ability to create polls.
+		///
+		/// - `public_key`: The public key of the coordinator.
+		/// - `verify_key`: The verification key of the coordinator.
+		/// - `signature`: A signature of the callers account id made with the private key of `public_key`.
+		///
+		/// Emits `CoordinatorRegistered`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::register_as_coordinator())]
+		pub fn register_as_coordinator(
+			origin: OriginFor<T>,
+			public_key: PublicKey,
+			verify_key: VerifyingKeys,
+			signature: Signature
+		) -> DispatchResult
+		{
+			// Check that the extrinsic was signed and get the signer.
+			let sender = ensure_signed(origin)?;
+
+			// Ensure the public key and verification keys are well formed.
+			Self::validate_coordinator_keys(&public_key, &verify_key)?;
+
+			// Ensure the caller possesses the private key of the public key.
+			ensure!(
+				crypto::verify_signature(&public_key, crypto::proof_of_possession_message(&sender), &signature),
+				Error::<T, I>::ProofOfPossessionInvalid
+			);
+
+			// A coordinator may only be registered once.
+			ensure!(
+				!Coordinators::<T, I>::contains_key(&sender), 
+				Error::<T, I>::CoordinatorAlreadyRegistered
+			);
+
+			// Store the coordinator keys.
+			Coordinators::<T, I>::insert(&sender, Coordinator {
+				last_poll: None,
+				public_key,
+				verify_key: verify_key.clone()
+			});
+
+			// Emit a registration event.
+			Self::deposit_event(Event::CoordinatorRegistered {
+				who: sender,
+				public_key,
+				verify_key
+			});
+
+			Ok(())
+		}
+
+		/// Permits a coordinator to rotate their public and verification keys.
+		/// Rejected while their most recent poll is ongoing. A poll which awaits processing continues to be
+		/// proven against the keys snapshotted at its creation.
+		///
 		/// - `public_key`: The new public key for the coordinator.
 		/// - `verify_key`: The new verification key for the coordinator.
+		/// - `signature`: A signature of the callers account id made with the private key of `public_key`.
+		///
+		/// Emits `CoordinatorKeyChanged`, and `CoordinatorVerifyKeyRotated` for each unfinished poll of the coordinator
+		/// if the verifying keys changed.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::rotate_keys())]
+		pub fn rotate_keys(
+			origin: OriginFor<T>,
+			public_key: PublicKey,
+			verify_key: VerifyingKeys,
+			signature: Signature
+		) -> DispatchResult
+		{
+			// Check that the extrinsic was signed and get the signer.
+			let sender = ensure_signed(origin)?;
+
+			// Ensure the public key and verification keys are well formed.
+			Self::validate_coordinator_keys(&public_key, &verify_key)?;
+
+			// Ensure the caller possesses the private key of the new public key.
+			ensure!(
+				crypto::verify_signature(&public_key, crypto::proof_of_possession_message(&sender), &signature),
+				Error::<T, I>::ProofOfPossessionInvalid
+			);
+
+			// Check if origin is registered as a coordinator.
+			let Some(mut coordinator) = Coordinators::<T, I>::get(&sender) else { Err(<Error::<T, I>>::CoordinatorNotRegistered)? };
+
+			// Ensure that the most recent poll is not currently in progress, if it exists. Its proofs are verified
+			// against its snapshotted keys, such that it need not be fulfilled.
+			if let Some(index) = coordinator.last_poll
+			{
+				if let Some(phase) = PollPhases::<T, I>::get(index)
+				{
+					let now = <frame_system::Pallet<T>>::block_number().saturated_into::<u64>();
+					ensure!(phase.is_over(now), Error::<T, I>::PollCurrentlyActive);
+				}
+			}
+
+			// Alert the provers of any unfinished poll of the coordinator that the verifying keys were rotated.
+			if coordinator.verify_key != verify_key
+			{
+				// Only the most recent poll of the coordinator may be unfinished, as with `create_poll`.
+				let unfinished: vec::Vec<T::PollId> = coordinator.last_poll
+					.into_iter()
+					.filter(|poll_id| !Self::is_poll_finished(*poll_id))
+					.collect();
+
+				if !unfinished.is_empty()
+				{
+					let old_key_hash = compute_verify_key_hash(&coordinator.verify_key);
+					let new_key_hash = compute_verify_key_hash(&verify_key);
+					if let (Some(old_key_hash), Some(new_key_hash)) = (old_key_hash, new_key_hash)
+					{
+						for poll_id in unfinished
+						{
+							Self::deposit_event(Event::CoordinatorVerifyKeyRotated {
+								poll_id,
+								coordinator: sender.clone(),
+								old_key_hash,
+								new_key_hash
+							});
+						}
+					}
+				}
+			}
+
+			coordinator.public_key = public_key.clone();
+			coordinator.verify_key = verify_key.clone();
+
+			// Update and store the coordinators updated key(s).
+			Coordinators::<T, I>::insert(&sender, coordinator);
+	
+			// Emit the key rotation event.
+			Self::deposit_event(Event::CoordinatorKeysChanged {
+				who: sender,
+				public_key,
+				verify_key
+			});
+
+			Ok(())
+		}
+
+		/// Create a new poll object where the caller is the designated coordinator. Rejected if the coordinator created
+		/// a poll within the last `PollCreationCooldown` blocks.
+		///
+		/// - `config`: The parameters of the poll, e.g. the signup and voting periods, tree depths, and vote options.
+		///			   Each call attached to a vote option must decode, and be within `MaxOptionCallWeight`. Polls
+		///			   which attach calls may only be created by `OptionCallOrigin`.
+		///
+		/// Emits `PollCreated`.
+		#[pallet::call_index(2)]
+		#[pallet::weight(Pallet::<T, I>::create_poll_weight(config.option_calls.len()))]
+		pub fn create_poll(
+			origin: OriginFor<T>,
+			config: PollConfigOf<T, I>
+		) -> DispatchResult
+		{
+			// Check that the extrinsic was signed and get the signer. Only `OptionCallOrigin` may attach calls to the
+			// vote options, as these are dispatched with `OutcomeOrigin`.
+			let sender = if config.option_calls.is_empty() { ensure_signed(origin)? } else { T::OptionCallOrigin::ensure_origin(origin)? };
+
+			// Validate config parameters.
+			let created_at = <frame_system::Pallet<T>>::block_number().saturated_into::<u64>();
+			let Some(poll_config) = config.validate() else { Err(<Error::<T, I>>::PollConfigInvalid)? };
+			let Some(config_hash) = config.config_hash() else { Err(<Error::<T, I>>::PollConfigInvalid)? };
+			Self::ensure_valid_option_calls(&config)?;
+
+			// Check that the circuit which the poll references is registered.
+			if let Some(circuit_id) = config.circuit_id
+			{
+				ensure!(CircuitRegistry::<T, I>::contains_key(circuit_id), Error::<T, I>::CircuitNotFound);
+			}
+
+			// Check that sender is registered as a coordinator.
+			let Some(mut coordinator) = Coordinators::<T, I>::get(&sender) else { Err(<Error::<T, I>>::CoordinatorNotRegistered)? };
+
+			// Check that the coordinator did not create a poll within the creation cooldown.
+			if let Some(last_creation) = CoordinatorLastCreation::<T, I>::get(&sender)
+			{
+				ensure!(
+					created_at >= last_creation.saturating_add(T::PollCreationCooldown::get()),
+					Error::<T, I>::CreationCooldownActive
+				);
+			}
+
+			// A coordinator may only have a single active poll at a given time, so reject if any of their polls
+			// is on-going, or has yet to be processed. Polls never change hands, and a coordinator may only create a
+			// poll once their previous polls are finished, such that only their most recent poll may be unfinished.
+			// Only that poll is decoded, rather than every poll of the coordinator.
+			if let Some(poll_id) = coordinator.last_poll
+			{
+				ensure!(Self::is_poll_finished(poll_id), Error::<T, I>::PollCurrentlyActive);
+			}
+
+			let index = NextPollId::<T, I>::get();
+			let Some(next_index) = index.checked_add(&One::one()) else { Err(<Error::<T, I>>::PollIdOverflow)? };
+			let registration_arity = T::RegistrationTreeArity::get();
+			let interaction_arity = T::InteractionTreeArity::get();
+			let mut poll = Poll {
+				index,
+				created_at,
+				coordinator: sender.clone(),
+				state: PollState::new(
+					registration_arity.saturated_into(),
+					config.registration_depth,
+					interaction_arity.saturated_into(),
+					config.interaction_depth
+				),
+				config: poll_config,
+				schema_version: POLL_SCHEMA_VERSION
+			};
+			poll.state.pending = config.pending;
+
+			// Ensure that every hash the poll requires is supported, so that hashing can not fail later on.
+			ensure!(poll.hash_arities_supported(), Error::<T, I>::PollConfigInvalid);
+
+			// Ensure that both batch sizes are non-zero, so that the number of expected proofs can be computed.
+			ensure!(poll.batch_sizes_valid(), Error::<T, I>::InvalidBatchSize);
+
+			// Schedule the closure of the poll, i.e. the first block at which the poll is over, and the start of its
+			// voting period ahead of any other write, as either block may already be full. Those of a pending poll are
+			// only scheduled upon its activation, and its activation timeout instead. The periods are stored before
+			// the poll, from which its phase cache is derived.
+			let (starts_at, ends_at) = Self::poll_periods(created_at, config.signup_period, config.voting_period)?;
+			if !config.pending
+			{
+				PollClosures::<T, I>::try_append(ends_at, index).map_err(|_| Error::<T, I>::PollClosuresFull)?;
+				Self::schedule_voting_start(index, starts_at)?;
+			}
+			else
+			{
+				PollActivationExpiries::<T, I>::try_append(created_at.saturating_add(T::ActivationTimeout::get()), index)
+					.map_err(|_| Error::<T, I>::PollScheduleFull)?;
+			}
+			PollRegistrationPeriodEnd::<T, I>::insert(&index, starts_at);
+			PollVotingPeriodEnd::<T, I>::insert(&index, ends_at - 1);
+
+			// Reserve the allowance from which the fees of participants are paid.
+			if !config.fee_allowance.is_zero()
+			{
+				Self::ensure_can_reserve_keep_alive(&sender, config.fee_allowance)?;
+				T::Currency::reserve_named(&T::FeeAllowanceReserveId::get(), &sender, config.fee_allowance)?;
+				FeeAllowances::<T, I>::insert(&index, config.fee_allowance);
+			}
+
+			// Insert the poll into storage, alongside the key with which its circuits are parameterized.
+			let max_registrations = poll.config.max_registrations;
+			let max_interactions = poll.config.max_interactions;
+			let vote_option_count = config.vote_options.len() as u32;
+			Self::insert_poll_with_frontiers(index, poll);
+			NextPollId::<T, I>::put(next_index);
+			PollCoordinatorPubKey::<T, I>::insert(&index, coordinator.public_key);
+			match config.circuit_id
+			{
+				Some(circuit_id) =>
+				{
+					PollCircuit::<T, I>::insert(&index, circuit_id);
+					CircuitReferences::<T, I>::mutate(circuit_id, |count| *count = count.saturating_add(1));
+				},
+				None => PollVerifyKey::<T, I>::insert(&index, coordinator.verify_key.clone())
+			}
+			PollCoordKeyHash::<T, I>::insert(&index, config.coord_key_hash);
+			PollRegistrationTreeArity::<T, I>::insert(&index, registration_arity);
+			PollInteractionTreeArity::<T, I>::insert(&index, interaction_arity);
+			PollRegistrationTreeDepth::<T, I>::insert(&index, config.registration_depth);
+			PollInteractionTreeDepth::<T, I>::insert(&index, config.interaction_depth);
+			PollVoteOptionCount::<T, I>::insert(&index, vote_option_count);
+			if config.collect_intents { PollIntents::<T, I>::insert(&index, 0); }
+			if config.unique_keys { UniqueKeyPolls::<T, I>::insert(&index, ()); }
+			if config.compact_interactions { CompactInteractionPolls::<T, I>::insert(&index, ()); }
+			for (option_index, call) in config.option_calls.iter()
+			{
+				PollOptionCalls::<T, I>::insert(&index, option_index, call);
+			}
+
+			Self::record_resource_usage(index, Self::create_poll_weight(config.option_calls.len()));
+
+			let coordinator_public_key = coordinator.public_key;
+			coordinator.last_poll = Some(index);
+			Coordinators::<T, I>::insert(&sender, coordinator);
+			Self::insert_poll_id(&sender, index);
+			Self::archive_excess_poll_ids(&sender);
+			Self::insert_recent_poll(index);
+			CoordinatorLastCreation::<T, I>::insert(&sender, created_at);
+			CoordinatorStats::<T, I>::mutate(&sender, |record| record.polls_created = record.polls_created.saturating_add(1));
+
+			// Emit the creation event.
+			Self::deposit_event(Event::PollCreated { 
+				coordinator: sender,
+				coordinator_public_key,
+				poll_id: index,
+				starts_at,
+				ends_at,
+				max_registrations,
+				max_interactions,
+				vote_option_count,
+				fee_allowance: config.fee_allowance,
+				config_hash
+			});
+
+			if !config.pending { Self::emit_voting_start_if_commenced(index, starts_at); }
+
+			Ok(())
+		}
+
+		/// Compute the roots of the current poll state trees. This operation must be performed prior to commiting the poll outcome. 
+		/// Registration tree may be merged as long as the registration period has elapsed, and the interaction tree may be merged 
+		/// as long as the voting period has elapsed. NB Coordinator's are required to call this extrinsic twice: once to merge the 
+		/// registration state tree, and once to merge the interaction state tree.
+		///
+		/// Emits `PollStateMerged`, and `PollMergeCompleted` once both trees are merged.
+		#[pallet::call_index(3)]
+		#[pallet::weight(Pallet::<T, I>::merge_poll_state_weight())]
+		pub fn merge_poll_state(
+			origin: OriginFor<T>
+		) -> DispatchResult
+		{
+			// Check that the extrinsic was signed and get the signer.
+			let sender = ensure_signed(origin)?;
+			
+			// Get the coordinators most recent poll.
+			let Some(coordinator) = Coordinators::<T, I>::get(&sender) else { Err(<Error::<T, I>>::CoordinatorNotRegistered)? };
+			let Some(poll_id) = coordinator.last_poll else { Err(<Error::<T, I>>::PollDoesNotExist)? };
+
+			Self::with_poll_with_frontiers_mut(poll_id, |poll| {
+				// Check that either of the state trees may currently be merged.
+				Self::can_merge(poll)?;
+
+				let public_key = Self::poll_public_key(poll_id, &coordinator);
+				Self::record_resource_usage(poll_id, Self::merge_poll_state_weight());
+
+				if poll.state.registrations.root.is_none()
+				{
+					// Compute the root of the registration tree.
+					*poll = poll
+						.clone()
+						.merge_registrations()
+						.map_err(Error::<T, I>::from)?;
+
+					// Emit the hash event.
+					Self::deposit_event(Event::PollStateMerged {
+						poll_id,
+						registration_root: poll.state.registrations.root,
+						interaction_root: None
+					});
+				}
+
+				else
+				{
+					// Compute the root of the interaction tree.
+					*poll = poll
+						.clone()
+						.merge_interactions()
+						.map_err(Error::<T, I>::from)?;
+
+					// Emit the hash event.
+					Self::deposit_event(Event::PollStateMerged {
+						poll_id,
+						registration_root: None,
+						interaction_root: poll.state.interactions.root
+					});
+				}
+
+				Self::try_emit_merge_completed(poll_id, poll, &public_key);
+
+				// Drain the poll from the merge queue once neither of its state trees may be merged.
+				if Self::can_merge(poll).is_err() { Self::dequeue_keeper_action(KeeperAction::Merge, poll_id); }
+
+				Ok(())
+			})
+		}
+
+		/// Permits the coordinator to commit, in batches, proofs that all of the valid participant registrations and poll interactions 
+		/// were included in the computation which decided the winning vote option. Each individual proof carries a commitment value 
+		/// which is utilized to chain all of the proofs together, and in effect, to validate the final result.
+		///
+		/// Calls to this extrinsic are rejected if the poll has not ended, or if the root of the state trees have not yet been computed.
+		///
+		/// A proof which fails to verify is not reverted, but recorded and published with `ProofRejected`, such that
+		/// the proofs preceding it are retained, and the full weight of the call is charged. Once a poll exceeds
+		/// `MaxProofFailures`, at most one call per `ProofFailureCooldown` blocks may submit proofs.
+		///
+		/// - `proof_index`: The index of the first proof of `batches`, counting the proofs of both circuits in order. Ignored
+		///				 if `batches` is empty. Resubmitting verified proofs with identical commitments fails with the benign
+		///				 `ProofAlreadyAccepted` free of charge, such that submissions may be safely retried.
+		/// - `batches`: The ordered proofs alongside 
+		/// - `outcome`: The index of the option voted for (from the `VoteOptions` vec in the poll configuration). This parameter
+		///				 should only be included only with the last batch, or in a separate call after the final batch has been verified.
+		/// 
+		/// Emits `PollOutcome` once the outcome been verified, and `PollCommitmentUpdated` to reflect the updated commitment.
+		#[pallet::call_index(4)]
+		#[pallet::weight(Pallet::<T, I>::commit_outcome_weight(batches.len(), outcome.is_some()))]
+		pub fn commit_outcome(
+			origin: OriginFor<T>,
+			proof_index: CommitmentIndex,
+			batches: ProofBatches<T, I>,
+			outcome: Option<PollOutcome>
+		) -> DispatchResultWithPostInfo
+		{
+			// Check that the extrinsic was signed and get the signer.
+			let sender = ensure_signed(origin)?;
+
+			// Get the coordinators most recent poll.
+			let Some(coordinator) = Coordinators::<T, I>::get(&sender) else { Err(<Error::<T, I>>::CoordinatorNotRegistered)? };
+			let Some(poll_id) = coordinator.last_poll else { Err(<Error::<T, I>>::PollDoesNotExist)? };
+
+			// The poll state is only updated if the call succeeds.
+			Self::with_poll_mut(poll_id, |poll| {
+				// Check that the state trees have been merged, and that the outcome has not already been committed.
+				Self::can_commit_outcome(poll)?;
+
+				// Ensure at least one of the inputs have been provided.
+				ensure!(batches.len() > 0 || outcome.is_some(), Error::<T, I>::MalformedInput);
+
+				// Check that the batches start at the next proof, rather than resubmitting verified proofs.
+				if batches.len() > 0 { Self::ensure_next_proof(poll_id, poll, proof_index, &batches)?; }
+
+				// Check that the poll is not cooling off after repeated proof failures.
+				if batches.len() > 0 { Self::ensure_proof_cooldown_elapsed(poll_id)?; }
+
+				Self::record_resource_usage(poll_id, Self::commit_outcome_weight(batches.len(), outcome.is_some()));
+
+				// The proofs are verified against the keys of the coordinator at the time the poll was created.
+				let (public_key, verify_key) = Self::poll_keys(poll_id, coordinator)?;
+				let coord_key_hash = PollCoordKeyHash::<T, I>::get(poll_id);
+
+				// Check that the coordinates of the key are canonical, rather than deriving public inputs which no proof
+				// can match.
+				if batches.len() > 0
+				{
+					ensure!(
+						crypto::is_valid_fr_element(&public_key.x) && crypto::is_valid_fr_element(&public_key.y),
+						Error::<T, I>::CoordinatorKeyMalformed
+					);
+				}
+
+				// Verify each batch of proofs in order, until one fails to verify.
+				let mut verified = 0usize;
+				for (proof, new_commitment) in batches.iter()
+				{
+					// Check that the commitment is a canonical field element.
+					ensure!(crypto::is_valid_fr_element(new_commitment), Error::<T, I>::InvalidCommitment);
+
+					let index = poll.state.commitment.completed();
+					let Some((
+						verify_key,
+						public_inputs,
+						commitment
+					)) = poll.clone().prepare_public_inputs(
+						public_key,
+						coord_key_hash,
+						verify_key.clone(),
+						*new_commitment
+					) else { Err(<Error::<T, I>>::MalformedProof)? };
+
+					let kind = if commitment.process.completed != poll.state.commitment.process.completed
+					{
+						ProofKind::Process
+					}
+					else
+					{
+						ProofKind::Tally
+					};
+
+					// Record the failure and retain the proofs verified so far.
+					if !verify_proof(verify_key, public_inputs, proof.clone())
+					{
+						Self::record_proof_failure(poll_id, kind);
+						break;
+					}
+
+					// Publish the progress of the circuit which the proof was verified against.
+					if kind == ProofKind::Process
+					{
+						Self::deposit_event(Event::ProcessProgress {
+							poll_id,
+							batches_done: commitment.process.completed,
+							batches_total: commitment.process.expected
+						});
+					}
+					else
+					{
+						Self::deposit_event(Event::TallyProgress {
+							poll_id,
+							batches_done: commitment.tally.completed,
+							batches_total: commitment.tally.expected
+						});
+					}
+
+					poll.state.commitment = commitment;
+					ProofCommitments::<T, I>::insert(poll_id, index, new_commitment);
+					verified += 1;
+				}
+
+				// Publish the commitment from the final verified batch.
+				if verified > 0
+				{
+					Self::deposit_event(Event::PollCommitmentUpdated {
+						poll_id,
+						commitment: poll.clone().state.commitment
+					})
+				}
+
+				// The vote options are immutable once the poll is created, so the outcome is verified against the
+				// option set the proofs were generated for.
+				debug_assert!(
+					PollVoteOptionCount::<T, I>::get(poll_id).map_or(true, |count| count as usize == poll.config.vote_options.len()),
+					"the vote options of a poll are immutable once it is created"
+				);
+
+				// Once the final proof batch is verified, verify that the outcome matches the final commitment.
+				if let (Some(outcome_index), Some(outcome)) = (poll.clone().verify_outcome(outcome.clone()), outcome)
+				{
+					let header = outcome.header(poll.config.vote_options.len() as u32);
+					Self::finalize_poll(poll_id, poll, &sender, outcome_index, OutcomeRecord::new(header, &outcome.tally_results));
+				}
+
+				Ok(())
+			})?;
+
+			Ok(().into())
+		}
+
+		/// Permits the coordinator to nullify a poll which expired without recording a single interaction.
+		///
+		/// Calls to this extrinsic are rejected if the poll has not ended, or there was at least one interaction.
+		/// The remaining fee allowance of a poll which participants registered in is forfeited, as the poll is left
+		/// unproven, while that of a poll without registrations is returned to the coordinator.
+		/// 
+		/// Emits `PollNullified`, and `FeeAllowanceForfeited` if the fee allowance is forfeited.
+		#[pallet::call_index(5)]
+		#[pallet::weight(Pallet::<T, I>::nullify_poll_weight())]
+		pub fn nullify_poll(
+			origin: OriginFor<T>
+		) -> DispatchResult
+		{
+			// Check that the extrinsic was signed and get the signer.
+			let sender = ensure_signed(origin)?;
+
+			// Get the coordinators most recent poll.
+			let Some(coordinator) = Coordinators::<T, I>::get(&sender) else { Err(<Error::<T, I>>::CoordinatorNotRegistered)? };
+			let Some(poll_id) = coordinator.last_poll else { Err(<Error::<T, I>>::PollDoesNotExist)? };
+
+			Self::with_poll_mut(poll_id, |poll| {
+				// Check that the poll ended without registrations or interactions.
+				Self::can_nullify(poll)?;
+				Self::record_resource_usage(poll_id, Self::nullify_poll_weight());
+
+				Self::deposit_event(Event::PollNullified {
+					poll_id
+				});
+
+				if poll.state.registrations.count > 0 { Self::forfeit_fee_allowance(poll_id, &sender); }
+				Self::prune_poll_data(poll_id, &sender);
+				Self::prune_registration_receipts(poll_id);
+				Self::record_abandoned_poll(&sender);
+
+				// Mark the poll as dead.
+				*poll = poll.clone().nullify();
+
+				Ok(())
+			})
+		}
+
+		/// Permits a signer to participate in an upcoming poll. Rejected if signup period has elapsed.
+		///
+		///	- `poll_id`: The id of the poll.
+		/// - `public_key`: The ephemeral public key of the registrant.
+		///
+		/// Emits `ParticipantRegistered`.
+		#[pallet::call_index(6)]
+		#[pallet::weight(Pallet::<T, I>::register_as_participant_weight())]
+		pub fn register_as_participant(
+			origin: OriginFor<T>,
+			poll_id: T::PollId,
+			public_key: PublicKey
+		) -> DispatchResultWithPostInfo
+		{
+			// Check that the extrinsic was signed and get the signer.
+			let who = ensure_signed(origin)?;
+
+			// Ensure that the poll exists and get its phase cache and registration tree, rather than the whole poll.
+			let Some(phase) = PollPhases::<T, I>::get(poll_id) else { Err(<Error::<T, I>>::PollDoesNotExist)? };
+			let Some(tree) = Self::get_registration_tree(poll_id) else { Err(<Error::<T, I>>::PollDoesNotExist)? };
+
+			// Check that the poll currently accepts registrations.
+			Self::can_register(poll_id, &phase, &tree)?;
+
+			// Ensure the public key is a point in the prime order subgroup.
+			Self::ensure_valid_public_key(&public_key)?;
+
+			// Check that the key was not registered before, if the poll indexes the keys of its registrations.
+			let unique_keys = UniqueKeyPolls::<T, I>::contains_key(poll_id);
+			ensure!(
+				!unique_keys || !RegisteredKeys::<T, I>::contains_key(poll_id, public_key),
+				Error::<T, I>::KeyAlreadyRegistered
+			);
+
+			// Check that the signer has room for another registration receipt.
+			let mut receipts = RegistrationReceipts::<T, I>::get(poll_id, &who);
+			ensure!(
+				(receipts.len() as u32) < T::MaxRegistrationsPerAccount::get(),
+				Error::<T, I>::TooManyRegistrations
+			);
+
+			// Draw the fee from the allowance of the poll, if the coordinator provided one.
+			let pays_fee = Self::charge_fee_allowance(poll_id, &phase.coordinator, &who, Self::register_as_participant_weight())?;
+			Self::record_resource_usage(poll_id, Self::register_as_participant_weight());
+
+			// Record the hash of the registration data.
+			let block = <frame_system::Pallet<T>>::block_number().saturated_into::<u64>();
+			
+			// Insert the registration data into the registration tree.
+			let (count, leaf, tree) = insert_registration_leaf(tree, public_key, block)
+				.map_err(Error::<T, I>::from)?;
+
+			Self::insert_registration_tree(poll_id, tree);
+
+			// Retain the leaf, such that the coordinator may rebuild the registration tree from storage.
+			if T::StoreRegistrationLeaves::get()
+			{
+				RegistrationLeaves::<T, I>::insert(poll_id, count - 1, leaf);
+			}
+
+			if unique_keys { RegisteredKeys::<T, I>::insert(poll_id, public_key, ()); }
+
+			// Record the receipt of the signer, such that they may later claim rewards for the registration.
+			receipts.try_push(count - 1).map_err(|_| Error::<T, I>::TooManyRegistrations)?;
+			RegistrationReceipts::<T, I>::insert(poll_id, &who, receipts);
+
+			// Emit the registration data for future processing by the coordinator.
+			Self::deposit_event(Event::ParticipantRegistered { 
+				poll_id,
+				who,
+				count,
+				public_key,
+				block
+			});
+
+			Ok(pays_fee.into())
+		}
+
+		/// Permits a signer to interact with an ongoing poll. Rejects if not within the voting period. 
+		/// Valid messages include: a vote, and a key rotation. Participants may secretly call this 
+		/// method (read: using a different signer) in order to override their previous vote. 
+		///
+		/// - `poll_id`: The index of the poll in storage.
+		/// - `public_key`: The current ephemeral public key of the registrant. May be different than 
+		///					the one used for registration.
+		/// - `cipher`: The encrypted interaction data, split into the chunks hashed by the circuit.
+		///
+		/// Emits `PollInteraction`.
+		///
+		/// Weighed at the deepest interaction tree permitted by `MaxPollInteractions`, and refunded down to the
+		/// depth of the interaction tree of the poll, as each level of the tree may cost a hash upon insertion.
+		#[pallet::call_index(7)]
+		#[pallet::weight(Pallet::<T, I>::interact_with_poll_weight(Pallet::<T, I>::max_interaction_depth()))]
+		pub fn interact_with_poll(
+			origin: OriginFor<T>,
+			poll_id: T::PollId,
+			public_key: PublicKey,
+			cipher: PollInteractionCipher
+		) -> DispatchResultWithPostInfo
+		{
+			// Ensure that the extrinsic was signed and get the signer.
+			let who = ensure_signed(origin)?;
+
+			// Ensure that the poll exists and get its phase cache and interaction tree, rather than the whole poll.
+			let Some(phase) = PollPhases::<T, I>::get(poll_id) else { Err(<Error::<T, I>>::PollDoesNotExist)? };
+			let Some(tree) = Self::get_interaction_tree(poll_id) else { Err(<Error::<T, I>>::PollDoesNotExist)? };
+
+			// Check that the poll currently accepts interactions.
+			Self::can_interact(poll_id, &phase, &tree)?;
+
+			Self::ensure_valid_interaction(poll_id, &phase, &public_key, &cipher)?;
+
+			// Draw the fee for the actual weight from the allowance of the poll, if the coordinator provided one.
+			let actual_weight = Self::interact_with_poll_weight(tree.full_depth.into());
+			let pays_fee = Self::charge_fee_allowance(poll_id, &phase.coordinator, &who, actual_weight)?;
+
+			// Insert the interaction data into the interaction tree.
+			let tree = Self::insert_interaction(poll_id, tree, public_key, cipher)?;
+			Self::insert_interaction_tree(poll_id, tree);
+
+			Self::record_resource_usage(poll_id, actual_weight);
+
+			Ok(PostDispatchInfo {
+				actual_weight: Some(actual_weight),
+				pays_fee
+			})
+		}
+
+		/// Deprecated: use `create_poll`, which accepts the parameters as a single `PollConfigOf`.
+		/// This call will be removed in the next release. The vote options are bounded by `MaxVoteOptions`, as with
+		/// `create_poll`.
+		///
+		/// Emits `PollCreated`.
+		#[pallet::call_index(8)]
+		#[pallet::weight(Pallet::<T, I>::create_poll_weight(0))]
+		pub fn create_poll_legacy(
+			origin: OriginFor<T>,
+			signup_period: BlockNumber,
+			voting_period: BlockNumber,
+			registration_depth: u8,
+			interaction_depth: u8,
+			process_subtree_depth: u8,
+			tally_subtree_depth: u8,
+			vote_option_tree_depth: u8,
+			vote_options: VoteOptions<T, I>
+		) -> DispatchResult
+		{
+			Self::create_poll(origin, PollConfigOf {
+				signup_period,
+				voting_period,
+				registration_depth,
+				interaction_depth,
+				process_subtree_depth,
+				tally_subtree_depth,
+				vote_option_tree_depth,
+				vote_options,
+				fee_allowance: Zero::zero(),
+				reject_coordinator_key: false,
+				allow_early_interactions: false,
+				message_words: 10,
+				pending: false,
+				collect_intents: false,
+				unique_keys: false,
+				compact_interactions: false,
+				coord_key_hash: CoordKeyHash::Poseidon2Coords,
+				circuit_id: None,
+				option_calls: Default::default()
+			})
+		}
+
+		/// Permits the coordinator of a pending poll to activate it, such that its signup period commences at the
+		/// current block.
+		///
+		/// - `poll_id`: The index of the poll in storage.
+		///
+		/// Emits `PollActivated`.
+		#[pallet::call_index(9)]
+		#[pallet::weight(Pallet::<T, I>::activate_poll_weight())]
+		pub fn activate_poll(
+			origin: OriginFor<T>,
+			poll_id: T::PollId
+		) -> DispatchResult
+		{
+			// Check that the extrinsic was signed and get the signer.
+			let sender = ensure_signed(origin)?;
+
+			let (starts_at, ends_at) = Self::with_poll_mut(poll_id, |poll| {
+				// Check that the sender coordinates the poll, and that the poll awaits activation.
+				Self::can_activate(poll, &sender)?;
+				Self::record_resource_usage(poll_id, Self::activate_poll_weight());
+
+				// Anchor the periods of the poll on the current block.
+				let now = <frame_system::Pallet<T>>::block_number().saturated_into::<u64>();
+				poll.created_at = now;
+				poll.state.pending = false;
+
+				let (starts_at, ends_at) = Self::poll_periods(now, poll.config.signup_period, poll.config.voting_period)?;
+
+				// The periods are stored before the poll, from which its phase cache is derived.
+				PollClosures::<T, I>::try_append(ends_at, poll_id).map_err(|_| Error::<T, I>::PollClosuresFull)?;
+				Self::schedule_voting_start(poll_id, starts_at)?;
+				PollRegistrationPeriodEnd::<T, I>::insert(&poll_id, starts_at);
+				PollVotingPeriodEnd::<T, I>::insert(&poll_id, ends_at - 1);
+
+				// The poll may no longer be reaped.
+				Self::dequeue_keeper_action(KeeperAction::Reap, poll_id);
+
+				Ok((starts_at, ends_at))
+			})?;
+
+			Self::deposit_event(Event::PollActivated {
+				poll_id,
+				starts_at,
+				ends_at
+			});
+
+			Self::emit_voting_start_if_commenced(poll_id, starts_at);
+
+			Ok(())
+		}
+
+		/// Permits any signer to nullify a pending poll which was not activated within `ActivationTimeout` blocks of its
+		/// creation, returning the fee allowance of the poll to its coordinator.
+		///
+		/// - `poll_id`: The index of the poll in storage.
+		///
+		/// Emits `PollReaped`.
+		#[pallet::call_index(10)]
+		#[pallet::weight(Pallet::<T, I>::reap_poll_weight())]
+		pub fn reap_poll(
+			origin: OriginFor<T>,
+			poll_id: T::PollId
+		) -> DispatchResult
+		{
+			// Check that the extrinsic was signed.
+			ensure_signed(origin)?;
+
+			Self::with_poll_mut(poll_id, |poll| {
+				// Check that the poll still awaits activation, and that the activation timeout has elapsed.
+				Self::can_reap(poll)?;
+				Self::record_resource_usage(poll_id, Self::reap_poll_weight());
+
+				Self::prune_poll_data(poll_id, &poll.coordinator);
+				Self::prune_registration_receipts(poll_id);
+				Self::record_abandoned_poll(&poll.coordinator);
+
+				// Mark the poll as dead.
+				*poll = poll.clone().nullify();
+
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::PollReaped {
+				poll_id
+			});
+
+			Ok(())
+		}
+
+		/// Permits the coordinator of a poll to replace the verifying keys against which its proofs are verified, e.g. if
+		/// the keys were found to be wrong after the poll was created. Only permitted once the voting period has ended, and
+		/// before any proof of the poll has been verified.
+		///
+		/// - `poll_id`: The index of the poll in storage.
+		/// - `verify_key`: The new verifying keys of the message processing and tally circuits.
+		///
+		/// Emits `PollVerifyKeyReplaced`.
+		#[pallet::call_index(11)]
+		#[pallet::weight(Pallet::<T, I>::replace_poll_verify_key_weight())]
+		pub fn replace_poll_verify_key(
+			origin: OriginFor<T>,
+			poll_id: T::PollId,
+			verify_key: VerifyingKeys
+		) -> DispatchResult
+		{
+			// Check that the extrinsic was signed and get the signer.
+			let sender = ensure_signed(origin)?;
+
+			Self::with_poll_mut(poll_id, |poll| {
+				// Check that the sender coordinates the poll, that voting has closed, and that no proof has been verified.
+				Self::can_replace_verify_key(poll, &sender)?;
+
+				// Ensure that the verifying keys are deserializable.
+				Self::ensure_valid_verify_keys(&verify_key)?;
+
+				// The proofs of the poll are verified against its snapshotted keys, i.e. the keys of the coordinator
+				// remain unchanged for later polls, and a poll which referenced a circuit no longer does.
+				PollVerifyKey::<T, I>::insert(&poll_id, verify_key.clone());
+				if let Some(circuit_id) = PollCircuit::<T, I>::take(&poll_id) { Self::release_circuit(circuit_id); }
+				Self::record_resource_usage(poll_id, Self::replace_poll_verify_key_weight());
+
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::PollVerifyKeyReplaced {
+				poll_id,
+				verify_key
+			});
+
+			Ok(())
+		}
+
+		/// Permits a relayer to submit a batch of interactions with an ongoing poll in a single transaction, e.g. votes
+		/// which were collected off-chain. Either all of the interactions are inserted, or none of them are. The relayer
+		/// pays for the batch, i.e. the fee allowance of the poll is not drawn from.
+		///
+		/// - `poll_id`: The id of the poll.
+		/// - `items`: The ephemeral public keys and encrypted interaction data of each interaction.
+		///
+		/// Emits `PollInteraction` or `PollInteractionStored` for each interaction, and `InteractionsBatchSubmitted`.
+		#[pallet::call_index(12)]
+		#[pallet::weight(Pallet::<T, I>::interact_with_poll_batch_weight(items.len()))]
+		pub fn interact_with_poll_batch(
+			origin: OriginFor<T>,
+			poll_id: T::PollId,
+			items: BoundedVec<(PublicKey, PollInteractionCipher), T::MaxInteractionsPerCall>
+		) -> DispatchResult
+		{
+			// Ensure that the extrinsic was signed.
+			ensure_signed(origin)?;
+
+			// Ensure that the poll exists and get its phase cache and interaction tree, rather than the whole poll.
+			let Some(phase) = PollPhases::<T, I>::get(poll_id) else { Err(<Error::<T, I>>::PollDoesNotExist)? };
+			let Some(mut tree) = Self::get_interaction_tree(poll_id) else { Err(<Error::<T, I>>::PollDoesNotExist)? };
+
+			// Check that the poll currently accepts interactions.
+			Self::ensure_interaction_period(&phase)?;
+
+			// Check that the entire batch fits within the maximum number of interactions.
+			let first_index = tree.count;
+			let count = items.len() as u32;
+			ensure!(
+				first_index.saturating_add(count) <= phase.max_interactions,
+				Error::<T, I>::ParticipantInteractionLimitReached
+			);
+
+			// Insert the interaction data of each item into the interaction tree.
+			for (public_key, cipher) in items
+			{
+				Self::ensure_valid_interaction(poll_id, &phase, &public_key, &cipher)?;
+				tree = Self::insert_interaction(poll_id, tree, public_key, cipher)?;
+			}
+
+			Self::insert_interaction_tree(poll_id, tree);
+			Self::record_resource_usage(poll_id, Self::interact_with_poll_batch_weight(count as usize));
+
+			Self::deposit_event(Event::InteractionsBatchSubmitted {
+				poll_id,
+				first_index,
+				count
+			});
+
+			Ok(())
+		}
+
+		/// Permits any account to signal their non-binding intent to vote in a poll during its signup period, such
+		/// that the coordinator may estimate turnout. Intents have no bearing on the tally, and are only collected
+		/// by polls created with `collect_intents`. Each account may signal once per poll.
+		///
+		/// - `poll_id`: The index of the poll in storage.
+		///
+		/// Emits `IntentSignaled`.
+		#[pallet::call_index(13)]
+		#[pallet::weight(Pallet::<T, I>::signal_intent_weight())]
+		pub fn signal_intent(
+			origin: OriginFor<T>,
+			poll_id: T::PollId
+		) -> DispatchResult
+		{
+			// Check that the extrinsic was signed and get the signer.
+			let sender = ensure_signed(origin)?;
+
+			// Ensure that the poll exists and get it.
+			let Some(phase) = PollPhases::<T, I>::get(poll_id) else { Err(<Error::<T, I>>::PollDoesNotExist)? };
+
+			// Check that the poll collects intents during its signup period, and that the caller has not yet signalled.
+			Self::can_signal_intent(poll_id, &phase, &sender)?;
+
+			let count = PollIntents::<T, I>::get(&poll_id).unwrap_or_default().saturating_add(1);
+			PollIntents::<T, I>::insert(&poll_id, count);
+			IntentSignals::<T, I>::insert(&poll_id, &sender, ());
+			Self::record_resource_usage(poll_id, Self::signal_intent_weight());
+
+			Self::deposit_event(Event::IntentSignaled {
+				poll_id,
+				who: sender,
+				count
+			});
+
+			Ok(())
+		}
+
+		/// Permits the coordinator of a proven poll to begin staging its outcome across multiple extrinsics, e.g. if
+		/// the tally results of its vote options exceed the size of a single extrinsic. Replaces any outcome staged
+		/// before.
+		///
+		/// - `poll_id`: The index of the poll in storage.
+		/// - `header`: The commitments and salts against which the tally results are verified, and the number of vote
+		///			   options of the poll.
+		///
+		/// Emits `OutcomeStagingBegun`.
+		#[pallet::call_index(14)]
+		#[pallet::weight(Pallet::<T, I>::begin_outcome_weight())]
+		pub fn begin_outcome(
+			origin: OriginFor<T>,
+			poll_id: T::PollId,
+			header: OutcomeHeader
+		) -> DispatchResult
+		{
+			// Check that the extrinsic was signed and get the signer.
+			let sender = ensure_signed(origin)?;
+
+			// Ensure that the poll exists and get it.
+			let Some(poll) = Self::polls(poll_id) else { Err(<Error::<T, I>>::PollDoesNotExist)? };
+
+			// Check that the sender coordinates the poll, and that every proof of the poll has been verified.
+			Self::can_stage_outcome(&poll, &sender)?;
+
+			// Check that a tally result is staged for each vote option of the poll.
+			ensure!(header.option_count as usize == poll.config.vote_options.len(), Error::<T, I>::MalformedInput);
+
+			let now = <frame_system::Pallet<T>>::block_number().saturated_into::<u64>();
+			let expires_at = now.saturating_add(T::OutcomeStagingTimeout::get());
+
+			StagedOutcomes::<T, I>::insert(poll_id, StagedOutcome {
+				header,
+				tally_results: BoundedVec::new(),
+				expires_at
+			});
+			Self::record_resource_usage(poll_id, Self::begin_outcome_weight());
+
+			Self::deposit_event(Event::OutcomeStagingBegun {
+				poll_id,
+				expires_at
+			});
+
+			Ok(())
+		}
+
+		/// Permits the coordinator of a poll to stage the tally results of a contiguous range of its vote options,
+		/// starting from the first option which is not yet staged. Each tally result is verified against the tally
+		/// commitment of the poll as it is staged, and the chunk is rejected as a whole if any of them fails.
+		///
+		/// - `poll_id`: The index of the poll in storage.
+		/// - `options`: The index, tally result and vote option tree path of each vote option of the chunk, of at most
+		///				 `MaxVoteOptions` options.
+		///
+		/// Emits `OutcomeChunkAccepted`.
+		#[pallet::call_index(15)]
+		#[pallet::weight(Pallet::<T, I>::submit_outcome_chunk_weight(options.len()))]
+		pub fn submit_outcome_chunk(
+			origin: OriginFor<T>,
+			poll_id: T::PollId,
+			options: OutcomeChunk<T, I>
+		) -> DispatchResult
+		{
+			// Check that the extrinsic was signed and get the signer.
+			let sender = ensure_signed(origin)?;
+
+			// Ensure that the poll exists and get it.
+			let Some(poll) = Self::polls(poll_id) else { Err(<Error::<T, I>>::PollDoesNotExist)? };
+
+			// Check that the sender coordinates the poll, that it is proven, and that its outcome is being staged.
+			Self::can_stage_outcome(&poll, &sender)?;
+			let mut staged = Self::ensure_staged_outcome(poll_id)?;
+
+			ensure!(!options.is_empty(), Error::<T, I>::MalformedInput);
+			let chunk_len = options.len();
+
+			// Verify each tally result in order of vote option, from the first option which is not yet staged.
+			for (option_index, tally_result, tally_path) in options.into_iter()
+			{
+				ensure!(
+					option_index == staged.next_option() && option_index < staged.header.option_count,
+					Error::<T, I>::InvalidOutcomeChunk
+				);
+				ensure!(
+					poll.verify_tally_result(
+						option_index,
+						tally_result,
+						tally_path.into_iter().map(|siblings| siblings.into_inner()).collect(),
+						staged.header.tally_result_salt,
+						staged.header.spent_votes_hash
+					),
+					Error::<T, I>::InvalidOutcomeChunk
+				);
+
+				// The option index is below the option count of the poll, which is at most `MaxVoteOptions`.
+				staged.tally_results.try_push(tally_result).map_err(|_| Error::<T, I>::InvalidOutcomeChunk)?;
+			}
+
+			let (options_done, options_total) = (staged.next_option(), staged.header.option_count);
+			StagedOutcomes::<T, I>::insert(poll_id, staged);
+			Self::record_resource_usage(poll_id, Self::submit_outcome_chunk_weight(chunk_len));
+
+			Self::deposit_event(Event::OutcomeChunkAccepted {
+				poll_id,
+				options_done,
+				options_total
+			});
+
+			Ok(())
+		}
+
+		/// Permits the coordinator of a poll to finalize its staged outcome once the tally result of every vote
+		/// option has been staged, verifying the total number of votes cast. The poll is then fulfilled exactly as if
+		/// the outcome had been submitted with `commit_outcome`.
+		///
+		/// - `poll_id`: The index of the poll in storage.
+		///
+		/// Emits `PollOutcome`.
+		#[pallet::call_index(16)]
+		#[pallet::weight(Pallet::<T, I>::finalize_outcome_weight())]
+		pub fn finalize_outcome(
+			origin: OriginFor<T>,
+			poll_id: T::PollId
+		) -> DispatchResult
+		{
+			// Check that the extrinsic was signed and get the signer.
+			let sender = ensure_signed(origin)?;
+
+			Self::with_poll_mut(poll_id, |poll| {
+				// Check that the sender coordinates the poll, that it is proven, and that its outcome is being staged.
+				Self::can_stage_outcome(poll, &sender)?;
+				let staged = Self::ensure_staged_outcome(poll_id)?;
+
+				// Check that every tally result was staged, and that the total number of votes cast matches the
+				// commitment.
+				ensure!(staged.is_complete(), Error::<T, I>::OutcomeIncomplete);
+				ensure!(
+					poll.verify_total_spent(
+						staged.header.total_spent,
+						staged.header.total_spent_salt,
+						staged.header.new_results_commitment
+					),
+					Error::<T, I>::OutcomeVerificationFailed
+				);
+
+				Self::record_resource_usage(poll_id, Self::finalize_outcome_weight());
+
+				let outcome_index = select_winner(&staged.tally_results);
+				Self::finalize_poll(poll_id, poll, &sender, outcome_index, OutcomeRecord::new(staged.header, &staged.tally_results));
+
+				Ok(())
+			})
+		}
+
+		/// Permits the coordinator of a poll to commit several of its tally proofs at once, which are verified together
+		/// in a single batched pairing check rather than one after another. The commitment of the poll only advances if
+		/// every proof verifies; otherwise the failure is recorded and published with `ProofRejected`, and the full
+		/// weight of the call is charged. Every process proof of the poll must already have been verified, and the
+		/// outcome is committed separately, with `commit_outcome` or by staging it.
+		///
+		/// - `poll_id`: The index of the poll in storage.
+		/// - `proof_index`: The index of the first proof of `batches`, as with `commit_outcome`.
+		/// - `batches`: The ordered tally proofs alongside the commitment which each proof advances the tally to.
+		///
+		/// Emits `TallyProgress` for each proof, and `PollCommitmentUpdated` once every proof is verified.
+		#[pallet::call_index(17)]
+		#[pallet::weight(Pallet::<T, I>::commit_tally_proofs_weight(batches.len()))]
+		pub fn commit_tally_proofs(
+			origin: OriginFor<T>,
+			poll_id: T::PollId,
+			proof_index: CommitmentIndex,
+			batches: ProofBatches<T, I>
+		) -> DispatchResultWithPostInfo
+		{
+			// Check that the extrinsic was signed and get the signer.
+			let sender = ensure_signed(origin)?;
+
+			Self::with_poll_mut(poll_id, |poll| {
+				let Some(coordinator) = Coordinators::<T, I>::get(&sender) else { Err(<Error::<T, I>>::CoordinatorNotRegistered)? };
+
+				// Check that the sender coordinates the poll, that its state trees have been merged, and that its outcome
+				// has not already been committed.
+				ensure!(poll.coordinator == sender, Error::<T, I>::NotPollCoordinator);
+				Self::can_commit_outcome(poll)?;
+				ensure!(!batches.is_empty(), Error::<T, I>::MalformedInput);
+
+				// Check that the batches start at the next proof, and that the poll is not cooling off after repeated
+				// proof failures.
+				Self::ensure_next_proof(poll_id, poll, proof_index, &batches)?;
+				Self::ensure_proof_cooldown_elapsed(poll_id)?;
+
+				// The proofs are verified against the keys of the coordinator at the time the poll was created.
+				let (public_key, verify_key) = Self::poll_keys(poll_id, coordinator)?;
+				let coord_key_hash = PollCoordKeyHash::<T, I>::get(poll_id);
+				ensure!(
+					crypto::is_valid_fr_element(&public_key.x) && crypto::is_valid_fr_element(&public_key.y),
+					Error::<T, I>::CoordinatorKeyMalformed
+				);
+
+				// Derive the public inputs of each proof from the commitment which the proof before it advances to.
+				let mut proven = poll.clone();
+				let mut statements = vec::Vec::with_capacity(batches.len());
+				for (proof, new_commitment) in batches.iter()
+				{
+					// Check that the commitment is a canonical field element.
+					ensure!(crypto::is_valid_fr_element(new_commitment), Error::<T, I>::InvalidCommitment);
+
+					let Some((
+						_,
+						public_inputs,
+						commitment
+					)) = proven.clone().prepare_public_inputs(
+						public_key,
+						coord_key_hash,
+						verify_key.clone(),
+						*new_commitment
+					) else { Err(<Error::<T, I>>::MalformedProof)? };
+
+					// Check that the proof is a tally proof, rather than one of the remaining process proofs.
+					ensure!(
+						commitment.process.completed == proven.state.commitment.process.completed,
+						Error::<T, I>::ProcessProofsIncomplete
+					);
+
+					proven.state.commitment = commitment;
+					statements.push((public_inputs, proof.clone()));
+				}
+
+				Self::record_resource_usage(poll_id, Self::commit_tally_proofs_weight(batches.len()));
+
+				// Record the failure without advancing the commitment unless every proof verifies.
+				if !verify_proofs_batched(verify_key.tally, statements)
+				{
+					Self::record_proof_failure(poll_id, ProofKind::Tally);
+					return Ok(());
+				}
+
+				// Publish the progress of the tally, and record the commitment of each proof.
+				let first_index = poll.state.commitment.completed();
+				let batches_done = poll.state.commitment.tally.completed;
+				for (offset, (_, new_commitment)) in batches.iter().enumerate()
+				{
+					let offset = offset.saturated_into::<CommitmentIndex>();
+					ProofCommitments::<T, I>::insert(poll_id, first_index.saturating_add(offset), new_commitment);
+					Self::deposit_event(Event::TallyProgress {
+						poll_id,
+						batches_done: batches_done.saturating_add(offset).saturating_add(1),
+						batches_total: proven.state.commitment.tally.expected
+					});
+				}
+
+				poll.state.commitment = proven.state.commitment;
+				Self::deposit_event(Event::PollCommitmentUpdated {
+					poll_id,
+					commitment: poll.state.commitment.clone()
+				});
+
+				Ok(())
+			})?;
+
+			Ok(().into())
+		}
+
+		/// Register the verifying keys of a standard pair of circuits, such that polls may reference them rather than
+		/// the keys of their coordinator. The keys of a circuit are immutable once registered.
+		///
+		/// - `circuit_id`: The unused id under which the keys are registered.
+		/// - `verify_key`: The verifying keys of the message processing and tally circuits.
+		///
+		/// Emits `CircuitRegistered`.
+		#[pallet::call_index(18)]
+		#[pallet::weight(Pallet::<T, I>::register_circuit_weight(verify_key))]
+		pub fn register_circuit(
+			origin: OriginFor<T>,
+			circuit_id: CircuitId,
+			verify_key: VerifyingKeys
+		) -> DispatchResult
+		{
+			// Check that the extrinsic was dispatched by the circuit origin.
+			T::CircuitOrigin::ensure_origin(origin)?;
+
+			// Ensure that the verifying keys are deserializable.
+			Self::ensure_valid_verify_keys(&verify_key)?;
+
+			// The proofs of the polls which reference a circuit are verified against its keys, so they are not replaced.
+			ensure!(
+				!CircuitRegistry::<T, I>::contains_key(circuit_id),
+				Error::<T, I>::CircuitAlreadyRegistered
+			);
+
+			CircuitRegistry::<T, I>::insert(circuit_id, verify_key.clone());
+
+			Self::deposit_event(Event::CircuitRegistered {
+				circuit_id,
+				verify_key
+			});
+
+			Ok(())
+		}
+
+		/// Remove a circuit from the registry, such that new polls may no longer reference it. Rejected while an
+		/// unfinished poll references the circuit, as its proofs are verified against the keys of the circuit, until
+		/// the poll is fulfilled or its coordinator replaces its keys with `replace_poll_verify_key`.
+		///
+		/// - `circuit_id`: The id of the circuit.
+		///
+		/// Emits `CircuitRemoved`.
+		#[pallet::call_index(19)]
+		#[pallet::weight(Pallet::<T, I>::remove_circuit_weight())]
+		pub fn remove_circuit(
+			origin: OriginFor<T>,
+			circuit_id: CircuitId
+		) -> DispatchResult
+		{
+			// Check that the extrinsic was dispatched by the circuit origin.
+			T::CircuitOrigin::ensure_origin(origin)?;
+
+			// Ensure that the circuit is registered, and that no unfinished poll verifies its proofs against it.
+			ensure!(CircuitRegistry::<T, I>::contains_key(circuit_id), Error::<T, I>::CircuitNotFound);
+			ensure!(CircuitReferences::<T, I>::get(circuit_id) == 0, Error::<T, I>::CircuitInUse);
+
+			CircuitRegistry::<T, I>::remove(circuit_id);
+
+			Self::deposit_event(Event::CircuitRemoved { circuit_id });
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config<I>, I: 'static> Pallet<T, I>
+	{
+		/// Ensure that the storage invariants between polls and their coordinators hold.
+		#[cfg(any(feature = "try-runtime", test))]
+		pub fn do_try_state() -> Result<(), TryRuntimeError>
+		{
+			// Every poll must be stored under its own index, and be owned by its coordinator.
+			for poll_id in Polls::<T, I>::iter_keys()
+			{
+				let Some(poll) = Self::polls(poll_id) else {
+					return Err(TryRuntimeError::Other("Poll is undecodable"));
+				};
+				ensure!(
+					PollRegistrationTree::<T, I>::contains_key(poll_id) && PollInteractionTree::<T, I>::contains_key(poll_id),
+					TryRuntimeError::Other("Poll state trees are not stored apart from the poll")
+				);
+				ensure!(
+					PollPhases::<T, I>::get(poll_id) == Some(PollPhaseCache::from(&poll)),
+					TryRuntimeError::Other("Poll phase cache does not match the poll")
+				);
+				ensure!(
+					poll.index == poll_id,
+					TryRuntimeError::Other("Poll index does not match its storage key")
+				);
+				ensure!(
+					poll.schema_version == POLL_SCHEMA_VERSION,
+					TryRuntimeError::Other("Poll was not migrated to the current schema version")
+				);
+				ensure!(
+					poll_id < NextPollId::<T, I>::get(),
+					TryRuntimeError::Other("Poll id was not yet assigned")
+				);
+				ensure!(
+					CoordinatorPollIds::<T, I>::get(&poll.coordinator).contains(&poll_id) ||
+					CoordinatorHistory::<T, I>::get(&poll.coordinator).contains(&poll_id),
+					TryRuntimeError::Other("Poll is missing from the poll ids of its coordinator")
+				);
+				ensure!(
+					!poll.is_sealed() || poll.is_over(),
+					TryRuntimeError::Other("Poll was sealed before its voting period ended")
+				);
+				ensure!(
+					poll.state.interactions.root.is_none() || poll.is_sealed(),
+					TryRuntimeError::Other("Poll interactions were merged before the poll was sealed")
+				);
+				ensure!(
+					PollVoteOptionCount::<T, I>::get(poll_id).map_or(true, |count| count as usize == poll.config.vote_options.len()),
+					TryRuntimeError::Other("Poll vote options changed after the poll was created")
+				);
+
+				// The expected number of proofs of a merged poll must cover exactly the leaves which are proven.
+				if poll.state.interactions.root.is_some()
+				{
+					ensure!(
+						poll.expected_batches() == Some((poll.state.commitment.process.expected, poll.state.commitment.tally.expected)),
+						TryRuntimeError::Other("Expected proofs of a merged poll do not match its interaction and registration counts")
+					);
+				}
+
+				// The outcome of a finalized poll must have been proven against a vote option tree which holds every option.
+				if poll.state.outcome.is_some()
+				{
+					ensure!(
+						vote_option_tree_capacity(poll.config.vote_option_tree_depth)
+							.map_or(true, |capacity| poll.config.vote_options.len() <= capacity as usize),
+						TryRuntimeError::Other("Finalized poll has more vote options than its vote option tree holds")
+					);
+				}
+			}
+
+			// Archived poll ids must belong to finished polls.
+			for (_, poll_ids) in CoordinatorHistory::<T, I>::iter()
+			{
+				ensure!(
+					poll_ids.iter().all(|poll_id| Self::is_poll_finished(*poll_id)),
+					TryRuntimeError::Other("Archived poll id does not correspond to a finished poll")
+				);
+			}
+
+			// Fee allowances must be returned to the coordinator once a poll is fulfilled.
+			for (poll_id, allowance) in FeeAllowances::<T, I>::iter()
+			{
+				let Some(poll) = Self::polls(poll_id) else { return Err(TryRuntimeError::Other("Fee allowance of a missing poll")); };
+				ensure!(!poll.is_fulfilled(), TryRuntimeError::Other("Fee allowance was not returned once the poll was fulfilled"));
+				ensure!(
+					T::Currency::reserved_balance_named(&T::FeeAllowanceReserveId::get(), &poll.coordinator) >= allowance,
+					TryRuntimeError::Other("Fee allowance is not held in the named reserve of the coordinator")
+				);
+			}
+
+			// Every unfinished poll which references a circuit must be counted against it.
+			use sp_std::collections::btree_map::BTreeMap;
+			let mut references: BTreeMap<CircuitId, u32> = BTreeMap::new();
+			for (poll_id, circuit_id) in PollCircuit::<T, I>::iter()
+			{
+				if Self::polls(poll_id).map_or(false, |poll| !poll.is_fulfilled())
+				{
+					*references.entry(circuit_id).or_default() += 1;
+				}
+			}
+			ensure!(
+				CircuitReferences::<T, I>::iter().collect::<BTreeMap<_, _>>() == references,
+				TryRuntimeError::Other("Circuit references do not match the unfinished polls which reference the circuit")
+			);
+
+			// Every poll id owned by a coordinator must exist, and the most recent must be tracked.
+			let mut poll_count: u32 = 0;
+			for (who, poll_ids) in CoordinatorPollIds::<T, I>::iter().chain(CoordinatorHistory::<T, I>::iter())
+			{
+				let Some(coordinator) = Coordinators::<T, I>::get(&who) else {
+					return Err(TryRuntimeError::Other("Poll ids are owned by an unregistered coordinator"));
+				};
+				let mut all_poll_ids = CoordinatorHistory::<T, I>::get(&who);
+				all_poll_ids.extend(CoordinatorPollIds::<T, I>::get(&who));
+				ensure!(
+					coordinator.last_poll == all_poll_ids.iter().max().copied(),
+					TryRuntimeError::Other("Coordinator last poll does not match their most recent poll id")
+				);
+				ensure!(
+					poll_ids.windows(2).all(|ids| ids[0] < ids[1]),
+					TryRuntimeError::Other("Coordinator poll ids are not sorted in ascending order")
+				);
+				for poll_id in poll_ids.iter()
+				{
+					let Some(poll) = Self::polls(*poll_id) else {
+						return Err(TryRuntimeError::Other("Coordinator poll id does not correspond to a poll"));
+					};
+					ensure!(
+						poll.coordinator == who,
+						TryRuntimeError::Other("Coordinator poll id is owned by another coordinator")
+					);
+				}
+				poll_count = poll_count.saturating_add(poll_ids.len() as u32);
+			}
+			ensure!(
+				poll_count == Polls::<T, I>::count(),
+				TryRuntimeError::Other("Poll count does not match the number of coordinator poll ids")
+			);
+
+			// A coordinators most recent poll must exist.
+			for (_, coordinator) in Coordinators::<T, I>::iter()
+			{
+				if let Some(poll_id) = coordinator.last_poll
+				{
+					ensure!(
+						Polls::<T, I>::contains_key(poll_id),
+						TryRuntimeError::Other("Coordinator last poll does not correspond to a poll")
+					);
+				}
+			}
+
+			// Each keeper queue must hold a poll at most once, and may only leave polls in the backlog once full.
+			for action in [KeeperAction::Merge, KeeperAction::NullificationCheck, KeeperAction::Reap]
+			{
+				let queue = Self::keeper_queue(action);
+				let mut sorted = queue.clone().into_inner();
+				sorted.sort();
+				sorted.dedup();
+				ensure!(
+					sorted.len() == queue.len() && queue.iter().all(|poll_id| !KeeperBacklog::<T, I>::contains_key(action, poll_id)),
+					TryRuntimeError::Other("Keeper queue holds a poll more than once")
+				);
+				ensure!(
+					queue.is_full() || KeeperBacklog::<T, I>::iter_key_prefix(action).next().is_none(),
+					TryRuntimeError::Other("Keeper backlog holds a poll while its queue has room")
+				);
+			}
+
+			Ok(())
+		}
+
+		/// Ensure that the poll currently accepts registrations: it was activated, was not nullified, is within its
+		/// signup period, has room for another registration, and its fee allowance (if any) covers the call.
 		///
-		/// Emits `CoordinatorKeyChanged`.
-		#[pallet::call_index(1)]
-		#[pallet::weight(T::DbWeight::get().reads_writes(2, 1))]
-		pub fn rotate_keys(
-			origin: OriginFor<T>,
-			public_key: PublicKey,
-			verify_key: VerifyingKeys
+		/// The `can_*` checks gate both the extrinsics and `allowed_actions`, such that the two never disagree. The
+		/// checks of the hot extrinsics take the phase cache and state tree of the poll rather than the whole poll.
+		pub(crate) fn can_register(
+			poll_id: T::PollId,
+			phase: &PollPhaseCache<T::AccountId>,
+			registrations: &PollStateTree
+		) -> DispatchResult
+		{
+			let now = <frame_system::Pallet<T>>::block_number().saturated_into::<u64>();
+			ensure!(!phase.is_pending(), Error::<T, I>::PollPending);
+			ensure!(!phase.tombstone, Error::<T, I>::PollRegistrationHasEnded);
+			ensure!(phase.is_registration_period(now), Error::<T, I>::PollRegistrationHasEnded);
+			ensure!(!phase.registration_limit_reached(registrations.count), Error::<T, I>::ParticipantRegistrationLimitReached);
+
+			Self::ensure_fee_allowance(poll_id, Self::register_as_participant_weight())
+		}
+
+		/// Ensure that the poll currently accepts interactions: it is within its interaction period, has room for
+		/// another interaction, and its fee allowance (if any) covers the call.
+		pub(crate) fn can_interact(
+			poll_id: T::PollId,
+			phase: &PollPhaseCache<T::AccountId>,
+			interactions: &PollStateTree
+		) -> DispatchResult
+		{
+			Self::ensure_interaction_period(phase)?;
+			ensure!(!phase.interaction_limit_reached(interactions.count), Error::<T, I>::ParticipantInteractionLimitReached);
+
+			Self::ensure_fee_allowance(poll_id, Self::interact_with_poll_weight(interactions.full_depth.into()))
+		}
+
+		/// Ensure that `who` may signal their intent to vote in the poll: it was activated, is within its signup
+		/// period, collects intents, and `who` has not yet signalled.
+		pub(crate) fn can_signal_intent(
+			poll_id: T::PollId,
+			phase: &PollPhaseCache<T::AccountId>,
+			who: &T::AccountId
+		) -> DispatchResult
+		{
+			let now = <frame_system::Pallet<T>>::block_number().saturated_into::<u64>();
+			ensure!(!phase.is_pending(), Error::<T, I>::PollPending);
+			ensure!(phase.is_registration_period(now), Error::<T, I>::PollRegistrationHasEnded);
+			ensure!(PollIntents::<T, I>::contains_key(poll_id), Error::<T, I>::PollIntentsNotCollected);
+			ensure!(!IntentSignals::<T, I>::contains_key(poll_id, who), Error::<T, I>::IntentAlreadySignaled);
+
+			Ok(())
+		}
+
+		/// Ensure that either state tree of the poll may be merged: the registration tree once the signup period
+		/// ended, and the interaction tree once the poll was sealed. The registration tree must hold at least one
+		/// registration, whereas the interaction tree may be empty, such that a poll without votes may conclude.
+		pub(crate) fn can_merge(poll: &Poll<T, I>) -> DispatchResult
+		{
+			ensure!(!poll.is_registration_period(), Error::<T, I>::PollRegistrationInProgress);
+
+			if poll.state.registrations.root.is_none()
+			{
+				ensure!(poll.state.registrations.count > 0, Error::<T, I>::PollDataEmpty);
+			}
+
+			else if poll.state.interactions.root.is_none()
+			{
+				ensure!(poll.is_over(), Error::<T, I>::PollVotingInProgress);
+				ensure!(poll.is_sealed(), Error::<T, I>::PollNotSealed);
+			}
+
+			// Poll data has already been merged.
+			else { Err(<Error::<T, I>>::PollDataEmpty)? }
+
+			Ok(())
+		}
+
+		/// Ensure that proofs and the outcome of the poll may be committed, i.e. both of its state trees are merged
+		/// and its outcome has not been determined.
+		pub(crate) fn can_commit_outcome(poll: &Poll<T, I>) -> DispatchResult
+		{
+			ensure!(poll.is_merged(), Error::<T, I>::PollStateNotMerged);
+			ensure!(!poll.is_fulfilled(), Error::<T, I>::PollOutcomeAlreadyDetermined);
+
+			Ok(())
+		}
+
+		/// Ensure that `who` coordinates the poll, and that every proof of the poll has been verified, such that its
+		/// outcome may be staged.
+		pub(crate) fn can_stage_outcome(poll: &Poll<T, I>, who: &T::AccountId) -> DispatchResult
+		{
+			ensure!(poll.coordinator == *who, Error::<T, I>::NotPollCoordinator);
+			Self::can_commit_outcome(poll)?;
+			ensure!(poll.is_proven(), Error::<T, I>::PollNotProven);
+
+			Ok(())
+		}
+
+		/// Returns the staged outcome of the poll, unless none was staged or it was abandoned.
+		fn ensure_staged_outcome(poll_id: T::PollId) -> Result<StagedOutcome<T, I>, DispatchError>
+		{
+			let Some(staged) = StagedOutcomes::<T, I>::get(poll_id) else { Err(<Error::<T, I>>::OutcomeNotStaged)? };
+
+			let now = <frame_system::Pallet<T>>::block_number().saturated_into::<u64>();
+			ensure!(now <= staged.expires_at, Error::<T, I>::OutcomeStagingExpired);
+
+			Ok(staged)
+		}
+
+		/// Fulfill the poll with its verified outcome: record the outcome, prune the poll data, and dispatch the call
+		/// attached to the winning option.
+		fn finalize_poll(
+			poll_id: T::PollId,
+			poll: &mut Poll<T, I>,
+			coordinator: &T::AccountId,
+			outcome_index: OutcomeIndex,
+			outcome: OutcomeRecord<T, I>
+		)
+		{
+			poll.state.outcome = Some(outcome_index);
+
+			let participants = u64::from(poll.state.registrations.count);
+			CoordinatorStats::<T, I>::mutate(coordinator, |record| {
+				record.polls_finalized = record.polls_finalized.saturating_add(1);
+				record.participants_served = record.participants_served.saturating_add(participants);
+			});
+
+			// Take the call attached to the winning option before the poll data is pruned.
+			let option_call = PollOptionCalls::<T, I>::take(poll_id, outcome_index);
+			Self::prune_poll_data(poll_id, coordinator);
+
+			// Retain the tally results and commitments of the outcome, so that its verification may be replayed.
+			PollOutcomes::<T, I>::insert(poll_id, outcome);
+
+			Self::deposit_event(Event::PollOutcome { 
+				poll_id,
+				outcome_index,
+				weight_consumed: PollResourceUsage::<T, I>::get(poll_id)
+			});
+
+			T::OnPollFinalized::on_finalized(poll_id, outcome_index);
+
+			if let Some(call) = option_call { Self::execute_option_call(poll_id, outcome_index, call); }
+		}
+
+		/// Ensure that the poll may be nullified, i.e. its signup period ended without registrations, or its voting
+		/// period ended without interactions.
+		pub(crate) fn can_nullify(poll: &Poll<T, I>) -> DispatchResult
+		{
+			// A nullified poll still satisfies the conditions below, but its data was already pruned.
+			ensure!(!poll.is_nullified(), Error::<T, I>::PollAlreadyNullified);
+			ensure!(
+				(!poll.is_registration_period() && poll.state.registrations.count == 0) ||
+				(poll.is_over() && poll.state.interactions.count == 0),
+				Error::<T, I>::PollCurrentlyActive
+			);
+
+			Ok(())
+		}
+
+		/// Ensure that `who` coordinates the poll, and that the poll awaits activation.
+		pub(crate) fn can_activate(poll: &Poll<T, I>, who: &T::AccountId) -> DispatchResult
+		{
+			ensure!(poll.coordinator == *who, Error::<T, I>::NotPollCoordinator);
+			ensure!(poll.is_pending(), Error::<T, I>::PollNotPending);
+
+			Ok(())
+		}
+
+		/// Ensure that the poll still awaits activation, and that its activation timeout has elapsed.
+		pub(crate) fn can_reap(poll: &Poll<T, I>) -> DispatchResult
+		{
+			ensure!(poll.is_pending(), Error::<T, I>::PollNotPending);
+
+			let now = <frame_system::Pallet<T>>::block_number().saturated_into::<u64>();
+			ensure!(
+				now >= poll.created_at.saturating_add(T::ActivationTimeout::get()),
+				Error::<T, I>::PollActivationNotExpired
+			);
+
+			Ok(())
+		}
+
+		/// Ensure that `who` coordinates the poll, that its voting has closed, that its outcome has not been
+		/// determined, and that no proof has been verified against its current keys.
+		pub(crate) fn can_replace_verify_key(poll: &Poll<T, I>, who: &T::AccountId) -> DispatchResult
+		{
+			ensure!(poll.coordinator == *who, Error::<T, I>::NotPollCoordinator);
+			ensure!(poll.is_over(), Error::<T, I>::PollVotingInProgress);
+			ensure!(!poll.is_fulfilled(), Error::<T, I>::PollOutcomeAlreadyDetermined);
+			ensure!(
+				poll.state.commitment.process.completed == 0 && poll.state.commitment.tally.completed == 0,
+				Error::<T, I>::ProvingAlreadyStarted
+			);
+
+			Ok(())
+		}
+
+		/// Ensure that the proof batches start at the next proof of the poll. Batches which only resubmit verified proofs
+		/// with their verified commitments fail with `ProofAlreadyAccepted`, for which no fee is charged.
+		fn ensure_next_proof(
+			poll_id: T::PollId,
+			poll: &Poll<T, I>,
+			proof_index: CommitmentIndex,
+			batches: &[(ProofData, CommitmentData)]
+		) -> Result<(), DispatchErrorWithPostInfo>
+		{
+			let next_index = poll.state.commitment.completed();
+			if proof_index == next_index { return Ok(()); }
+
+			// Check that the batches neither skip proofs nor extend past the verified proofs.
+			let count = batches.len().saturated_into::<CommitmentIndex>();
+			ensure!(proof_index.saturating_add(count) <= next_index, Error::<T, I>::ProofIndexMismatch);
+
+			// Check that each proof was verified with the same commitment.
+			for (index, (_, new_commitment)) in (proof_index..).zip(batches.iter())
+			{
+				let Some(commitment) = ProofCommitments::<T, I>::get(poll_id, index) else { Err(<Error::<T, I>>::ProofIndexMismatch)? };
+				ensure!(commitment == *new_commitment, Error::<T, I>::ConflictingProofCommitment);
+			}
+
+			Err(DispatchErrorWithPostInfo {
+				post_info: Pays::No.into(),
+				error: Error::<T, I>::ProofAlreadyAccepted.into()
+			})
+		}
+
+		/// Ensure that each call attached to a vote option names a distinct option of the poll, decodes, and is
+		/// within `MaxOptionCallWeight`.
+		fn ensure_valid_option_calls(config: &PollConfigOf<T, I>) -> DispatchResult
+		{
+			let mut attached: vec::Vec<bool> = vec::Vec::new();
+			attached.resize(config.vote_options.len(), false);
+			for (option_index, call) in config.option_calls.iter()
+			{
+				// Check that the option exists, and has no other call attached.
+				let Some(is_attached) = attached.get_mut(*option_index as usize) else { Err(<Error::<T, I>>::OptionCallInvalid)? };
+				ensure!(!*is_attached, Error::<T, I>::OptionCallInvalid);
+				*is_attached = true;
+
+				let Some(call) = Self::decode_option_call(call) else { Err(<Error::<T, I>>::OptionCallInvalid)? };
+				ensure!(
+					call.get_dispatch_info().weight.all_lte(T::MaxOptionCallWeight::get()),
+					Error::<T, I>::OptionCallTooHeavy
+				);
+			}
+
+			Ok(())
+		}
+
+		fn decode_option_call(call: &OptionCall<T, I>) -> Option<<T as Config<I>>::RuntimeCall>
+		{
+			<T as Config<I>>::RuntimeCall::decode_all_with_depth_limit(MAX_OPTION_CALL_DEPTH, &mut &call[..]).ok()
+		}
+
+		/// Dispatch the call attached to the winning vote option of a poll with `OutcomeOrigin`. A call which fails
+		/// is reverted and published with `OutcomeExecutionFailed`, without reverting the outcome of the poll.
+		fn execute_option_call(poll_id: T::PollId, outcome_index: OutcomeIndex, call: OptionCall<T, I>)
+		{
+			let result = match Self::decode_option_call(&call)
+			{
+				Some(call) => frame_support::storage::with_storage_layer(|| {
+					call.dispatch(T::OutcomeOrigin::get().into())
+						.map(|_| ())
+						.map_err(|e| e.error)
+				}),
+				None => Err(Error::<T, I>::OptionCallInvalid.into())
+			};
+
+			match result
+			{
+				Ok(()) => Self::deposit_event(Event::OutcomeExecuted {
+					poll_id,
+					outcome_index
+				}),
+				Err(error) => Self::deposit_event(Event::OutcomeExecutionFailed {
+					poll_id,
+					outcome_index,
+					error
+				})
+			}
+		}
+
+		/// Ensure that a poll which exceeded `MaxProofFailures` has cooled off since its last failed proof.
+		fn ensure_proof_cooldown_elapsed(poll_id: T::PollId) -> DispatchResult
+		{
+			let failures = FailedProofAttempts::<T, I>::get(poll_id);
+			if failures.attempts <= T::MaxProofFailures::get() { return Ok(()); }
+
+			let now = <frame_system::Pallet<T>>::block_number().saturated_into::<u64>();
+			ensure!(
+				now >= failures.last_failed_at.saturating_add(T::ProofFailureCooldown::get()),
+				Error::<T, I>::ProofCooldownActive
+			);
+
+			Ok(())
+		}
+
+		/// Record a proof of the poll which failed to verify.
+		fn record_proof_failure(poll_id: T::PollId, kind: ProofKind)
+		{
+			let now = <frame_system::Pallet<T>>::block_number().saturated_into::<u64>();
+			let attempt = FailedProofAttempts::<T, I>::mutate(poll_id, |failures| {
+				failures.attempts = failures.attempts.saturating_add(1);
+				failures.last_failed_at = now;
+				failures.attempts
+			});
+
+			Self::deposit_event(Event::ProofRejected {
+				poll_id,
+				kind,
+				attempt
+			});
+		}
+
+		/// Ensure that the poll was activated by its coordinator, has not been nullified, and is currently within its
+		/// voting period, or its signup period if the poll permits it.
+		fn ensure_interaction_period(phase: &PollPhaseCache<T::AccountId>) -> DispatchResult
+		{
+			let now = <frame_system::Pallet<T>>::block_number().saturated_into::<u64>();
+			ensure!(!phase.is_pending(), Error::<T, I>::PollPending);
+			// A poll without registrations may be nullified before its voting period is over.
+			ensure!(!phase.tombstone, Error::<T, I>::PollVotingHasEnded);
+			ensure!(
+				phase.allow_early_interactions || !phase.is_registration_period(now),
+				Error::<T, I>::PollRegistrationInProgress
+			);
+			ensure!(!phase.is_over(now), Error::<T, I>::PollVotingHasEnded);
+
+			Ok(())
+		}
+
+		/// Ensure that the message and ephemeral public key of an interaction are acceptable to the poll.
+		fn ensure_valid_interaction(
+			poll_id: T::PollId,
+			phase: &PollPhaseCache<T::AccountId>,
+			public_key: &PublicKey,
+			cipher: &PollInteractionCipher
 		) -> DispatchResult
 		{
-			// Check that the extrinsic was signed and get the signer.
-			let sender = ensure_signed(origin)?;
+			// Check that the message consists of the number of words the poll expects.
+			ensure!(
+				cipher.is_well_formed() && cipher.words == phase.message_words,
+				Error::<T, I>::MessageLengthMismatch
+			);
+
+			// Ensure the public key is a point in the prime order subgroup.
+			Self::ensure_valid_public_key(public_key)?;
+
+			// Ensure the ephemeral key differs from the key of the coordinator, if the poll requires it.
+			if phase.reject_coordinator_key
+			{
+				let Some(coordinator_key) = PollCoordinatorPubKey::<T, I>::get(poll_id) else { Err(<Error::<T, I>>::CoordinatorNotRegistered)? };
+				ensure!(*public_key != coordinator_key, Error::<T, I>::InteractionKeyIsCoordinatorKey);
+			}
+
+			Ok(())
+		}
+
+		/// Insert an interaction into the interaction tree of the poll, and emit its data for future processing by
+		/// the coordinator. Returns the updated tree, which is not written to storage.
+		fn insert_interaction(
+			poll_id: T::PollId,
+			tree: PollStateTree,
+			public_key: PublicKey,
+			cipher: PollInteractionCipher
+		) -> Result<PollStateTree, DispatchError>
+		{
+			let (count, leaf, tree) = insert_interaction_leaf(tree, public_key, &cipher)
+				.map_err(Error::<T, I>::from)?;
+			let data = PollInteractionData::from(cipher);
+
+			// Store only the leaf of an interaction with a compact poll, and emit its data for the coordinator to
+			// capture off-chain.
+			let compact = CompactInteractionPolls::<T, I>::contains_key(poll_id);
+			if compact { InteractionLeaves::<T, I>::insert(poll_id, count - 1, leaf); }
+
+			// Emit the interaction data for future processing by the coordinator.
+			if compact || T::EmitFullInteractionData::get()
+			{
+				Self::deposit_event(Event::PollInteraction {
+					poll_id,
+					count,
+					public_key,
+					leaf,
+					data
+				});
+			}
+
+			// Otherwise store the interaction data and only emit its hash.
+			else
+			{
+				let index = count - 1;
+				let hash = sp_io::hashing::blake2_256(&(public_key, &data).encode());
+				Messages::<T, I>::insert(poll_id, index, (public_key, data));
+
+				Self::deposit_event(Event::PollInteractionStored {
+					poll_id,
+					index,
+					public_key,
+					leaf,
+					hash
+				});
+			}
+
+			Ok(tree)
+		}
+
+		/// Emits `PollMergeCompleted` iff the roots of both state trees of the poll are available. Only called
+		/// after a merge, such that the event is emitted once per poll.
+		fn try_emit_merge_completed(poll_id: T::PollId, poll: &Poll<T, I>, public_key: &PublicKey)
+		{
+			let (Some(registration_root), Some(interaction_root)) = (
+				poll.state.registrations.root,
+				poll.state.interactions.root
+			) else { return; };
 
-			// Ensure the verification keys can be serialized as affine points.
-			ensure!(serialize_vkey(verify_key.process.clone()).is_some(), Error::<T>::MalformedKeys);
-			ensure!(serialize_vkey(verify_key.tally.clone()).is_some(), Error::<T>::MalformedKeys);
+			let Some(coord_pub_key_hash) = compute_coordinator_key_hash(
+				public_key,
+				PollCoordKeyHash::<T, I>::get(poll_id)
+			) else { return; };
 
-			// Check if origin is registered as a coordinator.
-			let Some(mut coordinator) = Coordinators::<T>::get(&sender) else { Err(<Error::<T>>::CoordinatorNotRegistered)? };
+			Self::deposit_event(Event::PollMergeCompleted {
+				poll_id,
+				registration_root,
+				interaction_root,
+				coord_pub_key_hash
+			});
+		}
 
-			// Ensure that the most recent poll is not currently in progress and is not missing an outcome, if it exists.
-			if let Some(index) = coordinator.last_poll
+		/// Schedules `PollVotingPeriodStarted` for the first block of the voting period of the poll, unless the voting
+		/// period already commenced, e.g. for a poll without a signup period. Rejects if the block is full.
+		fn schedule_voting_start(poll_id: T::PollId, starts_at: BlockNumber) -> DispatchResult
+		{
+			let now = <frame_system::Pallet<T>>::block_number().saturated_into::<u64>();
+			if starts_at > now
+			{
+				PollVotingStarts::<T, I>::try_append(starts_at, poll_id).map_err(|_| Error::<T, I>::PollScheduleFull)?;
+			}
+
+			Ok(())
+		}
+
+		/// Emits `PollVotingPeriodStarted` at once if the voting period of the poll already commenced, such that it
+		/// was not scheduled by `schedule_voting_start`. Called once the poll is stored.
+		fn emit_voting_start_if_commenced(poll_id: T::PollId, starts_at: BlockNumber)
+		{
+			let now = <frame_system::Pallet<T>>::block_number().saturated_into::<u64>();
+			if starts_at <= now { Self::try_emit_voting_started(poll_id); }
+		}
+
+		/// Emits `PollVotingPeriodStarted` unless it was emitted for the poll before, and enqueues the poll for a merge
+		/// of its registration tree, or for nullification should it have no registrations.
+		fn try_emit_voting_started(poll_id: T::PollId)
+		{
+			if PollVotingStartedEmitted::<T, I>::contains_key(poll_id) { return; }
+			let Some(voting_period_end) = PollVotingPeriodEnd::<T, I>::get(poll_id) else { return; };
+
+			PollVotingStartedEmitted::<T, I>::insert(poll_id, ());
+			Self::deposit_event(Event::PollVotingPeriodStarted {
+				poll_id,
+				voting_period_end
+			});
+
+			let registrations = Self::get_registration_tree(poll_id).map_or(0, |tree| tree.count);
+			let action = if registrations == 0 { KeeperAction::NullificationCheck } else { KeeperAction::Merge };
+			Self::enqueue_keeper_action(action, poll_id);
+		}
+
+		/// Applies the poll transitions scheduled for `block` for as long as the meter permits, and writes back those
+		/// which remain. Returns true iff every transition of the block was applied.
+		fn apply_transitions(block: BlockNumber, meter: &mut WeightMeter) -> bool
+		{
+			// Taking each of the schedules of the block, and writing back its remainder, reads and writes it.
+			let db = T::DbWeight::get();
+			if meter.try_consume(Self::transition_schedules_weight()).is_err() { return false; }
+
+			// Signal that voting opened for every poll whose signup period ended. Announcing a poll reads its
+			// registration tree, and enqueueing it reads and writes its keeper queue and backlog.
+			let started_ids = PollVotingStarts::<T, I>::take(block);
+			let started = Self::apply_metered(started_ids.into_inner(), meter, db.reads_writes(5, 3), Self::try_emit_voting_started);
+			if !started.is_empty()
 			{
-				if let Some(poll) = Polls::<T>::get(index)
+				PollVotingStarts::<T, I>::insert(block, BoundedVec::truncate_from(started));
+				return false;
+			}
+
+			// Enqueue every pending poll whose activation timeout elapsed, unless it was activated since.
+			let expired_ids = PollActivationExpiries::<T, I>::take(block);
+			let expired = Self::apply_metered(expired_ids.into_inner(), meter, db.reads_writes(3, 2), |poll_id| {
+				if PollPhases::<T, I>::get(poll_id).map_or(false, |phase| phase.is_pending())
 				{
-					ensure!(
-						poll.is_over() && poll.is_fulfilled(),
-						Error::<T>::PollCurrentlyActive
-					);
+					Self::enqueue_keeper_action(KeeperAction::Reap, poll_id);
 				}
+			});
+			if !expired.is_empty()
+			{
+				PollActivationExpiries::<T, I>::insert(block, BoundedVec::truncate_from(expired));
+				return false;
 			}
 
-			coordinator.public_key = public_key.clone();
-			coordinator.verify_key = verify_key.clone();
+			// Seal every poll whose voting period ended.
+			let closed_ids = PollClosures::<T, I>::take(block);
+			let closed = Self::apply_metered(closed_ids.into_inner(), meter, Self::seal_poll_weight(), Self::seal_poll);
+			if !closed.is_empty()
+			{
+				// The remainder is a suffix of the schedule, and therefore within its bound.
+				PollClosures::<T, I>::insert(block, BoundedVec::truncate_from(closed));
+				return false;
+			}
 
-			// Update and store the coordinators updated key(s).
-			Coordinators::<T>::insert(&sender, coordinator);
-	
-			// Emit the key rotation event.
-			Self::deposit_event(Event::CoordinatorKeysChanged {
-				who: sender,
-				public_key,
-				verify_key
+			true
+		}
+
+		/// The weight of taking the three schedules of a block in `apply_transitions`.
+		fn transition_schedules_weight() -> Weight
+		{
+			T::DbWeight::get().reads_writes(3, 3)
+		}
+
+		/// The weight of sealing a poll, the greatest of any transition. Reading a poll reads its state trees, and
+		/// storing it refreshes its phase cache.
+		fn seal_poll_weight() -> Weight
+		{
+			T::DbWeight::get().reads_writes(5, 4)
+		}
+
+		/// The least weight with which `on_initialize` makes progress: reading and writing its cursor, the schedules
+		/// of a block, and a single transition.
+		fn min_initialize_weight() -> Weight
+		{
+			T::DbWeight::get().reads_writes(1, 1)
+				.saturating_add(Self::transition_schedules_weight())
+				.saturating_add(Self::seal_poll_weight())
+		}
+
+		/// Applies `transition` to each poll in order for as long as the meter permits `weight` per poll, and returns
+		/// the polls which remain.
+		fn apply_metered(
+			poll_ids: vec::Vec<T::PollId>,
+			meter: &mut WeightMeter,
+			weight: Weight,
+			mut transition: impl FnMut(T::PollId)
+		) -> vec::Vec<T::PollId>
+		{
+			for (index, poll_id) in poll_ids.iter().enumerate()
+			{
+				if meter.try_consume(weight).is_err() { return poll_ids[index..].to_vec(); }
+				transition(*poll_id);
+			}
+
+			vec::Vec::new()
+		}
+
+		/// Seals a poll whose voting period ended, enqueues it for the keeper action it permits, and emits its turnout.
+		fn seal_poll(poll_id: T::PollId)
+		{
+			let Some(mut poll) = Self::polls(poll_id) else { return; };
+			let registrations = poll.state.registrations.count;
+			let interactions = poll.state.interactions.count;
+			let tombstone = poll.state.tombstone;
+
+			poll.state.sealed = true;
+			Self::insert_poll(poll_id, poll);
+
+			// Unless it was already nullified, a poll without interactions may be nullified, whereas the interaction
+			// tree of any other may be merged.
+			if !tombstone
+			{
+				let action = if interactions == 0 { KeeperAction::NullificationCheck } else { KeeperAction::Merge };
+				Self::enqueue_keeper_action(action, poll_id);
+			}
+
+			Self::deposit_event(Event::PollClosed {
+				poll_id,
+				registrations,
+				interactions
 			});
+		}
 
-			Ok(())
+		/// The keeper queue of the given action.
+		pub fn keeper_queue(action: KeeperAction) -> BoundedVec<T::PollId, T::MaxKeeperQueueLen>
+		{
+			match action
+			{
+				KeeperAction::Merge => NeedsMerge::<T, I>::get(),
+				KeeperAction::NullificationCheck => NeedsNullificationCheck::<T, I>::get(),
+				KeeperAction::Reap => Reapable::<T, I>::get()
+			}
+		}
+
+		/// Applies `f` to the keeper queue of the given action.
+		fn mutate_keeper_queue<R>(
+			action: KeeperAction,
+			f: impl FnOnce(&mut BoundedVec<T::PollId, T::MaxKeeperQueueLen>) -> R
+		) -> R
+		{
+			match action
+			{
+				KeeperAction::Merge => NeedsMerge::<T, I>::mutate(f),
+				KeeperAction::NullificationCheck => NeedsNullificationCheck::<T, I>::mutate(f),
+				KeeperAction::Reap => Reapable::<T, I>::mutate(f)
+			}
+		}
+
+		/// Enqueues the poll for the keeper action, unless it is already enqueued. Should the queue be full, the poll
+		/// is held in `KeeperBacklog` instead, such that no poll which permits the action is lost.
+		pub(crate) fn enqueue_keeper_action(action: KeeperAction, poll_id: T::PollId)
+		{
+			if KeeperBacklog::<T, I>::contains_key(action, poll_id) { return; }
+
+			Self::mutate_keeper_queue(action, |queue| {
+				if queue.contains(&poll_id) { return; }
+				if queue.try_push(poll_id).is_err() { KeeperBacklog::<T, I>::insert(action, poll_id, ()); }
+			});
+		}
+
+		/// Removes the poll from the queue of the keeper action and from the backlog, and moves an entry of the
+		/// backlog into the slot it freed.
+		pub(crate) fn dequeue_keeper_action(action: KeeperAction, poll_id: T::PollId)
+		{
+			KeeperBacklog::<T, I>::remove(action, poll_id);
+
+			Self::mutate_keeper_queue(action, |queue| {
+				let Some(position) = queue.iter().position(|id| *id == poll_id) else { return; };
+				queue.remove(position);
+
+				if let Some(next) = KeeperBacklog::<T, I>::iter_key_prefix(action).next()
+				{
+					KeeperBacklog::<T, I>::remove(action, next);
+					let _ = queue.try_push(next);
+				}
+			});
+		}
+
+		/// Insert the poll id into the poll ids of the coordinator, such that they remain sorted.
+		pub(crate) fn insert_poll_id(coordinator: &T::AccountId, poll_id: T::PollId)
+		{
+			CoordinatorPollIds::<T, I>::mutate(coordinator, |poll_ids| {
+				if let Err(position) = poll_ids.binary_search(&poll_id) { poll_ids.insert(position, poll_id); }
+			});
+		}
+
+		/// Archive the oldest poll ids of the coordinator beyond `MaxCoordinatorPolls` into `CoordinatorHistory`. Called
+		/// once the coordinator created a poll, at which point every other poll of theirs is finished, such that the
+		/// polls are not decoded. The most recent poll id is always retained.
+		pub(crate) fn archive_excess_poll_ids(coordinator: &T::AccountId)
+		{
+			let max_polls = (T::MaxCoordinatorPolls::get() as usize).max(1);
+			let mut poll_ids = CoordinatorPollIds::<T, I>::get(coordinator);
+			if poll_ids.len() <= max_polls { return; }
+
+			// Archived ids precede every retained id, so appending them keeps the history sorted.
+			for poll_id in poll_ids.drain(..poll_ids.len() - max_polls)
+			{
+				CoordinatorHistory::<T, I>::append(coordinator, poll_id);
+			}
+			CoordinatorPollIds::<T, I>::insert(coordinator, poll_ids);
+		}
+
+		/// Append the poll id to `RecentPolls`, evicting the oldest id if the buffer is full.
+		pub(crate) fn insert_recent_poll(poll_id: T::PollId)
+		{
+			RecentPolls::<T, I>::mutate(|recent_polls| {
+				if recent_polls.is_full() { recent_polls.remove(0); }
+				let _ = recent_polls.try_push(poll_id);
+			});
+		}
+
+		/// Adds the weight of a successful extrinsic to the resource usage of the poll which it is scoped to. The
+		/// write is reverted alongside the rest of the extrinsic should it fail, so only successful calls accrue.
+		pub(crate) fn record_resource_usage(poll_id: T::PollId, weight: Weight)
+		{
+			PollResourceUsage::<T, I>::mutate(poll_id, |usage| usage.saturating_accrue(weight));
+		}
+
+		/// The weight of `create_poll`, given the number of option calls attached to the poll.
+		pub(crate) fn create_poll_weight(option_calls: usize) -> Weight
+		{
+			T::DbWeight::get().reads_writes(12, 17)
+				.saturating_add(T::DbWeight::get().writes(option_calls as u64))
+		}
+
+		/// The weight of `merge_poll_state`, i.e. that of merging the more expensive of the two trees.
+		pub(crate) fn merge_poll_state_weight() -> Weight
+		{
+			T::WeightInfo::merge_registrations().max(T::WeightInfo::merge_interactions())
+				.saturating_add(T::DbWeight::get().reads_writes(5, 3))
+		}
+
+		/// The weight of `commit_outcome`, given the number of proof batches and whether an outcome is attached.
+		pub(crate) fn commit_outcome_weight(batches: usize, has_outcome: bool) -> Weight
+		{
+			T::WeightInfo::commit_outcome(T::MaxVoteOptions::get())
+				.saturating_add(
+					T::WeightInfo::verify_proof()
+						.saturating_add(T::DbWeight::get().writes(1))
+						.saturating_mul(batches as u64)
+				)
+				.saturating_add(T::DbWeight::get().reads_writes(4, 3))
+				.saturating_add(
+					// Finalizing the poll dispatches its option call, dequeues it, records its prune cursor, releases its
+					// circuit, and stores its outcome.
+					if has_outcome
+					{
+						T::MaxOptionCallWeight::get()
+							.saturating_add(Self::keeper_dequeue_weight())
+							.saturating_add(T::DbWeight::get().reads_writes(2, 3))
+					}
+					else { Weight::zero() }
+				)
+		}
+
+		/// The weight of `commit_tally_proofs`, given the number of proof batches.
+		pub(crate) fn commit_tally_proofs_weight(batches: usize) -> Weight
+		{
+			T::WeightInfo::verify_proofs_batched(batches as u32)
+				.saturating_add(T::DbWeight::get().writes(batches as u64))
+				.saturating_add(T::DbWeight::get().reads_writes(6, 2))
+		}
+
+		/// The weight of `nullify_poll`.
+		pub(crate) fn nullify_poll_weight() -> Weight
+		{
+			T::DbWeight::get().reads_writes(9, 9)
+				.saturating_add(Self::keeper_dequeue_weight())
+		}
+
+		/// The weight of `register_as_participant`.
+		pub(crate) fn register_as_participant_weight() -> Weight
+		{
+			T::WeightInfo::register_as_participant()
+				.saturating_add(T::DbWeight::get().reads_writes(2, 2))
+				.saturating_add(Self::charge_fee_allowance_weight())
+		}
+
+		/// The weight of `interact_with_poll`, given the depth of the interaction tree of the poll.
+		pub(crate) fn interact_with_poll_weight(depth: u32) -> Weight
+		{
+			T::WeightInfo::interact_with_poll(depth)
+				.saturating_add(T::DbWeight::get().reads_writes(2, 2))
+				.saturating_add(Self::charge_fee_allowance_weight())
+		}
+
+		/// The weight of `register_circuit`, given the verifying keys. At most `MAX_PUBLIC_INPUTS + 1` points of the
+		/// `gamma_abc_g1` of either key are deserialized, as larger keys are rejected beforehand.
+		pub(crate) fn register_circuit_weight(verify_key: &VerifyingKeys) -> Weight
+		{
+			let points = [&verify_key.process, &verify_key.tally]
+				.iter()
+				.map(|key| key.gamma_abc_g1.len().min(MAX_PUBLIC_INPUTS + 1))
+				.sum::<usize>();
+
+			T::WeightInfo::register_circuit(points as u32)
+		}
+
+		/// The weight of `remove_circuit`.
+		pub(crate) fn remove_circuit_weight() -> Weight
+		{
+			T::WeightInfo::remove_circuit()
+		}
+
+		/// The weight of drawing a fee from the fee allowance of a poll: the sponsored calls of the caller, and the
+		/// named reserves and account of the coordinator.
+		fn charge_fee_allowance_weight() -> Weight
+		{
+			T::DbWeight::get().reads_writes(3, 3)
+		}
+
+		/// The weight of `activate_poll`.
+		pub(crate) fn activate_poll_weight() -> Weight
+		{
+			T::DbWeight::get().reads_writes(6, 9)
+		}
+
+		/// The weight of `reap_poll`.
+		pub(crate) fn reap_poll_weight() -> Weight
+		{
+			T::DbWeight::get().reads_writes(8, 10)
+				.saturating_add(Self::keeper_dequeue_weight())
+		}
+
+		/// The weight of `replace_poll_verify_key`.
+		pub(crate) fn replace_poll_verify_key_weight() -> Weight
+		{
+			T::DbWeight::get().reads_writes(5, 6)
+		}
+
+		/// The weight of `interact_with_poll_batch`, given the number of interactions in the batch.
+		pub(crate) fn interact_with_poll_batch_weight(items: usize) -> Weight
+		{
+			T::WeightInfo::interact_with_poll_batch(items as u32)
+				.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+				.saturating_add(T::DbWeight::get().reads_writes(items as u64, items as u64))
+		}
+
+		/// The weight of `signal_intent`.
+		pub(crate) fn signal_intent_weight() -> Weight
+		{
+			T::DbWeight::get().reads_writes(4, 3)
+		}
+
+		/// The weight of `begin_outcome`.
+		pub(crate) fn begin_outcome_weight() -> Weight
+		{
+			T::DbWeight::get().reads_writes(4, 2)
+		}
+
+		/// The weight of `submit_outcome_chunk`, given the number of vote options in the chunk.
+		pub(crate) fn submit_outcome_chunk_weight(options: usize) -> Weight
+		{
+			T::WeightInfo::commit_outcome(options as u32)
+				.saturating_add(T::DbWeight::get().reads_writes(5, 2))
+		}
+
+		/// The weight of `finalize_outcome`.
+		pub(crate) fn finalize_outcome_weight() -> Weight
+		{
+			T::WeightInfo::commit_outcome(0)
+				.saturating_add(T::DbWeight::get().reads_writes(5, 5))
+				.saturating_add(T::MaxOptionCallWeight::get())
+				.saturating_add(Self::keeper_dequeue_weight())
+		}
+
+		/// The weight of dequeuing a concluded poll from every keeper queue, and from the backlog.
+		pub(crate) fn keeper_dequeue_weight() -> Weight
+		{
+			T::DbWeight::get().reads_writes(6, 6)
+		}
+
+		/// The depth of the deepest interaction tree which a poll may have, i.e. the largest depth at which the tree
+		/// holds at most `MaxPollInteractions` leaves.
+		pub(crate) fn max_interaction_depth() -> u32
+		{
+			let arity = T::InteractionTreeArity::get();
+			let mut depth = 0u32;
+			while arity.checked_pow(depth + 1).map_or(false, |capacity| capacity <= T::MaxPollInteractions::get()) { depth += 1; }
+
+			depth
+		}
+
+		/// Returns the public key snapshotted when the given poll was created, falling back to the current key of its
+		/// coordinator for polls created before the key was snapshotted.
+		fn poll_public_key(poll_id: T::PollId, coordinator: &Coordinator<T::PollId>) -> PublicKey
+		{
+			PollCoordinatorPubKey::<T, I>::get(poll_id).unwrap_or(coordinator.public_key)
+		}
+
+		/// Returns the public key and verifying keys against which the proofs of the given poll are verified. The
+		/// verifying keys are those of the circuit which the poll references, if any, and otherwise those snapshotted
+		/// when the poll was created, falling back to the current keys of its coordinator as for `poll_public_key`.
+		fn poll_keys(poll_id: T::PollId, coordinator: Coordinator<T::PollId>) -> Result<(PublicKey, VerifyingKeys), DispatchError>
+		{
+			let public_key = Self::poll_public_key(poll_id, &coordinator);
+			let verify_key = match PollCircuit::<T, I>::get(poll_id)
+			{
+				Some(circuit_id) => CircuitRegistry::<T, I>::get(circuit_id).ok_or(Error::<T, I>::CircuitNotFound)?,
+				None => PollVerifyKey::<T, I>::get(poll_id).unwrap_or(coordinator.verify_key)
+			};
+
+			Ok((public_key, verify_key))
+		}
+
+		/// Returns the poll alongside its state trees, which are stored apart from the poll, without their frontiers.
+		pub fn polls(poll_id: T::PollId) -> Option<Poll<T, I>>
+		{
+			let mut poll = Polls::<T, I>::get(poll_id)?;
+			if let Some(registrations) = PollRegistrationTree::<T, I>::get(poll_id) { poll.state.registrations = registrations; }
+			if let Some(interactions) = PollInteractionTree::<T, I>::get(poll_id) { poll.state.interactions = interactions; }
+
+			Some(poll)
+		}
+
+		/// Stores the poll and refreshes its phase cache. The state trees of the poll are not written, as they are
+		/// only updated where they change, i.e. by `insert_poll_with_frontiers` and the hot extrinsics. The stored
+		/// poll embeds only the shape of either tree, such that its trees are neither duplicated nor left stale;
+		/// polls stored before embed their trees until they are next written.
+		pub(crate) fn insert_poll(poll_id: T::PollId, mut poll: Poll<T, I>)
+		{
+			PollPhases::<T, I>::insert(poll_id, PollPhaseCache::from(&poll));
+
+			poll.state.registrations = poll.state.registrations.shape();
+			poll.state.interactions = poll.state.interactions.shape();
+			Polls::<T, I>::insert(poll_id, poll);
+		}
+
+		/// Loads the poll, applies `f` to it, and stores the poll only if `f` succeeds, such that no error path of
+		/// an extrinsic writes the poll.
+		pub(crate) fn with_poll_mut<R>(
+			poll_id: T::PollId,
+			f: impl FnOnce(&mut Poll<T, I>) -> Result<R, DispatchError>
+		) -> Result<R, DispatchError>
+		{
+			let Some(mut poll) = Self::polls(poll_id) else { Err(<Error::<T, I>>::PollDoesNotExist)? };
+
+			let result = f(&mut poll)?;
+			Self::insert_poll(poll_id, poll);
+
+			Ok(result)
+		}
+
+		/// As `with_poll_mut`, for the poll alongside the frontiers of its state trees, which are stored alongside the
+		/// trees only if `f` succeeds.
+		pub(crate) fn with_poll_with_frontiers_mut<R>(
+			poll_id: T::PollId,
+			f: impl FnOnce(&mut Poll<T, I>) -> Result<R, DispatchError>
+		) -> Result<R, DispatchError>
+		{
+			let Some(mut poll) = Self::get_poll_with_frontiers(poll_id) else { Err(<Error::<T, I>>::PollDoesNotExist)? };
+
+			let result = f(&mut poll)?;
+			Self::insert_poll_with_frontiers(poll_id, poll);
+
+			Ok(result)
+		}
+
+		/// Returns the poll alongside the frontiers of its state trees, which are stored apart from the poll. Only
+		/// required to insert into or merge the trees.
+		pub(crate) fn get_poll_with_frontiers(poll_id: T::PollId) -> Option<Poll<T, I>>
+		{
+			let mut poll = Self::polls(poll_id)?;
+			poll.state.registrations.hashes = PollRegistrationFrontier::<T, I>::get(poll_id).unwrap_or_default();
+			poll.state.interactions.hashes = PollInteractionFrontier::<T, I>::get(poll_id).unwrap_or_default();
+
+			Some(poll)
+		}
+
+		/// Stores the poll and both of its state trees, moving the frontiers of the trees into their own storage.
+		/// Empty frontiers, e.g. those of merged trees, are removed.
+		pub(crate) fn insert_poll_with_frontiers(poll_id: T::PollId, mut poll: Poll<T, I>)
+		{
+			let registrations = sp_std::mem::take(&mut poll.state.registrations.hashes);
+			let interactions = sp_std::mem::take(&mut poll.state.interactions.hashes);
+
+			PollRegistrationFrontier::<T, I>::set(poll_id, Some(registrations).filter(|hashes| !hashes.is_empty()));
+			PollInteractionFrontier::<T, I>::set(poll_id, Some(interactions).filter(|hashes| !hashes.is_empty()));
+			PollRegistrationTree::<T, I>::insert(poll_id, &poll.state.registrations);
+			PollInteractionTree::<T, I>::insert(poll_id, &poll.state.interactions);
+			Self::insert_poll(poll_id, poll);
+		}
+
+		/// Returns the registration tree of the poll alongside its frontier, without reading the poll.
+		fn get_registration_tree(poll_id: T::PollId) -> Option<PollStateTree>
+		{
+			let mut tree = PollRegistrationTree::<T, I>::get(poll_id)?;
+			tree.hashes = PollRegistrationFrontier::<T, I>::get(poll_id).unwrap_or_default();
+
+			Some(tree)
 		}
 
-		/// Create a new poll object where the caller is the designated coordinator.
+		/// Returns the interaction tree of the poll alongside its frontier, without reading the poll.
+		fn get_interaction_tree(poll_id: T::PollId) -> Option<PollStateTree>
+		{
+			let mut tree = PollInteractionTree::<T, I>::get(poll_id)?;
+			tree.hashes = PollInteractionFrontier::<T, I>::get(poll_id).unwrap_or_default();
+
+			Some(tree)
+		}
+
+		/// Stores the registration tree of the poll, moving its frontier into its own storage.
+		fn insert_registration_tree(poll_id: T::PollId, mut tree: PollStateTree)
+		{
+			let hashes = sp_std::mem::take(&mut tree.hashes);
+
+			PollRegistrationFrontier::<T, I>::set(poll_id, Some(hashes).filter(|hashes| !hashes.is_empty()));
+			PollRegistrationTree::<T, I>::insert(poll_id, tree);
+		}
+
+		/// Stores the interaction tree of the poll, moving its frontier into its own storage.
+		fn insert_interaction_tree(poll_id: T::PollId, mut tree: PollStateTree)
+		{
+			let hashes = sp_std::mem::take(&mut tree.hashes);
+
+			PollInteractionFrontier::<T, I>::set(poll_id, Some(hashes).filter(|hashes| !hashes.is_empty()));
+			PollInteractionTree::<T, I>::insert(poll_id, tree);
+		}
+
+		/// Returns true iff the poll does not exist, or has ended and been fulfilled.
+		pub(crate) fn is_poll_finished(poll_id: T::PollId) -> bool
+		{
+			Self::polls(poll_id).map_or(true, |poll| poll.is_over() && poll.is_fulfilled())
+		}
+
+		/// Returns the poll with the given id, for pallets which compose with this pallet, e.g. to distribute
+		/// rewards based on its outcome.
 		///
-		/// - `signup_period`: The number of blocks for which the registration period is active.
-		/// - `voting_period`: The number of blocks for which the voting period is active.
-		/// - `max_registrations`: The maximum number of participants permitted.
-		/// - `vote_options`: The possible outcomes of the poll.
+		/// Nullified polls are included. A poll is nullified, i.e. its `tombstone` is set, if it ended without
+		/// interactions or was reaped before its activation. Its configuration and state are retained, but it
+		/// will never be processed nor produce an outcome. Use `get_active_poll` to exclude such polls.
 		///
-		/// Emits `PollCreated`.
-		#[pallet::call_index(2)]
-		#[pallet::weight(T::DbWeight::get().reads_writes(4, 3))]
-		pub fn create_poll(
-			origin: OriginFor<T>,
-			signup_period: BlockNumber,
-			voting_period: BlockNumber,
-			registration_depth: u8,
-			interaction_depth: u8,
-			process_subtree_depth: u8,
-			tally_subtree_depth: u8,
-			vote_option_tree_depth: u8,
-			vote_options: vec::Vec<u128>
-		) -> DispatchResult
+		/// The frontiers of the state trees are stored apart from the poll, and are therefore empty.
+		pub fn get_poll(poll_id: T::PollId) -> Option<Poll<T, I>>
+		{
+			Self::polls(poll_id)
+		}
+
+		/// Returns the poll with the given id, unless it has been nullified.
+		pub fn get_active_poll(poll_id: T::PollId) -> Option<Poll<T, I>>
+		{
+			Self::get_poll(poll_id).filter(|poll| !poll.is_nullified())
+		}
+
+		/// The number of interactions which are processed per proof of the given poll.
+		pub fn interaction_batch_size(poll_id: T::PollId) -> Option<u32>
+		{
+			Self::polls(poll_id)?.interaction_batch_size()
+		}
+
+		/// The number of registrations which are tallied per proof of the given poll.
+		pub fn tally_batch_size(poll_id: T::PollId) -> Option<u32>
+		{
+			Self::polls(poll_id)?.tally_batch_size()
+		}
+
+		/// Returns the ids of polls which have not been fulfilled, in ascending order, among the at most
+		/// `MAX_ACTIVE_POLLS_SCAN` poll ids from `start`. At most `limit` ids are returned, which is capped at
+		/// `MAX_ACTIVE_POLLS_PAGE`. Indexers may page through the results by resuming from one past the last
+		/// returned id if the page is full, and from `start + MAX_ACTIVE_POLLS_SCAN` otherwise, until `NextPollId`.
+		pub fn active_polls(start: T::PollId, limit: u32) -> vec::Vec<T::PollId>
+		{
+			let limit = limit.min(MAX_ACTIVE_POLLS_PAGE) as usize;
+			let end = NextPollId::<T, I>::get();
+
+			// Every examined id decodes its poll, so a page examines a bounded number of ids, however sparse the
+			// unfulfilled polls are.
+			sp_std::iter::successors(Some(start), |poll_id| poll_id.checked_add(&One::one()))
+				.take_while(|poll_id| *poll_id < end)
+				.take(MAX_ACTIVE_POLLS_SCAN as usize)
+				.filter(|poll_id| Self::polls(*poll_id).map_or(false, |poll| !poll.is_fulfilled()))
+				.take(limit)
+				.collect()
+		}
+
+		/// Returns the most recently created polls, most recent first, alongside their coordinator and phase at the
+		/// current block. At most `limit` polls are returned, which is capped at `RECENT_POLLS_CAPACITY`. Nullified
+		/// polls are included, marked with `PollPhase::Nullified`.
+		pub fn recent_poll_summaries(limit: u32) -> vec::Vec<PollSummary<T::AccountId, T::PollId>>
+		{
+			RecentPolls::<T, I>::get()
+				.iter()
+				.rev()
+				.take(limit.min(RECENT_POLLS_CAPACITY) as usize)
+				.filter_map(|poll_id| {
+					let poll = Self::polls(*poll_id)?;
+					let phase = poll.phase();
+
+					Some(PollSummary { poll_id: *poll_id, coordinator: poll.coordinator, phase })
+				})
+				.collect()
+		}
+
+		/// Returns the ids of the polls managed by the coordinator, in ascending order. Poll ids which were archived
+		/// to `CoordinatorHistory` are not included.
+		pub fn get_coordinator_poll_ids(who: T::AccountId) -> vec::Vec<T::PollId>
 		{
-			// Check that the extrinsic was signed and get the signer.
-			let sender = ensure_signed(origin)?;
+			CoordinatorPollIds::<T, I>::get(&who)
+		}
 
-			// Validate config parameters.
-			let created_at = <frame_system::Pallet<T>>::block_number().saturated_into::<u64>();
-			let max_registrations = 2_u32.pow(registration_depth.into());
-			ensure!(
-				max_registrations <= T::MaxPollRegistrations::get(),
-				Error::<T>::PollConfigInvalid
-			);
-			let max_interactions = 5_u32.pow(interaction_depth.into());
-			ensure!(
-				max_interactions <= T::MaxPollInteractions::get(),
-				Error::<T>::PollConfigInvalid
-			);
+		/// Returns the current public key, most recent poll and track record of the coordinator, or `None` if they
+		/// are not registered.
+		pub fn coordinator_info(who: T::AccountId) -> Option<CoordinatorInfo<T::PollId>>
+		{
+			let coordinator = Coordinators::<T, I>::get(&who)?;
 
-			ensure!(vote_options.len() > 1, Error::<T>::PollConfigInvalid);
-			let vote_options: VoteOptions<T> = vote_options
-				.try_into()
-				.map_err(|_| Error::<T>::PollConfigInvalid)?;
+			Some(CoordinatorInfo {
+				public_key: coordinator.public_key,
+				last_poll: coordinator.last_poll,
+				record: CoordinatorStats::<T, I>::get(&who)
+			})
+		}
 
-			// Check that sender is registered as a coordinator.
-			let Some(mut coordinator) = Coordinators::<T>::get(&sender) else { Err(<Error::<T>>::CoordinatorNotRegistered)? };
+		/// Returns the page with index `page` of the ids of the polls managed by the coordinator, in ascending order.
+		/// Each page holds `page_size` ids, which is capped at `MAX_COORDINATOR_POLL_IDS_PAGE`.
+		pub fn get_coordinator_poll_ids_paginated(who: T::AccountId, page: u32, page_size: u32) -> vec::Vec<T::PollId>
+		{
+			let page_size = page_size.min(MAX_COORDINATOR_POLL_IDS_PAGE) as usize;
 
-			let coord_poll_ids = Self::poll_ids(&sender);
+			CoordinatorPollIds::<T, I>::get(&who)
+				.into_iter()
+				.skip((page as usize).saturating_mul(page_size))
+				.take(page_size)
+				.collect()
+		}
 
-			// A coordinator may have at most `MaxCoordinatorPolls` polls, skipped if zero.
-			let max_polls = T::MaxCoordinatorPolls::get() as usize;
-			ensure!(
-				coord_poll_ids.len() < max_polls,
-				Error::<T>::CoordinatorPollLimitReached
-			);
+		/// Returns the registration leaves of the given poll in order of registration, starting from the registration
+		/// index `start`. At most `count` leaves are returned, which is capped at `MAX_REGISTRATION_LEAVES_PAGE`.
+		/// The registration tree is rebuilt by inserting the leaves after the zero leaf, which occupies the first
+		/// position of the tree. Empty unless `StoreRegistrationLeaves` is true.
+		pub fn registration_leaves(poll_id: T::PollId, start: u32, count: u32) -> vec::Vec<HashBytes>
+		{
+			let count = count.min(MAX_REGISTRATION_LEAVES_PAGE);
 
-			// A coordinator may only have a single active poll at a given time.
-			if let Some(index) = coord_poll_ids.last()
-			{
-				if let Some(poll) = Polls::<T>::get(index)
-				{
-					// Reject if last created poll is on-going, or has yet to be processed.
-					ensure!(
-						poll.is_over() && poll.is_fulfilled(),
-						Error::<T>::PollCurrentlyActive 
-					);
-				}
-			}
+			(start..start.saturating_add(count))
+				.map_while(|index| RegistrationLeaves::<T, I>::get(poll_id, index))
+				.collect()
+		}
 
-			// Insert the poll into storage.
-			let index = Polls::<T>::count();
-			Polls::<T>::insert(&index, Poll {
-				index,
-				created_at,
-				coordinator: sender.clone(),
-				state: PollState::new(
-					registration_depth,
-					interaction_depth
-				),
-				config: PollConfiguration {
-					signup_period,
-					voting_period,
-					max_registrations,
-					max_interactions,
-					process_subtree_depth,
-					tally_subtree_depth,
-					vote_option_tree_depth,
-					vote_options
-				}
-			});
+		/// Returns the stored interaction messages of the given poll in order of interaction, starting from the
+		/// interaction index `start`, such that clients may catch up on interactions without replaying past events.
+		/// At most `count` messages are returned, which is capped at `MAX_INTERACTION_MESSAGES_PAGE`. Empty unless
+		/// `EmitFullInteractionData` is false, and once the poll is fulfilled and its data pruned.
+		pub fn interaction_messages(poll_id: T::PollId, start: u32, count: u32) -> vec::Vec<(PublicKey, PollInteractionData)>
+		{
+			let count = count.min(MAX_INTERACTION_MESSAGES_PAGE);
 
-			coordinator.last_poll = Some(index);
-			Coordinators::<T>::insert(&sender, coordinator);
-			CoordinatorPollIds::<T>::append(&sender, index);
+			(start..start.saturating_add(count))
+				.map_while(|index| Messages::<T, I>::get(poll_id, index))
+				.collect()
+		}
 
-			// Emit the creation event.
-			let starts_at = created_at + signup_period;
-			let ends_at = starts_at + voting_period + 1;
-			Self::deposit_event(Event::PollCreated { 
-				coordinator: sender,
-				poll_id: index,
-				starts_at,
-				ends_at
-			});
+		/// Returns true iff the leaf is included at the registration index `index`, i.e. `count - 1` of its
+		/// `ParticipantRegistered` event, in the merged registration tree of the given poll. The path holds the
+		/// siblings of each level of the tree from the leaf upwards. False until the registration tree is merged.
+		pub fn verify_registration_inclusion(
+			poll_id: T::PollId,
+			index: u32,
+			leaf: HashBytes,
+			path: vec::Vec<vec::Vec<HashBytes>>
+		) -> bool
+		{
+			let Some(tree) = PollRegistrationTree::<T, I>::get(poll_id) else { return false; };
+			let Some(index) = index.checked_add(BLANK_STATE_LEAVES) else { return false; };
 
-			Ok(())
+			tree.verify_leaf(index, leaf, path)
 		}
 
-		/// Compute the roots of the current poll state trees. This operation must be performed prior to commiting the poll outcome. 
-		/// Registration tree may be merged as long as the registration period has elapsed, and the interaction tree may be merged 
-		/// as long as the voting period has elapsed. NB Coordinator's are required to call this extrinsic twice: once to merge the 
-		/// registration state tree, and once to merge the interaction state tree.
-		///
-		/// Emits `PollStateMerged`.
-		#[pallet::call_index(3)]
-		#[pallet::weight(T::DbWeight::get().reads_writes(2, 1))] 
-		pub fn merge_poll_state(
-			origin: OriginFor<T>
-		) -> DispatchResult
+		/// Returns the registration indices of the registrations which the account submitted to the given poll, from
+		/// which a reward pallet may settle the claim of the account. `None` unless the poll is finalized and the
+		/// account registered in it.
+		pub fn participation_receipt(poll_id: T::PollId, who: T::AccountId) -> Option<vec::Vec<u32>>
 		{
-			// Check that the extrinsic was signed and get the signer.
-			let sender = ensure_signed(origin)?;
-			
-			// Get the coordinators most recent poll.
-			let Some(coordinator) = Coordinators::<T>::get(&sender) else { Err(<Error::<T>>::CoordinatorNotRegistered)? };
-			let Some(poll_id) = coordinator.last_poll else { Err(<Error::<T>>::PollDoesNotExist)? };
-			let Some(poll) = Polls::<T>::get(poll_id) else { Err(<Error::<T>>::PollDoesNotExist)? };
+			if !PollOutcomes::<T, I>::contains_key(poll_id) { return None; }
 
-			// Check that the poll is not currently in the registration period.
-			ensure!(
-				!poll.is_registration_period(),
-				Error::<T>::PollRegistrationInProgress
-			);
+			let receipts = RegistrationReceipts::<T, I>::get(poll_id, &who);
+			if receipts.is_empty() { None } else { Some(receipts.into_inner()) }
+		}
 
-			if poll.state.registrations.root.is_none()
-			{
-				// Ensure that there was at least one registration.
-				ensure!(
-					poll.state.registrations.count > 0,
-					Error::<T>::PollDataEmpty
-				);
+		/// Returns the page with index `page` of the accounts which registered in the given poll, alongside the
+		/// registration indices of their registrations. Each page holds `page_size` accounts, which is capped at
+		/// `MAX_PARTICIPATION_RECEIPTS_PAGE`, in the order of their storage keys rather than of registration. Empty
+		/// unless the poll is finalized, such that the pages are stable.
+		pub fn participation_receipts(
+			poll_id: T::PollId,
+			page: u32,
+			page_size: u32
+		) -> vec::Vec<(T::AccountId, vec::Vec<u32>)>
+		{
+			if !PollOutcomes::<T, I>::contains_key(poll_id) { return vec::Vec::new(); }
 
-				// Compute the root of the registration tree and save it.
-				let poll = poll
-					.merge_registrations()
-					.map_err(|error| Error::<T>::PollMergeFailed { reason: error.into() })?;
+			let page_size = page_size.min(MAX_PARTICIPATION_RECEIPTS_PAGE) as usize;
 
-				Polls::<T>::insert(&poll_id, poll.clone());
+			RegistrationReceipts::<T, I>::iter_prefix(poll_id)
+				.skip((page as usize).saturating_mul(page_size))
+				.take(page_size)
+				.map(|(who, receipts)| (who, receipts.into_inner()))
+				.collect()
+		}
 
-				// Emit the hash event.
-				Self::deposit_event(Event::PollStateMerged {
-					poll_id,
-					registration_root: poll.state.registrations.root,
-					interaction_root: None
-				});
-			}
+		/// Returns true iff the public key is registered in the given poll. Only polls created with `unique_keys`
+		/// index the keys of their registrations, so this is false for the keys of other polls, and once the poll
+		/// is fulfilled.
+		pub fn is_key_registered(poll_id: T::PollId, public_key: PublicKey) -> bool
+		{
+			RegisteredKeys::<T, I>::contains_key(poll_id, public_key)
+		}
 
-			else if poll.state.interactions.root.is_none()
-			{
-				// Check that the poll is not currenltly in the voting period.
-				ensure!(
-					poll.is_over(),
-					Error::<T>::PollVotingInProgress
-				);
+		/// Returns the encoded public key (x followed by y) of the coordinator at the time the poll was created.
+		/// Off-chain provers must use this key rather than the current key of the coordinator, which may have
+		/// been rotated since.
+		pub fn coordinator_public_key_at_poll_creation(poll_id: T::PollId) -> Option<vec::Vec<u8>>
+		{
+			let public_key = PollCoordinatorPubKey::<T, I>::get(poll_id)?;
 
-				// Ensure that there was at least one interaction.
-				ensure!(
-					poll.state.interactions.count > 0,
-					Error::<T>::PollDataEmpty
-				);
+			Some([public_key.x, public_key.y].concat())
+		}
 
-				// Compute the root of the interaction tree and save it.
-				let poll = poll
-					.merge_interactions()
-					.map_err(|error| Error::<T>::PollMergeFailed { reason: error.into() })?;
+		/// Returns the depths of the registration and interaction trees of the given poll, which parameterize
+		/// its circuits, without decoding the poll.
+		pub fn poll_tree_depths(poll_id: T::PollId) -> Option<(u8, u8)>
+		{
+			let registration_depth = PollRegistrationTreeDepth::<T, I>::get(poll_id)?;
+			let interaction_depth = PollInteractionTreeDepth::<T, I>::get(poll_id)?;
 
-				Polls::<T>::insert(&poll_id, poll.clone());
+			Some((registration_depth, interaction_depth))
+		}
 
-				// Emit the hash event.
-				Self::deposit_event(Event::PollStateMerged {
-					poll_id,
-					registration_root: None,
-					interaction_root: poll.state.interactions.root
-				});
-			}
+		/// Returns the diagnostics of the registration tree of the given poll, read from the dedicated storage of the
+		/// tree, or `None` if the poll does not exist.
+		pub fn registration_tree_stats(poll_id: T::PollId) -> Option<MerkleTreeStats>
+		{
+			Some(PollRegistrationTree::<T, I>::get(poll_id)?.stats())
+		}
 
-			// Poll data has already been merged.
-			else { Err(<Error::<T>>::PollDataEmpty)? }
+		/// Returns the diagnostics of the interaction tree of the given poll, read as in `registration_tree_stats`.
+		pub fn interaction_tree_stats(poll_id: T::PollId) -> Option<MerkleTreeStats>
+		{
+			Some(PollInteractionTree::<T, I>::get(poll_id)?.stats())
+		}
 
-			Ok(())
+		/// Returns the number of vote options of the given poll, without decoding the poll.
+		pub fn vote_option_count(poll_id: T::PollId) -> Option<u32>
+		{
+			PollVoteOptionCount::<T, I>::get(poll_id)
 		}
 
-		/// Permits the coordinator to commit, in batches, proofs that all of the valid participant registrations and poll interactions 
-		/// were included in the computation which decided the winning vote option. Each individual proof carries a commitment value 
-		/// which is utilized to chain all of the proofs together, and in effect, to validate the final result.
-		///
-		/// Calls to this extrinsic are rejected if the poll has not ended, or if the root of the state trees have not yet been computed.
-		///
-		/// - `batches`: The ordered proofs alongside 
-		/// - `outcome`: The index of the option voted for (from the `VoteOptions` vec in the poll configuration). This parameter
-		///				 should only be included only with the last batch, or in a separate call after the final batch has been verified.
-		/// 
-		/// Emits `PollOutcome` once the outcome been verified, and `PollCommitmentUpdated` to reflect the updated commitment.
-		#[pallet::call_index(4)]
-		#[pallet::weight(T::DbWeight::get().reads_writes(2, 1))]
-		pub fn commit_outcome(
-			origin: OriginFor<T>,
-			batches: ProofBatches,
-			outcome: Option<PollOutcome>
-		) -> DispatchResult
+		/// Returns the actions which the poll accepts at the current block, or `None` if the poll does not exist. The
+		/// actions reserved to the coordinator, or which depend on the caller, are only permitted if `who` is given.
+		/// Merging, committing and nullifying are further restricted to the most recent poll of the coordinator, as
+		/// the corresponding extrinsics act on that poll.
+		pub fn allowed_actions(poll_id: T::PollId, who: Option<T::AccountId>) -> Option<AllowedActions>
 		{
-			// Check that the extrinsic was signed and get the signer.
-			let sender = ensure_signed(origin)?;
+			let poll = Self::polls(poll_id)?;
+			let phase = PollPhases::<T, I>::get(poll_id)?;
 
-			// Get the coordinators most recent poll.
-			let Some(coordinator) = Coordinators::<T>::get(&sender) else { Err(<Error::<T>>::CoordinatorNotRegistered)? };
-			let Some(poll_id) = coordinator.last_poll else { Err(<Error::<T>>::PollDoesNotExist)? };
-			let Some(mut poll) = Polls::<T>::get(poll_id) else { Err(<Error::<T>>::PollDoesNotExist)? };
+			let is_current_poll = who
+				.as_ref()
+				.and_then(|who| Coordinators::<T, I>::get(who)?.last_poll)
+				== Some(poll_id);
+
+			Some(AllowedActions {
+				register: Self::can_register(poll_id, &phase, &poll.state.registrations).is_ok(),
+				interact: Self::can_interact(poll_id, &phase, &poll.state.interactions).is_ok(),
+				signal_intent: who.as_ref().map_or(false, |who| Self::can_signal_intent(poll_id, &phase, who).is_ok()),
+				merge: is_current_poll && Self::can_merge(&poll).is_ok(),
+				commit_outcome: is_current_poll && Self::can_commit_outcome(&poll).is_ok(),
+				nullify: is_current_poll && Self::can_nullify(&poll).is_ok(),
+				activate: who.as_ref().map_or(false, |who| Self::can_activate(&poll, who).is_ok()),
+				reap: Self::can_reap(&poll).is_ok(),
+				replace_verify_key: who.as_ref().map_or(false, |who| Self::can_replace_verify_key(&poll, who).is_ok())
+			})
+		}
 
-			// Check that the state trees have been merged 
-			ensure!(poll.is_merged(), Error::<T>::PollStateNotMerged);
+		/// Returns the configuration of the poll in a human readable form, where `block_time` is the target block
+		/// time of the runtime in milliseconds.
+		pub fn poll_config(poll_id: T::PollId, block_time: u64) -> Option<SerializablePollConfig>
+		{
+			let poll = Self::polls(poll_id)?;
+			let registration_tree_arity = PollRegistrationTreeArity::<T, I>::get(poll_id)?;
+			let interaction_tree_arity = PollInteractionTreeArity::<T, I>::get(poll_id)?;
 
-			// Check that the outcome has not already been committed.
-			ensure!(!poll.is_fulfilled(), Error::<T>::PollOutcomeAlreadyDetermined);
+			Some(poll.config.to_serializable(registration_tree_arity, interaction_tree_arity, block_time))
+		}
 
-			// Ensure at least one of the inputs have been provided.
-			ensure!(batches.len() > 0 || outcome.is_some(), Error::<T>::MalformedInput);
+		/// Returns the stored outcome of a fulfilled poll alongside the commitments and roots against which it was
+		/// verified, or `None` if the poll has no outcome, e.g. as it was nullified.
+		pub fn outcome_export(poll_id: T::PollId) -> Option<OutcomeExport<T::PollId>>
+		{
+			let poll = Self::polls(poll_id)?;
+			let outcome = PollOutcomes::<T, I>::get(poll_id)?;
 
-			// Verify each batch of proofs in order.
-			for (proof, new_commitment) in batches.iter()
-			{
-				let Some((
-					verify_key,
-					public_inputs,
-					commitment
-				)) = poll.clone().prepare_public_inputs(
-					coordinator.clone(),
-					*new_commitment
-				) else { Err(<Error::<T>>::MalformedProof)? };
+			Some(OutcomeExport {
+				poll_id,
+				outcome_index: poll.state.outcome?,
+				header: outcome.header,
+				tally_results: outcome.tally_results.into_inner(),
+				process_commitment: poll.state.commitment.process.current,
+				tally_commitment: poll.state.commitment.tally.current,
+				registration_root: poll.state.registrations.root?,
+				interaction_root: poll.state.interactions.root?
+			})
+		}
 
-				ensure!(
-					verify_proof(verify_key, public_inputs, proof.clone()),
-					Error::<T>::MalformedProof
-				);
+		/// Re-run the verification of the stored outcome of a fulfilled poll against its stored commitments, i.e. that
+		/// the total number of votes cast is committed to by the final tally commitment, and that the stored tally
+		/// results elect the stored outcome. The vote option tree paths of the tally results are not retained, so the
+		/// individual results were only verified when the poll was fulfilled.
+		pub fn replay_poll_outcome(poll_id: T::PollId) -> bool
+		{
+			let Some(poll) = Self::polls(poll_id) else { return false; };
+			let Some(outcome_index) = poll.state.outcome else { return false; };
+			let Some(outcome) = PollOutcomes::<T, I>::get(poll_id) else { return false; };
 
-				poll.state.commitment = commitment;
-			}
+			poll.is_proven() &&
+			outcome.tally_results.len() == poll.config.vote_options.len() &&
+			poll.verify_total_spent(
+				outcome.header.total_spent,
+				outcome.header.total_spent_salt,
+				outcome.header.new_results_commitment
+			) &&
+			select_winner(&outcome.tally_results) == outcome_index
+		}
 
-			// Publish the commitment from the final batch.
-			if batches.len() > 0
+		/// Map the result of validating a public key onto the corresponding pallet error.
+		fn ensure_valid_public_key(public_key: &PublicKey) -> DispatchResult
+		{
+			match crypto::validate_public_key(public_key)
 			{
-				Self::deposit_event(Event::PollCommitmentUpdated {
-					poll_id,
-					commitment: poll.clone().state.commitment
-				})
+				Ok(_) => Ok(()),
+				Err(crypto::PublicKeyError::Malformed) => Err(Error::<T, I>::MalformedKeys.into()),
+				Err(crypto::PublicKeyError::NotInSubgroup) => Err(Error::<T, I>::PublicKeyNotInSubgroup.into())
 			}
+		}
 
-			// Once the final proof batch is verified, verify that the outcome matches the final commitment.
-			if let Some(outcome_index) = poll.clone().verify_outcome(outcome)
-			{
-				poll.state.outcome = Some(outcome_index);
+		/// Ensure that the keys of a coordinator are well formed, i.e. that the public key is a point in the prime order
+		/// subgroup, and that both verifying keys are bounded and deserialize into affine points. Returns the first error
+		/// encountered.
+		pub(crate) fn validate_coordinator_keys(public_key: &PublicKey, verify_key: &VerifyingKeys) -> DispatchResult
+		{
+			Self::ensure_valid_public_key(public_key)?;
+			Self::ensure_valid_verify_keys(verify_key)
+		}
 
-				Self::deposit_event(Event::PollOutcome { 
-					poll_id,
-					outcome_index
-				});
-			}
+		/// Ensure that the process and tally verifying keys are bounded and deserialize into affine points, and that
+		/// their sizes match the number of public inputs of their circuits.
+		fn ensure_valid_verify_keys(verify_key: &VerifyingKeys) -> DispatchResult
+		{
+			ensure!(serialize_vkey(verify_key.process.clone()).is_some(), Error::<T, I>::MalformedKeys);
+			ensure!(serialize_vkey(verify_key.tally.clone()).is_some(), Error::<T, I>::MalformedKeys);
 
-			// Update the poll state.
-			Polls::<T>::insert(poll_id, poll);
+			// A key of the wrong size would otherwise only be rejected once the coordinator attempts to prove.
+			ensure!(
+				verify_key.process.byte_len() == VerifyKey::expected_byte_len(PROCESS_PUBLIC_INPUTS) &&
+				verify_key.tally.byte_len() == VerifyKey::expected_byte_len(TALLY_PUBLIC_INPUTS),
+				Error::<T, I>::VerifyKeySizeMismatch
+			);
 
 			Ok(())
 		}
 
-		/// Permits the coordinator to nullify a poll which expired without recording a single interaction.
-		///
-		/// Calls to this extrinsic are rejected if the poll has not ended, or there was at least one interaction.
-		/// 
-		/// Emits `PollNullified`.
-		#[pallet::call_index(5)]
-		#[pallet::weight(T::DbWeight::get().reads_writes(2, 1))]
-		pub fn nullify_poll(
-			origin: OriginFor<T>
-		) -> DispatchResult
+		/// Ensure that `amount` may be reserved from the account without reducing its free balance below the
+		/// existential deposit, i.e. with the semantics of `ExistenceRequirement::KeepAlive`.
+		fn ensure_can_reserve_keep_alive(who: &T::AccountId, amount: BalanceOf<T, I>) -> DispatchResult
 		{
-			// Check that the extrinsic was signed and get the signer.
-			let sender = ensure_signed(origin)?;
+			let Some(new_balance) = T::Currency::free_balance(who).checked_sub(&amount) else { Err(<Error::<T, I>>::InsufficientFreeBalance)? };
 
-			// Get the coordinators most recent poll.
-			let Some(coordinator) = Coordinators::<T>::get(&sender) else { Err(<Error::<T>>::CoordinatorNotRegistered)? };
-			let Some(poll_id) = coordinator.last_poll else { Err(<Error::<T>>::PollDoesNotExist)? };
-			let Some(poll) = Polls::<T>::get(poll_id) else { Err(<Error::<T>>::PollDoesNotExist)? };
+			T::Currency::ensure_can_withdraw(who, amount, WithdrawReasons::RESERVE, new_balance)
+				.map_err(|_| Error::<T, I>::InsufficientFreeBalance)?;
 
+			// Keep the account alive.
 			ensure!(
-				(!poll.is_registration_period() && poll.state.registrations.count == 0) || 
-				(poll.is_over() && poll.state.interactions.count == 0),
-				Error::<T>::PollCurrentlyActive
+				new_balance >= T::Currency::minimum_balance(),
+				Error::<T, I>::InsufficientFreeBalance
 			);
 
-			Self::deposit_event(Event::PollNullified {
-				poll_id
-			});
+			Ok(())
+		}
+
+		/// The fee of a registration or interaction of the given actual weight: `ParticipantFee` and the fee for
+		/// the weight.
+		fn participant_fee(weight: Weight) -> BalanceOf<T, I>
+		{
+			T::ParticipantFee::get().saturating_add(T::WeightToFee::weight_to_fee(&weight))
+		}
 
-			// Mark the poll as dead.
-			Polls::<T>::insert(poll_id, poll.nullify());
+		/// Ensure that the fee allowance of the poll, if it has one, covers the fee of a call of the given weight.
+		fn ensure_fee_allowance(poll_id: T::PollId, weight: Weight) -> DispatchResult
+		{
+			let Some(allowance) = FeeAllowances::<T, I>::get(poll_id) else { return Ok(()); };
+			ensure!(allowance >= Self::participant_fee(weight), Error::<T, I>::FeeAllowanceExhausted);
 
 			Ok(())
 		}
 
-		/// Permits a signer to participate in an upcoming poll. Rejected if signup period has elapsed.
-		///
-		///	- `poll_id`: The id of the poll.
-		/// - `public_key`: The ephemeral public key of the registrant.
-		///
-		/// Emits `ParticipantRegistered`.
-		#[pallet::call_index(6)]
-		#[pallet::weight(T::DbWeight::get().reads_writes(1, 1))]
-		pub fn register_as_participant(
-			origin: OriginFor<T>,
-			poll_id: PollId,
-			public_key: PublicKey
-		) -> DispatchResult
+		/// Draw the fee of a call of the given actual weight from the fee allowance of the poll, which is held in the
+		/// named reserve of its coordinator. Returns whether the caller must still pay the fee for the call, i.e. iff
+		/// the poll has no fee allowance or `who` exhausted their `MaxSponsoredCalls` in the poll.
+		fn charge_fee_allowance(
+			poll_id: T::PollId,
+			coordinator: &T::AccountId,
+			who: &T::AccountId,
+			weight: Weight
+		) -> Result<Pays, DispatchError>
 		{
-			// Check that the extrinsic was signed and get the signer.
-			ensure_signed(origin)?;
+			let Some(allowance) = FeeAllowances::<T, I>::get(poll_id) else { return Ok(Pays::Yes); };
 
-			// Ensure that the poll exists and get it.
-			let Some(poll) = Polls::<T>::get(&poll_id) else { Err(<Error::<T>>::PollDoesNotExist)? };
+			let sponsored = SponsoredCalls::<T, I>::get(poll_id, who);
+			if sponsored >= T::MaxSponsoredCalls::get() { return Ok(Pays::Yes); }
 
-			// Check that the poll is still in the signup period.
-			ensure!(
-				poll.is_registration_period(),
-				Error::<T>::PollRegistrationHasEnded
-			);
+			let fee = Self::participant_fee(weight);
+			ensure!(allowance >= fee, Error::<T, I>::FeeAllowanceExhausted);
 
-			// Check that the maximum number of sign-ups has not been reached.
-			ensure!(
-				!poll.registration_limit_reached(),
-				Error::<T>::ParticipantRegistrationLimitReached
-			);
+			let (imbalance, _) = T::Currency::slash_reserved_named(&T::FeeAllowanceReserveId::get(), coordinator, fee);
+			T::OnFeeAllowanceCharged::on_unbalanced(imbalance);
+			FeeAllowances::<T, I>::insert(poll_id, allowance - fee);
+			SponsoredCalls::<T, I>::insert(poll_id, who, sponsored + 1);
 
-			// Record the hash of the registration data.
-			let block = <frame_system::Pallet<T>>::block_number().saturated_into::<u64>();
-			
-			// Insert the registration data into the poll state.
-			let (count, poll) = poll
-				.register_participant(public_key, block)
-				.map_err(|error| Error::<T>::PollRegistrationFailed { reason: error.into() })?;
-
-			Polls::<T>::insert(
-				&poll_id, 
-				poll
-			);
+			Ok(Pays::No)
+		}
 
-			// Emit the registration data for future processing by the coordinator.
-			Self::deposit_event(Event::ParticipantRegistered { 
+		/// Slash the remaining fee allowance of the poll from the named reserve of its coordinator, and route it to
+		/// `SlashDestination`.
+		fn forfeit_fee_allowance(poll_id: T::PollId, coordinator: &T::AccountId)
+		{
+			let Some(allowance) = FeeAllowances::<T, I>::take(poll_id) else { return; };
+
+			let (imbalance, _) = T::Currency::slash_reserved_named(&T::FeeAllowanceReserveId::get(), coordinator, allowance);
+			let amount = imbalance.peek();
+			T::SlashDestination::on_unbalanced(imbalance);
+
+			Self::deposit_event(Event::FeeAllowanceForfeited {
 				poll_id,
-				count,
-				public_key,
-				block
+				coordinator: coordinator.clone(),
+				amount
 			});
+		}
 
-			Ok(())
+		/// Count a nullified poll against the track record of its coordinator.
+		fn record_abandoned_poll(coordinator: &T::AccountId)
+		{
+			CoordinatorStats::<T, I>::mutate(coordinator, |record| record.polls_abandoned = record.polls_abandoned.saturating_add(1));
 		}
 
-		/// Permits a signer to interact with an ongoing poll. Rejects if not within the voting period. 
-		/// Valid messages include: a vote, and a key rotation. Participants may secretly call this 
-		/// method (read: using a different signer) in order to override their previous vote. 
-		///
-		/// - `poll_id`: The index of the poll in storage.
-		/// - `public_key`: The current ephemeral public key of the registrant. May be different than 
-		///					the one used for registration.
-		/// - `data`: The encrypted interaction data.
-		///
-		/// Emits `PollInteraction`.
-		#[pallet::call_index(7)]
-		#[pallet::weight(T::DbWeight::get().reads_writes(1, 1))]
-		pub fn interact_with_poll(
-			origin: OriginFor<T>,
-			poll_id: PollId,
-			public_key: PublicKey,
-			data: PollInteractionData
-		) -> DispatchResult
+		/// Returns the first block of the voting period of a poll whose signup period commences at `from`, and the
+		/// block at which the poll closes, rejecting periods which overflow the block number.
+		fn poll_periods(
+			from: BlockNumber,
+			signup_period: BlockNumber,
+			voting_period: BlockNumber
+		) -> Result<(BlockNumber, BlockNumber), DispatchError>
 		{
-			// Ensure that the extrinsic was signed.
-			ensure_signed(origin)?;
+			let Some(starts_at) = from.checked_add(signup_period) else { Err(<Error::<T, I>>::PollConfigInvalid)? };
+			let Some(ends_at) = starts_at.checked_add(voting_period).and_then(|end| end.checked_add(1)) else {
+				Err(<Error::<T, I>>::PollConfigInvalid)?
+			};
 
-			// Ensure that the poll exists and get it.
-			let Some(poll) = Polls::<T>::get(&poll_id) else { Err(<Error::<T>>::PollDoesNotExist)? };
+			Ok((starts_at, ends_at))
+		}
 
-			// Confirm that the poll is currently within it's voting period.
-			ensure!(!poll.is_registration_period(), Error::<T>::PollRegistrationInProgress);
-			ensure!(!poll.is_over(), Error::<T>::PollVotingHasEnded);
+		/// Release the reference of a poll to a circuit, such that the circuit may be removed once unreferenced.
+		fn release_circuit(circuit_id: CircuitId)
+		{
+			CircuitReferences::<T, I>::mutate_exists(circuit_id, |count| {
+				*count = count.and_then(|count| count.checked_sub(1)).filter(|count| *count > 0);
+			});
+		}
 
-			// Check that we've not reached the maximum number of interactions.
-			ensure!(
-				!poll.interaction_limit_reached(),
-				Error::<T>::ParticipantInteractionLimitReached
-			);
+		/// Remove the registration receipts of a poll which was nullified or reaped, as it pays no rewards. The
+		/// receipts are cleared in `on_idle` before the data pruned by `prune_poll_data`, which must precede this.
+		fn prune_registration_receipts(poll_id: T::PollId)
+		{
+			PollPruneCursors::<T, I>::insert(poll_id, PruneCursor { stage: PruneStage::RegistrationReceipts, cursor: None });
+		}
 
-			// Insert the interaction data into the poll state.
-			let (count, poll) = poll
-				.consume_interaction(public_key, data)
-				.map_err(|error| Error::<T>::PollInteractionFailed { reason: error.into() })?;
+		/// Clear the per-poll maps of a fulfilled poll from its cursor onwards, removing at most `limit` keys. Returns
+		/// the number of keys removed, and whether every map of the poll was cleared.
+		fn prune_poll(poll_id: T::PollId, progress: &mut PruneCursor, limit: u32) -> (u32, bool)
+		{
+			let mut removed: u32 = 0;
+			while removed < limit
+			{
+				let remaining = limit - removed;
+				let cursor = progress.cursor.as_deref();
+				let results = match progress.stage
+				{
+					PruneStage::RegistrationReceipts => RegistrationReceipts::<T, I>::clear_prefix(poll_id, remaining, cursor),
+					PruneStage::Messages => Messages::<T, I>::clear_prefix(poll_id, remaining, cursor),
+					PruneStage::RegistrationLeaves => RegistrationLeaves::<T, I>::clear_prefix(poll_id, remaining, cursor),
+					PruneStage::IntentSignals => IntentSignals::<T, I>::clear_prefix(poll_id, remaining, cursor),
+					PruneStage::ProofCommitments => ProofCommitments::<T, I>::clear_prefix(poll_id, remaining, cursor),
+					PruneStage::OptionCalls => PollOptionCalls::<T, I>::clear_prefix(poll_id, remaining, cursor),
+					PruneStage::RegisteredKeys => RegisteredKeys::<T, I>::clear_prefix(poll_id, remaining, cursor),
+					PruneStage::InteractionLeaves => InteractionLeaves::<T, I>::clear_prefix(poll_id, remaining, cursor),
+					PruneStage::SponsoredCalls => SponsoredCalls::<T, I>::clear_prefix(poll_id, remaining, cursor)
+				};
+				removed = removed.saturating_add(results.loops);
 
-			Polls::<T>::insert(
-				&poll_id, 
-				poll
-			);
+				// A cursor which exceeds its bound is dropped, such that the map is cleared from its start in the
+				// following block.
+				if let Some(cursor) = results.maybe_cursor
+				{
+					progress.cursor = BoundedVec::try_from(cursor).ok();
+					return (removed, false);
+				}
 
-			// Emit the interaction data for future processing by the coordinator.
-			Self::deposit_event(Event::PollInteraction {
-				poll_id,
-				count,
-				public_key,
-				data
-			});
+				let Some(stage) = progress.stage.next() else { return (removed, true); };
+				*progress = PruneCursor { stage, cursor: None };
+			}
 
-			Ok(())
+			(removed, false)
+		}
+
+		/// Remove any per-poll data which is no longer required once a poll is fulfilled, and return the unused
+		/// fee allowance to the coordinator.
+		fn prune_poll_data(poll_id: T::PollId, coordinator: &T::AccountId)
+		{
+			// The per-poll maps are cleared in `on_idle`, as they hold up to a key per registration or interaction.
+			PollPruneCursors::<T, I>::insert(poll_id, PruneCursor::default());
+
+			// The circuit is taken rather than read, such that its reference is released exactly once.
+			if let Some(circuit_id) = PollCircuit::<T, I>::take(poll_id) { Self::release_circuit(circuit_id); }
+			FailedProofAttempts::<T, I>::remove(poll_id);
+			UniqueKeyPolls::<T, I>::remove(poll_id);
+			CompactInteractionPolls::<T, I>::remove(poll_id);
+			PollVotingStartedEmitted::<T, I>::remove(poll_id);
+			PollRegistrationFrontier::<T, I>::remove(poll_id);
+			PollInteractionFrontier::<T, I>::remove(poll_id);
+			StagedOutcomes::<T, I>::remove(poll_id);
+
+			// A concluded poll permits no further keeper action.
+			Self::dequeue_keeper_action(KeeperAction::Merge, poll_id);
+			Self::dequeue_keeper_action(KeeperAction::NullificationCheck, poll_id);
+			Self::dequeue_keeper_action(KeeperAction::Reap, poll_id);
+
+			if let Some(allowance) = FeeAllowances::<T, I>::take(poll_id)
+			{
+				T::Currency::unreserve_named(&T::FeeAllowanceReserveId::get(), coordinator, allowance);
+			}
 		}
 	}
 
@@ -785,6 +4477,9 @@ pub mod pallet
 		vkey: VerifyKey
 	) -> Option<VerifyingKey::<Bn254>>
 	{
+		// The keys must be bounded, and commit to at least the constant term of the public inputs.
+		if !vkey.is_bounded() || vkey.gamma_abc_g1.is_empty() { return None; }
+
 		let Some(alpha_g1) = G1Affine::deserialize_uncompressed(&*vkey.alpha_g1).ok() else { return None; };
 		let Some(beta_g2) = G2Affine::deserialize_uncompressed(&*vkey.beta_g2).ok() else { return None; };
 		let Some(gamma_g2) = G2Affine::deserialize_uncompressed(&*vkey.gamma_g2).ok() else { return None; };
@@ -812,7 +4507,7 @@ pub mod pallet
 		Some(Proof::<Bn254> { a, b, c })
 	}
 
-	fn verify_proof(
+	pub(crate) fn verify_proof(
 		verify_key: VerifyKey,
 		public_inputs: vec::Vec<Fr>,
 		proof_data: ProofData
@@ -825,4 +4520,55 @@ pub mod pallet
 
 		result
 	}
+
+	/// Verify several proofs against the same key at once, by checking a random linear combination of their pairing
+	/// equations, i.e. `n + 3` pairings rather than the `4n` of verifying each proof in turn. The scalars of the
+	/// combination are derived from a hash of every proof and its public inputs, such that they are only fixed once
+	/// the proofs are. Returns true iff every proof verifies, except with negligible probability.
+	pub(crate) fn verify_proofs_batched(
+		verify_key: VerifyKey,
+		statements: vec::Vec<(vec::Vec<Fr>, ProofData)>
+	) -> bool
+	{
+		if statements.is_empty() { return false; }
+
+		let Some(vk) = serialize_vkey(verify_key) else { return false; };
+		let Some(pvk) = Groth16::<Bn254>::process_vk(&vk).ok() else { return false; };
+
+		let mut transcript = vec::Vec::new();
+		for (public_inputs, proof_data) in statements.iter()
+		{
+			transcript.extend(proof_data.encode());
+			for input in public_inputs { transcript.extend(input.into_bigint().to_bytes_le()); }
+		}
+		let seed = sp_io::hashing::blake2_256(&transcript);
+
+		// Each proof satisfies e(A, B) = e(alpha, beta) * e(inputs, gamma) * e(C, delta), so the product of the
+		// equations raised to the scalars is checked as a single multi-pairing which equals one.
+		let mut g1 = vec::Vec::with_capacity(statements.len() + 3);
+		let mut g2 = vec::Vec::with_capacity(statements.len() + 3);
+		let mut scalar_sum = Fr::ZERO;
+		let mut inputs_sum = G1Projective::default();
+		let mut c_sum = G1Projective::default();
+		for (index, (public_inputs, proof_data)) in statements.into_iter().enumerate()
+		{
+			let Some(proof) = serialize_proof(proof_data) else { return false; };
+			let Some(prepared_inputs) = Groth16::<Bn254>::prepare_inputs(&pvk, &public_inputs).ok() else { return false; };
+			let scalar = Fr::from_le_bytes_mod_order(&sp_io::hashing::blake2_256(&(seed, index as u32).encode()));
+
+			g1.push(proof.a * scalar);
+			g2.push(proof.b);
+			scalar_sum += scalar;
+			inputs_sum += prepared_inputs * scalar;
+			c_sum += proof.c * scalar;
+		}
+		g1.push(-(vk.alpha_g1 * scalar_sum));
+		g2.push(vk.beta_g2);
+		g1.push(-inputs_sum);
+		g2.push(vk.gamma_g2);
+		g1.push(-c_sum);
+		g2.push(vk.delta_g2);
+
+		Bn254::multi_pairing(G1Projective::normalize_batch(&g1), g2).0 == <Bn254 as Pairing>::TargetField::ONE
+	}
 }
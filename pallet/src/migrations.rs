@@ -0,0 +1,814 @@
+//! Storage migrations for `pallet_infimum`, to be included in the migrations of the runtime.
+
+use crate::*;
+use codec::DecodeAll;
+use frame_support::{
+    pallet_prelude::*,
+    storage::unhashed,
+    traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion}
+};
+use sp_std::marker::PhantomData;
+
+/// Decodes every stored poll, whether it is stored in the current layout or in the layout which preceded the
+/// `schema_version` field, in which case it is assigned `legacy_version`. Polls which decode in neither layout are
+/// skipped, as they would be by iterating `Polls`.
+pub(crate) fn stored_polls<T: Config<I>, I: 'static>(legacy_version: u16) -> sp_std::vec::Vec<(T::PollId, Poll<T, I>)>
+{
+    Polls::<T, I>::iter_keys()
+        .collect::<sp_std::vec::Vec<_>>()
+        .into_iter()
+        .filter_map(|poll_id| {
+            let raw = unhashed::get_raw(&Polls::<T, I>::hashed_key_for(poll_id))?;
+            let poll = Poll::<T, I>::decode_all(&mut &raw[..])
+                .ok()
+                .or_else(|| Some(v6::v5::Poll::<T, I>::decode_all(&mut &raw[..]).ok()?.into_schema(legacy_version)))?;
+
+            Some((poll_id, poll))
+        })
+        .collect()
+}
+
+/// Version 1 replaces the positional proof commitments of each poll, i.e. `(index, commitment)` pairs alongside
+/// separate expected counts, with a `ProofChain` per circuit.
+pub mod v1
+{
+    use super::*;
+    use crate::poll::state::PollStateTree;
+
+    /// The storage layout of version 0.
+    pub mod v0
+    {
+        use super::*;
+
+        #[derive(Encode, Decode)]
+        pub struct Commitment
+        {
+            pub process: (CommitmentIndex, CommitmentData),
+            pub tally: (CommitmentIndex, CommitmentData),
+            pub expected_process: CommitmentIndex,
+            pub expected_tally: CommitmentIndex
+        }
+
+        #[derive(Encode, Decode)]
+        pub struct PollState
+        {
+            pub registrations: PollStateTree,
+            pub interactions: PollStateTree,
+            pub commitment: Commitment,
+            pub outcome: Option<OutcomeIndex>,
+            pub tombstone: bool,
+            pub pending: bool,
+            pub sealed: bool
+        }
+
+        #[derive(Encode, Decode)]
+        pub struct Poll<T: Config<I>, I: 'static = ()>
+        {
+            pub index: T::PollId,
+            pub coordinator: T::AccountId,
+            pub created_at: BlockNumber,
+            pub state: PollState,
+            pub config: PollConfiguration<T, I>
+        }
+    }
+
+    impl From<v0::Commitment> for Commitment
+    {
+        fn from(commitment: v0::Commitment) -> Self
+        {
+            Commitment {
+                process: ProofChain {
+                    completed: commitment.process.0,
+                    expected: commitment.expected_process,
+                    current: commitment.process.1
+                },
+                tally: ProofChain {
+                    completed: commitment.tally.0,
+                    expected: commitment.expected_tally,
+                    current: commitment.tally.1
+                }
+            }
+        }
+    }
+
+    impl<T: Config<I>, I: 'static> From<v0::Poll<T, I>> for Poll<T, I>
+    {
+        fn from(poll: v0::Poll<T, I>) -> Self
+        {
+            Poll {
+                index: poll.index,
+                coordinator: poll.coordinator,
+                created_at: poll.created_at,
+                state: PollState {
+                    registrations: poll.state.registrations,
+                    interactions: poll.state.interactions,
+                    commitment: poll.state.commitment.into(),
+                    outcome: poll.state.outcome,
+                    tombstone: poll.state.tombstone,
+                    pending: poll.state.pending,
+                    sealed: poll.state.sealed
+                },
+                config: poll.config,
+                schema_version: 1
+            }
+        }
+    }
+
+    /// Translates every poll to the version 1 layout. Does nothing unless the on-chain storage version is 0.
+    pub struct MigrateToV1<T, I = ()>(PhantomData<(T, I)>);
+
+    impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV1<T, I>
+    {
+        fn on_runtime_upgrade() -> Weight
+        {
+            if Pallet::<T, I>::on_chain_storage_version() != 0 { return T::DbWeight::get().reads(1); }
+
+            let mut translated = 0u64;
+            Polls::<T, I>::translate::<v0::Poll<T, I>, _>(|_, poll| {
+                translated += 1;
+                Some(poll.into())
+            });
+
+            StorageVersion::new(1).put::<Pallet<T, I>>();
+
+            T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<sp_std::vec::Vec<u8>, sp_runtime::TryRuntimeError>
+        {
+            Ok(Polls::<T, I>::count().encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: sp_std::vec::Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError>
+        {
+            let count = u32::decode(&mut &state[..]).map_err(|_| "the pre-upgrade state is undecodable")?;
+
+            ensure!(Pallet::<T, I>::on_chain_storage_version() == 1, "the storage version was not updated");
+            ensure!(Polls::<T, I>::iter_values().count() as u32 == count, "a poll was lost in translation");
+
+            Ok(())
+        }
+    }
+}
+
+/// Version 2 widens the tally results of the stored poll outcomes from `u32` to `u128`.
+pub mod v2
+{
+    use super::*;
+
+    /// The storage layout of version 1.
+    pub mod v1
+    {
+        use super::*;
+
+        #[derive(Encode, Decode)]
+        pub struct PollOutcome
+        {
+            pub tally_results: sp_std::vec::Vec<u32>,
+            pub tally_result_proofs: sp_std::vec::Vec<sp_std::vec::Vec<sp_std::vec::Vec<HashBytes>>>,
+            pub total_spent: HashBytes,
+            pub total_spent_salt: HashBytes,
+            pub tally_result_salt: HashBytes,
+            pub new_results_commitment: HashBytes,
+            pub spent_votes_hash: HashBytes
+        }
+    }
+
+    impl From<v1::PollOutcome> for PollOutcome
+    {
+        fn from(outcome: v1::PollOutcome) -> Self
+        {
+            PollOutcome {
+                tally_results: outcome.tally_results.into_iter().map(u128::from).collect(),
+                tally_result_proofs: outcome.tally_result_proofs,
+                total_spent: outcome.total_spent,
+                total_spent_salt: outcome.total_spent_salt,
+                tally_result_salt: outcome.tally_result_salt,
+                new_results_commitment: outcome.new_results_commitment,
+                spent_votes_hash: outcome.spent_votes_hash
+            }
+        }
+    }
+
+    /// Translates every stored poll outcome to the version 2 layout. Does nothing unless the on-chain storage
+    /// version is 1, i.e. must be preceded by `v1::MigrateToV1` on chains at version 0.
+    pub struct MigrateToV2<T, I = ()>(PhantomData<(T, I)>);
+
+    impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV2<T, I>
+    {
+        fn on_runtime_upgrade() -> Weight
+        {
+            if Pallet::<T, I>::on_chain_storage_version() != 1 { return T::DbWeight::get().reads(1); }
+
+            // The outcomes are written in the layout of versions 2 to 9, which `v10::MigrateToV10` translates into the
+            // current layout.
+            let keys: sp_std::vec::Vec<T::PollId> = PollOutcomes::<T, I>::iter_keys().collect();
+            let read = keys.len() as u64;
+            let mut translated = 0u64;
+            for poll_id in keys.into_iter()
+            {
+                let key = PollOutcomes::<T, I>::hashed_key_for(poll_id);
+                let Some(outcome) = unhashed::get::<v1::PollOutcome>(&key) else { continue; };
+
+                unhashed::put(&key, &PollOutcome::from(outcome));
+                translated += 1;
+            }
+
+            StorageVersion::new(2).put::<Pallet<T, I>>();
+
+            T::DbWeight::get().reads_writes(read + 1, translated + 1)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<sp_std::vec::Vec<u8>, sp_runtime::TryRuntimeError>
+        {
+            Ok((PollOutcomes::<T, I>::iter_keys().count() as u32).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: sp_std::vec::Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError>
+        {
+            let count = u32::decode(&mut &state[..]).map_err(|_| "the pre-upgrade state is undecodable")?;
+
+            ensure!(Pallet::<T, I>::on_chain_storage_version() == 2, "the storage version was not updated");
+            ensure!(
+                PollOutcomes::<T, I>::iter_keys()
+                    .filter(|poll_id| unhashed::get::<PollOutcome>(&PollOutcomes::<T, I>::hashed_key_for(*poll_id)).is_some())
+                    .count() as u32 == count,
+                "an outcome was lost in translation"
+            );
+
+            Ok(())
+        }
+    }
+}
+
+/// Version 3 moves the frontiers of the state trees of each poll into `PollRegistrationFrontier` and
+/// `PollInteractionFrontier`, such that reading a poll does not decode them. The layout of the polls is unchanged,
+/// their frontiers are merely emptied.
+pub mod v3
+{
+    use super::*;
+
+    /// Moves the frontiers of every stored poll into their own storage. Does nothing unless the on-chain storage
+    /// version is 2, i.e. must be preceded by `v2::MigrateToV2`.
+    pub struct MigrateToV3<T, I = ()>(PhantomData<(T, I)>);
+
+    impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV3<T, I>
+    {
+        fn on_runtime_upgrade() -> Weight
+        {
+            if Pallet::<T, I>::on_chain_storage_version() != 2 { return T::DbWeight::get().reads(1); }
+
+            let polls = stored_polls::<T, I>(2);
+            let translated = polls.len() as u64;
+            for (poll_id, mut poll) in polls.into_iter()
+            {
+                poll.schema_version = poll.schema_version.max(3);
+                Pallet::<T, I>::insert_poll_with_frontiers(poll_id, poll);
+            }
+
+            StorageVersion::new(3).put::<Pallet<T, I>>();
+
+            T::DbWeight::get().reads_writes(translated + 1, translated.saturating_mul(3) + 1)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<sp_std::vec::Vec<u8>, sp_runtime::TryRuntimeError>
+        {
+            Ok(Polls::<T, I>::count().encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: sp_std::vec::Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError>
+        {
+            let count = u32::decode(&mut &state[..]).map_err(|_| "the pre-upgrade state is undecodable")?;
+
+            ensure!(Pallet::<T, I>::on_chain_storage_version() == 3, "the storage version was not updated");
+            ensure!(Polls::<T, I>::count() == count, "a poll was lost in the migration");
+            ensure!(
+                Polls::<T, I>::iter_values().all(|poll| poll.state.registrations.hashes.is_empty() && poll.state.interactions.hashes.is_empty()),
+                "a frontier was not moved out of its poll"
+            );
+
+            Ok(())
+        }
+    }
+}
+
+/// Version 4 stores the state trees of each poll in `PollRegistrationTree` and `PollInteractionTree`, and the
+/// metadata which registrations and interactions are checked against in `PollPhases`, such that the hot extrinsics
+/// do not decode the poll. The layout of the polls is unchanged, their embedded trees are merely superseded.
+pub mod v4
+{
+    use super::*;
+
+    /// Copies the state trees of every stored poll into their own storage, and derives the phase cache of every
+    /// poll. Does nothing unless the on-chain storage version is 3, i.e. must be preceded by `v3::MigrateToV3`.
+    pub struct MigrateToV4<T, I = ()>(PhantomData<(T, I)>);
+
+    impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV4<T, I>
+    {
+        fn on_runtime_upgrade() -> Weight
+        {
+            if Pallet::<T, I>::on_chain_storage_version() != 3 { return T::DbWeight::get().reads(1); }
+
+            let polls = stored_polls::<T, I>(3);
+            let translated = polls.len() as u64;
+            for (poll_id, mut poll) in polls.into_iter()
+            {
+                poll.schema_version = poll.schema_version.max(4);
+
+                // The frontiers were moved out of the polls by version 3, so the embedded trees are copied as is,
+                // unless version 3 was applied by the same upgrade, which already stored the trees apart and left
+                // only their shapes in the poll.
+                if !PollRegistrationTree::<T, I>::contains_key(poll_id)
+                {
+                    PollRegistrationTree::<T, I>::insert(poll_id, &poll.state.registrations);
+                }
+                if !PollInteractionTree::<T, I>::contains_key(poll_id)
+                {
+                    PollInteractionTree::<T, I>::insert(poll_id, &poll.state.interactions);
+                }
+                Pallet::<T, I>::insert_poll(poll_id, poll);
+            }
+
+            StorageVersion::new(4).put::<Pallet<T, I>>();
+
+            // Deriving the phase cache reads the period ends of the poll.
+            T::DbWeight::get().reads_writes(translated.saturating_mul(3) + 1, translated.saturating_mul(4) + 1)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<sp_std::vec::Vec<u8>, sp_runtime::TryRuntimeError>
+        {
+            Ok(Polls::<T, I>::count().encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: sp_std::vec::Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError>
+        {
+            let count = u32::decode(&mut &state[..]).map_err(|_| "the pre-upgrade state is undecodable")?;
+
+            ensure!(Pallet::<T, I>::on_chain_storage_version() == 4, "the storage version was not updated");
+            ensure!(Polls::<T, I>::count() == count, "a poll was lost in the migration");
+            ensure!(
+                Polls::<T, I>::iter_keys().all(|poll_id| {
+                    PollRegistrationTree::<T, I>::contains_key(poll_id) &&
+                    PollInteractionTree::<T, I>::contains_key(poll_id) &&
+                    PollPhases::<T, I>::contains_key(poll_id)
+                }),
+                "the state trees or phase cache of a poll were not stored"
+            );
+
+            Ok(())
+        }
+    }
+}
+
+/// Version 5 assigns poll ids from `NextPollId` rather than the `u32` counter of `Polls`, such that runtimes may
+/// choose a wider `PollId`.
+pub mod v5
+{
+    use super::*;
+
+    /// Initializes `NextPollId` to the number of stored polls, which are indexed consecutively from zero. Does
+    /// nothing unless the on-chain storage version is 4, i.e. must be preceded by `v4::MigrateToV4`.
+    pub struct MigrateToV5<T, I = ()>(PhantomData<(T, I)>);
+
+    impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV5<T, I>
+    {
+        fn on_runtime_upgrade() -> Weight
+        {
+            if Pallet::<T, I>::on_chain_storage_version() != 4 { return T::DbWeight::get().reads(1); }
+
+            NextPollId::<T, I>::put(T::PollId::from(Polls::<T, I>::count()));
+
+            StorageVersion::new(5).put::<Pallet<T, I>>();
+
+            T::DbWeight::get().reads_writes(2, 2)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<sp_std::vec::Vec<u8>, sp_runtime::TryRuntimeError>
+        {
+            Ok(Polls::<T, I>::count().encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: sp_std::vec::Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError>
+        {
+            let count = u32::decode(&mut &state[..]).map_err(|_| "the pre-upgrade state is undecodable")?;
+
+            ensure!(Pallet::<T, I>::on_chain_storage_version() == 5, "the storage version was not updated");
+            ensure!(NextPollId::<T, I>::get() == T::PollId::from(count), "the next poll id does not follow the stored polls");
+            ensure!(!Polls::<T, I>::contains_key(NextPollId::<T, I>::get()), "the next poll id is already assigned");
+
+            Ok(())
+        }
+    }
+}
+
+/// Version 6 stores the schema version inside each poll, such that later migrations may skip polls which are already
+/// current, and `try_state` may detect polls which a migration missed.
+pub mod v6
+{
+    use super::*;
+
+    /// The storage layout of versions 1 to 5.
+    pub mod v5
+    {
+        use super::*;
+
+        #[derive(Encode, Decode)]
+        pub struct Poll<T: Config<I>, I: 'static = ()>
+        {
+            pub index: T::PollId,
+            pub coordinator: T::AccountId,
+            pub created_at: BlockNumber,
+            pub state: PollState,
+            pub config: PollConfiguration<T, I>
+        }
+
+        impl<T: Config<I>, I: 'static> Poll<T, I>
+        {
+            /// Translates the poll to the current layout, at the given schema version.
+            pub fn into_schema(self, schema_version: u16) -> crate::Poll<T, I>
+            {
+                crate::Poll {
+                    index: self.index,
+                    coordinator: self.coordinator,
+                    created_at: self.created_at,
+                    state: self.state,
+                    config: self.config,
+                    schema_version
+                }
+            }
+        }
+    }
+
+    /// Bumps every stored poll to `POLL_SCHEMA_VERSION`, translating the polls which are stored in the version 5
+    /// layout, and skipping those which are already current. Does nothing unless the on-chain storage version is 5,
+    /// i.e. must be preceded by `v5::MigrateToV5`.
+    pub struct MigrateToV6<T, I = ()>(PhantomData<(T, I)>);
+
+    impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV6<T, I>
+    {
+        fn on_runtime_upgrade() -> Weight
+        {
+            if Pallet::<T, I>::on_chain_storage_version() != 5 { return T::DbWeight::get().reads(1); }
+
+            let polls = stored_polls::<T, I>(5);
+            let read = polls.len() as u64;
+            let mut translated = 0u64;
+            for (poll_id, mut poll) in polls.into_iter()
+            {
+                if poll.schema_version >= POLL_SCHEMA_VERSION { continue; }
+
+                poll.schema_version = POLL_SCHEMA_VERSION;
+                Polls::<T, I>::insert(poll_id, poll);
+                translated += 1;
+            }
+
+            StorageVersion::new(6).put::<Pallet<T, I>>();
+
+            T::DbWeight::get().reads_writes(read + 1, translated + 1)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<sp_std::vec::Vec<u8>, sp_runtime::TryRuntimeError>
+        {
+            Ok(Polls::<T, I>::count().encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: sp_std::vec::Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError>
+        {
+            let count = u32::decode(&mut &state[..]).map_err(|_| "the pre-upgrade state is undecodable")?;
+
+            ensure!(Pallet::<T, I>::on_chain_storage_version() == 6, "the storage version was not updated");
+            ensure!(Polls::<T, I>::count() == count, "a poll was lost in the migration");
+            ensure!(
+                Polls::<T, I>::iter_values().all(|poll| poll.schema_version == POLL_SCHEMA_VERSION),
+                "a poll was not bumped to the current schema version"
+            );
+
+            Ok(())
+        }
+    }
+}
+
+/// Version 7 bounds the poll ids of each coordinator in `CoordinatorPollIds` by `MaxCoordinatorPolls`, which is
+/// thereafter maintained upon the creation of a poll.
+pub mod v7
+{
+    use super::*;
+
+    /// Archives the oldest finished poll ids of every coordinator which exceeds `MaxCoordinatorPolls` into
+    /// `CoordinatorHistory`. Unfinished polls are never archived. Does nothing unless the on-chain storage version is 6,
+    /// i.e. must be preceded by `v6::MigrateToV6`.
+    pub struct MigrateToV7<T, I = ()>(PhantomData<(T, I)>);
+
+    impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV7<T, I>
+    {
+        fn on_runtime_upgrade() -> Weight
+        {
+            if Pallet::<T, I>::on_chain_storage_version() != 6 { return T::DbWeight::get().reads(1); }
+
+            let max_polls = T::MaxCoordinatorPolls::get() as usize;
+            let mut reads: u64 = 1;
+            let mut writes: u64 = 1;
+
+            let coordinators: sp_std::vec::Vec<(T::AccountId, sp_std::vec::Vec<T::PollId>)> = CoordinatorPollIds::<T, I>::iter().collect();
+            for (who, poll_ids) in coordinators.into_iter()
+            {
+                reads = reads.saturating_add(1);
+                if poll_ids.len() <= max_polls { continue; }
+
+                let mut excess = poll_ids.len() - max_polls;
+                let mut archived: sp_std::vec::Vec<T::PollId> = sp_std::vec::Vec::new();
+                let mut retained: sp_std::vec::Vec<T::PollId> = sp_std::vec::Vec::new();
+                for poll_id in poll_ids.into_iter()
+                {
+                    reads = reads.saturating_add(1);
+                    if excess > 0 && Pallet::<T, I>::is_poll_finished(poll_id)
+                    {
+                        archived.push(poll_id);
+                        excess -= 1;
+                    }
+                    else { retained.push(poll_id); }
+                }
+
+                if archived.is_empty() { continue; }
+
+                CoordinatorHistory::<T, I>::mutate(&who, |history| {
+                    history.extend(archived);
+                    history.sort_unstable();
+                });
+                if retained.is_empty() { CoordinatorPollIds::<T, I>::remove(&who); }
+                else { CoordinatorPollIds::<T, I>::insert(&who, retained); }
+                reads = reads.saturating_add(1);
+                writes = writes.saturating_add(2);
+            }
+
+            StorageVersion::new(7).put::<Pallet<T, I>>();
+
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<sp_std::vec::Vec<u8>, sp_runtime::TryRuntimeError>
+        {
+            let poll_ids = CoordinatorPollIds::<T, I>::iter_values()
+                .chain(CoordinatorHistory::<T, I>::iter_values())
+                .map(|poll_ids| poll_ids.len() as u64)
+                .sum::<u64>();
+
+            Ok(poll_ids.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: sp_std::vec::Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError>
+        {
+            let count = u64::decode(&mut &state[..]).map_err(|_| "the pre-upgrade state is undecodable")?;
+            let poll_ids = CoordinatorPollIds::<T, I>::iter_values()
+                .chain(CoordinatorHistory::<T, I>::iter_values())
+                .map(|poll_ids| poll_ids.len() as u64)
+                .sum::<u64>();
+
+            ensure!(Pallet::<T, I>::on_chain_storage_version() == 7, "the storage version was not updated");
+            ensure!(poll_ids == count, "a poll id was lost in the migration");
+
+            Ok(())
+        }
+    }
+}
+
+/// Version 8 holds the fee allowances of polls in the named reserve `FeeAllowanceReserveId` of their coordinator,
+/// rather than in the anonymous reserve which other pallets may slash or release.
+pub mod v8
+{
+    use super::*;
+    use frame_support::traits::{NamedReservableCurrency, ReservableCurrency};
+    use sp_runtime::traits::Saturating;
+
+    /// Moves the fee allowance of every poll from the anonymous reserve of its coordinator into the named reserve.
+    /// An allowance which cannot be held in the named reserve is released to the coordinator, and the poll falls
+    /// back to participants paying their own fees. Does nothing unless the on-chain storage version is 7, i.e. must
+    /// be preceded by `v7::MigrateToV7`.
+    pub struct MigrateToV8<T, I = ()>(PhantomData<(T, I)>);
+
+    impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV8<T, I>
+    {
+        fn on_runtime_upgrade() -> Weight
+        {
+            if Pallet::<T, I>::on_chain_storage_version() != 7 { return T::DbWeight::get().reads(1); }
+
+            let id = T::FeeAllowanceReserveId::get();
+            let allowances: sp_std::vec::Vec<(T::PollId, BalanceOf<T, I>)> = FeeAllowances::<T, I>::iter().collect();
+            let moved = allowances.len() as u64;
+            for (poll_id, allowance) in allowances.into_iter()
+            {
+                let Some(phase) = PollPhases::<T, I>::get(poll_id) else { continue; };
+
+                let unreserved = allowance.saturating_sub(T::Currency::unreserve(&phase.coordinator, allowance));
+                if T::Currency::reserve_named(&id, &phase.coordinator, unreserved).is_ok()
+                {
+                    FeeAllowances::<T, I>::insert(poll_id, unreserved);
+                }
+                else { FeeAllowances::<T, I>::remove(poll_id); }
+            }
+
+            StorageVersion::new(8).put::<Pallet<T, I>>();
+
+            T::DbWeight::get().reads_writes(moved.saturating_mul(4) + 1, moved.saturating_mul(4) + 1)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_: sp_std::vec::Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError>
+        {
+            ensure!(Pallet::<T, I>::on_chain_storage_version() == 8, "the storage version was not updated");
+            ensure!(
+                FeeAllowances::<T, I>::iter().all(|(poll_id, allowance)| {
+                    PollPhases::<T, I>::get(poll_id).map_or(false, |phase| {
+                        T::Currency::reserved_balance_named(&T::FeeAllowanceReserveId::get(), &phase.coordinator) >= allowance
+                    })
+                }),
+                "a fee allowance was not moved into the named reserve"
+            );
+
+            Ok(())
+        }
+    }
+}
+
+/// Version 9 counts the unfinished polls which reference each circuit in `CircuitReferences`, such that a circuit
+/// is not removed from under the polls whose proofs are verified against it.
+pub mod v9
+{
+    use super::*;
+
+    /// Counts the unfinished polls which reference each circuit. Does nothing unless the on-chain storage version is
+    /// 8, i.e. must be preceded by `v8::MigrateToV8`.
+    pub struct MigrateToV9<T, I = ()>(PhantomData<(T, I)>);
+
+    impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV9<T, I>
+    {
+        fn on_runtime_upgrade() -> Weight
+        {
+            if Pallet::<T, I>::on_chain_storage_version() != 8 { return T::DbWeight::get().reads(1); }
+
+            let references: sp_std::vec::Vec<(T::PollId, CircuitId)> = PollCircuit::<T, I>::iter().collect();
+            let read = references.len() as u64;
+            let mut counted = 0u64;
+            for (poll_id, circuit_id) in references.into_iter()
+            {
+                if Pallet::<T, I>::polls(poll_id).map_or(true, |poll| poll.is_fulfilled()) { continue; }
+
+                CircuitReferences::<T, I>::mutate(circuit_id, |count| *count = count.saturating_add(1));
+                counted += 1;
+            }
+
+            StorageVersion::new(9).put::<Pallet<T, I>>();
+
+            // Each reference reads its poll and state trees.
+            T::DbWeight::get().reads_writes(read.saturating_mul(4) + 1, counted + 1)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_: sp_std::vec::Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError>
+        {
+            ensure!(Pallet::<T, I>::on_chain_storage_version() == 9, "the storage version was not updated");
+            ensure!(
+                PollCircuit::<T, I>::iter().all(|(poll_id, circuit_id)| {
+                    Pallet::<T, I>::polls(poll_id).map_or(true, |poll| poll.is_fulfilled()) ||
+                    CircuitReferences::<T, I>::get(circuit_id) > 0
+                }),
+                "an unfinished poll which references a circuit was not counted"
+            );
+
+            Ok(())
+        }
+    }
+}
+
+/// Version 10 drops the vote option tree paths from the stored poll outcomes, retaining only their tally results
+/// and commitments, such that the stored outcomes are bounded by `MaxVoteOptions`.
+pub mod v10
+{
+    use super::*;
+
+    /// Translates every stored poll outcome to the version 10 layout. Does nothing unless the on-chain storage
+    /// version is 9.
+    pub struct MigrateToV10<T, I = ()>(PhantomData<(T, I)>);
+
+    impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV10<T, I>
+    {
+        fn on_runtime_upgrade() -> Weight
+        {
+            if Pallet::<T, I>::on_chain_storage_version() != 9 { return T::DbWeight::get().reads(1); }
+
+            let mut translated = 0u64;
+            PollOutcomes::<T, I>::translate::<PollOutcome, _>(|poll_id, outcome| {
+                translated += 1;
+
+                // The outcome was verified against every vote option of the poll.
+                let option_count = PollVoteOptionCount::<T, I>::get(poll_id).unwrap_or(outcome.tally_results.len() as u32);
+                Some(OutcomeRecord::new(outcome.header(option_count), &outcome.tally_results))
+            });
+
+            StorageVersion::new(10).put::<Pallet<T, I>>();
+
+            // Each outcome reads the vote option count of its poll.
+            T::DbWeight::get().reads_writes(translated.saturating_mul(2) + 1, translated + 1)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<sp_std::vec::Vec<u8>, sp_runtime::TryRuntimeError>
+        {
+            Ok((PollOutcomes::<T, I>::iter_keys().count() as u32).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: sp_std::vec::Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError>
+        {
+            let count = u32::decode(&mut &state[..]).map_err(|_| "the pre-upgrade state is undecodable")?;
+
+            ensure!(Pallet::<T, I>::on_chain_storage_version() == 10, "the storage version was not updated");
+            ensure!(PollOutcomes::<T, I>::iter_values().count() as u32 == count, "an outcome was lost in translation");
+
+            Ok(())
+        }
+    }
+}
+
+/// Version 11 drops the vote option tree paths from the staged outcomes, which are verified as they are staged, such
+/// that the staged outcomes are bounded by `MaxVoteOptions`.
+pub mod v11
+{
+    use super::*;
+
+    /// The storage layout of version 10.
+    pub mod v10
+    {
+        use super::*;
+
+        /// A staged outcome alongside the vote option tree paths of its tally results.
+        #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+        pub struct StagedOutcome
+        {
+            pub header: OutcomeHeader,
+            pub tally_results: sp_std::vec::Vec<u128>,
+            pub tally_result_proofs: sp_std::vec::Vec<sp_std::vec::Vec<sp_std::vec::Vec<HashBytes>>>,
+            pub expires_at: BlockNumber
+        }
+    }
+
+    /// Translates every staged outcome to the version 11 layout, retaining the tally results staged so far. Does
+    /// nothing unless the on-chain storage version is 10.
+    pub struct MigrateToV11<T, I = ()>(PhantomData<(T, I)>);
+
+    impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV11<T, I>
+    {
+        fn on_runtime_upgrade() -> Weight
+        {
+            if Pallet::<T, I>::on_chain_storage_version() != 10 { return T::DbWeight::get().reads(1); }
+
+            let mut translated = 0u64;
+            StagedOutcomes::<T, I>::translate::<v10::StagedOutcome, _>(|_, staged| {
+                translated += 1;
+
+                // Each tally result was staged for a vote option of the poll, of which there are at most
+                // `MaxVoteOptions`.
+                Some(StagedOutcome {
+                    header: staged.header,
+                    tally_results: BoundedVec::truncate_from(staged.tally_results),
+                    expires_at: staged.expires_at
+                })
+            });
+
+            StorageVersion::new(11).put::<Pallet<T, I>>();
+
+            T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<sp_std::vec::Vec<u8>, sp_runtime::TryRuntimeError>
+        {
+            Ok((StagedOutcomes::<T, I>::iter_keys().count() as u32).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: sp_std::vec::Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError>
+        {
+            let count = u32::decode(&mut &state[..]).map_err(|_| "the pre-upgrade state is undecodable")?;
+
+            ensure!(Pallet::<T, I>::on_chain_storage_version() == 11, "the storage version was not updated");
+            ensure!(StagedOutcomes::<T, I>::iter_values().count() as u32 == count, "a staged outcome was lost in translation");
+
+            Ok(())
+        }
+    }
+}
@@ -2,8 +2,12 @@ use crate::*;
 use crate as pallet_infimum;
 use frame_support::{
     derive_impl,
-	traits::{ConstU32, ConstU64}
+    ord_parameter_types,
+    parameter_types,
+	traits::{ConstU32, ConstU64, Imbalance, OnUnbalanced},
+    weights::{constants::WEIGHT_REF_TIME_PER_MILLIS, RuntimeDbWeight, Weight, WeightToFee}
 };
+use frame_system::RawOrigin;
 use sp_core::H256;
 use sp_runtime::{
 	traits::{BlakeTwo256, IdentityLookup},
@@ -16,6 +20,7 @@ frame_support::construct_runtime!(
 	pub enum Test
 	{
 		System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 		Infimum: pallet_infimum::{Pallet, Call, Storage, Event<T>},
 	}
 );
@@ -25,7 +30,7 @@ impl frame_system::Config for Test {
 	type BaseCallFilter = frame_support::traits::Everything;
 	type BlockWeights = ();
 	type BlockLength = ();
-	type DbWeight = ();
+	type DbWeight = DbWeight;
 	type RuntimeOrigin = RuntimeOrigin;
 	type Nonce = u64;
 	type Hash = H256;
@@ -44,19 +49,137 @@ impl frame_system::Config for Test {
 	type SS58Prefix = ();
 	type OnSetCode = ();
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
+	type AccountData = pallet_balances::AccountData<u64>;
+}
+
+impl pallet_balances::Config for Test {
+	type MaxLocks = ();
+	type MaxReserves = ConstU32<2>;
+	type ReserveIdentifier = [u8; 8];
+	type Balance = u64;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU64<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+	type RuntimeHoldReason = ();
+	type MaxHolds = ();
+}
+
+parameter_types! {
+    pub static DbWeight: RuntimeDbWeight = RuntimeDbWeight { read: 0, write: 0 };
+    pub static MaxCoordinatorPolls: u32 = 2;
+    pub static MaxVoteOptions: u32 = 1024;
+    pub static EmitFullInteractionData: bool = true;
+    pub static StoreRegistrationLeaves: bool = true;
+    pub static RegistrationTreeArity: u32 = 2;
+    pub static InteractionTreeArity: u32 = 5;
+    pub static MaxProofFailures: u32 = 2;
+    pub static PollCreationCooldown: u64 = 0;
+    pub static MaxOptionCallWeight: Weight = Weight::from_parts(1_000_000_000_000, 1_000_000);
+    pub static MaxInitializeWeight: Weight = Weight::MAX;
+    pub static MaxPruneKeys: u32 = 1024;
+    pub static OutcomeOrigin: RawOrigin<u64> = RawOrigin::Root;
+    pub static FinalizedPolls: Vec<(u32, OutcomeIndex)> = Vec::new();
+    pub static SlashedAmount: u64 = 0;
+    pub static MaxKeeperQueueLen: u32 = 16;
+    pub static MaxPollClosuresPerBlock: u32 = 16;
+    pub static MaxScheduledPollsPerBlock: u32 = 16;
+    pub static MaxRegistrationsPerAccount: u32 = 8;
+    pub static FeePerMillisecond: u64 = 0;
+    pub static MaxSponsoredCalls: u32 = 8;
+    pub const FeeAllowanceReserveId: [u8; 8] = *b"infimfee";
+}
+
+ord_parameter_types! {
+    pub const OptionCallCoordinator: u64 = 0;
+}
+
+/// Records the polls whose outcome was verified, in order.
+pub struct RecordFinalizedPolls;
+
+impl OnPollFinalized<u32> for RecordFinalizedPolls
+{
+    fn on_finalized(poll_id: u32, outcome: OutcomeIndex)
+    {
+        FinalizedPolls::mutate(|polls| polls.push((poll_id, outcome)));
+    }
+}
+
+/// Charges `FeePerMillisecond` for each whole millisecond of reference time, in place of the `WeightToFee` of the
+/// transaction payment pallet.
+pub struct MillisecondsToFee;
+
+impl WeightToFee for MillisecondsToFee
+{
+    type Balance = u64;
+
+    fn weight_to_fee(weight: &Weight) -> u64
+    {
+        weight.ref_time() / WEIGHT_REF_TIME_PER_MILLIS * FeePerMillisecond::get()
+    }
+}
+
+/// Records the total amount routed to `SlashDestination`.
+pub struct RecordSlashes;
+
+impl OnUnbalanced<NegativeImbalanceOf<Test>> for RecordSlashes
+{
+    fn on_nonzero_unbalanced(amount: NegativeImbalanceOf<Test>)
+    {
+        SlashedAmount::mutate(|slashed| *slashed += amount.peek());
+    }
 }
 
 impl Config for Test {
-    type MaxCoordinatorPolls = ConstU32<2>;
-    type MaxVoteOptions = ConstU32<1024>;
+    type PollId = u32;
+    type MaxCoordinatorPolls = MaxCoordinatorPolls;
+    type MaxVoteOptions = MaxVoteOptions;
+    type RuntimeCall = RuntimeCall;
+    type OutcomeOrigin = OutcomeOrigin;
+    type OptionCallOrigin = frame_system::EnsureSignedBy<OptionCallCoordinator, u64>;
+    type MaxOptionCallLen = ConstU32<128>;
+    type MaxOptionCallWeight = MaxOptionCallWeight;
+    type MaxInitializeWeight = MaxInitializeWeight;
+    type MaxPruneKeys = MaxPruneKeys;
     type MaxPollRegistrations = ConstU32<2_147_483_648>;
     type MaxPollInteractions = ConstU32<1024>;
+    type RegistrationTreeArity = RegistrationTreeArity;
+    type InteractionTreeArity = InteractionTreeArity;
+    type EmitFullInteractionData = EmitFullInteractionData;
+    type StoreRegistrationLeaves = StoreRegistrationLeaves;
+    type ActivationTimeout = ConstU64<20>;
+    type MaxProofFailures = MaxProofFailures;
+    type ProofFailureCooldown = ConstU64<10>;
+    type PollCreationCooldown = PollCreationCooldown;
+    type MaxInteractionsPerCall = ConstU32<4>;
+    type MaxProofsPerCall = ConstU32<16>;
+    type MaxKeeperQueueLen = MaxKeeperQueueLen;
+    type MaxPollClosuresPerBlock = MaxPollClosuresPerBlock;
+    type MaxScheduledPollsPerBlock = MaxScheduledPollsPerBlock;
+    type MaxRegistrationsPerAccount = MaxRegistrationsPerAccount;
+    type OutcomeStagingTimeout = ConstU64<20>;
+    type Currency = Balances;
+    type FeeAllowanceReserveId = FeeAllowanceReserveId;
+    type ParticipantFee = ConstU64<10>;
+    type WeightToFee = MillisecondsToFee;
+    type MaxSponsoredCalls = MaxSponsoredCalls;
+    type OnFeeAllowanceCharged = ();
+    type SlashDestination = RecordSlashes;
+    type OnPollFinalized = RecordFinalizedPolls;
+    type CircuitOrigin = frame_system::EnsureRoot<u64>;
+    type WeightInfo = ();
 	type RuntimeEvent = RuntimeEvent;
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
 	let t = RuntimeGenesisConfig {
 		system: Default::default(),
+		balances: pallet_balances::GenesisConfig {
+			balances: vec![(0, 1_000), (1, 100), (2, 1_000), (3, 100)]
+		},
 	}
 	.build_storage()
 	.unwrap();
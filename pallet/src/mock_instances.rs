@@ -0,0 +1,180 @@
+use crate::*;
+use crate as pallet_infimum;
+use frame_support::{
+    derive_impl,
+    instances::Instance1,
+    ord_parameter_types,
+    parameter_types,
+	traits::{ConstBool, ConstU32, ConstU64},
+    weights::{ConstantMultiplier, Weight}
+};
+use frame_system::RawOrigin;
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	BuildStorage,
+};
+
+type Block = frame_system::mocking::MockBlock<TestInstances>;
+
+frame_support::construct_runtime!(
+	pub enum TestInstances
+	{
+		System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		Community: pallet_infimum::{Pallet, Call, Storage, Event<T>},
+		Council: pallet_infimum::<Instance1>::{Pallet, Call, Storage, Event<T>},
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for TestInstances {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type Nonce = u64;
+	type Hash = H256;
+	type RuntimeCall = RuntimeCall;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Block = Block;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+	type AccountData = pallet_balances::AccountData<u64>;
+}
+
+impl pallet_balances::Config for TestInstances {
+	type MaxLocks = ();
+	type MaxReserves = ConstU32<2>;
+	type ReserveIdentifier = [u8; 8];
+	type Balance = u64;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU64<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+	type RuntimeHoldReason = ();
+	type MaxHolds = ();
+}
+
+parameter_types! {
+    pub OutcomeOrigin: RawOrigin<u64> = RawOrigin::Root;
+    pub MaxOptionCallWeight: Weight = Weight::from_parts(1_000_000_000_000, 1_000_000);
+    pub MaxInitializeWeight: Weight = Weight::MAX;
+    pub const CommunityReserveId: [u8; 8] = *b"communty";
+    pub const CouncilReserveId: [u8; 8] = *b"council_";
+}
+
+ord_parameter_types! {
+    pub const OptionCallCoordinator: u64 = 0;
+}
+
+/// The default instance, open to every coordinator.
+impl Config for TestInstances {
+    type PollId = u32;
+    type MaxCoordinatorPolls = ConstU32<2>;
+    type MaxVoteOptions = ConstU32<1024>;
+    type RuntimeCall = RuntimeCall;
+    type OutcomeOrigin = OutcomeOrigin;
+    type OptionCallOrigin = frame_system::EnsureSignedBy<OptionCallCoordinator, u64>;
+    type MaxOptionCallLen = ConstU32<128>;
+    type MaxOptionCallWeight = MaxOptionCallWeight;
+    type MaxInitializeWeight = MaxInitializeWeight;
+    type MaxPruneKeys = ConstU32<1024>;
+    type MaxPollRegistrations = ConstU32<2_147_483_648>;
+    type MaxPollInteractions = ConstU32<1024>;
+    type RegistrationTreeArity = ConstU32<2>;
+    type InteractionTreeArity = ConstU32<5>;
+    type EmitFullInteractionData = ConstBool<true>;
+    type StoreRegistrationLeaves = ConstBool<true>;
+    type ActivationTimeout = ConstU64<20>;
+    type MaxProofFailures = ConstU32<3>;
+    type ProofFailureCooldown = ConstU64<10>;
+    type PollCreationCooldown = ConstU64<0>;
+    type MaxInteractionsPerCall = ConstU32<4>;
+    type MaxProofsPerCall = ConstU32<16>;
+    type MaxKeeperQueueLen = ConstU32<16>;
+    type MaxPollClosuresPerBlock = ConstU32<16>;
+    type MaxScheduledPollsPerBlock = ConstU32<16>;
+    type MaxRegistrationsPerAccount = ConstU32<8>;
+    type OutcomeStagingTimeout = ConstU64<20>;
+    type Currency = Balances;
+    type FeeAllowanceReserveId = CommunityReserveId;
+    type ParticipantFee = ConstU64<10>;
+    type WeightToFee = ConstantMultiplier<u64, ConstU64<0>>;
+    type MaxSponsoredCalls = ConstU32<8>;
+    type OnFeeAllowanceCharged = ();
+    type SlashDestination = ();
+    type OnPollFinalized = ();
+    type CircuitOrigin = frame_system::EnsureRoot<u64>;
+    type WeightInfo = ();
+	type RuntimeEvent = RuntimeEvent;
+}
+
+/// A second instance with smaller polls, whose interaction data is written to storage, and which indexes polls
+/// with `u64`.
+impl Config<Instance1> for TestInstances {
+    type PollId = u64;
+    type MaxCoordinatorPolls = ConstU32<1>;
+    type MaxVoteOptions = ConstU32<25>;
+    type RuntimeCall = RuntimeCall;
+    type OutcomeOrigin = OutcomeOrigin;
+    type OptionCallOrigin = frame_system::EnsureSignedBy<OptionCallCoordinator, u64>;
+    type MaxOptionCallLen = ConstU32<128>;
+    type MaxOptionCallWeight = MaxOptionCallWeight;
+    type MaxInitializeWeight = MaxInitializeWeight;
+    type MaxPruneKeys = ConstU32<1024>;
+    type MaxPollRegistrations = ConstU32<1024>;
+    type MaxPollInteractions = ConstU32<25>;
+    type RegistrationTreeArity = ConstU32<2>;
+    type InteractionTreeArity = ConstU32<5>;
+    type EmitFullInteractionData = ConstBool<false>;
+    type StoreRegistrationLeaves = ConstBool<false>;
+    type ActivationTimeout = ConstU64<10>;
+    type MaxProofFailures = ConstU32<3>;
+    type ProofFailureCooldown = ConstU64<10>;
+    type PollCreationCooldown = ConstU64<0>;
+    type MaxInteractionsPerCall = ConstU32<2>;
+    type MaxProofsPerCall = ConstU32<16>;
+    type MaxKeeperQueueLen = ConstU32<16>;
+    type MaxPollClosuresPerBlock = ConstU32<16>;
+    type MaxScheduledPollsPerBlock = ConstU32<16>;
+    type MaxRegistrationsPerAccount = ConstU32<8>;
+    type OutcomeStagingTimeout = ConstU64<20>;
+    type Currency = Balances;
+    type FeeAllowanceReserveId = CouncilReserveId;
+    type ParticipantFee = ConstU64<20>;
+    type WeightToFee = ConstantMultiplier<u64, ConstU64<0>>;
+    type MaxSponsoredCalls = ConstU32<8>;
+    type OnFeeAllowanceCharged = ();
+    type SlashDestination = ();
+    type OnPollFinalized = ();
+    type CircuitOrigin = frame_system::EnsureRoot<u64>;
+    type WeightInfo = ();
+	type RuntimeEvent = RuntimeEvent;
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let t = RuntimeGenesisConfig {
+		system: Default::default(),
+		balances: pallet_balances::GenesisConfig {
+			balances: vec![(0, 1_000), (1, 100), (2, 1_000), (3, 100)]
+		},
+	}
+	.build_storage()
+	.unwrap();
+	t.into()
+}
@@ -0,0 +1,35 @@
+use frame_support::pallet_prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// The actions which a poll accepts at the current block, as returned by the `infimum_allowedActions` RPC. Each
+/// flag is computed with the same check which gates the corresponding extrinsic.
+#[derive(Clone, Copy, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, Serialize, Deserialize)]
+pub struct AllowedActions
+{
+    /// Whether `register_as_participant` is accepted.
+    pub register: bool,
+
+    /// Whether `interact_with_poll` is accepted, given a well-formed interaction.
+    pub interact: bool,
+
+    /// Whether `signal_intent` is accepted from the caller.
+    pub signal_intent: bool,
+
+    /// Whether `merge_poll_state` is accepted from the caller.
+    pub merge: bool,
+
+    /// Whether `commit_outcome` is accepted from the caller, given a valid proof or outcome.
+    pub commit_outcome: bool,
+
+    /// Whether `nullify_poll` is accepted from the caller.
+    pub nullify: bool,
+
+    /// Whether `activate_poll` is accepted from the caller.
+    pub activate: bool,
+
+    /// Whether `reap_poll` is accepted.
+    pub reap: bool,
+
+    /// Whether `replace_poll_verify_key` is accepted from the caller.
+    pub replace_verify_key: bool
+}
@@ -0,0 +1,102 @@
+use frame_support::pallet_prelude::*;
+
+use crate::poll::{BlockNumber, Poll, PollProvider, effective_state_size};
+
+/// The metadata of a poll which registrations and interactions are checked against, stored apart from the poll
+/// such that the hot extrinsics neither decode nor re-encode its configuration, commitments or vote options.
+/// Rewritten whenever the poll is stored, so that it never diverges from the poll.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PollPhaseCache<AccountId>
+{
+    /// The poll creator, from whose reserve the fee allowance is drawn.
+    pub coordinator: AccountId,
+
+    /// The number of the block in which the poll was created, or activated.
+    pub created_at: BlockNumber,
+
+    /// The first block after the registration period.
+    pub registration_period_end: BlockNumber,
+
+    /// The last block of the voting period.
+    pub voting_period_end: BlockNumber,
+
+    /// The maximum number of participants permitted.
+    pub max_registrations: u32,
+
+    /// The maximum number of interactions permitted.
+    pub max_interactions: u32,
+
+    /// The number of words in an interaction message.
+    pub message_words: u8,
+
+    /// Whether interactions are accepted during the signup period.
+    pub allow_early_interactions: bool,
+
+    /// Whether interactions encrypted with the public key of the coordinator are rejected.
+    pub reject_coordinator_key: bool,
+
+    /// Whether the poll awaits activation by its coordinator.
+    pub pending: bool,
+
+    /// Whether the poll was nullified.
+    pub tombstone: bool
+}
+
+impl<AccountId> PollPhaseCache<AccountId>
+{
+    /// Returns true iff the poll awaits activation by its coordinator, and has not been nullified.
+    pub fn is_pending(&self) -> bool
+    {
+        self.pending && !self.tombstone
+    }
+
+    /// Returns true iff `now` is within the registration period of the poll.
+    pub fn is_registration_period(&self, now: BlockNumber) -> bool
+    {
+        if self.pending { return false; }
+
+        now >= self.created_at && now < self.registration_period_end
+    }
+
+    /// Returns true iff the poll has ended at `now`. A pending poll has not started, so it is only over once it
+    /// is nullified.
+    pub fn is_over(&self, now: BlockNumber) -> bool
+    {
+        if self.pending { return self.tombstone; }
+
+        now > self.voting_period_end
+    }
+
+    /// Returns true iff a registration tree holding `count` registrations admits no further registration, as
+    /// with `PollProvider::registration_limit_reached`.
+    pub fn registration_limit_reached(&self, count: u32) -> bool
+    {
+        effective_state_size(count) >= self.max_registrations
+    }
+
+    /// Returns true iff an interaction tree holding `count` interactions admits no further interaction.
+    pub fn interaction_limit_reached(&self, count: u32) -> bool
+    {
+        count >= self.max_interactions
+    }
+}
+
+impl<T: crate::Config<I>, I: 'static> From<&Poll<T, I>> for PollPhaseCache<T::AccountId>
+{
+    fn from(poll: &Poll<T, I>) -> Self
+    {
+        PollPhaseCache {
+            coordinator: poll.coordinator.clone(),
+            created_at: poll.created_at,
+            registration_period_end: poll.get_registration_period_end(),
+            voting_period_end: poll.get_voting_period_end(),
+            max_registrations: poll.config.max_registrations,
+            max_interactions: poll.config.max_interactions,
+            message_words: poll.config.message_words,
+            allow_early_interactions: poll.config.allow_early_interactions,
+            reject_coordinator_key: poll.config.reject_coordinator_key,
+            pending: poll.state.pending,
+            tombstone: poll.state.tombstone
+        }
+    }
+}
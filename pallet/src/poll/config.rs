@@ -1,10 +1,33 @@
 use frame_support::pallet_prelude::*;
+use scale_info::prelude::string::{String, ToString};
+use serde::{Deserialize, Serialize};
+use sp_std::vec;
 
-use crate::poll::{BlockNumber, VoteOptions};
+use ark_bn254::Fr;
+use ark_ff::PrimeField;
+use sp_runtime::traits::SaturatedConversion;
 
-#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
-#[scale_info(skip_type_params(T))]
-pub struct PollConfiguration<T: crate::Config>
+use crate::poll::{BalanceOf, BlockNumber, CircuitId, HashBytes, OptionCalls, VoteOptions, MAX_MESSAGE_WORDS, MAX_PATH_DEPTH};
+use crate::poll::leaves::{compute_encoding_hash, hash};
+
+/// The convention with which the public key of the coordinator is hashed into the public inputs of the message
+/// processing circuit, which differs between circuit versions.
+#[derive(Clone, Copy, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum CoordKeyHash
+{
+    /// `H2(x, y)`, i.e. the coordinates of the key hashed separately.
+    #[default]
+    Poseidon2Coords,
+
+    /// `H1(packed)`, where `packed` is the key packed as by circomlib `packPoint`, i.e. the little-endian
+    /// encoding of `y` with the most significant bit set iff `x > (r - 1) / 2`, reduced modulo `r`.
+    PoseidonPacked
+}
+
+#[derive(CloneNoBound, Encode, Decode, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T, I))]
+#[codec(mel_bound())]
+pub struct PollConfiguration<T: crate::Config<I>, I: 'static = ()>
 {
     /// The number of blocks for which the registration period is active.
     pub signup_period: BlockNumber,
@@ -27,6 +50,269 @@ pub struct PollConfiguration<T: crate::Config>
     /// The vote option tree depth.
     pub vote_option_tree_depth: u8,
 
+    /// The possible outcomes of the poll. Immutable once the poll is created, since the tally proofs and the
+    /// verification of the outcome are bound to the option set; no call may add, remove or reorder options.
+    pub vote_options: VoteOptions<T, I>,
+
+    /// Whether interactions encrypted with the public key of the coordinator are rejected.
+    pub reject_coordinator_key: bool,
+
+    /// Whether interactions are accepted during the signup period.
+    pub allow_early_interactions: bool,
+
+    /// The number of words in an interaction message.
+    pub message_words: u8
+}
+
+/// The parameters supplied by a coordinator in order to create a poll.
+#[derive(CloneNoBound, Encode, Decode, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T, I))]
+#[codec(mel_bound())]
+pub struct PollConfigOf<T: crate::Config<I>, I: 'static = ()>
+{
+    /// The number of blocks for which the registration period is active.
+    pub signup_period: BlockNumber,
+
+    /// The number of blocks for which the voting period is active.
+    pub voting_period: BlockNumber,
+
+    /// The depth of the registration tree, whose arity is `RegistrationTreeArity`.
+    pub registration_depth: u8,
+
+    /// The depth of the interaction tree, whose arity is `InteractionTreeArity`.
+    pub interaction_depth: u8,
+
+    /// The subtree depth to process per commitment.
+    pub process_subtree_depth: u8,
+
+    /// The intermediate tally subtree depth.
+    pub tally_subtree_depth: u8,
+
+    /// The vote option tree depth. At most `MAX_PATH_DEPTH`.
+    pub vote_option_tree_depth: u8,
+
     /// The possible outcomes of the poll.
-    pub vote_options: VoteOptions<T>,
+    pub vote_options: VoteOptions<T, I>,
+
+    /// The amount reserved from the coordinator in order to pay the fees of participants. Zero if the
+    /// participants of the poll pay their own fees.
+    pub fee_allowance: BalanceOf<T, I>,
+
+    /// Whether interactions encrypted with the public key of the coordinator are rejected, such
+    /// that malformed messages can be flagged before the poll is processed.
+    pub reject_coordinator_key: bool,
+
+    /// Whether interactions are accepted during the signup period as well as the voting period, e.g. in
+    /// order to queue messages before voting commences.
+    pub allow_early_interactions: bool,
+
+    /// The number of words in an interaction message, which must match the circuits of the coordinator.
+    /// At most `MAX_MESSAGE_WORDS`.
+    pub message_words: u8,
+
+    /// Whether the poll is created pending, such that its periods only commence once the coordinator
+    /// calls `activate_poll`, e.g. after standing up their off-chain infrastructure.
+    pub pending: bool,
+
+    /// Whether participants may signal their non-binding intent to vote during the signup period, such that
+    /// the coordinator may estimate turnout.
+    pub collect_intents: bool,
+
+    /// Whether the public keys of registrations are indexed, such that each key may register at most once, and
+    /// clients may query whether a key is registered before prompting a user to register.
+    pub unique_keys: bool,
+
+    /// Whether only the leaf of each interaction is stored, in `InteractionLeaves`, rather than its data, which is
+    /// always emitted in full with `PollInteraction` for the coordinator to capture off-chain, regardless of
+    /// `EmitFullInteractionData`.
+    pub compact_interactions: bool,
+
+    /// The convention with which the public key of the coordinator is hashed, which must match the circuits
+    /// of the coordinator.
+    pub coord_key_hash: CoordKeyHash,
+
+    /// The circuit of `CircuitRegistry` whose verifying keys the proofs of the poll are verified against, or `None`
+    /// for the verifying keys of the coordinator.
+    pub circuit_id: Option<CircuitId>,
+
+    /// The encoded calls attached to vote options, keyed by the index of the option. The call attached to the
+    /// winning option is dispatched with `OutcomeOrigin` once the outcome of the poll is verified.
+    pub option_calls: OptionCalls<T, I>
+}
+
+impl<T: crate::Config<I>, I: 'static> PollConfigOf<T, I>
+{
+    /// Returns the configuration to be stored alongside the poll, iff the parameters are valid.
+    pub fn validate(&self) -> Option<PollConfiguration<T, I>>
+    {
+        let max_registrations = T::RegistrationTreeArity::get().checked_pow(self.registration_depth.into())?;
+        if max_registrations > T::MaxPollRegistrations::get() { return None; }
+
+        let max_interactions = T::InteractionTreeArity::get().checked_pow(self.interaction_depth.into())?;
+        if max_interactions > T::MaxPollInteractions::get() { return None; }
+
+        if self.vote_options.len() < 2 { return None; }
+
+        if self.vote_option_tree_depth > MAX_PATH_DEPTH { return None; }
+
+        if self.message_words == 0 || u32::from(self.message_words) > MAX_MESSAGE_WORDS { return None; }
+
+        Some(PollConfiguration {
+            signup_period: self.signup_period,
+            voting_period: self.voting_period,
+            max_registrations,
+            max_interactions,
+            process_subtree_depth: self.process_subtree_depth,
+            tally_subtree_depth: self.tally_subtree_depth,
+            vote_option_tree_depth: self.vote_option_tree_depth,
+            vote_options: self.vote_options.clone(),
+            reject_coordinator_key: self.reject_coordinator_key,
+            allow_early_interactions: self.allow_early_interactions,
+            message_words: self.message_words
+        })
+    }
+
+    /// Returns the Poseidon commitment to every parameter of the configuration, iff the parameters are valid, such
+    /// that participants may check a poll against the configuration it was advertised with. The commitment is
+    /// `H3(limits, depths, options)`, where
+    /// - `limits = H5(signup_period, voting_period, max_registrations, max_interactions, vote_option_count)`,
+    /// - `depths = H5(registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth)`,
+    /// - `options = H5(message_words, fee_allowance, flags, coord_key_hash, calls)`, where `flags` packs the boolean
+    ///   parameters into bits from the least significant in the order they are declared, `coord_key_hash` is the
+    ///   index of the convention, and `calls` is the `compute_encoding_hash` of the SCALE encoding of the vote
+    ///   options followed by that of the option calls and the circuit id.
+    pub fn config_hash(&self) -> Option<HashBytes>
+    {
+        let configuration = self.validate()?;
+
+        let limits = hash(&[
+            Fr::from(self.signup_period),
+            Fr::from(self.voting_period),
+            Fr::from(configuration.max_registrations),
+            Fr::from(configuration.max_interactions),
+            Fr::from(self.vote_options.len() as u64)
+        ])?;
+
+        let depths = hash(&[
+            Fr::from(self.registration_depth),
+            Fr::from(self.interaction_depth),
+            Fr::from(self.process_subtree_depth),
+            Fr::from(self.tally_subtree_depth),
+            Fr::from(self.vote_option_tree_depth)
+        ])?;
+
+        let flags = [
+            self.reject_coordinator_key,
+            self.allow_early_interactions,
+            self.pending,
+            self.collect_intents,
+            self.unique_keys,
+            self.compact_interactions
+        ]
+            .iter()
+            .enumerate()
+            .fold(0u64, |flags, (bit, flag)| flags | (u64::from(*flag) << bit));
+
+        let mut encoded = self.vote_options.encode();
+        encoded.extend(self.option_calls.encode());
+        encoded.extend(self.circuit_id.encode());
+        let calls = compute_encoding_hash(&encoded)?;
+
+        let options = hash(&[
+            Fr::from(self.message_words),
+            Fr::from(self.fee_allowance.saturated_into::<u128>()),
+            Fr::from(flags),
+            Fr::from(self.coord_key_hash as u8),
+            Fr::from_be_bytes_mod_order(&calls)
+        ])?;
+
+        hash(&[
+            Fr::from_be_bytes_mod_order(&limits),
+            Fr::from_be_bytes_mod_order(&depths),
+            Fr::from_be_bytes_mod_order(&options)
+        ])
+    }
+}
+
+/// A human readable view of the configuration of a poll, as returned by the `infimum_getPollConfig` RPC.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, Serialize, Deserialize)]
+pub struct SerializablePollConfig
+{
+    /// The duration of the registration period in seconds, given the target block time of the runtime.
+    pub signup_period_secs: u64,
+
+    /// The duration of the voting period in seconds, given the target block time of the runtime.
+    pub voting_period_secs: u64,
+
+    /// The number of blocks for which the registration period is active.
+    pub signup_period_blocks: BlockNumber,
+
+    /// The number of blocks for which the voting period is active.
+    pub voting_period_blocks: BlockNumber,
+
+    /// The arity of the registration tree.
+    pub registration_tree_arity: u32,
+
+    /// The arity of the interaction tree.
+    pub interaction_tree_arity: u32,
+
+    /// The maximum number of participants permitted.
+    pub max_registrations: u32,
+
+    /// The maximum number of interactions permitted.
+    pub max_interactions: u32,
+
+    /// The subtree depth to process per commitment.
+    pub process_subtree_depth: u8,
+
+    /// The intermediate tally subtree depth.
+    pub tally_subtree_depth: u8,
+
+    /// The vote option tree depth.
+    pub vote_option_tree_depth: u8,
+
+    /// The possible outcomes of the poll, as decimal strings such that they survive JSON number precision.
+    pub vote_options: vec::Vec<String>,
+
+    /// Whether interactions encrypted with the public key of the coordinator are rejected.
+    pub reject_coordinator_key: bool,
+
+    /// Whether interactions are accepted during the signup period.
+    pub allow_early_interactions: bool,
+
+    /// The number of words in an interaction message.
+    pub message_words: u8
+}
+
+impl<T: crate::Config<I>, I: 'static> PollConfiguration<T, I>
+{
+    /// Returns the human readable view of the configuration, where `block_time` is the target block time of
+    /// the runtime in milliseconds.
+    pub fn to_serializable(
+        &self,
+        registration_tree_arity: u32,
+        interaction_tree_arity: u32,
+        block_time: u64
+    ) -> SerializablePollConfig
+    {
+        let secs = |blocks: BlockNumber| blocks.saturating_mul(block_time) / 1000;
+
+        SerializablePollConfig {
+            signup_period_secs: secs(self.signup_period),
+            voting_period_secs: secs(self.voting_period),
+            signup_period_blocks: self.signup_period,
+            voting_period_blocks: self.voting_period,
+            registration_tree_arity,
+            interaction_tree_arity,
+            max_registrations: self.max_registrations,
+            max_interactions: self.max_interactions,
+            process_subtree_depth: self.process_subtree_depth,
+            tally_subtree_depth: self.tally_subtree_depth,
+            vote_option_tree_depth: self.vote_option_tree_depth,
+            vote_options: self.vote_options.iter().map(|option| option.to_string()).collect(),
+            reject_coordinator_key: self.reject_coordinator_key,
+            allow_early_interactions: self.allow_early_interactions,
+            message_words: self.message_words
+        }
+    }
 }
@@ -2,17 +2,20 @@ use frame_support::pallet_prelude::*;
 use sp_std::vec;
 
 use crate::poll::{
+    BlockNumber,
     CommitmentIndex,
     CommitmentData,
-    PollId,
+    OutcomeIndex,
     PublicKey,
+    TallyResults,
     VerifyKey,
-    HashBytes
+    HashBytes,
+    MAX_PATH_DEPTH
 };
 
 /// Coordinator storage definition.
-#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
-pub struct Coordinator
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Coordinator<PollId>
 {
     /// The coordinators public key.
     pub public_key: PublicKey,
@@ -24,20 +27,116 @@ pub struct Coordinator
     pub last_poll: Option<PollId>
 }
 
-#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+/// The track record of a coordinator across all of their polls, from which voters may judge whether the
+/// coordinator reliably finalizes polls.
+#[derive(Clone, Copy, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct CoordinatorRecord
+{
+    /// The number of polls created.
+    pub polls_created: u32,
+
+    /// The number of polls whose outcome was verified.
+    pub polls_finalized: u32,
+
+    /// The number of polls which were nullified, i.e. ended without interactions or were reaped before their
+    /// activation.
+    pub polls_abandoned: u32,
+
+    /// The number of participants registered across the finalized polls.
+    pub participants_served: u64
+}
+
+/// A registered coordinator alongside their track record, as returned by the `coordinator_info` runtime API.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct CoordinatorInfo<PollId>
+{
+    /// The current public key of the coordinator.
+    pub public_key: PublicKey,
+
+    /// The most recent poll of the coordinator (may be active).
+    pub last_poll: Option<PollId>,
+
+    /// The track record of the coordinator.
+    pub record: CoordinatorRecord
+}
+
+/// The proof chains of the message processing and tallying circuits of a poll.
+#[derive(Clone, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub struct Commitment
 {
-    /// The commitment to the message processing circuit. 
-    pub process: (CommitmentIndex, CommitmentData),
+    /// The proof chain of the message processing circuit.
+    pub process: ProofChain,
+
+    /// The proof chain of the tallying circuit.
+    pub tally: ProofChain
+}
+
+impl Commitment
+{
+    /// The number of proofs verified across both circuits, i.e. the index of the next proof of the poll.
+    pub fn completed(&self) -> CommitmentIndex
+    {
+        self.process.completed.saturating_add(self.tally.completed)
+    }
+}
+
+/// The circuit which a proof attests to.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum ProofKind
+{
+    /// The message processing circuit.
+    Process,
+
+    /// The tallying circuit.
+    Tally
+}
+
+/// The failed proof verifications of a poll.
+#[derive(Clone, Copy, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ProofFailures
+{
+    /// The number of proofs which failed to verify.
+    pub attempts: u32,
 
-    /// The commitment to the tallying circuit.
-    pub tally: (CommitmentIndex, CommitmentData),
+    /// The block at which the last proof failed to verify.
+    pub last_failed_at: BlockNumber
+}
+
+/// The progress of the proofs of a single circuit, each of which attests to the transition of the commitment
+/// from its current value to the next.
+#[derive(Clone, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ProofChain
+{
+    /// The number of verified proofs.
+    pub completed: CommitmentIndex,
+
+    /// The number of proofs required to complete the chain. Zero until the state trees of the poll are merged.
+    pub expected: CommitmentIndex,
+
+    /// The commitment attested to by the last verified proof, or the initial commitment of the chain.
+    pub current: CommitmentData
+}
+
+impl ProofChain
+{
+    /// Record a verified proof which attests to the transition from the current commitment to `new`.
+    pub fn advance(&mut self, new: CommitmentData)
+    {
+        self.completed = self.completed.saturating_add(1);
+        self.current = new;
+    }
 
-    /// The expected number of process commitments.
-    pub expected_process: CommitmentIndex,
+    /// Returns true iff every expected proof has been verified.
+    pub fn is_complete(&self) -> bool
+    {
+        self.completed == self.expected
+    }
 
-    /// The expected number of tally commitments.
-    pub expected_tally: CommitmentIndex
+    /// The number of proofs which remain to be verified.
+    pub fn remaining(&self) -> CommitmentIndex
+    {
+        self.expected.saturating_sub(self.completed)
+    }
 }
 
 /// A serialized groth16 proof.
@@ -52,8 +151,9 @@ pub struct ProofData
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
 pub struct PollOutcome
 {
-    /// The results of the tally per option.
-    pub tally_results: vec::Vec<u32>,
+    /// The results of the tally per option. Quadratic tallies of large credit balances exceed `u32`, hence the
+    /// results are 128 bit, which any field element of the vote option tree may represent.
+    pub tally_results: vec::Vec<u128>,
 
     /// The proof of correctness for the results.
     pub tally_result_proofs: vec::Vec<vec::Vec<vec::Vec<HashBytes>>>,
@@ -74,8 +174,154 @@ pub struct PollOutcome
     pub spent_votes_hash: HashBytes
 }
 
-/// A pair of verification keys for message processing and tally verification circuits.
+/// The commitments and salts of an outcome which is submitted across multiple extrinsics, against which each of its
+/// tally results is verified as it is staged.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct OutcomeHeader
+{
+    /// The total number of votes cast represented as a (big-endian) byte array.
+    pub total_spent: HashBytes,
+
+    /// The salt for the total votes.
+    pub total_spent_salt: HashBytes,
+
+    /// The salt for the tally results.
+    pub tally_result_salt: HashBytes,
+
+    /// The salted commitment of the vote tally.
+    pub new_results_commitment: HashBytes,
+
+    /// The hash of the spent votes and salt.
+    pub spent_votes_hash: HashBytes,
+
+    /// The number of vote options of the poll, i.e. the number of tally results to be staged.
+    pub option_count: u32
+}
+
+/// The path of a tally result in the quinary vote option tree, i.e. the four siblings at each of at most
+/// `MAX_PATH_DEPTH` levels.
+pub type TallyResultPath = BoundedVec<BoundedVec<HashBytes, ConstU32<4>>, ConstU32<{ MAX_PATH_DEPTH as u32 }>>;
+
+/// The index of a vote option, its tally result, and the path of the result in the vote option tree.
+pub type OutcomeChunkItem = (u32, u128, TallyResultPath);
+
+/// The tally results of a contiguous range of vote options, staged by a single call to `submit_outcome_chunk`.
+pub type OutcomeChunk<T, I = ()> = BoundedVec<OutcomeChunkItem, <T as crate::Config<I>>::MaxVoteOptions>;
+
+/// An outcome whose tally results are staged in order across multiple extrinsics, each verified as it is staged.
+/// The paths of the tally results are only required to verify them, so are not retained.
+#[derive(CloneNoBound, Encode, Decode, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T, I))]
+#[codec(mel_bound())]
+pub struct StagedOutcome<T: crate::Config<I>, I: 'static = ()>
+{
+    /// The commitments and salts against which the tally results are verified.
+    pub header: OutcomeHeader,
+
+    /// The verified tally results, in order of vote option.
+    pub tally_results: TallyResults<T, I>,
+
+    /// The block after which the staged outcome is abandoned, and must be staged anew.
+    pub expires_at: BlockNumber
+}
+
+impl<T: crate::Config<I>, I: 'static> StagedOutcome<T, I>
+{
+    /// Returns the index of the next vote option whose tally result is to be staged.
+    pub fn next_option(&self) -> u32
+    {
+        self.tally_results.len() as u32
+    }
+
+    /// Returns true iff the tally result of every vote option has been staged.
+    pub fn is_complete(&self) -> bool
+    {
+        self.next_option() == self.header.option_count
+    }
+
+}
+
+impl PollOutcome
+{
+    /// Returns the commitments and salts of the outcome, for a poll with the given number of vote options.
+    pub fn header(&self, option_count: u32) -> OutcomeHeader
+    {
+        OutcomeHeader {
+            total_spent: self.total_spent,
+            total_spent_salt: self.total_spent_salt,
+            tally_result_salt: self.tally_result_salt,
+            new_results_commitment: self.new_results_commitment,
+            spent_votes_hash: self.spent_votes_hash,
+            option_count
+        }
+    }
+}
+
+/// The outcome of a fulfilled poll as retained in storage, i.e. its tally results alongside the commitments and salts
+/// against which they were verified. The vote option tree paths of the tally results are only required to verify
+/// them, so are not retained, which bounds the stored outcome by `MaxVoteOptions`.
+#[derive(CloneNoBound, Encode, Decode, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T, I))]
+#[codec(mel_bound())]
+pub struct OutcomeRecord<T: crate::Config<I>, I: 'static = ()>
+{
+    /// The commitments and salts of the outcome, and the number of vote options of the poll.
+    pub header: OutcomeHeader,
+
+    /// The verified tally result of each vote option, in order of vote option.
+    pub tally_results: TallyResults<T, I>
+}
+
+impl<T: crate::Config<I>, I: 'static> OutcomeRecord<T, I>
+{
+    /// Retain the tally results of the vote options named by the header, of which there are at most
+    /// `MaxVoteOptions`.
+    pub fn new(header: OutcomeHeader, tally_results: &[u128]) -> Self
+    {
+        let count = tally_results.len().min(header.option_count as usize);
+
+        OutcomeRecord {
+            header,
+            tally_results: BoundedVec::truncate_from(tally_results[..count].to_vec())
+        }
+    }
+}
+
+/// The stored outcome of a fulfilled poll alongside the commitments and roots against which it was verified, as
+/// returned by the `outcome_export` runtime API.
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct OutcomeExport<PollId>
+{
+    /// The poll id.
+    pub poll_id: PollId,
+
+    /// The index of the winning vote option, or `NO_VOTES`.
+    pub outcome_index: OutcomeIndex,
+
+    /// The commitments and salts of the outcome which was verified when the poll was fulfilled.
+    pub header: OutcomeHeader,
+
+    /// The verified tally result of each vote option.
+    pub tally_results: vec::Vec<u128>,
+
+    /// The final commitment of the message processing circuit.
+    pub process_commitment: HashBytes,
+
+    /// The final commitment of the tallying circuit.
+    pub tally_commitment: HashBytes,
+
+    /// The root of the registration tree.
+    pub registration_root: HashBytes,
+
+    /// The root of the interaction tree.
+    pub interaction_root: HashBytes
+}
+
+/// The identifier of a pair of verifying keys in `CircuitRegistry`.
+pub type CircuitId = u32;
+
+/// A pair of verification keys for message processing and tally verification circuits.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub struct VerifyingKeys
 {
     /// The verifying key for the message processing circuit.
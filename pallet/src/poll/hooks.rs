@@ -0,0 +1,18 @@
+use crate::poll::OutcomeIndex;
+
+/// A handler for polls whose outcome was verified, e.g. to dispatch the call associated with the winning vote
+/// option, or to distribute rewards to the participants.
+///
+/// Called from within `commit_outcome`, such that the handler is charged to the coordinator. Implementations
+/// should therefore be cheap, or defer their work to a later block.
+pub trait OnPollFinalized<PollId>
+{
+    /// Called once the outcome of the poll was verified and committed to state. The outcome is `NO_VOTES` if no
+    /// vote was cast in the poll.
+    fn on_finalized(poll_id: PollId, outcome: OutcomeIndex);
+}
+
+impl<PollId> OnPollFinalized<PollId> for ()
+{
+    fn on_finalized(_poll_id: PollId, _outcome: OutcomeIndex) {}
+}
@@ -1,6 +1,22 @@
 use frame_support::pallet_prelude::*;
+use codec::Compact;
 use sp_std::vec;
 
+/// The length of an uncompressed serialized G1 point.
+pub const G1_ENCODED_LEN: usize = 64;
+
+/// The length of an uncompressed serialized G2 point.
+pub const G2_ENCODED_LEN: usize = 128;
+
+/// The maximum number of public inputs of a circuit, such that `gamma_abc_g1` holds at most one more point.
+pub const MAX_PUBLIC_INPUTS: usize = 16;
+
+/// The number of public inputs of the message processing circuit.
+pub const PROCESS_PUBLIC_INPUTS: usize = 9;
+
+/// The number of public inputs of the tally circuit.
+pub const TALLY_PUBLIC_INPUTS: usize = 5;
+
 /// A zk verification key.
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
 pub struct VerifyKey
@@ -12,8 +28,57 @@ pub struct VerifyKey
     pub gamma_abc_g1: vec::Vec<vec::Vec<u8>>,
 }
 
+impl VerifyKey
+{
+    /// The length in bytes of the points of a key for a circuit with the given number of public inputs.
+    pub fn expected_byte_len(public_inputs: usize) -> usize
+    {
+        G1_ENCODED_LEN
+            .saturating_add(G2_ENCODED_LEN.saturating_mul(3))
+            .saturating_add(G1_ENCODED_LEN.saturating_mul(public_inputs.saturating_add(1)))
+    }
+
+    /// The length in bytes of the points of the key.
+    pub fn byte_len(&self) -> usize
+    {
+        self.gamma_abc_g1
+            .iter()
+            .fold(
+                self.alpha_g1.len() + self.beta_g2.len() + self.gamma_g2.len() + self.delta_g2.len(),
+                |len, point| len.saturating_add(point.len())
+            )
+    }
+
+    /// Returns true iff each point has the length of an uncompressed point, and the number of public inputs
+    /// does not exceed `MAX_PUBLIC_INPUTS`, such that the key respects its `MaxEncodedLen`.
+    pub fn is_bounded(&self) -> bool
+    {
+        self.alpha_g1.len() == G1_ENCODED_LEN &&
+        self.beta_g2.len() == G2_ENCODED_LEN &&
+        self.gamma_g2.len() == G2_ENCODED_LEN &&
+        self.delta_g2.len() == G2_ENCODED_LEN &&
+        self.gamma_abc_g1.len() <= MAX_PUBLIC_INPUTS + 1 &&
+        self.gamma_abc_g1.iter().all(|point| point.len() == G1_ENCODED_LEN)
+    }
+}
+
+impl MaxEncodedLen for VerifyKey
+{
+    fn max_encoded_len() -> usize
+    {
+        let prefix = Compact::<u32>::max_encoded_len();
+        let g1 = prefix.saturating_add(G1_ENCODED_LEN);
+        let g2 = prefix.saturating_add(G2_ENCODED_LEN);
+
+        g1
+            .saturating_add(g2.saturating_mul(3))
+            .saturating_add(prefix)
+            .saturating_add(g1.saturating_mul(MAX_PUBLIC_INPUTS + 1))
+    }
+}
+
 /// A public key used to facillitate secret sharing between participants and coordinators.
-#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub struct PublicKey 
 {
     /// A 256-bit x-coordinate of the public key.
@@ -22,3 +87,17 @@ pub struct PublicKey
     /// A 256-bit y-coordinate of the public key.
     pub y: [u8; 32]
 }
+
+/// An EdDSA (Baby Jubjub, Poseidon) signature.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct Signature
+{
+    /// A 256-bit x-coordinate of the signature point.
+    pub r8_x: [u8; 32],
+
+    /// A 256-bit y-coordinate of the signature point.
+    pub r8_y: [u8; 32],
+
+    /// The 256-bit signature scalar.
+    pub s: [u8; 32]
+}
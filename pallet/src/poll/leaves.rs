@@ -0,0 +1,114 @@
+use sp_std::vec;
+use codec::Encode;
+use ark_bn254::{Fr};
+use ark_ff::{PrimeField, BigInteger};
+use crate::hash::{Poseidon, PoseidonHasher};
+use crate::poll::{CoordKeyHash, HashBytes, PollInteractionCipher, PublicKey, VerifyingKeys, MESSAGE_CHUNK_WIDTH};
+
+/// Computes the state leaf of a registration, i.e. `H4(x, y, credits, timestamp)`, where the coordinates
+/// of the public key are interpreted as big-endian field elements. Returns `None` if hashing fails.
+pub fn compute_state_leaf(
+    key: &PublicKey,
+    credits: u64,
+    timestamp: u64
+) -> Option<HashBytes>
+{
+    let inputs: vec::Vec<Fr> = vec::Vec::from([
+        Fr::from_be_bytes_mod_order(&key.x),
+        Fr::from_be_bytes_mod_order(&key.y),
+        Fr::from(credits),
+        Fr::from(timestamp)
+    ]);
+
+    hash(&inputs)
+}
+
+/// Computes the message leaf of an interaction, i.e. the hash of the `H5` digest of each chunk of the
+/// cipher followed by the coordinates of the ephemeral public key, e.g. `H4(H5(left), H5(right), x, y)` for
+/// a ten word message. Returns `None` if hashing fails.
+pub fn compute_message_leaf(
+    key: &PublicKey,
+    cipher: &PollInteractionCipher
+) -> Option<HashBytes>
+{
+    let mut chunk_hasher = Poseidon::<Fr>::new_circom(MESSAGE_CHUNK_WIDTH).ok()?;
+
+    let mut inputs: vec::Vec<Fr> = vec::Vec::new();
+    for chunk in cipher.chunks.iter()
+    {
+        let chunk_inputs: vec::Vec<Fr> = chunk
+            .iter()
+            .map(|bytes| Fr::from_be_bytes_mod_order(bytes))
+            .collect();
+
+        inputs.push(chunk_hasher.hash(&chunk_inputs).ok()?);
+    }
+
+    inputs.push(Fr::from_be_bytes_mod_order(&key.x));
+    inputs.push(Fr::from_be_bytes_mod_order(&key.y));
+
+    hash(&inputs)
+}
+
+/// Computes the hash of the public key of the coordinator which the message processing circuit takes as a public
+/// input, following the given convention. Returns `None` if hashing fails.
+pub fn compute_coordinator_key_hash(
+    key: &PublicKey,
+    convention: CoordKeyHash
+) -> Option<HashBytes>
+{
+    let x = Fr::from_be_bytes_mod_order(&key.x);
+    let y = Fr::from_be_bytes_mod_order(&key.y);
+
+    let inputs: vec::Vec<Fr> = match convention
+    {
+        CoordKeyHash::Poseidon2Coords => vec::Vec::from([ x, y ]),
+        CoordKeyHash::PoseidonPacked =>
+        {
+            // Set the most significant bit of `y` iff `x` is in the upper half of the field.
+            let mut packed = y.into_bigint().to_bytes_be();
+            if x.into_bigint() > Fr::MODULUS_MINUS_ONE_DIV_TWO { packed[0] |= 0x80; }
+
+            vec::Vec::from([ Fr::from_be_bytes_mod_order(&packed) ])
+        }
+    };
+
+    hash(&inputs)
+}
+
+/// Computes the digest of a pair of verifying keys, i.e. the `compute_encoding_hash` of their SCALE encoding.
+/// Returns `None` if hashing fails.
+pub fn compute_verify_key_hash(key: &VerifyingKeys) -> Option<HashBytes>
+{
+    compute_encoding_hash(&key.encode())
+}
+
+/// Computes the digest of an encoding of arbitrary length, i.e. the hash chain `H2(acc, word)` over the encoding split
+/// into 31-byte big-endian words, seeded with the length of the encoding. Returns `None` if hashing fails.
+pub fn compute_encoding_hash(encoded: &[u8]) -> Option<HashBytes>
+{
+    let mut hasher = Poseidon::<Fr>::new_circom(2).ok()?;
+    let mut acc = Fr::from(encoded.len() as u64);
+    for word in encoded.chunks(31)
+    {
+        acc = hasher.hash(&[ acc, Fr::from_be_bytes_mod_order(word) ]).ok()?;
+    }
+
+    let bytes = acc.into_bigint().to_bytes_be();
+    let mut digest = [0u8; 32];
+    digest[..bytes.len()].copy_from_slice(&bytes);
+
+    Some(digest)
+}
+
+pub(crate) fn hash(inputs: &[Fr]) -> Option<HashBytes>
+{
+    let mut hasher = Poseidon::<Fr>::new_circom(inputs.len()).ok()?;
+    let result = hasher.hash(inputs).ok()?;
+
+    let bytes = result.into_bigint().to_bytes_be();
+    let mut leaf = [0u8; 32];
+    leaf[..bytes.len()].copy_from_slice(&bytes);
+
+    Some(leaf)
+}
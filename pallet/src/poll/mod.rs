@@ -1,17 +1,36 @@
+pub mod actions;
+pub mod cache;
 pub mod coordinator;
 pub mod config;
+pub mod hooks;
 pub mod poll;
 pub mod provider;
+pub mod pruning;
 pub mod state;
+pub mod summary;
 pub mod keys;
+pub mod leaves;
 pub mod zeroes;
 
+pub use actions::AllowedActions;
+pub use cache::PollPhaseCache;
 pub use coordinator::*;
-pub use config::{PollConfiguration};
+pub use config::{CoordKeyHash, PollConfiguration, PollConfigOf, SerializablePollConfig};
+pub use hooks::OnPollFinalized;
 pub use poll::*;
 pub use provider::*;
+pub use pruning::{PruneCursor, PruneStage};
 pub use keys::*;
+pub use leaves::{
+    compute_coordinator_key_hash,
+    compute_encoding_hash,
+    compute_message_leaf,
+    compute_state_leaf,
+    compute_verify_key_hash
+};
+pub use summary::{KeeperAction, PollPhase, PollSummary};
 pub use state::{
+    MerkleTreeStats,
     PollState,
     NewPollState,
     AmortizedIncrementalMerkleTree,
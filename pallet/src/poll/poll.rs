@@ -1,25 +1,110 @@
 use frame_support::pallet_prelude::*;
+use frame_support::traits::Currency;
 use sp_std::vec;
 use crate::poll::{PollConfiguration, PollState, ProofData};
 use crate::hash::poseidon::{HASH_LEN};
 
+/// The maximum number of words in an interaction message, i.e. three chunks of `MESSAGE_CHUNK_WIDTH` words
+/// such that the leaf hash of the message and public key remains quinary.
+pub const MAX_MESSAGE_WORDS: u32 = 15;
+
+/// The number of words of an interaction message which are hashed together.
+pub const MESSAGE_CHUNK_WIDTH: usize = 5;
+
+/// The maximum number of chunks in an interaction message.
+pub const MAX_MESSAGE_CHUNKS: u32 = MAX_MESSAGE_WORDS / MESSAGE_CHUNK_WIDTH as u32;
+
+/// The schema version of newly written polls, i.e. the storage version whose layout and invariants they follow.
+/// Migrations bump the schema version of every poll they translate, such that they may skip polls which are already
+/// current, and `try_state` may detect polls which a migration missed.
+pub const POLL_SCHEMA_VERSION: u16 = 6;
+
+/// The outcome of a poll in which no vote was cast, e.g. a poll without interactions. Never the index of a
+/// vote option, since polls have at most `MaxVoteOptions` options.
+pub const NO_VOTES: OutcomeIndex = OutcomeIndex::MAX;
+
 pub type BlockNumber = u64;
 pub type CommitmentIndex = u32;
 pub type CommitmentData = HashBytes;
 pub type HashBytes = [u8; HASH_LEN];
 pub type Outcome = u128;
 pub type OutcomeIndex = u32;
-pub type PollId = u32;
-pub type PollInteractionData = [[u8; 32]; 10]; 
-pub type ProofBatches = vec::Vec<(ProofData, CommitmentData)>;
-pub type VoteOptions<T> = BoundedVec<u128, <T as crate::Config>::MaxVoteOptions>;
-
-#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
-#[scale_info(skip_type_params(T))]
-pub struct Poll<T: crate::Config>
+pub type PollIdOf<T, I = ()> = <T as crate::Config<I>>::PollId;
+pub type PollInteractionData = BoundedVec<[u8; 32], ConstU32<MAX_MESSAGE_WORDS>>;
+pub type ProofBatches<T, I = ()> = BoundedVec<(ProofData, CommitmentData), <T as crate::Config<I>>::MaxProofsPerCall>;
+pub type VoteOptions<T, I = ()> = BoundedVec<u128, <T as crate::Config<I>>::MaxVoteOptions>;
+pub type TallyResults<T, I = ()> = BoundedVec<u128, <T as crate::Config<I>>::MaxVoteOptions>;
+pub type OptionCall<T, I = ()> = BoundedVec<u8, <T as crate::Config<I>>::MaxOptionCallLen>;
+pub type OptionCalls<T, I = ()> = BoundedVec<(OutcomeIndex, OptionCall<T, I>), <T as crate::Config<I>>::MaxVoteOptions>;
+pub type BalanceOf<T, I = ()> = <<T as crate::Config<I>>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+pub type NegativeImbalanceOf<T, I = ()> = <<T as crate::Config<I>>::Currency as Currency<<T as frame_system::Config>::AccountId>>::NegativeImbalance;
+
+/// An interaction message split into the chunks of `MESSAGE_CHUNK_WIDTH` words that the circuit hashes,
+/// e.g. the left and right halves of a ten word message.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PollInteractionCipher
+{
+    /// The chunks of the message in order, where the final chunk is zero padded.
+    pub chunks: BoundedVec<[HashBytes; MESSAGE_CHUNK_WIDTH], ConstU32<MAX_MESSAGE_CHUNKS>>,
+
+    /// The number of words in the message, excluding the padding of the final chunk.
+    pub words: u8
+}
+
+impl PollInteractionCipher
+{
+    /// Returns true iff the message spans exactly as many chunks as its words require, and the final chunk
+    /// is zero padded.
+    pub fn is_well_formed(&self) -> bool
+    {
+        let words = usize::from(self.words);
+
+        self.chunks.len() == (words + MESSAGE_CHUNK_WIDTH - 1) / MESSAGE_CHUNK_WIDTH &&
+        self.chunks.iter().flatten().skip(words).all(|word| *word == [0u8; HASH_LEN])
+    }
+}
+
+impl From<PollInteractionData> for PollInteractionCipher
+{
+    fn from(data: PollInteractionData) -> Self
+    {
+        let chunks: vec::Vec<[HashBytes; MESSAGE_CHUNK_WIDTH]> = data
+            .chunks(MESSAGE_CHUNK_WIDTH)
+            .map(|words| {
+                let mut chunk = [[0u8; HASH_LEN]; MESSAGE_CHUNK_WIDTH];
+                chunk[..words.len()].copy_from_slice(words);
+                chunk
+            })
+            .collect();
+
+        // The number of chunks is bounded by the number of words, so nothing is truncated.
+        PollInteractionCipher {
+            words: data.len() as u8,
+            chunks: BoundedVec::truncate_from(chunks)
+        }
+    }
+}
+
+impl From<PollInteractionCipher> for PollInteractionData
+{
+    fn from(cipher: PollInteractionCipher) -> Self
+    {
+        let words: vec::Vec<HashBytes> = cipher.chunks
+            .into_iter()
+            .flatten()
+            .take(usize::from(cipher.words))
+            .collect();
+
+        BoundedVec::truncate_from(words)
+    }
+}
+
+#[derive(CloneNoBound, Encode, Decode, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound, TypeInfo)]
+#[scale_info(skip_type_params(T, I))]
+pub struct Poll<T: crate::Config<I>, I: 'static = ()>
 {
     /// The poll id.
-    pub index: PollId,
+    pub index: T::PollId,
 
     /// The poll creator.
     pub coordinator: T::AccountId,
@@ -31,5 +116,26 @@ pub struct Poll<T: crate::Config>
     pub state: PollState,
 
     /// The poll config.
-    pub config: PollConfiguration<T>
+    pub config: PollConfiguration<T, I>,
+
+    /// The schema version of the poll, i.e. `POLL_SCHEMA_VERSION` once every migration was applied to it.
+    pub schema_version: u16
+}
+
+impl<T: crate::Config<I>, I: 'static> MaxEncodedLen for Poll<T, I>
+{
+    fn max_encoded_len() -> usize
+    {
+        let state = PollState::max_encoded_len_for(
+            T::MaxPollRegistrations::get(),
+            T::MaxPollInteractions::get()
+        );
+
+        T::PollId::max_encoded_len()
+            .saturating_add(T::AccountId::max_encoded_len())
+            .saturating_add(BlockNumber::max_encoded_len())
+            .saturating_add(state)
+            .saturating_add(PollConfiguration::<T, I>::max_encoded_len())
+            .saturating_add(u16::max_encoded_len())
+    }
 }
@@ -1,34 +1,62 @@
-use sp_std::vec;
+#![deny(clippy::unwrap_used, clippy::expect_used)]
+
+use sp_std::{cmp::Reverse, vec};
 use sp_runtime::traits::SaturatedConversion;
 use ark_bn254::{Fr};
 use ark_ff::{PrimeField, BigInteger};
-use crate::hash::{Poseidon, PoseidonHasher};
+use crate::crypto::is_valid_fr_element;
+use crate::hash::{is_supported_circom_arity, poseidon2, poseidon3, poseidon_hash};
 use crate::poll::{
     AmortizedIncrementalMerkleTree, 
     BlockNumber,
     Commitment,
-    Coordinator,
+    CommitmentIndex,
+    CoordKeyHash,
     HashBytes,
     MerkleTreeError,
     OutcomeIndex,
     PollOutcome,
     Poll, 
     PublicKey,
-    PollInteractionData,
+    PollInteractionCipher,
+    PollPhase,
     VerifyKey,
+    VerifyingKeys,
+    MESSAGE_CHUNK_WIDTH,
+    NO_VOTES,
+    leaves::{compute_coordinator_key_hash, compute_message_leaf, compute_state_leaf},
+    state::PollStateTree,
     zeroes::EMPTY_BALLOT_ROOTS
 };
 
-pub trait PollProvider<T: crate::Config>: Sized
+pub trait PollProvider<T: crate::Config<I>, I: 'static = ()>: Sized
 {
     fn verify_outcome(
         self,
         oucome: Option<PollOutcome>
     ) -> Option<OutcomeIndex>;
 
+    fn verify_tally_result(
+        &self,
+        option_index: u32,
+        tally_result: u128,
+        tally_path: vec::Vec<vec::Vec<HashBytes>>,
+        tally_result_salt: HashBytes,
+        spent_votes_hash: HashBytes
+    ) -> bool;
+
+    fn verify_total_spent(
+        &self,
+        total_spent: HashBytes,
+        total_spent_salt: HashBytes,
+        new_results_commitment: HashBytes
+    ) -> bool;
+
     fn prepare_public_inputs(
         self,
-        coordinator: Coordinator,
+        public_key: PublicKey,
+        coord_key_hash: CoordKeyHash,
+        verify_key: VerifyingKeys,
         new_commitment: HashBytes
     ) -> Option<(VerifyKey, vec::Vec<Fr>, Commitment)>;
 
@@ -36,13 +64,13 @@ pub trait PollProvider<T: crate::Config>: Sized
         self, 
         public_key: PublicKey, 
         timestamp: u64
-    ) -> Result<(u32, Self), MerkleTreeError>;
+    ) -> Result<(u32, HashBytes, Self), MerkleTreeError>;
 
     fn consume_interaction(
         self,
         public_key: PublicKey,
-        data: PollInteractionData
-    ) -> Result<(u32, Self), MerkleTreeError>;
+        cipher: &PollInteractionCipher
+    ) -> Result<(u32, HashBytes, Self), MerkleTreeError>;
 
     fn merge_registrations(self) -> Result<Self, MerkleTreeError>;
 
@@ -56,8 +84,20 @@ pub trait PollProvider<T: crate::Config>: Sized
 
     fn is_registration_period(&self) -> bool;
 
+    fn get_registration_period_end(&self) -> BlockNumber;
+
     fn get_voting_period_end(&self) -> BlockNumber;
 
+    fn interaction_batch_size(&self) -> Option<u32>;
+
+    fn tally_batch_size(&self) -> Option<u32>;
+
+    fn expected_batches(&self) -> Option<(CommitmentIndex, CommitmentIndex)>;
+
+    fn batch_sizes_valid(&self) -> bool;
+
+    fn hash_arities_supported(&self) -> bool;
+
     fn is_over(&self) -> bool;
 
     fn is_fulfilled(&self) -> bool;
@@ -68,10 +108,16 @@ pub trait PollProvider<T: crate::Config>: Sized
 
     fn is_nullified(&self) -> bool;
 
+    fn is_pending(&self) -> bool;
+
+    fn is_sealed(&self) -> bool;
+
+    fn phase(&self) -> PollPhase;
+
     fn nullify(self) -> Self;
 }
 
-impl<T: crate::Config> PollProvider<T> for Poll<T>
+impl<T: crate::Config<I>, I: 'static> PollProvider<T, I> for Poll<T, I>
 {
     fn verify_outcome(
         self,
@@ -82,72 +128,85 @@ impl<T: crate::Config> PollProvider<T> for Poll<T>
         if !self.is_proven() { return None; }
 
         let Some(outcome) = outcome else { return None; };
-        let Some(mut hasher) = Poseidon::<Fr>::new_circom(2).ok() else { return None; };
-        
-        let mut outcome_index: OutcomeIndex = 0;
-        let mut max_tally_result = 0;
 
         // Verify the tally result for each individual vote option.
         for option_index in 0..self.config.vote_options.len()
         {
             let Some(tally_result) = outcome.tally_results.get(option_index) else { return None; };
             let Some(tally_path) = outcome.tally_result_proofs.get(option_index) else { return None; };
-            let mut tally_result_bytes = [0u8; 32];
-            tally_result_bytes[28..].copy_from_slice(&tally_result.to_be_bytes());
 
-            let Some(root) = compute_merkle_root_from_path(
-                self.config.vote_option_tree_depth,
+            if !self.verify_tally_result(
                 option_index as u32,
-                tally_result_bytes,
-                tally_path.clone()
-            ) else { return None; };
+                *tally_result,
+                tally_path.clone(),
+                outcome.tally_result_salt,
+                outcome.spent_votes_hash
+            ) { return None; }
+        }
 
-            let mut inputs: vec::Vec<Fr> = vec::Vec::<Fr>::new();
-            inputs.push(Fr::from_be_bytes_mod_order(&root));
-            inputs.push(Fr::from_be_bytes_mod_order(&outcome.tally_result_salt));
-            let Some(hash) = hasher.hash(&inputs).ok() else { return None; };
+        // Verify the total number of votes cast.
+        if !self.verify_total_spent(outcome.total_spent, outcome.total_spent_salt, outcome.new_results_commitment) { return None; }
 
-            let mut inputs: vec::Vec<Fr> = vec::Vec::<Fr>::new();
-            inputs.push(Fr::from_be_bytes_mod_order(&hash.into_bigint().to_bytes_be()));
-            inputs.push(Fr::from_be_bytes_mod_order(&outcome.spent_votes_hash));
-            let Some(hash) = hasher.hash(&inputs).ok() else { return None; };
+        let Some(tally_results) = outcome.tally_results.get(..self.config.vote_options.len()) else { return None; };
 
-            if hash.into_bigint().to_bytes_be() != self.state.commitment.tally.1 { return None; }
+        Some(select_winner(tally_results))
+    }
 
-            // Track the vote option with the largest tally.
-            if *tally_result > max_tally_result
-            {
-                outcome_index = option_index as OutcomeIndex;
-                max_tally_result = *tally_result;
-            }
-        }
+    /// Returns true iff the tally result of the vote option is a leaf of the vote option tree whose salted root the
+    /// final tally commitment commits to.
+    fn verify_tally_result(
+        &self,
+        option_index: u32,
+        tally_result: u128,
+        tally_path: vec::Vec<vec::Vec<HashBytes>>,
+        tally_result_salt: HashBytes,
+        spent_votes_hash: HashBytes
+    ) -> bool
+    {
+        let Some(tally_result_bytes) = encode_tally_result(tally_result) else { return false; };
 
-        // Verify the total number of votes cast.
-        let mut inputs: vec::Vec<Fr> = vec::Vec::<Fr>::new();
-        inputs.push(Fr::from_be_bytes_mod_order(&outcome.total_spent));
-        inputs.push(Fr::from_be_bytes_mod_order(&outcome.total_spent_salt));
-        let Some(hash) = hasher.hash(&inputs).ok() else { return None; };
+        let Some(root) = compute_merkle_root_from_path(
+            self.config.vote_option_tree_depth,
+            option_index,
+            tally_result_bytes,
+            tally_path
+        ) else { return false; };
 
-        let mut inputs: vec::Vec<Fr> = vec::Vec::<Fr>::new();
-        inputs.push(Fr::from_be_bytes_mod_order(&outcome.new_results_commitment));
-        inputs.push(Fr::from_be_bytes_mod_order(&hash.into_bigint().to_bytes_be()));
-        let Some(hash) = hasher.hash(&inputs).ok() else { return None; };
+        let Some(hash) = poseidon2(root, tally_result_salt) else { return false; };
+        let Some(hash) = poseidon2(hash, spent_votes_hash) else { return false; };
 
-        if hash.into_bigint().to_bytes_be() != self.state.commitment.tally.1 { return None; }
+        hash == self.state.commitment.tally.current
+    }
 
-        Some(outcome_index)
+    /// Returns true iff the salted total number of votes cast is committed to by the final tally commitment.
+    fn verify_total_spent(
+        &self,
+        total_spent: HashBytes,
+        total_spent_salt: HashBytes,
+        new_results_commitment: HashBytes
+    ) -> bool
+    {
+        let Some(hash) = poseidon2(total_spent, total_spent_salt) else { return false; };
+        let Some(hash) = poseidon2(new_results_commitment, hash) else { return false; };
+
+        hash == self.state.commitment.tally.current
     }
 
     fn prepare_public_inputs(
         self,
-        coordinator: Coordinator,
+        public_key: PublicKey,
+        coord_key_hash: CoordKeyHash,
+        verify_key: VerifyingKeys,
         new_commitment: HashBytes
     ) -> Option<(VerifyKey, vec::Vec<Fr>, Commitment)>
     {
-        let verify_key: VerifyKey;
+        // The coordinates would otherwise be silently reduced into public inputs which match no proof.
+        if !is_valid_fr_element(&public_key.x) || !is_valid_fr_element(&public_key.y) { return None; }
+
+        let circuit_key: VerifyKey;
         let mut inputs: vec::Vec<Fr> = vec::Vec::<Fr>::new();
 
-        let message_batch_size: u32 = self.state.interactions.arity.pow(self.config.process_subtree_depth.into()).into();
+        let Some(message_batch_size) = self.interaction_batch_size().filter(|size| *size > 0) else { return None; };
         let mut current_batch_index = self.state.interactions.count;
         if current_batch_index > 0
         {
@@ -155,63 +214,57 @@ impl<T: crate::Config> PollProvider<T> for Poll<T>
             if r == 0 { current_batch_index -= message_batch_size; }
             else { current_batch_index -= r; }
         }
-        let mut proof_index = self.state.commitment.process.0;
-        let index_offset = proof_index * message_batch_size;
+        let proof_index = self.state.commitment.process.completed;
+        let Some(index_offset) = proof_index.checked_mul(message_batch_size) else { return None; };
 
-        // Return inputs for message processing circuit
-        if index_offset <= current_batch_index
+        // Return inputs for message processing circuit, unless there are no messages to process
+        if self.state.interactions.count > 0 && index_offset <= current_batch_index
         {
-            verify_key = coordinator.verify_key.process;
-
-            let Some(mut hasher) = Poseidon::<Fr>::new_circom(2).ok() else { return None; };
-            let coord_pub_key = coordinator.public_key.clone();
-            let coord_pub_key_fr: vec::Vec<Fr> = vec::Vec::from([ coord_pub_key.x, coord_pub_key.y ])
-                .iter()
-                .map(|bytes| Fr::from_be_bytes_mod_order(bytes))
-                .collect();
-            let Some(coord_pub_key_hash) = hasher.hash(&coord_pub_key_fr).ok() else { return None; };
+            circuit_key = verify_key.process;
+
+            let Some(coord_pub_key_hash) = compute_coordinator_key_hash(&public_key, coord_key_hash) else { return None; };
             let Some(root_bytes) = self.state.interactions.root else { return None; };
 
             current_batch_index -= index_offset;
             let mut end_batch_index = current_batch_index + message_batch_size;
             if end_batch_index > self.state.interactions.count { end_batch_index = self.state.interactions.count; }
             
-            inputs.push(Fr::from(self.state.registrations.count + 1));
+            inputs.push(Fr::from(effective_state_size(self.state.registrations.count)));
             inputs.push(Fr::from(self.get_voting_period_end()));
             inputs.push(Fr::from_be_bytes_mod_order(&root_bytes));
             inputs.push(Fr::from(self.state.registrations.depth));
             inputs.push(Fr::from(end_batch_index));
             inputs.push(Fr::from(current_batch_index));
-            inputs.push(coord_pub_key_hash);
-            inputs.push(Fr::from_be_bytes_mod_order(&self.state.commitment.process.1));
+            inputs.push(Fr::from_be_bytes_mod_order(&coord_pub_key_hash));
+            inputs.push(Fr::from_be_bytes_mod_order(&self.state.commitment.process.current));
             inputs.push(Fr::from_be_bytes_mod_order(&new_commitment));
 
             let mut commitment = self.state.commitment.clone();
-            commitment.process = (proof_index + 1, new_commitment);
+            commitment.process.advance(new_commitment);
     
-            Some((verify_key, inputs, commitment))
+            Some((circuit_key, inputs, commitment))
         }
 
         // Return inputs for tally circuit
         else
         {
-            proof_index = self.state.commitment.tally.0;
-            verify_key = coordinator.verify_key.tally;
+            let proof_index = self.state.commitment.tally.completed;
+            circuit_key = verify_key.tally;
 
-            let batch_size: u32 = self.state.registrations.arity.pow(self.config.tally_subtree_depth.into()).into();
-            let current_batch_index = proof_index * batch_size;
-            if current_batch_index >= self.state.registrations.count + 1 { return None; }
+            let Some(batch_size) = self.tally_batch_size().filter(|size| *size > 0) else { return None; };
+            let Some(current_batch_index) = proof_index.checked_mul(batch_size) else { return None; };
+            if current_batch_index >= effective_state_size(self.state.registrations.count) { return None; }
 
-            inputs.push(Fr::from_be_bytes_mod_order(&self.state.commitment.process.1));
-            inputs.push(Fr::from_be_bytes_mod_order(&self.state.commitment.tally.1));
+            inputs.push(Fr::from_be_bytes_mod_order(&self.state.commitment.process.current));
+            inputs.push(Fr::from_be_bytes_mod_order(&self.state.commitment.tally.current));
             inputs.push(Fr::from_be_bytes_mod_order(&new_commitment));
             inputs.push(Fr::from(current_batch_index));
-            inputs.push(Fr::from(self.state.registrations.count + 1));
+            inputs.push(Fr::from(effective_state_size(self.state.registrations.count)));
 
             let mut commitment = self.state.commitment.clone();
-            commitment.tally = (proof_index + 1, new_commitment);
+            commitment.tally.advance(new_commitment);
 
-            Some((verify_key, inputs, commitment))
+            Some((circuit_key, inputs, commitment))
         }
     }
 
@@ -219,71 +272,24 @@ impl<T: crate::Config> PollProvider<T> for Poll<T>
         mut self, 
         public_key: PublicKey,
         timestamp: u64
-    ) -> Result<(u32, Self), MerkleTreeError>
+    ) -> Result<(u32, HashBytes, Self), MerkleTreeError>
     {
-        let Some(mut hasher) = Poseidon::<Fr>::new_circom(4).ok() else { Err(MerkleTreeError::HashFailed)? };
-
-        let mut inputs: vec::Vec<Fr> = vec::Vec::from([ public_key.x, public_key.y ])
-            .iter()
-            .map(|bytes| Fr::from_be_bytes_mod_order(bytes))
-            .collect();
-        inputs.push(Fr::from(1));
-        inputs.push(Fr::from(timestamp));
-
-        let Some(result) = hasher.hash(&inputs).ok() else { Err(MerkleTreeError::HashFailed)? };
-        let bytes = result.into_bigint().to_bytes_be();
-        let mut leaf = [0u8; 32];
-        leaf[..bytes.len()].copy_from_slice(&bytes);
+        let (count, leaf, registrations) = insert_registration_leaf(self.state.registrations, public_key, timestamp)?;
+        self.state.registrations = registrations;
 
-        self.state.registrations = self.state.registrations.insert(leaf)?;
-
-        Ok((self.state.registrations.count, self))
+        Ok((count, leaf, self))
     }
 
     fn consume_interaction(
         mut self, 
         public_key: PublicKey,
-        data: PollInteractionData
-    ) -> Result<(u32, Self), MerkleTreeError>
+        cipher: &PollInteractionCipher
+    ) -> Result<(u32, HashBytes, Self), MerkleTreeError>
     {
-        let Some(mut hash4) = Poseidon::<Fr>::new_circom(4).ok() else { Err(MerkleTreeError::HashFailed)? };
-        let Some(mut hash5) = Poseidon::<Fr>::new_circom(5).ok() else { Err(MerkleTreeError::HashFailed)? };
-
-        let left_inputs: vec::Vec<Fr> = vec::Vec::from([ data[0], data[1], data[2], data[3], data[4] ])
-            .iter()
-            .map(|bytes| Fr::from_be_bytes_mod_order(bytes))
-            .collect();
-
-        let right_inputs: vec::Vec<Fr> = vec::Vec::from([ data[5], data[6], data[7], data[8], data[9] ])
-            .iter()
-            .map(|bytes| Fr::from_be_bytes_mod_order(bytes))
-            .collect();
-
-        let Some(left) = hash5.hash(&left_inputs).ok() else { Err(MerkleTreeError::HashFailed)? };
-        let Some(right) = hash5.hash(&right_inputs).ok() else { Err(MerkleTreeError::HashFailed)? };
+        let (count, leaf, interactions) = insert_interaction_leaf(self.state.interactions, public_key, cipher)?;
+        self.state.interactions = interactions;
 
-        let left_bytes = left.into_bigint().to_bytes_be();
-        let right_bytes = right.into_bigint().to_bytes_be();
-
-        let inputs: vec::Vec<Fr> = vec::Vec::from([
-            left_bytes,
-            right_bytes,
-            vec::Vec::from(public_key.x),
-            vec::Vec::from(public_key.y)
-        ])
-            .iter()
-            .map(|bytes| Fr::from_be_bytes_mod_order(bytes))
-            .collect();
-
-        let Some(result) = hash4.hash(&inputs).ok() else { Err(MerkleTreeError::HashFailed)? };
-
-        let bytes = result.into_bigint().to_bytes_be();
-        let mut leaf = [0u8; 32];
-        leaf[..bytes.len()].copy_from_slice(&bytes);
-
-        self.state.interactions = self.state.interactions.insert(leaf)?;
-
-        Ok((self.state.interactions.count, self))
+        Ok((count, leaf, self))
     }
 
     fn merge_registrations(
@@ -293,19 +299,10 @@ impl<T: crate::Config> PollProvider<T> for Poll<T>
         self.state.registrations = self.state.registrations.merge(false)?;
 
         let Some(root) = self.state.registrations.root else { Err(MerkleTreeError::MergeFailed)? };
-        let Some(mut hasher) = Poseidon::<Fr>::new_circom(3).ok() else { Err(MerkleTreeError::HashFailed)? };
-
-        let inputs: vec::Vec<Fr> = vec::Vec::from([ root, EMPTY_BALLOT_ROOTS[1], [0u8;32] ])
-            .iter()
-            .map(|bytes| Fr::from_be_bytes_mod_order(bytes))
-            .collect();
-
-        let Some(result) = hasher.hash(&inputs).ok() else { Err(MerkleTreeError::HashFailed)? };
-        let bytes = result.into_bigint().to_bytes_be();
-        let mut commitment = [0u8; 32];
-        commitment[..bytes.len()].copy_from_slice(&bytes);
+        let Some(commitment) = poseidon3(root, EMPTY_BALLOT_ROOTS[1], [0u8; 32]) else { Err(MerkleTreeError::HashFailed)? };
 
-        self.state.commitment.process = (0, commitment);
+        self.state.commitment.process.completed = 0;
+        self.state.commitment.process.current = commitment;
 
         Ok(self)
     }
@@ -314,23 +311,27 @@ impl<T: crate::Config> PollProvider<T> for Poll<T>
         mut self
     ) -> Result<Self, MerkleTreeError>
     {
-        self.state.interactions = self.state.interactions.merge(true)?;
+        // Batch sizes are validated upon poll creation, but are checked again rather than divided by zero.
+        let Some((expected_process, expected_tally)) = self.expected_batches() else { Err(MerkleTreeError::InvalidBatchSize)? };
 
-        let process_batch_size: u32 = self.state.interactions.arity.pow(self.config.process_subtree_depth.into()).into();
-        let process_extra_batch = if (self.state.interactions.count % process_batch_size) > 0 { 1 } else { 0 };
-        self.state.commitment.expected_process = (self.state.interactions.count / process_batch_size) + process_extra_batch;
+        // The poll is sealed, such that the counts from which the expected proofs are derived are final.
+        self.state.interactions = self.state.interactions.merge(true)?;
 
-        let tally_batch_size: u32 = self.state.registrations.arity.pow(self.config.tally_subtree_depth.into()).into();
-        self.state.commitment.expected_tally = 1 + (self.state.registrations.count / tally_batch_size);
+        self.state.commitment.process.expected = expected_process;
+        self.state.commitment.tally.expected = expected_tally;
 
         Ok(self)
     }
 
+    /// The first leaf of the registration tree is the blank state leaf, whose index the circuits reserve, such
+    /// that `max_registrations - 1` participants fill the tree.
     fn registration_limit_reached(&self) -> bool
     {
-        self.state.registrations.count >= self.config.max_registrations - 1
+        effective_state_size(self.state.registrations.count) >= self.config.max_registrations
     }
 
+    /// The interaction tree reserves no leaf, and its count is incremented once an interaction is inserted, such
+    /// that at most `max_interactions` interactions are stored, and the limit is reached upon the last of them.
     fn interaction_limit_reached(&self) -> bool
     {
         self.state.interactions.count >= self.config.max_interactions
@@ -339,27 +340,100 @@ impl<T: crate::Config> PollProvider<T> for Poll<T>
     /// Returns true iff poll is not None and `now` preceeds the end time of the poll.
     fn is_voting_period(&self) -> bool
     {
+        if self.state.pending { return false; }
+
         let now = <frame_system::Pallet<T>>::block_number().saturated_into::<u64>();
-        let voting_period_start = self.created_at + self.config.signup_period;
-        let voting_period_end = voting_period_start + self.config.voting_period;
+        let Some(voting_period_start) = self.created_at.checked_add(self.config.signup_period) else { return false; };
+        let Some(voting_period_end) = voting_period_start.checked_add(self.config.voting_period) else { return false; };
         now >= voting_period_start && now < voting_period_end
     }
 
     /// Returns true iff poll is currently within the registration period.
 	fn is_registration_period(&self) -> bool
 	{
+		if self.state.pending { return false; }
+
 		let now = <frame_system::Pallet<T>>::block_number().saturated_into::<u64>();
-		now >= self.created_at && now < self.created_at + self.config.signup_period
+		let Some(registration_period_end) = self.registration_period_end() else { return false; };
+		now >= self.created_at && now < registration_period_end
 	}
 
+    /// Returns the first block after the registration period, read from `PollRegistrationPeriodEnd`. Computed
+    /// from the periods of the poll if it is not stored, e.g. for polls which were never inserted.
+    /// Returns `BlockNumber::MAX` if the computed end overflows.
+    fn get_registration_period_end(&self) -> BlockNumber
+    {
+        self.registration_period_end().unwrap_or(BlockNumber::MAX)
+    }
+
+    /// Returns the last block of the voting period, read from `PollVotingPeriodEnd`. Computed from the periods
+    /// of the poll if it is not stored, e.g. for polls which were never inserted. Returns `BlockNumber::MAX` if the
+    /// computed end overflows, i.e. the poll is never over.
     fn get_voting_period_end(&self) -> BlockNumber
     {
-        self.created_at + self.config.signup_period + self.config.voting_period
+        crate::PollVotingPeriodEnd::<T, I>::get(self.index)
+            .or_else(|| self.created_at.checked_add(self.config.signup_period)?.checked_add(self.config.voting_period))
+            .unwrap_or(BlockNumber::MAX)
     }
 
-    /// Returns true iff poll has ended.
+    /// Returns the number of interactions processed per proof, or `None` on overflow.
+    fn interaction_batch_size(&self) -> Option<u32>
+    {
+        u32::from(self.state.interactions.arity).checked_pow(self.config.process_subtree_depth.into())
+    }
+
+    /// Returns the number of registrations tallied per proof, or `None` on overflow.
+    fn tally_batch_size(&self) -> Option<u32>
+    {
+        u32::from(self.state.registrations.arity).checked_pow(self.config.tally_subtree_depth.into())
+    }
+
+    /// Returns the number of message processing and tally proofs which cover the current interactions and
+    /// registrations, i.e. `ceil(interactions / process_batch_size)` and `1 + registrations / tally_batch_size`,
+    /// the latter accounting for the blank state leaf. Returns `None` if either batch size is zero or overflows.
+    fn expected_batches(&self) -> Option<(CommitmentIndex, CommitmentIndex)>
+    {
+        let process_batch_size = self.interaction_batch_size().filter(|size| *size > 0)?;
+        let tally_batch_size = self.tally_batch_size().filter(|size| *size > 0)?;
+
+        let interactions = self.state.interactions.count;
+        let process_extra_batch = if (interactions % process_batch_size) > 0 { 1 } else { 0 };
+
+        let state_size = effective_state_size(self.state.registrations.count);
+        let tally_extra_batch = if (state_size % tally_batch_size) > 0 { 1 } else { 0 };
+
+        Some((
+            (interactions / process_batch_size) + process_extra_batch,
+            (state_size / tally_batch_size) + tally_extra_batch
+        ))
+    }
+
+    /// Returns true iff both the interaction and the tally batch sizes are non-zero and fit in a `u32`.
+    fn batch_sizes_valid(&self) -> bool
+    {
+        matches!(self.interaction_batch_size(), Some(size) if size > 0) &&
+        matches!(self.tally_batch_size(), Some(size) if size > 0)
+    }
+
+    /// Returns true iff every Poseidon arity which the poll requires is supported.
+    fn hash_arities_supported(&self) -> bool
+    {
+        POLL_HASH_ARITIES
+            .iter()
+            .copied()
+            .chain([
+                self.state.registrations.arity.into(),
+                self.state.interactions.arity.into(),
+                (usize::from(self.config.message_words) + MESSAGE_CHUNK_WIDTH - 1) / MESSAGE_CHUNK_WIDTH + 2
+            ])
+            .all(is_supported_circom_arity)
+    }
+
+    /// Returns true iff poll has ended. A pending poll has not started, so it is only over once it is nullified.
     fn is_over(&self) -> bool
     {
+        if self.state.pending { return self.is_nullified(); }
+
 		let now = <frame_system::Pallet<T>>::block_number().saturated_into::<u64>();
 		now > self.get_voting_period_end()
     }
@@ -377,8 +451,7 @@ impl<T: crate::Config> PollProvider<T> for Poll<T>
 
     fn is_proven(&self) -> bool
     {
-        (self.state.commitment.process.0 == self.state.commitment.expected_process) && 
-            (self.state.commitment.tally.0 == self.state.commitment.expected_tally)
+        self.state.commitment.process.is_complete() && self.state.commitment.tally.is_complete()
     }
 
     fn is_nullified(&self) -> bool
@@ -386,6 +459,29 @@ impl<T: crate::Config> PollProvider<T> for Poll<T>
         self.state.tombstone
     }
 
+    /// Returns true iff the poll awaits activation by its coordinator, and has not been nullified.
+    fn is_pending(&self) -> bool
+    {
+        self.state.pending && !self.is_nullified()
+    }
+
+    /// Returns true iff the voting period of the poll was closed, i.e. its deadline may no longer change.
+    fn is_sealed(&self) -> bool
+    {
+        self.state.sealed
+    }
+
+    /// Returns the phase of the poll at the current block.
+    fn phase(&self) -> PollPhase
+    {
+        if self.is_nullified() { PollPhase::Nullified }
+        else if self.state.outcome.is_some() { PollPhase::Fulfilled }
+        else if self.is_pending() { PollPhase::Pending }
+        else if self.is_registration_period() { PollPhase::Registration }
+        else if self.is_voting_period() { PollPhase::Voting }
+        else { PollPhase::Processing }
+    }
+
     fn nullify(mut self) -> Self
     {
         self.state.tombstone = true;
@@ -393,43 +489,145 @@ impl<T: crate::Config> PollProvider<T> for Poll<T>
     }
 }
 
-fn compute_merkle_root_from_path(
+impl<T: crate::Config<I>, I: 'static> Poll<T, I>
+{
+    /// Returns the first block after the registration period, or `None` if it is not stored and computing it
+    /// overflows.
+    fn registration_period_end(&self) -> Option<BlockNumber>
+    {
+        crate::PollRegistrationPeriodEnd::<T, I>::get(self.index)
+            .or_else(|| self.created_at.checked_add(self.config.signup_period))
+    }
+}
+
+/// Inserts the state leaf of a registration into the registration tree, returning the number of registrations,
+/// the leaf and the updated tree. Operates on the tree alone, such that registering does not require the poll.
+pub fn insert_registration_leaf(
+    tree: PollStateTree,
+    public_key: PublicKey,
+    timestamp: u64
+) -> Result<(u32, HashBytes, PollStateTree), MerkleTreeError>
+{
+    let Some(leaf) = compute_state_leaf(&public_key, 1, timestamp) else { Err(MerkleTreeError::HashFailed)? };
+    let tree = tree.insert(leaf)?;
+
+    Ok((tree.count, leaf, tree))
+}
+
+/// Inserts the message leaf of an interaction into the interaction tree, returning the number of interactions,
+/// the leaf and the updated tree.
+pub fn insert_interaction_leaf(
+    tree: PollStateTree,
+    public_key: PublicKey,
+    cipher: &PollInteractionCipher
+) -> Result<(u32, HashBytes, PollStateTree), MerkleTreeError>
+{
+    let Some(leaf) = compute_message_leaf(&public_key, cipher) else { Err(MerkleTreeError::HashFailed)? };
+    let tree = tree.insert(leaf)?;
+
+    Ok((tree.count, leaf, tree))
+}
+
+/// The arity of the vote option tree.
+const VOTE_TREE_ARITY: u32 = 5;
+
+/// Returns the number of leaves of a vote option tree of the given depth, or `None` on overflow.
+pub fn vote_option_tree_capacity(depth: u8) -> Option<u32>
+{
+    VOTE_TREE_ARITY.checked_pow(depth.into())
+}
+
+/// The maximum depth of the vote option tree, i.e. the depth of the deepest precomputed zero root. Bounds the hashes
+/// computed per tally result path, such that `commit_outcome` hashes at most `MaxVoteOptions * MAX_PATH_DEPTH` times.
+pub const MAX_PATH_DEPTH: u8 = 32;
+
+/// The number of blank leaves which precede the registrations in the registration tree, i.e. the blank state leaf
+/// at index zero.
+pub const BLANK_STATE_LEAVES: u32 = 1;
+
+/// The number of state leaves which the circuits process given the number of registrations, i.e. including the
+/// blank state leaf. Every public input and the number of expected tally proofs are derived from this size.
+pub fn effective_state_size(registrations: u32) -> u32
+{
+    registrations.saturating_add(BLANK_STATE_LEAVES)
+}
+
+/// The Poseidon arities used to compute leaves, commitments and vote option tree roots.
+const POLL_HASH_ARITIES: [usize; 5] = [ 2, 3, 4, 5, VOTE_TREE_ARITY as usize ];
+
+/// Encode a tally result as the big-endian bytes of the leaf of the vote option tree. Returns `None` unless the
+/// value is a canonical field element, i.e. the circuit commits to the same value rather than its reduction.
+pub fn encode_tally_result(tally_result: u128) -> Option<HashBytes>
+{
+    let mut bytes = [0u8; 32];
+    bytes[32 - core::mem::size_of::<u128>()..].copy_from_slice(&tally_result.to_be_bytes());
+
+    if Fr::from_be_bytes_mod_order(&bytes).into_bigint().to_bytes_be() != bytes { return None; }
+
+    Some(bytes)
+}
+
+/// Returns the index of the vote option with the largest tally. Among equal tallies the lowest option index
+/// wins, irrespective of the order in which the tallies are visited. Returns `NO_VOTES` if every tally is zero.
+pub fn select_winner(tally_results: &[u128]) -> OutcomeIndex
+{
+    tally_results
+        .iter()
+        .enumerate()
+        .filter(|(_, tally)| **tally > 0)
+        .max_by_key(|(index, tally)| (**tally, Reverse(*index)))
+        .map_or(NO_VOTES, |(index, _)| index as OutcomeIndex)
+}
+
+/// Computes the root of the quinary vote option tree from the leaf at `index` and the siblings at each level of its
+/// path. Returns `None` if `depth` exceeds `MAX_PATH_DEPTH`, or the path does not hold four siblings per level.
+pub fn compute_merkle_root_from_path(
+    depth: u8,
+    index: u32,
+    leaf: HashBytes,
+    path: vec::Vec<vec::Vec<HashBytes>>
+) -> Option<HashBytes>
+{
+    compute_merkle_root_from_path_of_arity(VOTE_TREE_ARITY, depth, index, leaf, path)
+}
+
+/// Computes the root of a tree of the given arity from the leaf at `index` and the `arity - 1` siblings at each
+/// level of its path, as for `compute_merkle_root_from_path`. Returns `None` if `depth` exceeds `MAX_PATH_DEPTH`,
+/// the arity is not hashed by the circuits, or the path does not hold `arity - 1` siblings per level.
+pub fn compute_merkle_root_from_path_of_arity(
+    arity: u32,
     depth: u8,
     index: u32,
     leaf: HashBytes,
     path: vec::Vec<vec::Vec<HashBytes>>
 ) -> Option<HashBytes>
 {
-    const VOTE_TREE_ARITY: u32 = 5;
-    let Some(mut hasher) = Poseidon::<Fr>::new_circom(VOTE_TREE_ARITY as usize).ok() else { return None; };
+    if depth > MAX_PATH_DEPTH || path.len() != usize::from(depth) { return None; }
+    if !is_supported_circom_arity(arity as usize) { return None; }
 
     let mut idx = index;
-    let mut position = idx % (VOTE_TREE_ARITY);
-    let mut level = [[0u8; 32]; VOTE_TREE_ARITY as usize];
+    let mut position = idx % arity;
+    let mut level = vec::Vec::from_iter((0..arity).map(|_| [0u8; 32]));
     let mut current = leaf;
 
     for i in 0..depth
     {
-        for j in 0..VOTE_TREE_ARITY
+        for j in 0..arity
         {
             if j == position { level[j as usize] = current; }
             else
             {
                 let k = if j > position { j - 1 } else { j };
-                level[j as usize] = path[i as usize][k as usize];
+                let Some(sibling) = path.get(usize::from(i)).and_then(|siblings| siblings.get(k as usize)) else { return None; };
+                level[j as usize] = *sibling;
             }
         }
 
-        let mut inputs: vec::Vec<Fr> = vec::Vec::new();
-        for l in 0..VOTE_TREE_ARITY { inputs.push(Fr::from_be_bytes_mod_order(&level[l as usize])); }
-        let Some(result) = hasher.hash(&inputs).ok() else { return None; };
-        let bytes = result.into_bigint().to_bytes_be();
-        let mut leaf = [0u8; 32];
-        leaf[..bytes.len()].copy_from_slice(&bytes);
+        let Some(node) = poseidon_hash(&level) else { return None; };
 
-        idx /= VOTE_TREE_ARITY;
-        position = idx % VOTE_TREE_ARITY;
-        current = leaf;
+        idx /= arity;
+        position = idx % arity;
+        current = node;
     }
 
     Some(current)
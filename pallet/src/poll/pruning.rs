@@ -0,0 +1,69 @@
+use frame_support::pallet_prelude::*;
+
+/// The maximum length of the storage key from which the clearing of a map resumes.
+pub const MAX_PRUNE_CURSOR_LEN: u32 = 128;
+
+/// The per-poll maps which are cleared once a poll is fulfilled, in the order in which they are cleared.
+#[derive(Clone, Copy, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum PruneStage
+{
+    /// `RegistrationReceipts`, which are only cleared for polls which were nullified or reaped, as these pay no
+    /// rewards.
+    RegistrationReceipts,
+
+    /// `Messages`.
+    #[default]
+    Messages,
+
+    /// `RegistrationLeaves`.
+    RegistrationLeaves,
+
+    /// `IntentSignals`.
+    IntentSignals,
+
+    /// `ProofCommitments`.
+    ProofCommitments,
+
+    /// `PollOptionCalls`.
+    OptionCalls,
+
+    /// `RegisteredKeys`.
+    RegisteredKeys,
+
+    /// `InteractionLeaves`.
+    InteractionLeaves,
+
+    /// `SponsoredCalls`.
+    SponsoredCalls
+}
+
+impl PruneStage
+{
+    /// The map which is cleared after this one, if any.
+    pub fn next(self) -> Option<Self>
+    {
+        match self
+        {
+            PruneStage::RegistrationReceipts => Some(PruneStage::Messages),
+            PruneStage::Messages => Some(PruneStage::RegistrationLeaves),
+            PruneStage::RegistrationLeaves => Some(PruneStage::IntentSignals),
+            PruneStage::IntentSignals => Some(PruneStage::ProofCommitments),
+            PruneStage::ProofCommitments => Some(PruneStage::OptionCalls),
+            PruneStage::OptionCalls => Some(PruneStage::RegisteredKeys),
+            PruneStage::RegisteredKeys => Some(PruneStage::InteractionLeaves),
+            PruneStage::InteractionLeaves => Some(PruneStage::SponsoredCalls),
+            PruneStage::SponsoredCalls => None
+        }
+    }
+}
+
+/// The progress of pruning the per-poll data of a fulfilled poll.
+#[derive(Clone, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PruneCursor
+{
+    /// The map being cleared.
+    pub stage: PruneStage,
+
+    /// The storage key from which the clearing of the map resumes, if it was interrupted within the same block.
+    pub cursor: Option<BoundedVec<u8, ConstU32<MAX_PRUNE_CURSOR_LEN>>>
+}
@@ -6,10 +6,14 @@ use crate::poll::{
     Commitment,
     OutcomeIndex,
     HashBytes,
+    compute_merkle_root_from_path_of_arity,
     zeroes::get_merkle_zeroes
 };
 use crate::hash::{Poseidon, PoseidonHasher, PoseidonError};
 
+/// The arities of the state trees for which zero hashes are precomputed.
+const TREE_ARITIES: [u32; 2] = [ 2, 5 ];
+
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
 pub struct PollState
 {
@@ -26,13 +30,38 @@ pub struct PollState
     pub outcome: Option<OutcomeIndex>,
 
     /// Whether the poll was nullified
-    pub tombstone: bool
+    pub tombstone: bool,
+
+    /// Whether the poll awaits activation by its coordinator.
+    pub pending: bool,
+
+    /// Whether the voting period was closed, such that the deadline of the poll is final and its interactions
+    /// may be merged.
+    pub sealed: bool
+}
+
+impl PollState
+{
+    /// The maximum encoded length of the state of a poll whose trees hold at most the given number of leaves.
+    pub fn max_encoded_len_for(
+        max_registrations: u32,
+        max_interactions: u32
+    ) -> usize
+    {
+        PollStateTree::max_encoded_len_for(max_registrations)
+            .saturating_add(PollStateTree::max_encoded_len_for(max_interactions))
+            .saturating_add(Commitment::max_encoded_len())
+            .saturating_add(Option::<OutcomeIndex>::max_encoded_len())
+            .saturating_add(bool::max_encoded_len().saturating_mul(3))
+    }
 }
 
 pub trait NewPollState
 {
     fn new(
+        registration_arity: u8,
         registration_depth: u8,
+        interaction_arity: u8,
         interaction_depth: u8
     ) -> Self;
 }
@@ -40,29 +69,28 @@ pub trait NewPollState
 impl NewPollState for PollState
 {
     fn new(
+        registration_arity: u8,
         registration_depth: u8,
+        interaction_arity: u8,
         interaction_depth: u8
     ) -> PollState
     {
         PollState {
             registrations: PollStateTree::new(
-                2,
+                registration_arity,
                 registration_depth,
-                Some((0, get_merkle_zeroes(2)[0]))
+                Some((0, get_merkle_zeroes(registration_arity)[0]))
             ),
             interactions: PollStateTree::new(
-                5,
+                interaction_arity,
                 interaction_depth,
                 None
             ),
-            commitment: Commitment {
-                process: (0, [0; 32]),
-                tally: (0, [0; 32]),
-                expected_process: 0,
-                expected_tally: 0
-            },
+            commitment: Commitment::default(),
             outcome: None,
-            tombstone: false
+            tombstone: false,
+            pending: false,
+            sealed: false
         }
     }
 }
@@ -90,6 +118,123 @@ pub struct PollStateTree
     pub root: Option<HashBytes>
 }
 
+impl PollStateTree
+{
+    /// The maximum encoded length of a tree which holds at most `max_leaves` leaves, for any supported arity.
+    /// Each depth below the full depth retains at most `arity - 1` unmerged subtree roots, since a complete
+    /// set of siblings is immediately hashed into their parent.
+    pub fn max_encoded_len_for(max_leaves: u32) -> usize
+    {
+        let max_hashes = TREE_ARITIES
+            .iter()
+            .map(|&arity| (arity - 1).saturating_mul(max_leaves.checked_ilog(arity).unwrap_or(0)))
+            .max()
+            .unwrap_or(0) as usize;
+
+        u8::max_encoded_len()
+            .saturating_mul(3)
+            .saturating_add(u32::max_encoded_len())
+            .saturating_add(codec::Compact::<u32>::max_encoded_len())
+            .saturating_add(<(u8, HashBytes)>::max_encoded_len().saturating_mul(max_hashes))
+            .saturating_add(Option::<HashBytes>::max_encoded_len())
+    }
+
+    /// The tree without its leaves, subtree roots and root, as embedded in the stored poll, whose state trees are
+    /// stored apart from it.
+    pub fn shape(&self) -> Self
+    {
+        Self {
+            depth: 0,
+            full_depth: self.full_depth,
+            arity: self.arity,
+            count: 0,
+            hashes: vec::Vec::new(),
+            root: None
+        }
+    }
+
+    /// The diagnostics of the tree, as returned by the `registration_tree_stats` and `interaction_tree_stats`
+    /// runtime APIs.
+    pub fn stats(&self) -> MerkleTreeStats
+    {
+        let capacity = u32::from(self.arity).saturating_pow(self.full_depth.into());
+        let fill_percent = (u64::from(self.count) * 100)
+            .checked_div(capacity.into())
+            .unwrap_or(100)
+            .min(100) as u8;
+
+        MerkleTreeStats {
+            depth: self.full_depth,
+            arity: self.arity.into(),
+            leaf_count: self.count,
+            capacity,
+            fill_percent,
+            is_merged: self.root.is_some()
+        }
+    }
+}
+
+/// The diagnostics of a state tree, from which coordinators may estimate the time to generate the proofs of a poll
+/// and tell whether its tree is nearing capacity.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct MerkleTreeStats
+{
+    /// The maximal depth of the tree.
+    pub depth: u8,
+
+    /// The arity of the tree.
+    pub arity: u32,
+
+    /// The number of leaves inserted into the tree, excluding the zero leaf of a registration tree.
+    pub leaf_count: u32,
+
+    /// The number of leaves which the tree holds at its maximal depth, saturating at `u32::MAX`.
+    pub capacity: u32,
+
+    /// The share of the capacity which is filled, in percent and capped at 100.
+    pub fill_percent: u8,
+
+    /// Whether the root of the tree was computed.
+    pub is_merged: bool
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl PollStateTree
+{
+    /// Benchmarking only: appends `count` copies of a precomputed leaf to a fresh tree without hashing, such
+    /// that the tree takes on the shape it would have after `count` insertions. The subtree roots are not
+    /// consistent with the leaves, which is irrelevant to the cost of subsequent inserts and merges.
+    pub fn fill_for_benchmarks(
+        mut self,
+        count: u32,
+        leaf: HashBytes
+    ) -> Self
+    {
+        let arity: u32 = self.arity.into();
+
+        // A fresh tree holds at most the zero leaf, which occupies a leaf position.
+        let mut leaves = self.hashes.len() as u32 + count;
+        let mut digits = vec::Vec::<u32>::new();
+        while leaves > 0
+        {
+            digits.push(leaves % arity);
+            leaves /= arity;
+        }
+
+        // Each base-`arity` digit of the number of leaves is the number of subtrees at that depth.
+        self.hashes = vec::Vec::new();
+        for (depth, digit) in digits.iter().enumerate().rev()
+        {
+            self.hashes.extend((0..*digit).map(|_| (depth as u8, leaf)));
+        }
+
+        self.count += count;
+        self.depth = digits.len().saturating_sub(1) as u8;
+
+        self
+    }
+}
+
 #[derive(Debug)]
 pub enum MerkleTreeError
 {
@@ -100,7 +245,12 @@ pub enum MerkleTreeError
     /// The hash function did not succeed.
     HashFailed,
     /// The merge operation failed.
-    MergeFailed
+    MergeFailed,
+    /// The frontier does not describe a tree of the given arity and depth, i.e. it holds more than `arity`
+    /// subtrees of the same depth, or a subtree which is not below the full depth.
+    MalformedFrontier,
+    /// The number of leaves processed per proof is zero, or does not fit in a `u32`.
+    InvalidBatchSize
 }
 
 impl From<MerkleTreeError> for u8
@@ -113,6 +263,8 @@ impl From<MerkleTreeError> for u8
             MerkleTreeError::TreeAlreadyMerged => 2,
             MerkleTreeError::HashFailed => 3,
             MerkleTreeError::MergeFailed => 4,
+            MerkleTreeError::MalformedFrontier => 5,
+            MerkleTreeError::InvalidBatchSize => 6,
         }
     }
 }
@@ -125,7 +277,7 @@ pub trait AmortizedIncrementalMerkleTree: Sized
     /// Create a new tree.
     fn new(arity: u8, full_depth: u8, zero_hash: Option<(u8, HashBytes)>) -> Self;
 
-    /// Inserts a new leaf into the tree.
+    /// Inserts a new leaf into the tree. The root of a tree is only set by `merge`, even once it is full.
     fn insert(self, data: HashBytes) -> Result<Self, MerkleTreeError>;
 
     /// Compute the root of the tree.
@@ -133,8 +285,26 @@ pub trait AmortizedIncrementalMerkleTree: Sized
 
     /// Hash function used to compute roots.
     fn hash(inputs: vec::Vec<HashBytes>) -> Result<HashBytes, Self::HashError>;
+
+    /// Encode the tree in its compact wire format.
+    fn to_compact_bytes(&self) -> vec::Vec<u8>;
+
+    /// Decode a tree from its compact wire format.
+    fn from_compact_bytes(bytes: &[u8]) -> Result<Self, codec::Error>;
+
+    /// The `(index, leaf)` pairs of the leaves which the tree retains, in order of insertion.
+    fn iter_leaves(&self) -> vec::IntoIter<(u32, HashBytes)>;
+
+    /// Returns true iff the leaf at `index` and the siblings at each level of its path hash to the root of the tree.
+    fn verify_leaf(&self, index: u32, leaf: HashBytes, path: vec::Vec<vec::Vec<HashBytes>>) -> bool;
 }
 
+/// The length of the header of the compact wire format: arity, full depth, depth, count, and root flag.
+const COMPACT_HEADER_LEN: usize = 8;
+
+/// The length of a frontier node in the compact wire format: depth and hash.
+const COMPACT_NODE_LEN: usize = 33;
+
 impl AmortizedIncrementalMerkleTree for PollStateTree
 {
     type HashError = PoseidonError;
@@ -178,8 +348,9 @@ impl AmortizedIncrementalMerkleTree for PollStateTree
         leaf: HashBytes
     ) -> Result<Self, MerkleTreeError>
     {
-        // Ensure that the tree is not full (or merged).
-        if self.root != None { Err(MerkleTreeError::TreeAlreadyFull)? }
+        // Ensure that the tree is neither merged nor full.
+        if self.root != None { Err(MerkleTreeError::TreeAlreadyMerged)? }
+        if self.hashes.len() == 1 && self.hashes[0].0 == self.full_depth { Err(MerkleTreeError::TreeAlreadyFull)? }
 
         self.count += 1;
         self.hashes.push((0, leaf));
@@ -214,19 +385,17 @@ impl AmortizedIncrementalMerkleTree for PollStateTree
             else { break; }
         }
 
-        // If tree is full update the `root` property.
-        if self.hashes.len() == 1 && self.hashes[0].0 == self.full_depth
-        {
-            self.root = Some(self.hashes[0].1);
-            self.hashes.truncate(0);
-        }
-
+        // A full tree retains its root in `hashes` until it is merged, such that merging it still records
+        // the initial commitments of the poll.
         Ok(self)
     }
 
     /// Obtain the root of the tree, wherein the remaining leaves take on zero values.
     /// NB we require the state tree to have a fixed height since the circuits must 
     /// know this value at compile time.
+    ///
+    /// Partially filled subtrees, including an empty tree, are padded with zero hashes, such that merging only fails
+    /// if the tree was already merged, if a hash fails, or if the frontier is malformed.
     fn merge(
         mut self,
         to_depth: bool
@@ -250,6 +419,9 @@ impl AmortizedIncrementalMerkleTree for PollStateTree
             // Break as soon as the first full subroot has been computed.
             if self.hashes.len() == 1 && (!to_depth || depth == self.full_depth) {break; }
 
+            // Ensure that the remaining subtrees fit below the root, whose zero hashes are known.
+            if depth >= self.full_depth { Err(MerkleTreeError::MalformedFrontier)? }
+
             let mut subtree: vec::Vec<_> = self.hashes
                 .iter()
                 .rev()
@@ -261,9 +433,12 @@ impl AmortizedIncrementalMerkleTree for PollStateTree
             // We built the subtree in reverse order, so restore the original order.
             subtree.reverse();
 
+            // Ensure that the subtrees of this depth would have been hashed upon insertion, had there been `arity` of them.
             let size = subtree.len();
+            if size > arity { Err(MerkleTreeError::MalformedFrontier)? }
+
             let zero = zeroes[depth as usize];
-            if arity >= size { subtree.extend((0..(arity - size)).map(|_| zero)); }
+            subtree.extend((0..(arity - size)).map(|_| zero));
 
             let Some(hash) = Self::hash(subtree).ok() else { Err(MerkleTreeError::HashFailed)? };
             self.hashes.truncate(self.hashes.len() - size);
@@ -277,6 +452,14 @@ impl AmortizedIncrementalMerkleTree for PollStateTree
             self.hashes.truncate(0);
         }
 
+        // A tree without leaves merges to the root of the zero tree, e.g. the interaction tree of a poll which
+        // nobody interacted with.
+        else if self.hashes.is_empty()
+        {
+            let depth = if to_depth { self.full_depth } else { 0 };
+            self.root = Some(zeroes[usize::from(depth)]);
+        }
+
         Ok(self)
     }
 
@@ -300,4 +483,115 @@ impl AmortizedIncrementalMerkleTree for PollStateTree
 
         Ok(bytes)
     }
+
+    /// Encodes the tree as `[arity(1), full_depth(1), depth(1), count(4, little-endian), has_root(1)]`, followed
+    /// by the root if the tree is complete, or otherwise by the frontier as `[depth(1), hash(32)]` nodes. Each
+    /// node carries its depth since the leaves of the registration tree include the zero leaf, which is not counted.
+    fn to_compact_bytes(&self) -> vec::Vec<u8>
+    {
+        let mut bytes = vec::Vec::with_capacity(COMPACT_HEADER_LEN + COMPACT_NODE_LEN * self.hashes.len());
+        bytes.extend_from_slice(&[ self.arity, self.full_depth, self.depth ]);
+        bytes.extend_from_slice(&self.count.to_le_bytes());
+
+        match self.root
+        {
+            Some(root) =>
+            {
+                bytes.push(1);
+                bytes.extend_from_slice(&root);
+            },
+            None =>
+            {
+                bytes.push(0);
+                for (depth, hash) in self.hashes.iter()
+                {
+                    bytes.push(*depth);
+                    bytes.extend_from_slice(hash);
+                }
+            }
+        }
+
+        bytes
+    }
+
+    fn from_compact_bytes(bytes: &[u8]) -> Result<Self, codec::Error>
+    {
+        if bytes.len() < COMPACT_HEADER_LEN { Err("Compact tree header is truncated")? }
+
+        let (header, body) = bytes.split_at(COMPACT_HEADER_LEN);
+        let (arity, full_depth, depth) = (header[0], header[1], header[2]);
+        let count = u32::from_le_bytes([ header[3], header[4], header[5], header[6] ]);
+
+        if !TREE_ARITIES.contains(&arity.into()) { Err("Compact tree arity is unsupported")? }
+        if depth > full_depth { Err("Compact tree depth exceeds its full depth")? }
+
+        let mut tree = PollStateTree {
+            arity,
+            full_depth,
+            depth,
+            count,
+            hashes: vec::Vec::new(),
+            root: None
+        };
+
+        match header[7]
+        {
+            1 =>
+            {
+                let Ok(root) = HashBytes::try_from(body) else { Err("Compact tree root is malformed")? };
+                tree.root = Some(root);
+            },
+            0 =>
+            {
+                if body.len() % COMPACT_NODE_LEN != 0 { Err("Compact tree frontier is truncated")? }
+
+                for node in body.chunks(COMPACT_NODE_LEN)
+                {
+                    if node[0] > full_depth { Err("Compact tree node exceeds its full depth")? }
+
+                    let mut hash = [0u8; 32];
+                    hash.copy_from_slice(&node[1..]);
+                    tree.hashes.push((node[0], hash));
+                }
+            },
+            _ => Err("Compact tree root flag is malformed")?
+        }
+
+        Ok(tree)
+    }
+
+    /// Only the leaves which have not yet been hashed into a subtree root are retained, i.e. the trailing depth 0
+    /// nodes of the frontier. There are fewer than `arity` of them, which bounds the iterator, and none once the
+    /// tree is merged. The remaining leaves must be read from storage, e.g. `RegistrationLeaves`. Indices are leaf
+    /// positions in the tree, such that the zero leaf of the registration tree occupies index 0.
+    fn iter_leaves(&self) -> vec::IntoIter<(u32, HashBytes)>
+    {
+        let arity: u32 = self.arity.into();
+
+        let mut index = 0u32;
+        let mut leaves = vec::Vec::new();
+        for &(depth, hash) in self.hashes.iter()
+        {
+            if depth == 0 { leaves.push((index, hash)); }
+            index = index.saturating_add(arity.saturating_pow(depth.into()));
+        }
+
+        leaves.into_iter()
+    }
+
+    /// The path holds the `arity - 1` siblings of each level from the leaf upwards, and its length is the depth of
+    /// the root, which for a registration tree merged below its full depth is that of its first subtree. Always
+    /// false until the tree is merged. Indices are leaf positions in the tree, as for `iter_leaves`.
+    fn verify_leaf(&self, index: u32, leaf: HashBytes, path: vec::Vec<vec::Vec<HashBytes>>) -> bool
+    {
+        let Some(root) = self.root else { return false; };
+        let Ok(depth) = u8::try_from(path.len()) else { return false; };
+        if depth > self.full_depth { return false; }
+
+        // Reject indices beyond the leaves spanned by the path, which would otherwise alias a lower index.
+        let arity: u32 = self.arity.into();
+        if arity.checked_pow(depth.into()).map_or(false, |capacity| index >= capacity) { return false; }
+
+        compute_merkle_root_from_path_of_arity(arity, depth, index, leaf, path) == Some(root)
+    }
 }
@@ -0,0 +1,53 @@
+use frame_support::pallet_prelude::*;
+
+/// The phase of a poll at the current block.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum PollPhase
+{
+    /// The poll awaits activation by its coordinator.
+    Pending,
+
+    /// The poll accepts registrations.
+    Registration,
+
+    /// The poll accepts interactions.
+    Voting,
+
+    /// The voting period of the poll ended, and its outcome has yet to be committed.
+    Processing,
+
+    /// The outcome of the poll was committed.
+    Fulfilled,
+
+    /// The poll was nullified, i.e. it ended without interactions or was reaped before its activation.
+    Nullified
+}
+
+/// The action a keeper may take on a poll, each of which is tracked by a queue of the poll ids it applies to.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum KeeperAction
+{
+    /// Either state tree of the poll may be merged.
+    Merge,
+
+    /// The poll may be nullified, having ended its signup period without registrations or its voting period
+    /// without interactions.
+    NullificationCheck,
+
+    /// The poll was not activated within `ActivationTimeout` blocks of its creation, and may be reaped.
+    Reap
+}
+
+/// A recently created poll, as returned by the `recent_polls` runtime API.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PollSummary<AccountId, PollId>
+{
+    /// The poll id.
+    pub poll_id: PollId,
+
+    /// The poll creator.
+    pub coordinator: AccountId,
+
+    /// The phase of the poll at the current block.
+    pub phase: PollPhase
+}
@@ -0,0 +1,114 @@
+use codec::Codec;
+use frame_support::weights::Weight;
+use sp_std::vec;
+use crate::poll::{AllowedActions, CoordinatorInfo, HashBytes, MerkleTreeStats, OutcomeExport, PollInteractionData, PollSummary, PublicKey, SerializablePollConfig};
+
+sp_api::decl_runtime_apis! {
+    /// Read-only queries for off-chain provers and clients.
+    pub trait InfimumApi<AccountId, PollId>
+    where
+        AccountId: Codec,
+        PollId: Codec
+    {
+        /// The number of interactions which are processed per proof, i.e. `arity^process_subtree_depth`.
+        /// Returns `None` if the poll does not exist, or if the batch size overflows.
+        fn interaction_batch_size(poll_id: PollId) -> Option<u32>;
+
+        /// The number of registrations which are tallied per proof, i.e. `arity^tally_subtree_depth`.
+        /// Returns `None` if the poll does not exist, or if the batch size overflows.
+        fn tally_batch_size(poll_id: PollId) -> Option<u32>;
+
+        /// Re-run the verification of the stored outcome of a fulfilled poll against its stored commitments.
+        /// Returns `true` iff the stored outcome is consistent with the stored commitments.
+        fn replay_poll_outcome(poll_id: PollId) -> bool;
+
+        /// The ids of polls which have not yet been fulfilled, among the `MAX_ACTIVE_POLLS_SCAN` ids from `start`. At
+        /// most `limit` ids are returned (capped at `MAX_ACTIVE_POLLS_PAGE`). Further pages begin one past the last
+        /// returned id if the page is full, and at `start + MAX_ACTIVE_POLLS_SCAN` otherwise.
+        fn active_polls(start: PollId, limit: u32) -> vec::Vec<PollId>;
+
+        /// The public key (x followed by y) of the coordinator at the time the poll was created, which the poll
+        /// circuits are parameterized with. Provers must use this key, as the coordinator may since have rotated keys.
+        fn coordinator_public_key_at_poll_creation(poll_id: PollId) -> Option<vec::Vec<u8>>;
+
+        /// The depths of the registration and interaction trees of the poll, which parameterize its circuits.
+        /// Served from dedicated storage, such that the poll itself is not decoded.
+        fn poll_tree_depths(poll_id: PollId) -> Option<(u8, u8)>;
+
+        /// The number of vote options of the poll. Served from dedicated storage, such that the poll itself is
+        /// not decoded.
+        fn vote_option_count(poll_id: PollId) -> Option<u32>;
+
+        /// The depth, arity, leaf count and capacity of the registration tree of the poll, and whether it was
+        /// merged, from which coordinators may estimate the time to generate its proofs. Returns `None` if the poll
+        /// does not exist.
+        fn registration_tree_stats(poll_id: PollId) -> Option<MerkleTreeStats>;
+
+        /// The diagnostics of the interaction tree of the poll, as for `registration_tree_stats`.
+        fn interaction_tree_stats(poll_id: PollId) -> Option<MerkleTreeStats>;
+
+        /// The registration leaves of the poll in order of registration, starting from the registration index
+        /// `start`. At most `count` leaves are returned (capped at `MAX_REGISTRATION_LEAVES_PAGE`), such that a
+        /// coordinator may page through them to rebuild the registration tree. Backs the
+        /// `infimum_registrationLeaves` RPC.
+        fn registration_leaves(poll_id: PollId, start: u32, count: u32) -> vec::Vec<HashBytes>;
+
+        /// The stored interaction messages of the poll, i.e. the public key and data of each interaction, in order
+        /// of interaction, starting from the interaction index `start`. At most `count` messages are returned (capped
+        /// at `MAX_INTERACTION_MESSAGES_PAGE`), such that clients may catch up on the interactions without replaying
+        /// past events. Empty unless `EmitFullInteractionData` is false, and once the data of the poll is pruned.
+        fn interaction_messages(poll_id: PollId, start: u32, count: u32) -> vec::Vec<(PublicKey, PollInteractionData)>;
+
+        /// Whether the leaf is included at the registration index `index` in the merged registration tree of the
+        /// poll, given the siblings of each level of the tree from the leaf upwards. Allows a participant to check
+        /// that their registration was counted without rebuilding the tree. False until the tree is merged.
+        fn verify_registration_inclusion(poll_id: PollId, index: u32, leaf: HashBytes, path: vec::Vec<vec::Vec<HashBytes>>) -> bool;
+
+        /// The registration indices of the registrations which the account submitted to the poll, which may have
+        /// been relayed on behalf of their participants. Returns `None` unless the poll is finalized and the
+        /// account registered in it, such that reward pallets may settle claims against it.
+        fn participation_receipt(poll_id: PollId, who: AccountId) -> Option<vec::Vec<u32>>;
+
+        /// The page with index `page` of the accounts which registered in the finalized poll, alongside their
+        /// registration indices. Each page holds `page_size` accounts (capped at `MAX_PARTICIPATION_RECEIPTS_PAGE`),
+        /// and the pages are empty unless the poll is finalized.
+        fn participation_receipts(poll_id: PollId, page: u32, page_size: u32) -> vec::Vec<(AccountId, vec::Vec<u32>)>;
+
+        /// The configuration of the poll in a human readable form, where periods are additionally expressed in
+        /// seconds given the target block time of the runtime. Backs the `infimum_getPollConfig` RPC.
+        fn poll_config(poll_id: PollId) -> Option<SerializablePollConfig>;
+
+        /// The actions which the poll accepts at the current block, computed with the same checks which gate the
+        /// extrinsics. Actions which depend on the caller are only permitted if `who` is given. Returns `None` if
+        /// the poll does not exist. Backs the `infimum_allowedActions` RPC.
+        fn allowed_actions(poll_id: PollId, who: Option<AccountId>) -> Option<AllowedActions>;
+
+        /// Whether the public key is registered in the poll, such that clients need not prompt the user to
+        /// register again. Only answered for polls created with `unique_keys`, and false otherwise.
+        fn is_key_registered(poll_id: PollId, public_key: PublicKey) -> bool;
+
+        /// The most recently created polls, most recent first, alongside their coordinator and current phase. At
+        /// most `limit` polls are returned (capped at `RECENT_POLLS_CAPACITY`). Nullified polls are marked as such.
+        fn recent_polls(limit: u32) -> vec::Vec<PollSummary<AccountId, PollId>>;
+
+        /// The ids of the polls managed by the coordinator, in ascending order, for coordinator dashboards. Poll ids
+        /// which were archived to `CoordinatorHistory` are not included.
+        fn get_coordinator_poll_ids(who: AccountId) -> vec::Vec<PollId>;
+
+        /// The page with index `page` of the ids returned by `get_coordinator_poll_ids`, where each page holds
+        /// `page_size` ids (capped at `MAX_COORDINATOR_POLL_IDS_PAGE`).
+        fn get_coordinator_poll_ids_paginated(who: AccountId, page: u32, page_size: u32) -> vec::Vec<PollId>;
+
+        /// The current public key, most recent poll and track record of the coordinator, i.e. the number of polls
+        /// they created, finalized and abandoned. Returns `None` if the account is not a registered coordinator.
+        fn coordinator_info(who: AccountId) -> Option<CoordinatorInfo<PollId>>;
+
+        /// The stored outcome of a fulfilled poll alongside the commitments and roots against which it was verified.
+        /// Returns `None` unless the poll was fulfilled with an outcome. Backs the `infimum_exportMaciTally` RPC.
+        fn outcome_export(poll_id: PollId) -> Option<OutcomeExport<PollId>>;
+
+        /// The accumulated actual weight of every successful extrinsic scoped to the poll, for coordinators to bill
+        /// the resources which the poll consumed. Zero for polls which do not exist.
+        fn poll_resource_usage(poll_id: PollId) -> Weight;
+    }
+}
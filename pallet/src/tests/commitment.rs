@@ -0,0 +1,118 @@
+use frame_support::{
+    assert_ok,
+    storage::unhashed,
+    traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion}
+};
+use crate::{
+    mock::*,
+    Polls
+};
+use crate::migrations::v1::{v0, MigrateToV1};
+use crate::poll::{
+    Commitment,
+    ProofChain
+};
+use crate::tests::{
+    get_coordinator_data,
+    get_poll_config,
+    poll_config,
+    register_coordinator
+};
+
+/// A proof chain should only be complete once every expected proof has been verified.
+#[test]
+fn proof_chain_advance()
+{
+    let mut chain = ProofChain { completed: 0, expected: 2, current: [1u8; 32] };
+    assert!(!chain.is_complete());
+    assert_eq!(chain.remaining(), 2);
+
+    chain.advance([2u8; 32]);
+    assert_eq!(chain, ProofChain { completed: 1, expected: 2, current: [2u8; 32] });
+    assert!(!chain.is_complete());
+    assert_eq!(chain.remaining(), 1);
+
+    chain.advance([3u8; 32]);
+    assert_eq!(chain, ProofChain { completed: 2, expected: 2, current: [3u8; 32] });
+    assert!(chain.is_complete());
+    assert_eq!(chain.remaining(), 0);
+}
+
+/// A proof chain which expects no proofs, e.g. before the state trees are merged, should be complete.
+#[test]
+fn proof_chain_empty()
+{
+    let chain = ProofChain::default();
+
+    assert!(chain.is_complete());
+    assert_eq!(chain.remaining(), 0);
+    assert_eq!(chain.current, [0u8; 32]);
+}
+
+/// A proof chain which exceeds its expected proofs should neither be complete, nor underflow.
+#[test]
+fn proof_chain_overrun()
+{
+    let mut chain = ProofChain { completed: 1, expected: 1, current: [1u8; 32] };
+    chain.advance([2u8; 32]);
+
+    assert!(!chain.is_complete());
+    assert_eq!(chain.remaining(), 0);
+}
+
+/// The positional commitments of version 0 polls should be translated to proof chains.
+#[test]
+fn migrate_commitment_to_v1()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
+        let poll = Infimum::polls(0).unwrap();
+
+        // Overwrite the poll with its version 0 encoding.
+        let old = v0::Poll::<Test> {
+            index: poll.index,
+            coordinator: poll.coordinator,
+            created_at: poll.created_at,
+            state: v0::PollState {
+                registrations: poll.state.registrations.clone(),
+                interactions: poll.state.interactions.clone(),
+                commitment: v0::Commitment {
+                    process: (1, [1u8; 32]),
+                    tally: (0, [2u8; 32]),
+                    expected_process: 2,
+                    expected_tally: 3
+                },
+                outcome: None,
+                tombstone: false,
+                pending: false,
+                sealed: true
+            },
+            config: poll.config.clone()
+        };
+        unhashed::put(&Polls::<Test>::hashed_key_for(0), &old);
+        StorageVersion::new(0).put::<Infimum>();
+
+        MigrateToV1::<Test>::on_runtime_upgrade();
+
+        let migrated = Infimum::polls(0).unwrap();
+        assert_eq!(migrated.state.commitment, Commitment {
+            process: ProofChain { completed: 1, expected: 2, current: [1u8; 32] },
+            tally: ProofChain { completed: 0, expected: 3, current: [2u8; 32] }
+        });
+        assert_eq!(migrated.state.registrations, poll.state.registrations);
+        assert_eq!(migrated.config, poll.config);
+        assert!(migrated.state.sealed);
+        assert_eq!(Polls::<Test>::count(), 1);
+        assert_eq!(Infimum::on_chain_storage_version(), 1);
+
+        // The migration is not applied twice.
+        MigrateToV1::<Test>::on_runtime_upgrade();
+        assert_eq!(Infimum::polls(0).unwrap(), migrated);
+    })
+}
@@ -8,6 +8,7 @@ use crate::poll::{
     ProofData,
     PublicKey,
     PollOutcome,
+    Signature,
     VerifyKey,
     VerifyingKeys,
 };
@@ -68,6 +69,31 @@ pub fn get_coordinator_data() -> (PublicKey, VerifyingKeys)
     (pk, vk)
 }
 
+/// Coordinator keys alongside a proof of possession of the public key, signed over the account `who`.
+pub fn get_coordinator_signed_data(who: u64) -> (PublicKey, VerifyingKeys, Signature)
+{
+    let (_pk, vk) = get_coordinator_data();
+    let pk = PublicKey {
+        x: [ 5, 72, 214, 18, 213, 148, 140, 67, 18, 36, 147, 66, 138, 51, 248, 102, 104, 209, 33, 155, 70, 232, 249, 48, 255, 253, 103, 73, 148, 81, 215, 130 ],
+        y: [ 41, 218, 195, 220, 107, 137, 216, 147, 33, 141, 68, 128, 215, 243, 114, 42, 210, 96, 85, 96, 254, 106, 209, 224, 96, 173, 77, 126, 142, 57, 207, 69 ]
+    };
+    let signature = match who
+    {
+        0 => Signature {
+            r8_x: [ 8, 94, 212, 105, 201, 169, 241, 2, 182, 212, 246, 249, 9, 184, 206, 175, 108, 164, 155, 57, 117, 154, 194, 224, 254, 183, 224, 170, 218, 139, 113, 17 ],
+            r8_y: [ 36, 94, 37, 171, 43, 212, 47, 2, 128, 165, 173, 231, 80, 130, 141, 214, 134, 143, 82, 37, 174, 121, 141, 107, 81, 198, 118, 245, 25, 200, 244, 232 ],
+            s: [ 4, 106, 79, 184, 26, 141, 204, 198, 150, 155, 67, 27, 100, 41, 243, 1, 40, 210, 202, 231, 146, 157, 106, 200, 3, 225, 9, 159, 23, 62, 87, 39 ]
+        },
+        _ => Signature {
+            r8_x: [ 5, 205, 194, 210, 100, 82, 216, 136, 252, 92, 145, 163, 235, 233, 139, 32, 103, 7, 76, 224, 28, 3, 83, 227, 109, 19, 189, 210, 17, 82, 207, 165 ],
+            r8_y: [ 23, 226, 16, 78, 175, 146, 191, 119, 91, 139, 255, 27, 24, 212, 73, 52, 33, 166, 181, 168, 240, 87, 65, 52, 215, 246, 186, 142, 33, 32, 126, 134 ],
+            s: [ 2, 77, 48, 9, 31, 153, 171, 7, 0, 174, 118, 103, 135, 248, 247, 31, 248, 230, 62, 65, 255, 215, 202, 24, 222, 91, 94, 95, 205, 151, 106, 72 ]
+        }
+    };
+
+    (pk, vk, signature)
+}
+
 pub fn get_coordinator_data_malformed() -> (PublicKey, VerifyingKeys)
 {
     let pk = PublicKey {
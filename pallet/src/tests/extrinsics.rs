@@ -1,37 +1,90 @@
 use sp_std::vec;
+use codec::{Encode, MaxEncodedLen};
 use ark_bn254::{Fr};
 use ark_ff::{PrimeField};
 use frame_support::{
     assert_ok, 
     assert_err, 
-    error
+    error,
+    dispatch::{GetDispatchInfo, Pays},
+    traits::{Get, GetStorageVersion, Hooks, NamedReservableCurrency, OnRuntimeUpgrade, StorageVersion},
+    weights::{constants::WEIGHT_REF_TIME_PER_MILLIS, RuntimeDbWeight, Weight},
+    BoundedVec
 };
+use sp_runtime::traits::Dispatchable;
 use crate::{
     mock::*,
+    CoordinatorPollIds,
+    CoordinatorStats,
+    Coordinators,
     Error,
-    Event
+    Event,
+    InteractionLeaves,
+    KeeperBacklog,
+    Messages,
+    NextPollId,
+    PollCoordinatorPubKey,
+    PollInteractionFrontier,
+    PollInteractionTree,
+    PollOutcomes,
+    PollPhases,
+    PollRegistrationFrontier,
+    PollRegistrationTree,
+    PollVoteOptionCount,
+    Polls,
+    RegistrationLeaves,
+    RegistrationReceipts,
+    WeightInfo,
+    MAX_ACTIVE_POLLS_SCAN,
+    RECENT_POLLS_CAPACITY
 };
 use crate::tests::{
     run_to_block,
+    poll_config,
+    register_coordinator,
+    signed_coordinator_key,
     get_coordinator_data,
+    get_coordinator_signed_data,
     get_coordinator_data_malformed,
     get_proof,
     get_participant,
     get_participants,
     get_poll_config,
-    get_poll_scenario
+    get_poll_scenario,
+    interaction_data,
+    interaction_cipher,
+    merkle_path
 };
 use crate::poll::{
     CommitmentData,
+    CoordKeyHash,
+    CoordinatorRecord,
     HashBytes,
+    MAX_PUBLIC_INPUTS,
+    Poll,
+    PollConfigOf,
+    PollInteractionCipher,
+    PollInteractionData,
+    PollPhase,
+    PollSummary,
     PublicKey,
     ProofData,
-    provider::PollProvider
+    OptionCall,
+    KeeperAction,
+    ProofFailures,
+    ProofKind,
+    PruneStage,
+    AmortizedIncrementalMerkleTree,
+    compute_verify_key_hash,
+    provider::PollProvider,
+    state::PollStateTree,
+    zeroes::get_merkle_zeroes
 };
 use crate::hash::{
     Poseidon,
     PoseidonHasher
 };
+use crate::migrations::{v3::MigrateToV3, v4::MigrateToV4, v5::MigrateToV5, v7::MigrateToV7};
 
 /// Coordinators should be able to register.
 #[test]
@@ -40,10 +93,10 @@ fn coordinator_registration_successful()
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
 
-        let (pk, vk) = get_coordinator_data();
+        let (pk, vk, signature) = get_coordinator_signed_data(0);
 
         // Successful registration
-        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk.clone()));
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk.clone(), signature));
         assert_eq!(Infimum::coordinators(0).is_some(), true);
         System::assert_has_event(Event::CoordinatorRegistered { who: 0, public_key: pk, verify_key: vk.clone() }.into());
         assert_eq!(System::events().len(), 1);
@@ -55,11 +108,11 @@ fn coordinator_registration_successful()
 fn coordinator_registration_duplicated()
 {
     new_test_ext().execute_with(|| {
-        let (pk, vk) = get_coordinator_data();
+        let (pk, vk, signature) = get_coordinator_signed_data(0);
 
         // We should only be able to register a single coordinator once
-        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk.clone()));
-        assert_err!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk), Error::<Test>::CoordinatorAlreadyRegistered);
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk.clone(), signature));
+        assert_err!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk, signature), Error::<Test>::CoordinatorAlreadyRegistered);
     })
 }
 
@@ -68,8 +121,8 @@ fn coordinator_registration_duplicated()
 fn coordinator_registration_unsigned()
 {
     new_test_ext().execute_with(|| {
-        let (pk, vk) = get_coordinator_data();
-        assert_err!(Infimum::register_as_coordinator(RuntimeOrigin::none(), pk, vk.clone()), error::BadOrigin);
+        let (pk, vk, signature) = get_coordinator_signed_data(0);
+        assert_err!(Infimum::register_as_coordinator(RuntimeOrigin::none(), pk, vk.clone(), signature), error::BadOrigin);
     })
 }
 
@@ -78,8 +131,51 @@ fn coordinator_registration_unsigned()
 fn coordinator_registration_malformed()
 {
     new_test_ext().execute_with(|| {
+        let (_pk, _vk, signature) = get_coordinator_signed_data(0);
         let (pk, vk) = get_coordinator_data_malformed();
-        assert_err!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk), Error::<Test>::MalformedKeys);
+        assert_err!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk, signature), Error::<Test>::MalformedKeys);
+    })
+}
+
+/// Coordinator verification keys must respect their maximum encoded length.
+#[test]
+fn coordinator_registration_unbounded()
+{
+    new_test_ext().execute_with(|| {
+        let (pk, vk, signature) = get_coordinator_signed_data(0);
+
+        // Trailing bytes are ignored by deserialization, but not permitted in storage.
+        let mut padded = vk.clone();
+        padded.process.alpha_g1.push(0);
+        assert_err!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, padded, signature), Error::<Test>::MalformedKeys);
+
+        let mut excessive = vk;
+        let point = excessive.tally.gamma_abc_g1[0].clone();
+        excessive.tally.gamma_abc_g1.resize(MAX_PUBLIC_INPUTS + 2, point);
+        assert_err!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, excessive, signature), Error::<Test>::MalformedKeys);
+    })
+}
+
+/// Coordinators must prove possession of the private key corresponding to their public key.
+#[test]
+fn coordinator_registration_proof_of_possession()
+{
+    new_test_ext().execute_with(|| {
+        let (pk, vk, signature) = get_coordinator_signed_data(0);
+        let (alice_pk, _alice_vk) = get_coordinator_data();
+
+        // A signature over another account should be rejected.
+        assert_err!(Infimum::register_as_coordinator(RuntimeOrigin::signed(1), pk, vk.clone(), signature), Error::<Test>::ProofOfPossessionInvalid);
+
+        // A signature made with another private key should be rejected.
+        assert_err!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), alice_pk, vk.clone(), signature), Error::<Test>::ProofOfPossessionInvalid);
+
+        // A signature with a non-canonical scalar should be rejected.
+        let mut malleated = signature;
+        malleated.s = [255u8; 32];
+        assert_err!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk.clone(), malleated), Error::<Test>::ProofOfPossessionInvalid);
+
+        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk, signature));
     })
 }
 
@@ -91,27 +187,40 @@ fn coordinator_key_rotation_successful()
         System::set_block_number(1);
 
         let (pk1, vk1) = get_coordinator_data();
-        let (pk2, vk2) = get_coordinator_data();
+        let (pk2, vk2, signature) = get_coordinator_signed_data(0);
 
-        assert_err!(Infimum::rotate_keys(RuntimeOrigin::signed(0), pk1, vk1.clone()), Error::<Test>::CoordinatorNotRegistered);
-        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk1, vk1));
-        assert_ok!(Infimum::rotate_keys(RuntimeOrigin::signed(0), pk2, vk2.clone()));
+        assert_err!(Infimum::rotate_keys(RuntimeOrigin::signed(0), pk2, vk2.clone(), signature), Error::<Test>::CoordinatorNotRegistered);
+        register_coordinator(0, pk1, vk1);
+        assert_ok!(Infimum::rotate_keys(RuntimeOrigin::signed(0), pk2, vk2.clone(), signature));
         System::assert_has_event(Event::CoordinatorKeysChanged { who: 0, public_key: pk2, verify_key: vk2 }.into());
     })
 }
 
+/// Coordinators must prove possession of the private key corresponding to their new public key.
+#[test]
+fn coordinator_key_rotation_proof_of_possession() 
+{
+    new_test_ext().execute_with(|| {
+        let (pk1, vk1) = get_coordinator_data();
+        let (pk2, vk2, signature) = get_coordinator_signed_data(0);
+
+        register_coordinator(1, pk1, vk1);
+        assert_err!(Infimum::rotate_keys(RuntimeOrigin::signed(1), pk2, vk2, signature), Error::<Test>::ProofOfPossessionInvalid);
+    })
+}
+
 /// Coordinators should not be able to rotate their keys during a poll.
 #[test]
 fn coordinator_key_rotation_during_poll() 
 {
     new_test_ext().execute_with(|| {
         let (pk1, vk1) = get_coordinator_data();
-        let (pk2, vk2) = get_coordinator_data();
+        let (pk2, vk2, signature) = get_coordinator_signed_data(0);
         let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
 
-        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk1, vk1));
-        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
-        assert_err!(Infimum::rotate_keys(RuntimeOrigin::signed(0), pk2, vk2), Error::<Test>::PollCurrentlyActive);
+        register_coordinator(0, pk1, vk1);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
+        assert_err!(Infimum::rotate_keys(RuntimeOrigin::signed(0), pk2, vk2, signature), Error::<Test>::PollCurrentlyActive);
     })
 }
 
@@ -123,15 +232,70 @@ fn coordinator_key_rotation_after_poll()
         System::set_block_number(1);
 
         let (pk1, vk1) = get_coordinator_data();
-        let (pk2, vk2) = get_coordinator_data();
+        let (pk2, vk2, signature) = get_coordinator_signed_data(0);
         let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
 
-        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk1, vk1));
-        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
+        register_coordinator(0, pk1, vk1);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
         
         run_to_block(signup_period + voting_period + 2);
         assert_ok!(Infimum::nullify_poll(RuntimeOrigin::signed(0)));
-        assert_ok!(Infimum::rotate_keys(RuntimeOrigin::signed(0), pk2, vk2));
+        assert_ok!(Infimum::rotate_keys(RuntimeOrigin::signed(0), pk2, vk2, signature));
+    })
+}
+
+/// Coordinators should be able to rotate their keys once voting has closed, and the poll should still be proven
+/// against the keys snapshotted at its creation.
+#[test]
+fn coordinator_key_rotation_before_proving()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk1, vk1) = get_coordinator_data();
+        let (pk2, mut vk2, signature) = get_coordinator_signed_data(0);
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        // The rotated process key is of the right size, but from the wrong setup.
+        vk2.process.delta_g2 = vk2.process.gamma_g2.clone();
+
+        register_coordinator(0, pk1, vk1.clone());
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
+        assert_eq!(Infimum::poll_verify_key(0), Some(vk1.clone()));
+
+        run_to_block(2);
+        for (origin, pk) in &get_participants()
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
+        }
+
+        run_to_block(14);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let (_pk, bob_shared_pk, message_data) = get_participant();
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, bob_shared_pk, interaction_cipher(&message_data)));
+
+        run_to_block(26);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        // Rotate the keys while the poll awaits processing.
+        assert_ok!(Infimum::rotate_keys(RuntimeOrigin::signed(0), pk2, vk2.clone(), signature));
+        System::assert_has_event(Event::CoordinatorVerifyKeyRotated {
+            poll_id: 0,
+            coordinator: 0,
+            old_key_hash: compute_verify_key_hash(&vk1).unwrap(),
+            new_key_hash: compute_verify_key_hash(&vk2).unwrap()
+        }.into());
+        assert_eq!(Infimum::coordinators(0).unwrap().public_key, pk2);
+        assert_eq!(Infimum::coordinators(0).unwrap().verify_key, vk2);
+        assert_eq!(Infimum::poll_verify_key(0), Some(vk1));
+        assert_eq!(Infimum::coordinator_public_key_at_poll_creation(0), Some([pk1.x, pk1.y].concat()));
+
+        let (proof_data, new_proof_commitment, _tpf, _tc) = get_proof();
+        let proof_batches: vec::Vec<(ProofData, CommitmentData)> = vec::Vec::from([(proof_data, new_proof_commitment)]);
+        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), 0, proof_batches.try_into().unwrap(), None));
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment.process.completed, 1);
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment.process.current, new_proof_commitment);
     })
 }
 
@@ -140,12 +304,12 @@ fn coordinator_key_rotation_after_poll()
 fn coordinator_key_rotation_malformed() 
 {
     new_test_ext().execute_with(|| {
-        let (pk1, vk1) = get_coordinator_data();
+        let (pk1, vk1, signature) = get_coordinator_signed_data(0);
         let (pk2, vk2) = get_coordinator_data_malformed();
 
-        assert_err!(Infimum::rotate_keys(RuntimeOrigin::none(), pk1, vk1.clone()), error::BadOrigin);
-        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk1, vk1));
-        assert_err!(Infimum::rotate_keys(RuntimeOrigin::signed(0), pk2, vk2), Error::<Test>::MalformedKeys);
+        assert_err!(Infimum::rotate_keys(RuntimeOrigin::none(), pk1, vk1.clone(), signature), error::BadOrigin);
+        register_coordinator(0, pk1, vk1);
+        assert_err!(Infimum::rotate_keys(RuntimeOrigin::signed(0), pk2, vk2, signature), Error::<Test>::MalformedKeys);
     })
 }
 
@@ -159,35 +323,69 @@ fn poll_creation_successful()
         let (pk, vk) = get_coordinator_data();
         let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
 
-        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
-        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
+        let config = poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options);
+
+        register_coordinator(0, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), config.clone()));
 
         assert_eq!(Infimum::coordinators(0).unwrap().last_poll, Some(0));
         assert_eq!(Infimum::poll_ids(0).len(), 1);        
 
         System::assert_has_event(Event::PollCreated {
             coordinator: 0,
+            coordinator_public_key: pk,
             poll_id: 0,
             starts_at: 1 + signup_period,
-            ends_at: 2 + signup_period + voting_period
+            ends_at: 2 + signup_period + voting_period,
+            max_registrations: 1024,
+            max_interactions: 25,
+            vote_option_count: 25,
+            fee_allowance: 0,
+            config_hash: config.config_hash().unwrap()
         }.into());
     })
 }
 
-/// Polls can only be created by registered coordinators.
+/// The configuration hash should commit to every parameter of the configuration, and be a canonical field element.
 #[test]
-fn poll_creation_by_non_coordinator() 
+fn poll_creation_config_hash()
 {
     new_test_ext().execute_with(|| {
         let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+        let config = poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options);
+
+        let config_hash = config.config_hash().unwrap();
+        assert!(crate::crypto::is_valid_fr_element(&config_hash));
+        assert_eq!(config.clone().config_hash(), Some(config_hash));
+
+        let mutations: [fn(&mut PollConfigOf<Test>); 9] = [
+            |config| config.signup_period += 1,
+            |config| config.voting_period += 1,
+            |config| config.interaction_depth -= 1,
+            |config| config.tally_subtree_depth += 1,
+            |config| config.fee_allowance += 1,
+            |config| config.pending = true,
+            |config| config.coord_key_hash = CoordKeyHash::PoseidonPacked,
+            |config| config.circuit_id = Some(0),
+            |config| config.vote_options = config.vote_options.iter().rev().copied().collect::<vec::Vec<_>>().try_into().unwrap()
+        ];
+        for mutate in mutations
+        {
+            let mut mutated = config.clone();
+            mutate(&mut mutated);
+            assert_ne!(mutated.config_hash(), Some(config_hash));
+        }
 
-        assert_err!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options), Error::<Test>::CoordinatorNotRegistered);
+        // An invalid configuration has no commitment.
+        let mut invalid = config;
+        invalid.message_words = 0;
+        assert_eq!(invalid.config_hash(), None);
     })
 }
 
-/// Polls should be able to be nullified.
+/// Polls should only be created from valid configurations.
 #[test]
-fn poll_nullify_error() 
+fn poll_creation_invalid_config() 
 {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
@@ -195,49 +393,56 @@ fn poll_nullify_error()
         let (pk, vk) = get_coordinator_data();
         let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
 
-        assert_err!(Infimum::nullify_poll(RuntimeOrigin::signed(0)), Error::<Test>::CoordinatorNotRegistered);
+        register_coordinator(0, pk, vk);
 
-        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
-        assert_err!(Infimum::nullify_poll(RuntimeOrigin::signed(0)), Error::<Test>::PollDoesNotExist);
+        // Too few vote options.
+        assert_err!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vec::Vec::from([0]))), Error::<Test>::PollConfigInvalid);
 
-        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
+        // The registration tree exceeds the maximum number of registrations, or overflows.
+        assert_err!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, 32, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone())), Error::<Test>::PollConfigInvalid);
+        assert_err!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, 255, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone())), Error::<Test>::PollConfigInvalid);
 
-        let (pk, shared_pk, message) = get_participant();
-        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, pk));
-        
-        run_to_block(1 + signup_period);
-        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, message));
-        assert_err!(Infimum::nullify_poll(RuntimeOrigin::signed(0)), Error::<Test>::PollCurrentlyActive);
+        // The interaction tree exceeds the maximum number of interactions, or overflows.
+        assert_err!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, 5, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone())), Error::<Test>::PollConfigInvalid);
+        assert_err!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, 255, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone())), Error::<Test>::PollConfigInvalid);
+
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
     })
 }
 
-/// Coordinators can only create the allowed maximum number of polls.
+/// Polls whose periods overflow the block number should be rejected, both at creation and at activation time.
 #[test]
-fn poll_creation_beyond_limit() 
+fn poll_creation_period_overflow()
 {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
 
         let (pk, vk) = get_coordinator_data();
-        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
-        let duration = signup_period + voting_period;
+        let (signup_period, _, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
 
-        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
-        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone()));
+        register_coordinator(0, pk, vk);
 
-        run_to_block(2 + duration);
-        assert_ok!(Infimum::nullify_poll(RuntimeOrigin::signed(0)));
-        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone()));
+        // The voting period overflows the block number.
+        assert_err!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, u64::MAX, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone())), Error::<Test>::PollConfigInvalid);
 
-        run_to_block(2 + 2 * duration);
-        assert_ok!(Infimum::nullify_poll(RuntimeOrigin::signed(0)));
-        assert_err!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options), Error::<Test>::CoordinatorPollLimitReached);
+        // The signup period overflows the block number.
+        assert_err!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(u64::MAX, 12, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone())), Error::<Test>::PollConfigInvalid);
+        assert_eq!(Infimum::next_poll_id(), 0);
+
+        // A pending poll whose periods exactly fit at creation time overflow once it is activated at a later block.
+        let mut config = poll_config(u64::MAX - 14, 12, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options);
+        config.pending = true;
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), config));
+
+        System::set_block_number(2);
+        assert_err!(Infimum::activate_poll(RuntimeOrigin::signed(0), 0), Error::<Test>::PollConfigInvalid);
+        assert!(Infimum::poll_phase(0).unwrap().is_pending());
     })
 }
 
-/// A coordinator can only manage a single poll at a time.
+/// Polls whose trees require an unsupported hash arity should be detected at creation time.
 #[test]
-fn poll_creation_during_extant() 
+fn poll_creation_unsupported_arity() 
 {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
@@ -245,306 +450,3544 @@ fn poll_creation_during_extant()
         let (pk, vk) = get_coordinator_data();
         let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
 
-        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
-        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone()));
-        assert_err!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options), Error::<Test>::PollCurrentlyActive);
+        register_coordinator(0, pk, vk);
+
+        // A registration tree of depth one and arity 13 holds at most `MaxPollRegistrations` leaves, but can not be
+        // hashed, nor can one of arity zero.
+        RegistrationTreeArity::set(13);
+        assert_err!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, 1, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone())), Error::<Test>::PollConfigInvalid);
+        RegistrationTreeArity::set(0);
+        assert_err!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, 1, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone())), Error::<Test>::PollConfigInvalid);
+        assert_eq!(Infimum::next_poll_id(), 0);
+
+        RegistrationTreeArity::set(2);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
+        assert!(Infimum::polls(0).unwrap().hash_arities_supported());
     })
 }
 
-/// Users should be able to register as participants.
+/// The deprecated positional call should create the same poll as `create_poll`.
 #[test]
-fn register_as_participant()
+fn poll_creation_legacy() 
 {
-    new_test_ext().execute_with(|| { 
+    new_test_ext().execute_with(|| {
         System::set_block_number(1);
 
         let (pk, vk) = get_coordinator_data();
         let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+        let config = poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone());
 
-        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
-        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone()));
-        
-        let participant = get_participant();
-
-        assert_err!(Infimum::register_as_participant(RuntimeOrigin::none(), 0, participant.0), error::BadOrigin);
-        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, participant.0));
-        
-        assert_eq!(Infimum::polls(0).is_some(), true);
-        assert_eq!(Infimum::polls(0).unwrap().state.registrations.count, 1);
+        register_coordinator(0, pk, vk);
+        assert_ok!(Infimum::create_poll_legacy(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.try_into().unwrap()));
 
-        System::assert_has_event(Event::ParticipantRegistered { poll_id: 0, count: 1, public_key: participant.0, block: 1 }.into());
+        assert_eq!(Infimum::polls(0).unwrap().config, config.validate().unwrap());
+        System::assert_has_event(Event::PollCreated {
+            coordinator: 0,
+            coordinator_public_key: pk,
+            poll_id: 0,
+            starts_at: 1 + signup_period,
+            ends_at: 2 + signup_period + voting_period,
+            max_registrations: 1024,
+            max_interactions: 25,
+            vote_option_count: 25,
+            fee_allowance: 0,
+            config_hash: config.config_hash().unwrap()
+        }.into());
     })
 }
 
-/// Users can only register during the registration period.
+/// Storage invariants should hold after polls are created.
 #[test]
-fn register_as_participant_outside_period()
+fn poll_creation_storage_invariants()
 {
-    new_test_ext().execute_with(|| { 
+    new_test_ext().execute_with(|| {
         System::set_block_number(1);
 
         let (pk, vk) = get_coordinator_data();
         let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
 
-        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
-        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone()));
-        
-        let participant = get_participant();
+        register_coordinator(0, pk, vk.clone());
+        register_coordinator(1, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone())));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(1), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
+        assert_ok!(Infimum::do_try_state());
 
-        run_to_block(1 + signup_period);
-        assert_err!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, participant.0), Error::<Test>::PollRegistrationHasEnded);
+        // A poll which is missing from the poll ids of its coordinator should be detected.
+        CoordinatorPollIds::<Test>::mutate(1, |ids| ids.clear());
+        assert!(Infimum::do_try_state().is_err());
     })
 }
 
-/// Only the allowable number of participants should be allowed to register.
+/// The vote options of a poll should be immutable, and those of finalized polls should fit the vote option tree their
+/// outcome was proven against.
 #[test]
-fn participant_limit_reached()
+fn vote_options_storage_invariants()
 {
-    new_test_ext().execute_with(|| { 
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
         let (pk, vk) = get_coordinator_data();
-        let (signup_period, voting_period, _registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
 
-        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
-        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, 2, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone()));
-        
-        let participant = get_participant();
-        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, participant.0));
-        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(2), 0, participant.0));
-        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(3), 0, participant.0));
-        assert_err!(Infimum::register_as_participant(RuntimeOrigin::signed(4), 0, participant.0), Error::<Test>::ParticipantRegistrationLimitReached);
+        register_coordinator(0, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
+
+        // The test data fills the vote option tree.
+        Polls::<Test>::mutate(0, |poll| poll.as_mut().unwrap().state.outcome = Some(0));
+        assert_ok!(Infimum::do_try_state());
+
+        // An option appended after creation should be detected.
+        Polls::<Test>::mutate(0, |poll| poll.as_mut().unwrap().config.vote_options.try_push(25).unwrap());
+        assert!(Infimum::do_try_state().is_err());
+
+        // As should a finalized poll whose options exceed its vote option tree.
+        PollVoteOptionCount::<Test>::insert(0, 26);
+        assert!(Infimum::do_try_state().is_err());
+
+        Polls::<Test>::mutate(0, |poll| poll.as_mut().unwrap().state.outcome = None);
+        assert_ok!(Infimum::do_try_state());
     })
 }
 
-/// Users can only register in existing polls.
+/// Coordinator poll ids should remain sorted, and not be treated as a recency signal, if polls change hands.
 #[test]
-fn participant_registration_no_poll()
+fn poll_creation_after_transfer()
 {
-    new_test_ext().execute_with(|| { 
-        let participant = get_participant();
-        assert_err!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, participant.0), Error::<Test>::PollDoesNotExist);
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+        let config = poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options);
+
+        register_coordinator(0, pk, vk.clone());
+        register_coordinator(1, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), config.clone()));
+        let mut pending = config.clone();
+        pending.pending = true;
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(1), pending));
+
+        // The pending poll of the second coordinator is reaped, i.e. it is finished.
+        run_to_block(1 + <Test as crate::Config>::ActivationTimeout::get());
+        assert_ok!(Infimum::reap_poll(RuntimeOrigin::signed(3), 1));
+
+        // Transfer the on-going poll to the second coordinator.
+        let mut poll = Infimum::polls(0).unwrap();
+        poll.coordinator = 1;
+        Infimum::insert_poll(0, poll);
+        CoordinatorPollIds::<Test>::remove(0);
+        Coordinators::<Test>::mutate(0, |coordinator| coordinator.as_mut().unwrap().last_poll = None);
+        Infimum::insert_poll_id(&1, 0);
+        assert_eq!(Infimum::poll_ids(1), vec![0, 1]);
+        assert_ok!(Infimum::do_try_state());
+
+        // The on-going poll is not the most recently created poll of the second coordinator.
+        assert_err!(Infimum::create_poll(RuntimeOrigin::signed(1), config.clone()), Error::<Test>::PollCurrentlyActive);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), config));
+        assert_eq!(Infimum::poll_ids(0), vec![2]);
+        assert_ok!(Infimum::do_try_state());
+
+        // Unsorted poll ids should be detected.
+        CoordinatorPollIds::<Test>::insert(1, vec![1, 0]);
+        assert!(Infimum::do_try_state().is_err());
     })
 }
 
-/// Participants should be able to interact with polls they are registered in.
+/// A coordinator should only be able to create another poll once the creation cooldown has passed.
 #[test]
-fn participant_interaction()
+fn poll_creation_cooldown()
 {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
+        PollCreationCooldown::set(50);
 
         let (pk, vk) = get_coordinator_data();
         let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+        let config = poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options);
 
-        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
-        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
+        register_coordinator(0, pk, vk.clone());
+        register_coordinator(1, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), config.clone()));
+        assert_eq!(Infimum::last_poll_creation(0), Some(1));
 
-        let (pk, shared_pk, message) = get_participant();
-        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, pk));
-        
-        run_to_block(1 + signup_period);
-        assert_err!(Infimum::interact_with_poll(RuntimeOrigin::none(), 0, shared_pk, message), error::BadOrigin);
-        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, message));
+        // The cooldown is tracked per coordinator.
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(1), config.clone()));
 
-        assert_eq!(Infimum::polls(0).is_some(), true);
-        assert_eq!(Infimum::polls(0).unwrap().state.interactions.count, 1);
+        // The first poll is finished within the cooldown.
+        run_to_block(2 + signup_period + voting_period);
+        assert_ok!(Infimum::nullify_poll(RuntimeOrigin::signed(0)));
+        assert_err!(Infimum::create_poll(RuntimeOrigin::signed(0), config.clone()), Error::<Test>::CreationCooldownActive);
+
+        run_to_block(50);
+        assert_err!(Infimum::create_poll(RuntimeOrigin::signed(0), config.clone()), Error::<Test>::CreationCooldownActive);
 
-        System::assert_has_event(Event::PollInteraction { poll_id: 0, count: 1, public_key: shared_pk, data: message }.into());
+        run_to_block(51);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), config));
+        assert_eq!(Infimum::last_poll_creation(0), Some(51));
     })
 }
 
-/// Participants should only be able to interact during the voting period.
+/// Polls can only be created by registered coordinators.
 #[test]
-fn participant_interaction_outside_period()
+fn poll_creation_by_non_coordinator() 
 {
     new_test_ext().execute_with(|| {
-        System::set_block_number(1);
-
-        let (pk, vk) = get_coordinator_data();
         let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
 
-        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
-        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
-
-        let (pk, shared_pk, message) = get_participant();
-        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, pk));
-
-        assert_err!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, message), Error::<Test>::PollRegistrationInProgress);
-        run_to_block(2 + signup_period + voting_period);
-
-        assert_err!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, message), Error::<Test>::PollVotingHasEnded);
+        assert_err!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)), Error::<Test>::CoordinatorNotRegistered);
     })
 }
 
-/// The maximal number of allowable interactions should be enforced.
+/// Polls should be able to be nullified.
 #[test]
-fn participant_interaction_limit()
+fn poll_nullify_error() 
 {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
 
         let (pk, vk) = get_coordinator_data();
-        let (signup_period, voting_period, registration_depth, _interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert_err!(Infimum::nullify_poll(RuntimeOrigin::signed(0)), Error::<Test>::CoordinatorNotRegistered);
+
+        register_coordinator(0, pk, vk);
+        assert_err!(Infimum::nullify_poll(RuntimeOrigin::signed(0)), Error::<Test>::PollDoesNotExist);
 
-        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk));
-        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), signup_period, voting_period, registration_depth, 1, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
 
         let (pk, shared_pk, message) = get_participant();
         assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, pk));
-
+        
         run_to_block(1 + signup_period);
-        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, message));
-        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, message));
-        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, message));
-        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, message));
-        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, message));
-        assert_err!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, message), Error::<Test>::ParticipantInteractionLimitReached);
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, interaction_cipher(&message)));
+        assert_err!(Infimum::nullify_poll(RuntimeOrigin::signed(0)), Error::<Test>::PollCurrentlyActive);
     })
 }
 
-/// The registration tree should only be mergable after the signup period.
+/// A poll nullified during its voting period, as nobody registered, should reject further interactions.
 #[test]
-fn merge_registration_signup_period()
+fn poll_interaction_nullified()
 {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
 
-        let (alice_pk, alice_vk) = get_coordinator_data();
+        let (pk, vk) = get_coordinator_data();
         let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
 
-        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), alice_pk, alice_vk));
-        assert_ok!(
-            Infimum::create_poll(
-                RuntimeOrigin::signed(0),
-                signup_period,
-                voting_period,
-                registration_depth,
-                interaction_depth,
-                process_subtree_depth,
-                tally_subtree_depth,
-                vote_option_tree_depth,
-                vote_options
-            )
-        );
-
-        run_to_block(2);
+        register_coordinator(0, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
 
-        for (origin, pk) in &get_participants()
-        {
-            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
-        }
+        run_to_block(1 + signup_period);
+        assert_ok!(Infimum::nullify_poll(RuntimeOrigin::signed(0)));
 
-        assert_err!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)), Error::<Test>::PollRegistrationInProgress);
+        let (pk, shared_pk, message) = get_participant();
+        assert_err!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, interaction_cipher(&message)), Error::<Test>::PollVotingHasEnded);
+        assert_err!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, pk), Error::<Test>::PollRegistrationHasEnded);
     })
 }
 
-/// The interaction tree should only be mergable after the voting period.
+/// Nullified polls should be returned by `get_poll`, but not by `get_active_poll`.
 #[test]
-fn merge_interaction_voting_period()
+fn poll_accessors_nullified()
 {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
 
-        let (alice_pk, alice_vk) = get_coordinator_data();
+        let (pk, vk) = get_coordinator_data();
         let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
 
-        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), alice_pk, alice_vk));
-        assert_ok!(
-            Infimum::create_poll(
-                RuntimeOrigin::signed(0),
-                signup_period,
-                voting_period,
-                registration_depth,
-                interaction_depth,
-                process_subtree_depth,
-                tally_subtree_depth,
-                vote_option_tree_depth,
-                vote_options
-            )
-        );
-
-        run_to_block(2);
+        assert_eq!(Infimum::get_poll(0), None);
+        assert_eq!(Infimum::get_active_poll(0), None);
 
-        for (origin, pk) in &get_participants()
-        {
-            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
-        }
+        register_coordinator(0, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
+        assert_eq!(Infimum::get_poll(0), Infimum::polls(0));
+        assert_eq!(Infimum::get_active_poll(0), Infimum::polls(0));
 
-        run_to_block(1 + signup_period);
+        run_to_block(signup_period + voting_period + 2);
+        assert_ok!(Infimum::nullify_poll(RuntimeOrigin::signed(0)));
 
-        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
-        assert_err!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)), Error::<Test>::PollVotingInProgress);
+        let poll = Infimum::get_poll(0).unwrap();
+        assert!(poll.is_nullified());
+        assert_eq!(Infimum::get_active_poll(0), None);
     })
 }
 
-/// The registration tree should be able to be merged and produce the correct root and commitment value.
+/// The track record of a coordinator should count created polls, and nullified polls as abandoned rather than
+/// finalized.
 #[test]
-fn merge_registration_state_success()
+fn coordinator_stats_nullified()
 {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
 
-        let (alice_pk, alice_vk) = get_coordinator_data();
+        let (pk, vk) = get_coordinator_data();
         let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
 
-        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), alice_pk, alice_vk));
-        assert_ok!(
-            Infimum::create_poll(
-                RuntimeOrigin::signed(0),
-                signup_period,
-                voting_period,
-                registration_depth,
-                interaction_depth,
-                process_subtree_depth,
-                tally_subtree_depth,
-                vote_option_tree_depth,
-                vote_options
-            )
-        );
+        assert_eq!(Infimum::coordinator_info(0), None);
 
-        run_to_block(2);
+        register_coordinator(0, pk, vk);
+        assert_eq!(Infimum::coordinator_stats(0), CoordinatorRecord::default());
 
-        for (origin, pk) in &get_participants()
-        {
-            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
-        }
-        
-        run_to_block(14);
-        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone())));
+        assert_eq!(Infimum::coordinator_stats(0).polls_created, 1);
 
-        assert_eq!(
-            Infimum::polls(0).unwrap().state.registrations.root, 
-            Some([16, 44, 202, 10, 154, 154, 255, 162, 164, 69, 231, 62, 33, 104, 15, 112, 88, 216, 113, 111, 70, 122, 146, 189, 80, 94, 79, 213, 137, 73, 176, 205])
-        );
-        assert_eq!(
-            Infimum::polls(0).unwrap().state.commitment.process,
-            (0, [42, 172, 65, 18, 133, 85, 171, 69, 236, 46, 172, 46, 31, 229, 218, 229, 163, 201, 108, 165, 174, 141, 40, 17, 128, 246, 71, 216, 46, 235, 135, 32])
-        );
+        let (participant, _, _) = get_participant();
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, participant));
+
+        run_to_block(2 + signup_period + voting_period);
+        assert_ok!(Infimum::nullify_poll(RuntimeOrigin::signed(0)));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
+
+        let record = CoordinatorRecord { polls_created: 2, polls_finalized: 0, polls_abandoned: 1, participants_served: 0 };
+        assert_eq!(CoordinatorStats::<Test>::get(0), record);
+
+        let info = Infimum::coordinator_info(0).unwrap();
+        assert_eq!(info.public_key, pk);
+        assert_eq!(info.last_poll, Some(1));
+        assert_eq!(info.record, record);
     })
 }
 
-/// The registration tree should be able to be merged and produce the correct root and expected number of proofs.
+/// Upon the creation of a poll, the oldest poll ids of the coordinator beyond `MaxCoordinatorPolls` should be archived.
 #[test]
-fn merge_interaction_state_success()
+fn poll_creation_beyond_limit() 
 {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
 
-        let (alice_pk, alice_vk) = get_coordinator_data();
+        let (pk, vk) = get_coordinator_data();
         let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+        let duration = signup_period + voting_period;
 
-        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), alice_pk, alice_vk));
-        assert_ok!(
-            Infimum::create_poll(
-                RuntimeOrigin::signed(0),
-                signup_period,
-                voting_period,
-                registration_depth,
-                interaction_depth,
-                process_subtree_depth,
-                tally_subtree_depth,
-                vote_option_tree_depth,
-                vote_options
-            )
+        register_coordinator(0, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone())));
+
+        run_to_block(2 + duration);
+        assert_ok!(Infimum::nullify_poll(RuntimeOrigin::signed(0)));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone())));
+
+        assert_eq!(Infimum::poll_ids(0), vec::Vec::from([0, 1]));
+        assert_eq!(Infimum::poll_history(0), vec::Vec::<u32>::new());
+
+        // The oldest poll id is archived once the limit is exceeded.
+        run_to_block(3 + 2 * duration);
+        assert_ok!(Infimum::nullify_poll(RuntimeOrigin::signed(0)));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone())));
+        assert_eq!(Infimum::poll_ids(0), vec::Vec::from([1, 2]));
+        assert_eq!(Infimum::poll_history(0), vec::Vec::from([0]));
+
+        // Lowering the limit archives every excess poll id upon the next creation, but never the most recent one.
+        MaxCoordinatorPolls::set(0);
+        run_to_block(4 + 3 * duration);
+        assert_ok!(Infimum::nullify_poll(RuntimeOrigin::signed(0)));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
+        assert_eq!(Infimum::poll_ids(0), vec::Vec::from([3]));
+        assert_eq!(Infimum::poll_history(0), vec::Vec::from([0, 1, 2]));
+        assert_ok!(Infimum::do_try_state());
+    })
+}
+
+/// Only the ids of polls which have not been fulfilled should be reported as active.
+#[test]
+fn active_polls_paginated()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+        let duration = signup_period + voting_period;
+
+        assert_eq!(Infimum::active_polls(0, 10), vec::Vec::<u32>::new());
+
+        // Poll 0 is nullified, while poll 1 is still ongoing.
+        register_coordinator(0, pk, vk.clone());
+        register_coordinator(1, pk, vk.clone());
+        register_coordinator(2, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone())));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(1), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone())));
+        run_to_block(2 + duration);
+        assert_ok!(Infimum::nullify_poll(RuntimeOrigin::signed(0)));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(2), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone())));
+
+        assert_eq!(Infimum::active_polls(0, 10), vec::Vec::from([1, 2]));
+        assert_eq!(Infimum::active_polls(0, 1), vec::Vec::from([1]));
+        assert_eq!(Infimum::active_polls(2, 10), vec::Vec::from([2]));
+        assert_eq!(Infimum::active_polls(3, 10), vec::Vec::<u32>::new());
+
+        // A page only examines `MAX_ACTIVE_POLLS_SCAN` ids, such that a sparse poll is reported by a later page.
+        NextPollId::<Test>::put(2 * MAX_ACTIVE_POLLS_SCAN);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
+        assert_eq!(Infimum::active_polls(3, 10), vec::Vec::<u32>::new());
+        assert_eq!(Infimum::active_polls(MAX_ACTIVE_POLLS_SCAN, 10), vec::Vec::<u32>::new());
+        assert_eq!(Infimum::active_polls(2 * MAX_ACTIVE_POLLS_SCAN, 10), vec::Vec::from([2 * MAX_ACTIVE_POLLS_SCAN]));
+    })
+}
+
+/// The poll ids of a coordinator should be returned in full, or paged through in ascending order.
+#[test]
+fn coordinator_poll_ids_paginated()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert_eq!(Infimum::get_coordinator_poll_ids(0), vec::Vec::<u32>::new());
+
+        register_coordinator(0, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
+        assert_eq!(Infimum::get_coordinator_poll_ids(0), vec::Vec::from([0]));
+        assert_eq!(Infimum::get_coordinator_poll_ids(1), vec::Vec::<u32>::new());
+
+        CoordinatorPollIds::<Test>::insert(0, vec::Vec::from([0, 3, 4, 7, 9]));
+        assert_eq!(Infimum::get_coordinator_poll_ids_paginated(0, 0, 2), vec::Vec::from([0, 3]));
+        assert_eq!(Infimum::get_coordinator_poll_ids_paginated(0, 1, 2), vec::Vec::from([4, 7]));
+        assert_eq!(Infimum::get_coordinator_poll_ids_paginated(0, 2, 2), vec::Vec::from([9]));
+        assert_eq!(Infimum::get_coordinator_poll_ids_paginated(0, 3, 2), vec::Vec::<u32>::new());
+        assert_eq!(Infimum::get_coordinator_poll_ids_paginated(0, 0, 0), vec::Vec::<u32>::new());
+        assert_eq!(Infimum::get_coordinator_poll_ids_paginated(0, u32::MAX, u32::MAX), vec::Vec::<u32>::new());
+        assert_eq!(Infimum::get_coordinator_poll_ids_paginated(0, 0, u32::MAX), Infimum::get_coordinator_poll_ids(0));
+    })
+}
+
+/// Only the most recently created polls should be retained, and reported most recent first alongside their phase.
+#[test]
+fn recent_polls_evict_oldest()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert!(Infimum::recent_polls().is_empty());
+        assert_eq!(Infimum::recent_poll_summaries(10), vec::Vec::new());
+
+        // Overflow the buffer by a single poll, such that poll 0 is evicted.
+        for poll_id in 0..=RECENT_POLLS_CAPACITY
+        {
+            let coordinator = 100 + u64::from(poll_id);
+            register_coordinator(coordinator, pk, vk.clone());
+            assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(coordinator), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone())));
+        }
+        assert_eq!(Infimum::recent_polls().len(), RECENT_POLLS_CAPACITY as usize);
+        assert_eq!(Infimum::recent_polls().first(), Some(&1));
+        assert_eq!(Infimum::recent_polls().last(), Some(&RECENT_POLLS_CAPACITY));
+
+        // A pending poll evicts poll 1, and is marked once reaped.
+        let reaped = RECENT_POLLS_CAPACITY + 1;
+        let mut config = poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options);
+        config.pending = true;
+        register_coordinator(0, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), config));
+        assert_eq!(Infimum::recent_polls().first(), Some(&2));
+        assert_eq!(Infimum::recent_poll_summaries(1)[0].phase, PollPhase::Pending);
+
+        run_to_block(1 + <Test as crate::Config>::ActivationTimeout::get());
+        assert_ok!(Infimum::reap_poll(RuntimeOrigin::signed(3), reaped));
+
+        assert_eq!(Infimum::recent_poll_summaries(3), vec::Vec::from([
+            PollSummary { poll_id: reaped, coordinator: 0, phase: PollPhase::Nullified },
+            PollSummary { poll_id: reaped - 1, coordinator: 100 + u64::from(reaped - 1), phase: PollPhase::Voting },
+            PollSummary { poll_id: reaped - 2, coordinator: 100 + u64::from(reaped - 2), phase: PollPhase::Voting }
+        ]));
+        assert_eq!(Infimum::recent_poll_summaries(u32::MAX).len(), RECENT_POLLS_CAPACITY as usize);
+    })
+}
+
+/// A runtime whose tree arity has no precomputed zero hashes should fail the integrity test.
+#[test]
+#[should_panic(expected = "`InteractionTreeArity` must be either 2 or 5")]
+fn integrity_test_unsupported_arity()
+{
+    InteractionTreeArity::set(3);
+    Infimum::integrity_test();
+}
+
+/// The tree arities of the runtime and the tree depths of the poll should be recorded upon poll creation.
+#[test]
+fn poll_creation_tree_arities()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, pk, vk);
+        assert_eq!(Infimum::poll_registration_tree_arity(0), None);
+        assert_eq!(Infimum::poll_interaction_tree_arity(0), None);
+        assert_eq!(Infimum::poll_tree_depths(0), None);
+
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
+
+        let poll = Infimum::polls(0).unwrap();
+        assert_eq!(poll.state.registrations.arity, 2);
+        assert_eq!(poll.state.interactions.arity, 5);
+        assert_eq!(Infimum::poll_registration_tree_arity(0), Some(2));
+        assert_eq!(Infimum::poll_interaction_tree_arity(0), Some(5));
+        assert_eq!(Infimum::poll_tree_depths(0), Some((poll.state.registrations.depth, poll.state.interactions.depth)));
+        assert_eq!(Infimum::poll_tree_depths(0), Some((registration_depth, interaction_depth)));
+    })
+}
+
+/// The tree stats should follow the registrations and merges of a poll.
+#[test]
+fn poll_tree_stats()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, pk, vk);
+        assert_eq!(Infimum::registration_tree_stats(0), None);
+        assert_eq!(Infimum::interaction_tree_stats(0), None);
+
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
+
+        for (origin, participant) in &get_participants()
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *participant));
+        }
+
+        let stats = Infimum::registration_tree_stats(0).unwrap();
+        assert_eq!((stats.depth, stats.arity), (registration_depth, 2));
+        assert_eq!(stats.leaf_count, get_participants().len() as u32);
+        assert_eq!(stats.capacity, 2u32.pow(registration_depth.into()));
+        assert_eq!(stats.fill_percent as u32, stats.leaf_count * 100 / stats.capacity);
+        assert!(!stats.is_merged);
+
+        let stats = Infimum::interaction_tree_stats(0).unwrap();
+        assert_eq!((stats.depth, stats.arity, stats.leaf_count, stats.capacity, stats.fill_percent), (interaction_depth, 5, 0, 25, 0));
+
+        run_to_block(1 + signup_period);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+        assert!(Infimum::registration_tree_stats(0).unwrap().is_merged);
+        assert!(!Infimum::interaction_tree_stats(0).unwrap().is_merged);
+    })
+}
+
+/// The number of vote options should be recorded upon poll creation.
+#[test]
+fn poll_creation_vote_option_count()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, pk, vk);
+        assert_eq!(Infimum::vote_option_count(0), None);
+
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone())));
+
+        assert_eq!(Infimum::vote_option_count(0), Some(vote_options.len() as u32));
+        assert_eq!(Infimum::vote_option_count(0), Some(Infimum::polls(0).unwrap().config.vote_options.len() as u32));
+        assert_eq!(Infimum::vote_option_count(1), None);
+    })
+}
+
+/// The end of the voting period should be recorded upon poll creation, and determine whether the poll is over.
+#[test]
+fn poll_creation_voting_period_end()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, pk, vk);
+        assert_eq!(Infimum::poll_voting_period_end(0), None);
+
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
+
+        let end = 1 + signup_period + voting_period;
+        assert_eq!(Infimum::poll_voting_period_end(0), Some(end));
+        assert_eq!(Infimum::polls(0).unwrap().get_voting_period_end(), end);
+
+        run_to_block(end);
+        assert!(!Infimum::polls(0).unwrap().is_over());
+
+        run_to_block(end + 1);
+        assert!(Infimum::polls(0).unwrap().is_over());
+    })
+}
+
+/// The frontiers of the state trees should be stored apart from the poll, and removed once the trees are merged.
+#[test]
+fn poll_frontiers_stored_apart()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
+
+        // The registration tree starts out with the zero leaf, and the interaction tree empty.
+        let registrations = Infimum::polls(0).unwrap().state.registrations;
+        let zero = get_merkle_zeroes(registrations.arity)[0];
+        assert!(registrations.hashes.is_empty());
+        assert_eq!(Infimum::poll_registration_frontier(0), Some(vec::Vec::from([(0, zero)])));
+        assert_eq!(Infimum::poll_interaction_frontier(0), None);
+
+        run_to_block(2);
+        let participants = get_participants();
+        for (origin, pk) in &participants
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
+        }
+
+        // The stored frontier is that of the tree rebuilt from the registration leaves.
+        let tree = Infimum::registration_leaves(0, 0, participants.len() as u32)
+            .into_iter()
+            .fold(
+                PollStateTree::new(registrations.arity, registrations.full_depth, Some((0, zero))),
+                |tree, leaf| tree.insert(leaf).unwrap()
+            );
+        assert!(Infimum::polls(0).unwrap().state.registrations.hashes.is_empty());
+        assert_eq!(Infimum::poll_registration_frontier(0), Some(tree.hashes));
+
+        run_to_block(1 + signup_period);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+        assert_eq!(Infimum::poll_registration_frontier(0), None);
+        assert!(Infimum::polls(0).unwrap().state.registrations.root.is_some());
+
+        let (_pk, shared_pk, message) = get_participant();
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, interaction_cipher(&message)));
+        assert_eq!(Infimum::poll_interaction_frontier(0).map(|hashes| hashes.len()), Some(1));
+        assert!(Infimum::polls(0).unwrap().state.interactions.hashes.is_empty());
+
+        run_to_block(2 + signup_period + voting_period);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+        assert_eq!(Infimum::poll_interaction_frontier(0), None);
+        assert!(Infimum::polls(0).unwrap().state.interactions.root.is_some());
+    })
+}
+
+/// The frontiers of version 2 polls should be moved into their own storage.
+#[test]
+fn migrate_frontiers_to_v3()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, pk, vk);
+        let mut config = poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options);
+        config.allow_early_interactions = true;
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), config));
+
+        run_to_block(2);
+        let (pk, shared_pk, message) = get_participant();
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, pk));
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, interaction_cipher(&message)));
+
+        // Store the poll with its frontiers inline, as in version 2.
+        let poll = Infimum::get_poll_with_frontiers(0).unwrap();
+        let registrations = PollRegistrationFrontier::<Test>::take(0).unwrap();
+        let interactions = PollInteractionFrontier::<Test>::take(0).unwrap();
+        Polls::<Test>::insert(0, poll.clone());
+        StorageVersion::new(2).put::<Infimum>();
+
+        MigrateToV3::<Test>::on_runtime_upgrade();
+
+        let migrated = Infimum::polls(0).unwrap();
+        assert!(migrated.state.registrations.hashes.is_empty() && migrated.state.interactions.hashes.is_empty());
+        assert_eq!(Infimum::poll_registration_frontier(0), Some(registrations));
+        assert_eq!(Infimum::poll_interaction_frontier(0), Some(interactions));
+        assert_eq!(Infimum::get_poll_with_frontiers(0), Some(poll));
+        assert_eq!(Polls::<Test>::count(), 1);
+        assert_eq!(Infimum::on_chain_storage_version(), 3);
+
+        // The migration is not applied twice.
+        MigrateToV3::<Test>::on_runtime_upgrade();
+        assert_eq!(Infimum::polls(0).unwrap(), migrated);
+    })
+}
+
+/// The state trees of version 3 polls should be stored apart from the poll, alongside the phase cache of the poll.
+#[test]
+fn migrate_trees_to_v4()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, pk, vk);
+        let mut config = poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options);
+        config.allow_early_interactions = true;
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), config));
+
+        run_to_block(2);
+        let (pk, shared_pk, message) = get_participant();
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, pk));
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, interaction_cipher(&message)));
+
+        // Store the poll with its trees inline, as in version 3.
+        let poll = Infimum::polls(0).unwrap();
+        let registrations = PollRegistrationTree::<Test>::take(0).unwrap();
+        let interactions = PollInteractionTree::<Test>::take(0).unwrap();
+        let phase = PollPhases::<Test>::take(0).unwrap();
+        Polls::<Test>::insert(0, poll.clone());
+        StorageVersion::new(3).put::<Infimum>();
+
+        MigrateToV4::<Test>::on_runtime_upgrade();
+
+        assert_eq!(Infimum::poll_registration_tree(0), Some(registrations));
+        assert_eq!(Infimum::poll_interaction_tree(0), Some(interactions));
+        assert_eq!(Infimum::poll_phase(0), Some(phase));
+        assert_eq!(Infimum::polls(0), Some(poll.clone()));
+        assert_eq!(Polls::<Test>::count(), 1);
+        assert_eq!(Infimum::on_chain_storage_version(), 4);
+        assert_ok!(Infimum::do_try_state());
+
+        // The migrated poll continues to accept interactions.
+        let (_, shared_pk, message) = get_participant();
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, interaction_cipher(&message)));
+        assert_eq!(Infimum::polls(0).unwrap().state.interactions.count, 2);
+
+        // The migration is not applied twice.
+        let migrated = Infimum::polls(0).unwrap();
+        MigrateToV4::<Test>::on_runtime_upgrade();
+        assert_eq!(Infimum::polls(0).unwrap(), migrated);
+    })
+}
+
+/// Upgrading to version 5 should assign the next poll id after the stored polls.
+#[test]
+fn migrate_next_poll_id_to_v5()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone())));
+
+        // Version 4 assigned poll ids from the counter of `Polls`.
+        NextPollId::<Test>::kill();
+        StorageVersion::new(4).put::<Infimum>();
+
+        MigrateToV5::<Test>::on_runtime_upgrade();
+
+        assert_eq!(Infimum::next_poll_id(), 1);
+        assert_eq!(Infimum::on_chain_storage_version(), 5);
+        assert_ok!(Infimum::do_try_state());
+
+        // The next poll does not overwrite the stored poll.
+        run_to_block(2 + signup_period + voting_period);
+        assert_ok!(Infimum::nullify_poll(RuntimeOrigin::signed(0)));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
+        assert!(Infimum::polls(0).unwrap().is_nullified());
+        assert_eq!(Infimum::coordinators(0).unwrap().last_poll, Some(1));
+    })
+}
+
+/// The allowed actions of a poll should flip at the same blocks at which the corresponding extrinsics do.
+#[test]
+fn allowed_actions_boundaries()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, pk, vk);
+        assert_eq!(Infimum::allowed_actions(0, None), None);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
+
+        let actions = Infimum::allowed_actions(0, Some(0)).unwrap();
+        assert!(actions.register && !actions.interact && !actions.signal_intent && !actions.merge);
+        assert!(!actions.commit_outcome && !actions.nullify && !actions.activate && !actions.reap && !actions.replace_verify_key);
+        assert_err!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)), Error::<Test>::PollRegistrationInProgress);
+        assert_err!(Infimum::signal_intent(RuntimeOrigin::signed(1), 0), Error::<Test>::PollIntentsNotCollected);
+
+        // The last block of the signup period.
+        let (pk, shared_pk, message) = get_participant();
+        run_to_block(signup_period);
+        let actions = Infimum::allowed_actions(0, None).unwrap();
+        assert!(actions.register && !actions.interact);
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, pk));
+        assert_err!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, interaction_cipher(&message)), Error::<Test>::PollRegistrationInProgress);
+
+        // The first block of the voting period. Only the coordinator may merge, and only their most recent poll.
+        run_to_block(1 + signup_period);
+        let actions = Infimum::allowed_actions(0, Some(0)).unwrap();
+        assert!(!actions.register && actions.interact && actions.merge && !actions.nullify);
+        assert!(!Infimum::allowed_actions(0, None).unwrap().merge);
+        assert!(!Infimum::allowed_actions(0, Some(1)).unwrap().merge);
+        assert_err!(Infimum::register_as_participant(RuntimeOrigin::signed(2), 0, pk), Error::<Test>::PollRegistrationHasEnded);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        // The interaction tree may not be merged before the poll is over.
+        assert!(!Infimum::allowed_actions(0, Some(0)).unwrap().merge);
+        assert_err!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)), Error::<Test>::PollVotingInProgress);
+
+        // The last block at which interactions are accepted.
+        run_to_block(1 + signup_period + voting_period);
+        assert!(Infimum::allowed_actions(0, None).unwrap().interact);
+        assert!(!Infimum::allowed_actions(0, Some(0)).unwrap().replace_verify_key);
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, interaction_cipher(&message)));
+
+        // The poll is over and sealed.
+        run_to_block(2 + signup_period + voting_period);
+        let actions = Infimum::allowed_actions(0, Some(0)).unwrap();
+        assert!(!actions.interact && actions.merge && !actions.commit_outcome && actions.replace_verify_key && !actions.nullify);
+        assert!(!Infimum::allowed_actions(0, Some(1)).unwrap().replace_verify_key);
+        assert_err!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, interaction_cipher(&message)), Error::<Test>::PollVotingHasEnded);
+        assert_err!(Infimum::commit_outcome(RuntimeOrigin::signed(0), 0, Default::default(), None), Error::<Test>::PollStateNotMerged);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let actions = Infimum::allowed_actions(0, Some(0)).unwrap();
+        assert!(!actions.merge && actions.commit_outcome);
+        assert!(!Infimum::allowed_actions(0, None).unwrap().commit_outcome);
+        assert_err!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)), Error::<Test>::PollDataEmpty);
+    })
+}
+
+/// The allowed actions of pending polls and polls which collect intents should reflect their extrinsics.
+#[test]
+fn allowed_actions_pending_and_intents()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, pk, vk);
+        let mut config = poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options);
+        config.pending = true;
+        config.collect_intents = true;
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), config));
+
+        // Only the coordinator may activate the pending poll, which accepts neither registrations nor intents.
+        let actions = Infimum::allowed_actions(0, Some(0)).unwrap();
+        assert!(actions.activate && !actions.register && !actions.signal_intent && !actions.reap);
+        assert!(!Infimum::allowed_actions(0, Some(1)).unwrap().activate);
+        assert_err!(Infimum::activate_poll(RuntimeOrigin::signed(1), 0), Error::<Test>::NotPollCoordinator);
+        assert_err!(Infimum::signal_intent(RuntimeOrigin::signed(1), 0), Error::<Test>::PollPending);
+
+        // The poll may be reaped from the block at which its activation timeout elapses.
+        let timeout = <Test as crate::Config>::ActivationTimeout::get();
+        run_to_block(timeout);
+        assert!(!Infimum::allowed_actions(0, None).unwrap().reap);
+        assert_err!(Infimum::reap_poll(RuntimeOrigin::signed(1), 0), Error::<Test>::PollActivationNotExpired);
+
+        run_to_block(1 + timeout);
+        assert!(Infimum::allowed_actions(0, None).unwrap().reap);
+
+        // Once activated, intents may be signalled once per account.
+        assert_ok!(Infimum::activate_poll(RuntimeOrigin::signed(0), 0));
+        let actions = Infimum::allowed_actions(0, Some(1)).unwrap();
+        assert!(actions.signal_intent && actions.register && !actions.activate && !actions.reap);
+        assert!(!Infimum::allowed_actions(0, None).unwrap().signal_intent);
+
+        assert_ok!(Infimum::signal_intent(RuntimeOrigin::signed(1), 0));
+        assert!(!Infimum::allowed_actions(0, Some(1)).unwrap().signal_intent);
+        assert!(Infimum::allowed_actions(0, Some(2)).unwrap().signal_intent);
+        assert_err!(Infimum::signal_intent(RuntimeOrigin::signed(1), 0), Error::<Test>::IntentAlreadySignaled);
+    })
+}
+
+/// The end of the registration period should be recorded upon poll creation, and determine whether registrations
+/// are accepted.
+#[test]
+fn poll_creation_registration_period_end()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, pk, vk);
+        assert_eq!(Infimum::poll_registration_period_end(0), None);
+
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
+
+        let end = 1 + signup_period;
+        assert_eq!(Infimum::poll_registration_period_end(0), Some(end));
+        assert_eq!(Infimum::polls(0).unwrap().get_registration_period_end(), end);
+
+        run_to_block(end - 1);
+        assert!(Infimum::polls(0).unwrap().is_registration_period());
+
+        run_to_block(end);
+        assert!(!Infimum::polls(0).unwrap().is_registration_period());
+    })
+}
+
+/// The public key of the coordinator should be snapshotted upon poll creation, and be unaffected by later changes.
+#[test]
+fn coordinator_public_key_at_poll_creation()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert_eq!(Infimum::coordinator_public_key_at_poll_creation(0), None);
+
+        register_coordinator(0, pk, vk.clone());
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
+
+        let expected = [pk.x, pk.y].concat();
+        assert_eq!(Infimum::coordinator_public_key_at_poll_creation(0), Some(expected.clone()));
+
+        // The live key of the coordinator changes once the poll is over, while the snapshot does not.
+        run_to_block(2 + signup_period + voting_period);
+        let (new_pk, signature) = signed_coordinator_key(0);
+        assert_ok!(Infimum::rotate_keys(RuntimeOrigin::signed(0), new_pk, vk, signature));
+        assert_eq!(Infimum::coordinators(0).unwrap().public_key, new_pk);
+        assert_eq!(Infimum::coordinator_public_key_at_poll_creation(0), Some(expected));
+    })
+}
+
+/// The poll configuration should be exposed in a human readable form for RPC clients.
+#[test]
+fn poll_config_serializable()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert_eq!(Infimum::poll_config(0, 6000), None);
+
+        register_coordinator(0, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
+
+        let config = Infimum::poll_config(0, 6000).unwrap();
+        assert_eq!(config.signup_period_secs, 72);
+        assert_eq!(config.voting_period_secs, 72);
+        assert_eq!(config.signup_period_blocks, signup_period);
+        assert_eq!(config.registration_tree_arity, 2);
+        assert_eq!(config.interaction_tree_arity, 5);
+        assert_eq!(config.max_registrations, 1024);
+        assert_eq!(config.max_interactions, 25);
+        assert_eq!(config.vote_options.len(), 25);
+        assert_eq!(config.vote_options[24], "24");
+        assert_eq!(config.message_words, 10);
+
+        let json = serde_json::to_value(&config).unwrap();
+        assert_eq!(json["signup_period_secs"], 72);
+        assert_eq!(json["vote_options"][1], "1");
+    })
+}
+
+/// The maximum encoded length of a poll should be bounded by the configured maxima.
+#[test]
+fn poll_max_encoded_len()
+{
+    MaxVoteOptions::set(4);
+
+    // Both trees retain at most `arity - 1` subtree roots per level, of which the quinary layout retains more:
+    // 4 * floor(log5(2^31)) = 52 registration roots, and 4 * floor(log5(1024)) = 16 interaction roots.
+    let tree = |roots: usize| 3 + 4 + 5 + 33 * roots + 33;
+    let state = tree(52) + tree(16) + 80 + 5 + 1 + 1 + 1;
+    let config = 8 + 8 + 4 + 4 + 1 + 1 + 1 + (1 + 4 * 16) + 1 + 1 + 1;
+    let expected = 4 + 8 + 8 + state + config;
+
+    assert_eq!(<Poll<Test> as MaxEncodedLen>::max_encoded_len(), expected);
+    assert_eq!(expected, 2537);
+
+    // A full poll in the mock runtime encodes within the bound.
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        register_coordinator(0, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(12, 12, 10, 2, 1, 1, 1, vec![ 0, 1, 2, 3 ])));
+
+        let (pk, _, _) = get_participant();
+        for who in 1..=5 { assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(who), 0, pk)); }
+
+        assert!(Infimum::polls(0).unwrap().encode().len() <= expected);
+    })
+}
+
+/// The mock runtime configuration should be consistent.
+#[test]
+fn integrity_test_consistent_config()
+{
+    Infimum::integrity_test();
+}
+
+/// A runtime which does not permit at least two vote options should fail the integrity test.
+#[test]
+#[should_panic(expected = "`MaxVoteOptions` must permit at least two vote options")]
+fn integrity_test_inconsistent_config()
+{
+    MaxVoteOptions::set(1);
+    Infimum::integrity_test();
+}
+
+/// Upgrading to version 7 should archive the excess finished polls of each coordinator, without archiving ongoing polls.
+#[test]
+fn poll_limit_reduction_upgrade() 
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+        let duration = signup_period + voting_period;
+
+        MaxCoordinatorPolls::set(3);
+        register_coordinator(0, pk, vk);
+        for round in 0..3
+        {
+            run_to_block(1 + round * (duration + 1));
+            if round > 0 { assert_ok!(Infimum::nullify_poll(RuntimeOrigin::signed(0))); }
+            assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone())));
+        }
+
+        // The migration only applies to storage version 6.
+        MaxCoordinatorPolls::set(0);
+        MigrateToV7::<Test>::on_runtime_upgrade();
+        assert_eq!(Infimum::poll_ids(0), vec::Vec::from([0, 1, 2]));
+
+        // The ongoing poll is retained, even though it exceeds the new limit.
+        StorageVersion::new(6).put::<Infimum>();
+        MigrateToV7::<Test>::on_runtime_upgrade();
+        assert_eq!(Infimum::on_chain_storage_version(), 7);
+        assert_eq!(Infimum::poll_history(0), vec::Vec::from([0, 1]));
+        assert_eq!(Infimum::poll_ids(0), vec::Vec::from([2]));
+        assert_ok!(Infimum::do_try_state());
+
+        MaxCoordinatorPolls::set(1);
+        assert_err!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone())), Error::<Test>::PollCurrentlyActive);
+
+        // Creation is possible once the ongoing poll concludes, archiving the poll id of the concluded poll.
+        run_to_block(4 + 3 * duration);
+        assert_ok!(Infimum::nullify_poll(RuntimeOrigin::signed(0)));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
+        assert_eq!(Infimum::poll_ids(0), vec::Vec::from([3]));
+        assert_eq!(Infimum::poll_history(0), vec::Vec::from([0, 1, 2]));
+        assert_ok!(Infimum::do_try_state());
+    })
+}
+
+/// A coordinator can only manage a single poll at a time.
+#[test]
+fn poll_creation_during_extant() 
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone())));
+        assert_err!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)), Error::<Test>::PollCurrentlyActive);
+    })
+}
+
+/// Users should be able to register as participants.
+#[test]
+fn register_as_participant()
+{
+    new_test_ext().execute_with(|| { 
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone())));
+        
+        let participant = get_participant();
+
+        assert_err!(Infimum::register_as_participant(RuntimeOrigin::none(), 0, participant.0), error::BadOrigin);
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, participant.0));
+        
+        assert_eq!(Infimum::polls(0).is_some(), true);
+        assert_eq!(Infimum::polls(0).unwrap().state.registrations.count, 1);
+
+        System::assert_has_event(Event::ParticipantRegistered { poll_id: 0, who: 1, count: 1, public_key: participant.0, block: 1 }.into());
+    })
+}
+
+/// Users can only register during the registration period.
+#[test]
+fn register_as_participant_outside_period()
+{
+    new_test_ext().execute_with(|| { 
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone())));
+        
+        let participant = get_participant();
+
+        run_to_block(1 + signup_period);
+        assert_err!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, participant.0), Error::<Test>::PollRegistrationHasEnded);
+    })
+}
+
+/// Polls created with `unique_keys` should index the keys of their registrations, and reject a key registered twice.
+#[test]
+fn register_as_participant_unique_keys()
+{
+    new_test_ext().execute_with(|| { 
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+        let participants = get_participants();
+        let (registered, unregistered) = (participants[0].1, participants[1].1);
+
+        // Poll 0 does not index the keys of its registrations.
+        register_coordinator(0, pk, vk.clone());
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone())));
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, registered));
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, registered));
+        assert!(!Infimum::is_key_registered(0, registered));
+
+        // Poll 1 does.
+        let mut config = poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options);
+        config.unique_keys = true;
+        register_coordinator(2, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(2), config));
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 1, registered));
+
+        assert!(Infimum::is_key_registered(1, registered));
+        assert!(!Infimum::is_key_registered(1, unregistered));
+
+        assert_err!(Infimum::register_as_participant(RuntimeOrigin::signed(3), 1, registered), Error::<Test>::KeyAlreadyRegistered);
+        assert_eq!(Infimum::polls(1).unwrap().state.registrations.count, 1);
+    })
+}
+
+/// Registrations relayed by a single account should each be recorded as a receipt of the relayer, which may be
+/// claimed once the poll is finalized.
+#[test]
+fn register_as_participant_relayed()
+{
+    new_test_ext().execute_with(|| { 
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
+
+        // Account 9 relays the first two registrations, and account 3 submits its own.
+        let participants = get_participants();
+        for (index, (origin, pk)) in participants.iter().enumerate()
+        {
+            let who = if index < 2 { 9 } else { *origin };
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(who), 0, *pk));
+            System::assert_has_event(Event::ParticipantRegistered { poll_id: 0, who, count: index as u32 + 1, public_key: *pk, block: 1 }.into());
+        }
+
+        assert_eq!(Infimum::registration_receipts(0, 9).into_inner(), vec::Vec::from([0, 1]));
+        assert_eq!(Infimum::registration_receipts(0, 3).into_inner(), vec::Vec::from([2]));
+        assert!(Infimum::registration_receipts(0, 1).is_empty());
+
+        // The receipts may not be claimed until the poll is finalized.
+        assert_eq!(Infimum::participation_receipt(0, 9), None);
+        assert!(Infimum::participation_receipts(0, 0, 10).is_empty());
+
+        run_to_block(1 + signup_period);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let scenario = get_poll_scenario(1);
+        for (pk, data) in &scenario.interactions
+        {
+            assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, *pk, interaction_cipher(data)));
+        }
+
+        run_to_block(2 + signup_period + voting_period);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), 0, scenario.proof_batches.try_into().unwrap(), scenario.outcome));
+        assert_eq!(Infimum::polls(0).unwrap().state.outcome, scenario.expected);
+
+        // The receipts are retained once the poll is fulfilled.
+        assert_eq!(Infimum::participation_receipt(0, 9), Some(vec::Vec::from([0, 1])));
+        assert_eq!(Infimum::participation_receipt(0, 3), Some(vec::Vec::from([2])));
+        assert_eq!(Infimum::participation_receipt(0, 1), None);
+
+        let mut receipts = Infimum::participation_receipts(0, 0, 10);
+        receipts.sort();
+        assert_eq!(receipts, vec::Vec::from([(3, vec::Vec::from([2])), (9, vec::Vec::from([0, 1]))]));
+
+        // Each page holds the given number of accounts.
+        assert_eq!(Infimum::participation_receipts(0, 0, 1).len(), 1);
+        assert_eq!(Infimum::participation_receipts(0, 1, 1).len(), 1);
+        assert!(Infimum::participation_receipts(0, 2, 1).is_empty());
+    })
+}
+
+/// An account should only be able to submit `MaxRegistrationsPerAccount` registrations to a poll, and the receipts
+/// of a nullified poll should be pruned.
+#[test]
+fn registration_receipt_limit()
+{
+    new_test_ext().execute_with(|| { 
+        System::set_block_number(1);
+        MaxRegistrationsPerAccount::set(2);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
+
+        let participants = get_participants();
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(9), 0, participants[0].1));
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(9), 0, participants[1].1));
+        assert_err!(Infimum::register_as_participant(RuntimeOrigin::signed(9), 0, participants[2].1), Error::<Test>::TooManyRegistrations);
+
+        // The rejected registration is not counted, and other accounts are unaffected.
+        assert_eq!(Infimum::polls(0).unwrap().state.registrations.count, 2);
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(3), 0, participants[2].1));
+        assert_eq!(Infimum::registration_receipts(0, 9).into_inner(), vec::Vec::from([0, 1]));
+        assert_eq!(Infimum::registration_receipts(0, 3).into_inner(), vec::Vec::from([2]));
+
+        // A poll which ended without interactions pays no rewards.
+        run_to_block(2 + signup_period + voting_period);
+        assert_ok!(Infimum::nullify_poll(RuntimeOrigin::signed(0)));
+        assert_eq!(Infimum::poll_prune_cursor(0).map(|progress| progress.stage), Some(PruneStage::RegistrationReceipts));
+
+        // The receipts are pruned in `on_idle`, at most `MaxPruneKeys` keys per block.
+        MaxPruneKeys::set(1);
+        run_to_block(System::block_number() + 1);
+        assert_eq!(RegistrationReceipts::<Test>::iter_prefix(0).count(), 1);
+
+        run_to_block(System::block_number() + 1);
+        assert!(Infimum::registration_receipts(0, 9).is_empty());
+        assert!(Infimum::registration_receipts(0, 3).is_empty());
+        assert!(Infimum::participation_receipts(0, 0, 10).is_empty());
+    })
+}
+
+/// Only the allowable number of participants should be allowed to register.
+#[test]
+fn participant_limit_reached()
+{
+    new_test_ext().execute_with(|| { 
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, _registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, 2, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone())));
+        
+        let participant = get_participant();
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, participant.0));
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(2), 0, participant.0));
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(3), 0, participant.0));
+        assert_err!(Infimum::register_as_participant(RuntimeOrigin::signed(4), 0, participant.0), Error::<Test>::ParticipantRegistrationLimitReached);
+    })
+}
+
+/// Registrations which fill the registration tree should all succeed, and the full tree should still be merged
+/// into the initial commitment of the poll.
+#[test]
+fn registration_limit_fills_tree()
+{
+    new_test_ext().execute_with(|| { 
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, _registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, 2, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone())));
+        assert_eq!(Infimum::polls(0).unwrap().config.max_registrations, 4);
+
+        for (origin, participant) in &get_participants()
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *participant));
+        }
+
+        // Alongside the blank state leaf, the registrations fill the tree, whose root is only set once merged.
+        let poll = Infimum::polls(0).unwrap();
+        assert!(poll.registration_limit_reached());
+        assert_eq!(poll.state.registrations.count, 3);
+        assert_eq!(poll.state.registrations.root, None);
+        let frontier = Infimum::poll_registration_frontier(0).unwrap();
+        assert_eq!(frontier.len(), 1);
+        assert_eq!(frontier[0].0, 2);
+
+        run_to_block(1 + signup_period);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+        System::assert_last_event(Event::PollStateMerged { poll_id: 0, registration_root: Some(frontier[0].1), interaction_root: None }.into());
+
+        let poll = Infimum::polls(0).unwrap();
+        assert_eq!(poll.state.registrations.root, Some(frontier[0].1));
+        assert_ne!(poll.state.commitment.process.current, [0u8; 32]);
+        assert_eq!(poll.state.interactions.root, None);
+    })
+}
+
+/// A registration which the state tree rejects should surface the corresponding pallet error, e.g. once the stored
+/// frontier of the tree describes a full tree, even though the registration limit of the poll was not reached.
+#[test]
+fn registration_full_tree_error()
+{
+    new_test_ext().execute_with(|| { 
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, _registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, 2, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
+
+        let participants = get_participants();
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(participants[0].0), 0, participants[0].1));
+
+        PollRegistrationFrontier::<Test>::insert(0, vec::Vec::from([ (2, [1u8; 32]) ]));
+        assert!(!Infimum::polls(0).unwrap().registration_limit_reached());
+        assert_err!(
+            Infimum::register_as_participant(RuntimeOrigin::signed(participants[1].0), 0, participants[1].1),
+            Error::<Test>::StateTreeFull
+        );
+        assert_eq!(Infimum::polls(0).unwrap().state.registrations.count, 1);
+    })
+}
+
+/// A poll with `max_registrations = 4` should admit three participants, as the first of its four leaves is the
+/// blank state leaf which the circuits reserve.
+#[test]
+fn registration_limit_four_leaves()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, _registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, 2, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
+        assert_eq!(Infimum::polls(0).unwrap().config.max_registrations, 4);
+
+        let participant = get_participant();
+        for origin in 1..=2
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(origin), 0, participant.0));
+            assert!(!Infimum::polls(0).unwrap().registration_limit_reached());
+        }
+
+        // The third registration occupies the final leaf, such that the fourth is rejected.
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(3), 0, participant.0));
+        assert_eq!(Infimum::polls(0).unwrap().state.registrations.count, 3);
+        assert!(Infimum::polls(0).unwrap().registration_limit_reached());
+
+        assert_err!(Infimum::register_as_participant(RuntimeOrigin::signed(4), 0, participant.0), Error::<Test>::ParticipantRegistrationLimitReached);
+        assert_eq!(Infimum::polls(0).unwrap().state.registrations.count, 3);
+    })
+}
+
+/// The registration tree reserves its first leaf for the zero leaf, such that a poll with `max_registrations`
+/// leaves admits exactly `max_registrations - 1` participants.
+#[test]
+fn batch_registration_respects_limit()
+{
+    new_test_ext().execute_with(|| { 
+        System::set_block_number(1);
+        RegistrationTreeArity::set(5);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, _registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, 1, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone())));
+        assert_eq!(Infimum::polls(0).unwrap().config.max_registrations, 5);
+
+        let participant = get_participant();
+        for origin in 1..=3
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(origin), 0, participant.0));
+            assert!(!Infimum::polls(0).unwrap().registration_limit_reached());
+        }
+
+        // The final registration fills the tree alongside the zero leaf.
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(4), 0, participant.0));
+        assert_eq!(Infimum::polls(0).unwrap().state.registrations.count, 4);
+        assert!(Infimum::polls(0).unwrap().registration_limit_reached());
+
+        assert_err!(Infimum::register_as_participant(RuntimeOrigin::signed(5), 0, participant.0), Error::<Test>::ParticipantRegistrationLimitReached);
+    })
+}
+
+/// Intents to vote should only be counted in polls which collect them, once per account and during the signup period.
+#[test]
+fn signal_intent()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        assert_err!(Infimum::signal_intent(RuntimeOrigin::signed(1), 0), Error::<Test>::PollDoesNotExist);
+
+        // Poll 0 does not collect intents.
+        register_coordinator(0, pk, vk.clone());
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone())));
+        assert_eq!(Infimum::poll_intents(0), None);
+        assert_err!(Infimum::signal_intent(RuntimeOrigin::signed(1), 0), Error::<Test>::PollIntentsNotCollected);
+
+        // Poll 1 does.
+        let mut config = poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options);
+        config.collect_intents = true;
+        register_coordinator(2, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(2), config));
+        assert_eq!(Infimum::poll_intents(1), Some(0));
+
+        assert_err!(Infimum::signal_intent(RuntimeOrigin::none(), 1), error::BadOrigin);
+        assert_ok!(Infimum::signal_intent(RuntimeOrigin::signed(1), 1));
+        System::assert_has_event(Event::IntentSignaled { poll_id: 1, who: 1, count: 1 }.into());
+        assert_err!(Infimum::signal_intent(RuntimeOrigin::signed(1), 1), Error::<Test>::IntentAlreadySignaled);
+        assert_ok!(Infimum::signal_intent(RuntimeOrigin::signed(3), 1));
+        assert_eq!(Infimum::poll_intents(1), Some(2));
+
+        // Intents have no bearing on the poll state.
+        assert_eq!(Infimum::polls(1).unwrap().state.registrations.count, 0);
+
+        run_to_block(1 + signup_period);
+        assert_err!(Infimum::signal_intent(RuntimeOrigin::signed(0), 1), Error::<Test>::PollRegistrationHasEnded);
+        assert_eq!(Infimum::poll_intents(1), Some(2));
+        assert_eq!(Infimum::poll_intents(0), None);
+    })
+}
+
+/// Users can only register in existing polls.
+#[test]
+fn participant_registration_no_poll()
+{
+    new_test_ext().execute_with(|| { 
+        let participant = get_participant();
+        assert_err!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, participant.0), Error::<Test>::PollDoesNotExist);
+    })
+}
+
+/// Participants should be able to interact with polls they are registered in.
+#[test]
+fn participant_interaction()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
+
+        let (pk, shared_pk, message) = get_participant();
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, pk));
+        
+        run_to_block(1 + signup_period);
+        assert_err!(Infimum::interact_with_poll(RuntimeOrigin::none(), 0, shared_pk, interaction_cipher(&message)), error::BadOrigin);
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, interaction_cipher(&message)));
+
+        assert_eq!(Infimum::polls(0).is_some(), true);
+        assert_eq!(Infimum::polls(0).unwrap().state.interactions.count, 1);
+
+        let leaf = Infimum::poll_interaction_frontier(0).unwrap()[0].1;
+        System::assert_has_event(Event::PollInteraction { poll_id: 0, count: 1, public_key: shared_pk, leaf, data: interaction_data(&message) }.into());
+    })
+}
+
+/// Relayers should be able to submit batches of interactions, which are rejected in full if any would not fit.
+#[test]
+fn participant_interaction_batch()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, _, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        // The interaction tree has room for five interactions.
+        register_coordinator(0, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, 1, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
+
+        let (pk, shared_pk, message) = get_participant();
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, pk));
+        let batch = |size: usize| -> BoundedVec<(PublicKey, PollInteractionCipher), <Test as crate::Config>::MaxInteractionsPerCall> {
+            vec![(shared_pk, interaction_cipher(&message)); size].try_into().unwrap()
+        };
+
+        run_to_block(1 + signup_period);
+        assert_err!(Infimum::interact_with_poll_batch(RuntimeOrigin::none(), 0, batch(1)), error::BadOrigin);
+        assert_ok!(Infimum::interact_with_poll_batch(RuntimeOrigin::signed(3), 0, batch(4)));
+        System::assert_has_event(Event::InteractionsBatchSubmitted { poll_id: 0, first_index: 0, count: 4 }.into());
+        assert_eq!(Infimum::polls(0).unwrap().state.interactions.count, 4);
+
+        // A batch with a malformed interaction is rejected in full.
+        let mut malformed = batch(1);
+        malformed.try_push((shared_pk, interaction_cipher(&message[..7]))).unwrap();
+        assert_err!(Infimum::interact_with_poll_batch(RuntimeOrigin::signed(3), 0, malformed), Error::<Test>::MessageLengthMismatch);
+        assert_eq!(Infimum::polls(0).unwrap().state.interactions.count, 4);
+
+        // A batch which would overflow the interaction tree is rejected in full.
+        assert_err!(Infimum::interact_with_poll_batch(RuntimeOrigin::signed(3), 0, batch(2)), Error::<Test>::ParticipantInteractionLimitReached);
+        assert_eq!(Infimum::polls(0).unwrap().state.interactions.count, 4);
+
+        assert_ok!(Infimum::interact_with_poll_batch(RuntimeOrigin::signed(3), 0, batch(1)));
+        System::assert_has_event(Event::InteractionsBatchSubmitted { poll_id: 0, first_index: 4, count: 1 }.into());
+        assert_eq!(Infimum::polls(0).unwrap().state.interactions.count, 5);
+    })
+}
+
+/// The turnout of a poll should be emitted once its voting period ends.
+#[test]
+fn poll_closed_turnout()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+        let duration = signup_period + voting_period;
+
+        register_coordinator(0, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
+        assert_eq!(Infimum::poll_closures(2 + duration), vec::Vec::from([0]));
+
+        let (pk, shared_pk, message) = get_participant();
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, pk));
+
+        run_to_block(1 + signup_period);
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, interaction_cipher(&message)));
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(2), 0, shared_pk, interaction_cipher(&message)));
+
+        let closed = || System::events().iter().any(|record| matches!(record.event, RuntimeEvent::Infimum(Event::PollClosed { .. })));
+
+        run_to_block(1 + duration);
+        assert!(!closed());
+
+        run_to_block(2 + duration);
+        System::assert_has_event(Event::PollClosed { poll_id: 0, registrations: 1, interactions: 2 }.into());
+        assert_eq!(Infimum::poll_closures(2 + duration), vec::Vec::<u32>::new());
+    })
+}
+
+/// Polls should not be created or activated such that more than `MaxPollClosuresPerBlock` polls close at a block.
+#[test]
+fn poll_closures_bounded()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        MaxPollClosuresPerBlock::set(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+        let duration = signup_period + voting_period;
+        let config = poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options);
+
+        for coordinator in 0..3 { register_coordinator(coordinator, pk, vk.clone()); }
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), config.clone()));
+        assert_eq!(Infimum::poll_closures(2 + duration), vec::Vec::from([0]));
+
+        // The block at which the poll would close is full.
+        assert_err!(Infimum::create_poll(RuntimeOrigin::signed(1), config.clone()), Error::<Test>::PollClosuresFull);
+
+        let mut longer = config.clone();
+        longer.voting_period += 1;
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(1), longer));
+        assert_eq!(Infimum::poll_closures(3 + duration), vec::Vec::from([1]));
+
+        // Nor may a pending poll be activated such that it closes at a full block.
+        let mut pending = config;
+        pending.pending = true;
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(2), pending));
+        assert_err!(Infimum::activate_poll(RuntimeOrigin::signed(2), 2), Error::<Test>::PollClosuresFull);
+        assert!(Infimum::poll_phase(2).unwrap().is_pending());
+
+        run_to_block(3);
+        assert_ok!(Infimum::activate_poll(RuntimeOrigin::signed(2), 2));
+        assert_eq!(Infimum::poll_closures(4 + duration), vec::Vec::from([2]));
+    })
+}
+
+/// Polls should not be created or activated such that more than `MaxScheduledPollsPerBlock` polls commence their
+/// voting period, or time out pending activation, at a block.
+#[test]
+fn poll_schedules_bounded()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        MaxScheduledPollsPerBlock::set(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+        let config = poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options);
+
+        for coordinator in 0..4 { register_coordinator(coordinator, pk, vk.clone()); }
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), config.clone()));
+        assert_eq!(Infimum::poll_voting_starts(1 + signup_period), vec::Vec::from([0]));
+
+        // The poll would close at another block, but its voting period would commence at a full block.
+        let mut longer = config.clone();
+        longer.voting_period += 1;
+        assert_err!(Infimum::create_poll(RuntimeOrigin::signed(1), longer), Error::<Test>::PollScheduleFull);
+        assert_eq!(Infimum::poll_closures(2 + signup_period + voting_period + 1), vec::Vec::<u32>::new());
+
+        let mut later = config.clone();
+        later.signup_period += 1;
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(1), later));
+        assert_eq!(Infimum::poll_voting_starts(2 + signup_period), vec::Vec::from([1]));
+
+        // Nor may two pending polls time out at the same block.
+        let mut pending = config;
+        pending.pending = true;
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(2), pending.clone()));
+        assert_err!(Infimum::create_poll(RuntimeOrigin::signed(3), pending), Error::<Test>::PollScheduleFull);
+
+        // Nor may a pending poll be activated such that its voting period commences at a full block.
+        run_to_block(2);
+        assert_err!(Infimum::activate_poll(RuntimeOrigin::signed(2), 2), Error::<Test>::PollScheduleFull);
+        assert!(Infimum::poll_phase(2).unwrap().is_pending());
+
+        run_to_block(3);
+        assert_ok!(Infimum::activate_poll(RuntimeOrigin::signed(2), 2));
+        assert_eq!(Infimum::poll_voting_starts(3 + signup_period), vec::Vec::from([2]));
+    })
+}
+
+/// The start of the voting period of a poll should be announced once, on its first block, or upon creation if the
+/// poll has no signup period.
+#[test]
+fn poll_voting_period_started()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+        let starts_at = 1 + signup_period;
+
+        register_coordinator(0, pk, vk.clone());
+        register_coordinator(1, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone())));
+        assert_eq!(Infimum::poll_voting_starts(starts_at), vec::Vec::from([0]));
+
+        let started = |poll_id: u32| System::events().iter().filter(|record| matches!(
+            record.event,
+            RuntimeEvent::Infimum(Event::PollVotingPeriodStarted { poll_id: id, .. }) if id == poll_id
+        )).count();
+
+        // A poll without a signup period accepts interactions upon creation.
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(1), poll_config(0, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
+        System::assert_has_event(Event::PollVotingPeriodStarted { poll_id: 1, voting_period_end: 1 + voting_period }.into());
+        assert_eq!(Infimum::poll_voting_starts(1), vec::Vec::<u32>::new());
+
+        run_to_block(starts_at - 1);
+        assert_eq!(started(0), 0);
+
+        run_to_block(starts_at);
+        System::assert_has_event(Event::PollVotingPeriodStarted { poll_id: 0, voting_period_end: starts_at + voting_period }.into());
+        assert_eq!(Infimum::poll_voting_starts(starts_at), vec::Vec::<u32>::new());
+        assert_eq!(Infimum::poll_voting_started_emitted(0), Some(()));
+
+        // The announcement coincides with the first block at which interactions are accepted.
+        let (_, shared_pk, message) = get_participant();
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(2), 0, shared_pk, interaction_cipher(&message)));
+
+        run_to_block(starts_at + 1);
+        assert_eq!(started(0), 1);
+        assert_eq!(started(1), 1);
+    })
+}
+
+/// Pending polls should reject participants until activated, after which their periods anchor on the activation block.
+#[test]
+fn poll_activation()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+        let duration = signup_period + voting_period;
+
+        register_coordinator(0, pk, vk);
+        let mut config = poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options);
+        config.pending = true;
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), config));
+        assert_eq!(Infimum::poll_closures(2 + duration), vec::Vec::<u32>::new());
+        assert_eq!(Infimum::poll_registration_period_end(0), Some(1 + signup_period));
+        assert_eq!(Infimum::poll_voting_period_end(0), Some(1 + duration));
+
+        // Participants are rejected while the poll is pending.
+        let (pk, shared_pk, message) = get_participant();
+        assert_err!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, pk), Error::<Test>::PollPending);
+        assert_err!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, interaction_cipher(&message)), Error::<Test>::PollPending);
+
+        // Only the coordinator may activate the poll, and only once.
+        let activated_at = 5;
+        run_to_block(activated_at);
+        assert_err!(Infimum::activate_poll(RuntimeOrigin::signed(1), 0), Error::<Test>::NotPollCoordinator);
+        assert_ok!(Infimum::activate_poll(RuntimeOrigin::signed(0), 0));
+        assert_err!(Infimum::activate_poll(RuntimeOrigin::signed(0), 0), Error::<Test>::PollNotPending);
+        assert_err!(Infimum::reap_poll(RuntimeOrigin::signed(1), 0), Error::<Test>::PollNotPending);
+
+        let starts_at = activated_at + signup_period;
+        let ends_at = starts_at + voting_period + 1;
+        System::assert_has_event(Event::PollActivated { poll_id: 0, starts_at, ends_at }.into());
+        assert_eq!(Infimum::polls(0).unwrap().created_at, activated_at);
+        assert_eq!(Infimum::poll_closures(ends_at), vec::Vec::from([0]));
+        assert_eq!(Infimum::poll_registration_period_end(0), Some(starts_at));
+        assert_eq!(Infimum::poll_voting_period_end(0), Some(ends_at - 1));
+
+        // The signup period extends past the block at which it would have ended if anchored on creation.
+        run_to_block(1 + signup_period);
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, pk));
+        assert_err!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, interaction_cipher(&message)), Error::<Test>::PollRegistrationInProgress);
+
+        run_to_block(starts_at);
+        System::assert_has_event(Event::PollVotingPeriodStarted { poll_id: 0, voting_period_end: ends_at - 1 }.into());
+        assert_err!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, pk), Error::<Test>::PollRegistrationHasEnded);
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, interaction_cipher(&message)));
+
+        run_to_block(ends_at);
+        System::assert_has_event(Event::PollClosed { poll_id: 0, registrations: 1, interactions: 1 }.into());
+        assert_err!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, interaction_cipher(&message)), Error::<Test>::PollVotingHasEnded);
+    })
+}
+
+/// A poll should only be written back if the closure mutating it succeeds.
+#[test]
+fn with_poll_mut_error_leaves_storage()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
+
+        let poll = Infimum::polls(0).unwrap();
+        let phase = PollPhases::<Test>::get(0);
+
+        // The mutations of a failing closure are discarded.
+        assert_err!(
+            Infimum::with_poll_mut(0, |poll| {
+                poll.created_at = 99;
+                poll.state.pending = true;
+                Err::<(), _>(Error::<Test>::PollNotPending.into())
+            }),
+            Error::<Test>::PollNotPending
+        );
+        assert_eq!(Infimum::polls(0), Some(poll));
+        assert_eq!(PollPhases::<Test>::get(0), phase);
+
+        // The mutations of a successful closure are stored alongside the phase cache of the poll.
+        assert_eq!(Infimum::with_poll_mut(0, |poll| { poll.created_at = 5; Ok(poll.created_at) }), Ok(5));
+        assert_eq!(Infimum::polls(0).unwrap().created_at, 5);
+        assert_ne!(PollPhases::<Test>::get(0), phase);
+
+        assert_err!(Infimum::with_poll_mut(1, |_| Ok(())), Error::<Test>::PollDoesNotExist);
+    })
+}
+
+/// Pending polls which are not activated within the activation timeout should be reapable by anyone.
+#[test]
+fn poll_activation_timeout()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, pk, vk);
+        let mut config = poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone());
+        config.pending = true;
+        config.fee_allowance = 25;
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), config));
+        assert_eq!(Balances::reserved_balance(0), 25);
+
+        // A pending poll prevents its coordinator from creating another poll.
+        assert_err!(
+            Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone())),
+            Error::<Test>::PollCurrentlyActive
+        );
+
+        // The poll may not be reaped before the timeout has elapsed.
+        let timeout = <Test as crate::Config>::ActivationTimeout::get();
+        run_to_block(timeout);
+        assert_err!(Infimum::reap_poll(RuntimeOrigin::signed(3), 0), Error::<Test>::PollActivationNotExpired);
+
+        run_to_block(1 + timeout);
+        assert_err!(Infimum::reap_poll(RuntimeOrigin::none(), 0), error::BadOrigin);
+        assert_ok!(Infimum::reap_poll(RuntimeOrigin::signed(3), 0));
+        System::assert_has_event(Event::PollReaped { poll_id: 0 }.into());
+
+        // The reaped poll is dead, and its allowance was returned to the coordinator.
+        assert!(Infimum::polls(0).unwrap().is_nullified());
+        assert_eq!(Balances::reserved_balance(0), 0);
+        assert_err!(Infimum::reap_poll(RuntimeOrigin::signed(3), 0), Error::<Test>::PollNotPending);
+        assert_err!(Infimum::activate_poll(RuntimeOrigin::signed(0), 0), Error::<Test>::PollNotPending);
+
+        let (pk, _, _) = get_participant();
+        assert_err!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, pk), Error::<Test>::PollRegistrationHasEnded);
+
+        // The coordinator is free to create another poll.
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
+        assert_ok!(Infimum::do_try_state());
+    })
+}
+
+/// Interaction data should be written to storage when it is not emitted in full.
+#[test]
+fn participant_interaction_stored()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        EmitFullInteractionData::set(false);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
+
+        let (pk, shared_pk, message) = get_participant();
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, pk));
+
+        run_to_block(1 + signup_period);
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, interaction_cipher(&message)));
+
+        assert_eq!(Infimum::messages(0, 0), Some((shared_pk, interaction_data(&message))));
+        assert_eq!(Infimum::messages(0, 1), None);
+
+        let leaf = Infimum::poll_interaction_frontier(0).unwrap()[0].1;
+        let hash = sp_io::hashing::blake2_256(&(shared_pk, interaction_data(&message)).encode());
+        System::assert_has_event(Event::PollInteractionStored { poll_id: 0, index: 0, public_key: shared_pk, leaf, hash }.into());
+        assert!(!System::events().iter().any(|record| matches!(record.event, RuntimeEvent::Infimum(Event::PollInteraction { .. }))));
+    })
+}
+
+/// Interaction data should not be written to storage when it is emitted in full.
+#[test]
+fn participant_interaction_not_stored()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
+
+        let (pk, shared_pk, message) = get_participant();
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, pk));
+
+        run_to_block(1 + signup_period);
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, interaction_cipher(&message)));
+
+        assert_eq!(Infimum::messages(0, 0), None);
+
+        let leaf = Infimum::poll_interaction_frontier(0).unwrap()[0].1;
+        System::assert_has_event(Event::PollInteraction { poll_id: 0, count: 1, public_key: shared_pk, leaf, data: interaction_data(&message) }.into());
+    })
+}
+
+/// Only the interaction leaves of a compact poll should be stored, its data being emitted in full regardless.
+#[test]
+fn participant_interaction_compact()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        EmitFullInteractionData::set(false);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, pk, vk);
+        let mut config = poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options);
+        config.compact_interactions = true;
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), config));
+
+        for (origin, pk) in &get_participants()
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
+        }
+
+        run_to_block(1 + signup_period);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let scenario = get_poll_scenario(1);
+        let (shared_pk, message) = scenario.interactions[0];
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, interaction_cipher(&message)));
+
+        // The leaf is stored in place of the interaction data, which is emitted instead.
+        let leaf = Infimum::poll_interaction_frontier(0).unwrap()[0].1;
+        assert_eq!(Infimum::interaction_leaf(0, 0), Some(leaf));
+        assert_eq!(Infimum::interaction_leaf(0, 1), None);
+        assert_eq!(Infimum::messages(0, 0), None);
+        System::assert_has_event(Event::PollInteraction { poll_id: 0, count: 1, public_key: shared_pk, leaf, data: interaction_data(&message) }.into());
+        assert!(!System::events().iter().any(|record| matches!(record.event, RuntimeEvent::Infimum(Event::PollInteractionStored { .. }))));
+
+        for (pk, data) in scenario.interactions.iter().skip(1)
+        {
+            assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, *pk, interaction_cipher(data)));
+        }
+        assert_eq!(InteractionLeaves::<Test>::iter_prefix(0).count(), scenario.interactions.len());
+        assert_eq!(Messages::<Test>::iter_prefix(0).count(), 0);
+
+        // The proofs are unaffected by the mode of storage, and the leaves are pruned with the poll.
+        run_to_block(2 + signup_period + voting_period);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), 0, scenario.proof_batches.try_into().unwrap(), scenario.outcome));
+
+        assert_eq!(Infimum::polls(0).unwrap().state.outcome, scenario.expected);
+        run_to_block(System::block_number() + 1);
+        assert_eq!(InteractionLeaves::<Test>::iter_prefix(0).count(), 0);
+        assert_ok!(Infimum::do_try_state());
+    })
+}
+
+/// Interactions should be weighed at the deepest permitted interaction tree, and refunded down to the depth of the poll.
+#[test]
+fn participant_interaction_weight()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
+
+        // The deepest quinary tree with at most `MaxPollInteractions` leaves.
+        assert_eq!(Infimum::max_interaction_depth(), 4);
+
+        let (pk, shared_pk, message) = get_participant();
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, pk));
+
+        // Each level of the interaction tree adds the cost of a hash.
+        let weight_at = |depth: u32| <() as WeightInfo>::interact_with_poll(depth);
+        assert!(weight_at(0).ref_time() > 0);
+        for depth in 0..4 { assert!(weight_at(depth + 1).ref_time() > weight_at(depth).ref_time()); }
+
+        // The call is weighed at the deepest tree, and the levels the poll lacks are refunded.
+        let call = RuntimeCall::Infimum(crate::Call::interact_with_poll { poll_id: 0, public_key: shared_pk, cipher: interaction_cipher(&message) });
+        let declared_weight = call.get_dispatch_info().weight;
+        assert_eq!(declared_weight, Infimum::interact_with_poll_weight(4));
+
+        run_to_block(1 + signup_period);
+        let post_info = call.dispatch(RuntimeOrigin::signed(1)).unwrap();
+        let actual_weight = post_info.actual_weight.unwrap();
+        assert_eq!(actual_weight, Infimum::interact_with_poll_weight(interaction_depth.into()));
+        assert!(actual_weight.ref_time() < declared_weight.ref_time());
+    })
+}
+
+/// Participant fees should be drawn from the fee allowance of the poll, and the remainder returned to the coordinator.
+#[test]
+fn participant_fee_allowance()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+        let mut config = poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options);
+        config.fee_allowance = 25;
+
+        register_coordinator(0, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), config));
+        assert_eq!(Balances::reserved_balance(0), 25);
+        assert_eq!(Balances::reserved_balance_named(&FeeAllowanceReserveId::get(), &0), 25);
+        assert_eq!(Infimum::fee_allowances(0), Some(25));
+
+        let (pk, shared_pk, message) = get_participant();
+        let post_info = Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, pk).unwrap();
+        assert_eq!(post_info.pays_fee, Pays::No);
+        assert_eq!(Balances::free_balance(1), 100);
+        assert_eq!(Infimum::fee_allowances(0), Some(15));
+
+        run_to_block(1 + signup_period);
+        let post_info = Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, interaction_cipher(&message)).unwrap();
+        assert_eq!(post_info.pays_fee, Pays::No);
+        assert_eq!(Balances::free_balance(1), 100);
+        assert_eq!(Infimum::fee_allowances(0), Some(5));
+        assert_eq!(Balances::reserved_balance(0), 5);
+        assert_eq!(Balances::total_balance(&0), 980);
+
+        // The allowance no longer covers a call.
+        assert_err!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, interaction_cipher(&message)), Error::<Test>::FeeAllowanceExhausted);
+        assert_eq!(Infimum::polls(0).unwrap().state.interactions.count, 1);
+    })
+}
+
+/// The fee drawn from the fee allowance should cover the actual weight of each call, and an account should pay its
+/// own fees beyond `MaxSponsoredCalls`.
+#[test]
+fn participant_fee_allowance_actual_fee()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        FeePerMillisecond::set(1);
+        MaxSponsoredCalls::set(2);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+        let mut config = poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options);
+        config.fee_allowance = 500;
+
+        register_coordinator(0, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), config));
+
+        let (pk, shared_pk, message) = get_participant();
+        let register_fee = 10 + Infimum::register_as_participant_weight().ref_time() / WEIGHT_REF_TIME_PER_MILLIS;
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, pk));
+        assert_eq!(Infimum::fee_allowances(0), Some(500 - register_fee));
+
+        // The interaction is charged at the depth of the interaction tree of the poll, not the deepest tree.
+        run_to_block(1 + signup_period);
+        let interact_fee = 10 + Infimum::interact_with_poll_weight(interaction_depth.into()).ref_time() / WEIGHT_REF_TIME_PER_MILLIS;
+        let declared_fee = 10 + Infimum::interact_with_poll_weight(Infimum::max_interaction_depth()).ref_time() / WEIGHT_REF_TIME_PER_MILLIS;
+        assert!(interact_fee < declared_fee);
+
+        let post_info = Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, interaction_cipher(&message)).unwrap();
+        assert_eq!(post_info.pays_fee, Pays::No);
+        assert_eq!(Infimum::fee_allowances(0), Some(500 - register_fee - interact_fee));
+        assert_eq!(Infimum::sponsored_calls(0, 1), 2);
+
+        // The account exhausted its sponsored calls, so it pays for further calls itself.
+        let post_info = Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, interaction_cipher(&message)).unwrap();
+        assert_eq!(post_info.pays_fee, Pays::Yes);
+        assert_eq!(Infimum::fee_allowances(0), Some(500 - register_fee - interact_fee));
+        assert_eq!(Infimum::sponsored_calls(0, 1), 2);
+
+        // Other accounts are still sponsored.
+        let post_info = Infimum::interact_with_poll(RuntimeOrigin::signed(2), 0, shared_pk, interaction_cipher(&message)).unwrap();
+        assert_eq!(post_info.pays_fee, Pays::No);
+        assert_eq!(Infimum::sponsored_calls(0, 2), 1);
+        assert_eq!(Balances::reserved_balance_named(&FeeAllowanceReserveId::get(), &0), Infimum::fee_allowances(0).unwrap());
+        assert_ok!(Infimum::do_try_state());
+    })
+}
+
+/// Unused fee allowances should be returned once the poll is fulfilled.
+#[test]
+fn participant_fee_allowance_returned()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+        let mut config = poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options);
+        config.fee_allowance = 25;
+
+        register_coordinator(0, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), config.clone()));
+        assert_ok!(Infimum::do_try_state());
+
+        run_to_block(2 + signup_period + voting_period);
+        assert_ok!(Infimum::nullify_poll(RuntimeOrigin::signed(0)));
+        assert_eq!(Infimum::fee_allowances(0), None);
+        assert_eq!(Balances::reserved_balance(0), 0);
+        assert_eq!(Balances::free_balance(0), 1_000);
+        assert_ok!(Infimum::do_try_state());
+
+        // The allowance can not exceed the balance of the coordinator, nor reap their account.
+        config.fee_allowance = 1_001;
+        assert_err!(Infimum::create_poll(RuntimeOrigin::signed(0), config.clone()), Error::<Test>::InsufficientFreeBalance);
+        config.fee_allowance = 1_000;
+        assert_err!(Infimum::create_poll(RuntimeOrigin::signed(0), config.clone()), Error::<Test>::InsufficientFreeBalance);
+        config.fee_allowance = 999;
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), config));
+        assert_eq!(Balances::free_balance(0), 1);
+    })
+}
+
+/// The remaining fee allowance of a poll which participants registered in should be forfeited to the slash
+/// destination once the poll is nullified unproven.
+#[test]
+fn participant_fee_allowance_forfeited()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+        let mut config = poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options);
+        config.fee_allowance = 25;
+
+        register_coordinator(0, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), config));
+
+        let (pk, _, _) = get_participant();
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, pk));
+        assert_eq!(Infimum::fee_allowances(0), Some(15));
+
+        run_to_block(2 + signup_period + voting_period);
+        assert_ok!(Infimum::nullify_poll(RuntimeOrigin::signed(0)));
+        System::assert_last_event(Event::FeeAllowanceForfeited { poll_id: 0, coordinator: 0, amount: 15 }.into());
+
+        assert_eq!(SlashedAmount::get(), 15);
+        assert_eq!(Infimum::fee_allowances(0), None);
+        assert_eq!(Balances::reserved_balance(0), 0);
+        assert_eq!(Balances::free_balance(0), 975);
+        assert_ok!(Infimum::do_try_state());
+    })
+}
+
+/// Participants should only be able to interact during the voting period.
+#[test]
+fn participant_interaction_outside_period()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
+
+        let (pk, shared_pk, message) = get_participant();
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, pk));
+
+        assert_err!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, interaction_cipher(&message)), Error::<Test>::PollRegistrationInProgress);
+        run_to_block(2 + signup_period + voting_period);
+
+        assert_err!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, interaction_cipher(&message)), Error::<Test>::PollVotingHasEnded);
+    })
+}
+
+/// The maximal number of allowable interactions should be enforced, and only be reached by the last interaction.
+#[test]
+fn participant_interaction_limit()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, _interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, 1, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
+
+        let (pk, shared_pk, message) = get_participant();
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, pk));
+
+        run_to_block(1 + signup_period);
+        assert_eq!(Infimum::polls(0).unwrap().config.max_interactions, 5);
+        for count in 1..=5
+        {
+            assert!(!Infimum::polls(0).unwrap().interaction_limit_reached());
+            assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, interaction_cipher(&message)));
+            assert_eq!(Infimum::polls(0).unwrap().state.interactions.count, count);
+        }
+        assert!(Infimum::polls(0).unwrap().interaction_limit_reached());
+        assert_err!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, interaction_cipher(&message)), Error::<Test>::ParticipantInteractionLimitReached);
+    })
+}
+
+/// Public keys which are not points in the prime order subgroup should be rejected.
+#[test]
+fn participant_public_key_validation()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
+
+        // The point (0, -1) lies on the curve, but has order two.
+        let small_order_pk = PublicKey {
+            x: [0u8; 32],
+            y: [ 48, 100, 78, 114, 225, 49, 160, 41, 184, 80, 69, 182, 129, 129, 88, 93, 40, 51, 232, 72, 121, 185, 112, 145, 67, 225, 245, 147, 240, 0, 0, 0 ]
+        };
+        let off_curve_pk = PublicKey {
+            x: [ 1, 65, 89, 247, 81, 66, 57, 66, 160, 59, 9, 185, 3, 52, 188, 122, 132, 221, 26, 200, 129, 243, 234, 120, 128, 23, 19, 96, 94, 154, 207, 196 ],
+            y: [ 38, 38, 57, 70, 162, 8, 198, 245, 211, 231, 101, 158, 63, 226, 172, 117, 156, 26, 3, 50, 0, 241, 20, 66, 227, 150, 160, 78, 249, 106, 140, 69 ]
+        };
+
+        assert_err!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, small_order_pk), Error::<Test>::PublicKeyNotInSubgroup);
+        assert_err!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, off_curve_pk), Error::<Test>::MalformedKeys);
+
+        let (pk, _shared_pk, message) = get_participant();
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, pk));
+
+        run_to_block(1 + signup_period);
+        assert_err!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, small_order_pk, interaction_cipher(&message)), Error::<Test>::PublicKeyNotInSubgroup);
+        assert_err!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, off_curve_pk, interaction_cipher(&message)), Error::<Test>::MalformedKeys);
+        assert_eq!(Infimum::polls(0).unwrap().state.interactions.count, 0);
+    })
+}
+
+/// Interactions during the signup period should only be accepted by polls which allow early interactions.
+#[test]
+fn participant_interaction_early()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+        let duration = signup_period + voting_period;
+
+        register_coordinator(0, pk, vk.clone());
+        register_coordinator(1, pk, vk);
+
+        // Poll 0 does not allow early interactions, while poll 1 does.
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone())));
+
+        let mut config = poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options);
+        config.allow_early_interactions = true;
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(1), config));
+
+        let (pk, shared_pk, message) = get_participant();
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(2), 0, pk));
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(2), 1, pk));
+
+        assert_err!(Infimum::interact_with_poll(RuntimeOrigin::signed(2), 0, shared_pk, interaction_cipher(&message)), Error::<Test>::PollRegistrationInProgress);
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(2), 1, shared_pk, interaction_cipher(&message)));
+        assert_eq!(Infimum::polls(0).unwrap().state.interactions.count, 0);
+        assert_eq!(Infimum::polls(1).unwrap().state.interactions.count, 1);
+
+        // Early interactions are counted alongside those made during the voting period.
+        run_to_block(1 + signup_period);
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(2), 1, shared_pk, interaction_cipher(&message)));
+        assert_eq!(Infimum::polls(1).unwrap().state.interactions.count, 2);
+
+        run_to_block(2 + duration);
+        assert_err!(Infimum::interact_with_poll(RuntimeOrigin::signed(2), 1, shared_pk, interaction_cipher(&message)), Error::<Test>::PollVotingHasEnded);
+
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(1)));
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(1)));
+        assert_eq!(Infimum::polls(1).unwrap().state.commitment.process.expected, 1);
+    })
+}
+
+/// Interaction leaves should hash messages of the configured word count in chunks of five words.
+#[test]
+fn participant_interaction_message_words()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, pk, vk.clone());
+        register_coordinator(1, pk, vk);
+
+        // Message word counts outside of the supported range are rejected.
+        let mut config = poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone());
+        config.message_words = 0;
+        assert_err!(Infimum::create_poll(RuntimeOrigin::signed(0), config.clone()), Error::<Test>::PollConfigInvalid);
+        config.message_words = 16;
+        assert_err!(Infimum::create_poll(RuntimeOrigin::signed(0), config), Error::<Test>::PollConfigInvalid);
+
+        // Poll 0 expects 10 word messages, while poll 1 expects 7 word messages.
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone())));
+
+        let mut config = poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options);
+        config.message_words = 7;
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(1), config));
+
+        let (pk, shared_pk, message) = get_participant();
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(2), 0, pk));
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(2), 1, pk));
+
+        run_to_block(1 + signup_period);
+        assert_err!(Infimum::interact_with_poll(RuntimeOrigin::signed(2), 0, shared_pk, interaction_cipher(&message[..7])), Error::<Test>::MessageLengthMismatch);
+        assert_err!(Infimum::interact_with_poll(RuntimeOrigin::signed(2), 1, shared_pk, interaction_cipher(&message)), Error::<Test>::MessageLengthMismatch);
+
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(2), 0, shared_pk, interaction_cipher(&message)));
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(2), 1, shared_pk, interaction_cipher(&message[..7])));
+
+        // H4(H5(m[0..5]), H5(m[5..10]), pk.x, pk.y)
+        let expected_leaf: HashBytes = [ 17, 227, 242, 122, 28, 83, 74, 247, 98, 202, 135, 226, 42, 94, 247, 78, 190, 144, 142, 101, 232, 193, 55, 62, 162, 125, 60, 140, 88, 92, 250, 160 ];
+        assert_eq!(Infimum::poll_interaction_frontier(0).unwrap()[0].1, expected_leaf);
+
+        // H4(H5(m[0..5]), H5(m[5..7], 0, 0, 0), pk.x, pk.y)
+        let expected_leaf: HashBytes = [ 36, 18, 29, 231, 96, 142, 51, 209, 179, 158, 18, 72, 31, 162, 242, 119, 1, 67, 108, 170, 98, 229, 58, 155, 247, 45, 123, 15, 248, 182, 124, 60 ];
+        assert_eq!(Infimum::poll_interaction_frontier(1).unwrap()[0].1, expected_leaf);
+    })
+}
+
+/// Interaction ciphers should split messages into zero padded chunks, and reject malformed chunks.
+#[test]
+fn participant_interaction_cipher()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+        let mut config = poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options);
+        config.message_words = 7;
+
+        register_coordinator(0, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), config));
+
+        // The words of the message are split into a left and right chunk, and survive the round trip.
+        let (pk, shared_pk, message) = get_participant();
+        let cipher = interaction_cipher(&message[..7]);
+        assert!(cipher.is_well_formed());
+        assert_eq!(cipher.words, 7);
+        assert_eq!(cipher.chunks[0].as_slice(), &message[..5]);
+        assert_eq!(cipher.chunks[1][..2], message[5..7]);
+        assert_eq!(cipher.chunks[1][2..], [[0u8; 32]; 3]);
+        assert_eq!(PollInteractionData::from(cipher.clone()), interaction_data(&message[..7]));
+
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, pk));
+        run_to_block(1 + signup_period);
+
+        // A cipher with non-zero padding is rejected.
+        let mut malformed = cipher.clone();
+        malformed.chunks[1][4] = message[9];
+        assert!(!malformed.is_well_formed());
+        assert_err!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, malformed), Error::<Test>::MessageLengthMismatch);
+
+        // A cipher with more chunks than its words require is rejected.
+        let mut malformed = cipher.clone();
+        malformed.chunks.try_push([[0u8; 32]; 5]).unwrap();
+        assert!(!malformed.is_well_formed());
+        assert_err!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, malformed), Error::<Test>::MessageLengthMismatch);
+
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, cipher));
+    })
+}
+
+/// Polls may reject interactions encrypted with the public key of the coordinator.
+#[test]
+fn participant_interaction_coordinator_key()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (coordinator_pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, coordinator_pk, vk);
+
+        let mut config = poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options);
+        config.reject_coordinator_key = true;
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), config));
+
+        let off_curve_pk = PublicKey {
+            x: [ 1, 65, 89, 247, 81, 66, 57, 66, 160, 59, 9, 185, 3, 52, 188, 122, 132, 221, 26, 200, 129, 243, 234, 120, 128, 23, 19, 96, 94, 154, 207, 196 ],
+            y: [ 38, 38, 57, 70, 162, 8, 198, 245, 211, 231, 101, 158, 63, 226, 172, 117, 156, 26, 3, 50, 0, 241, 20, 66, 227, 150, 160, 78, 249, 106, 140, 69 ]
+        };
+
+        let (pk, shared_pk, message) = get_participant();
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, pk));
+
+        run_to_block(1 + signup_period);
+        assert_err!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, off_curve_pk, interaction_cipher(&message)), Error::<Test>::MalformedKeys);
+        assert_err!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, coordinator_pk, interaction_cipher(&message)), Error::<Test>::InteractionKeyIsCoordinatorKey);
+        assert_eq!(Infimum::polls(0).unwrap().state.interactions.count, 0);
+
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, interaction_cipher(&message)));
+        assert_eq!(Infimum::polls(0).unwrap().state.interactions.count, 1);
+    })
+}
+
+/// The registration tree should only be mergable after the signup period.
+#[test]
+fn merge_registration_signup_period()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (alice_pk, alice_vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, alice_pk, alice_vk);
+        assert_ok!(
+            Infimum::create_poll(
+                RuntimeOrigin::signed(0),
+                poll_config(
+                    signup_period,
+                    voting_period,
+                    registration_depth,
+                    interaction_depth,
+                    process_subtree_depth,
+                    tally_subtree_depth,
+                    vote_option_tree_depth,
+                    vote_options
+                )
+            )
+        );
+
+        run_to_block(2);
+
+        for (origin, pk) in &get_participants()
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
+        }
+
+        assert_err!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)), Error::<Test>::PollRegistrationInProgress);
+    })
+}
+
+/// The interaction tree should only be mergable after the voting period.
+#[test]
+fn merge_interaction_voting_period()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (alice_pk, alice_vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, alice_pk, alice_vk);
+        assert_ok!(
+            Infimum::create_poll(
+                RuntimeOrigin::signed(0),
+                poll_config(
+                    signup_period,
+                    voting_period,
+                    registration_depth,
+                    interaction_depth,
+                    process_subtree_depth,
+                    tally_subtree_depth,
+                    vote_option_tree_depth,
+                    vote_options
+                )
+            )
+        );
+
+        run_to_block(2);
+
+        for (origin, pk) in &get_participants()
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
+        }
+
+        run_to_block(1 + signup_period);
+
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+        assert_err!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)), Error::<Test>::PollVotingInProgress);
+    })
+}
+
+/// The interaction tree should only be merged once the closure of the poll sealed it, rather than as soon as the
+/// poll is over.
+#[test]
+fn merge_interaction_unsealed()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
+
+        let (pk, shared_pk, message) = get_participant();
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, pk));
+
+        run_to_block(1 + signup_period);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, interaction_cipher(&message)));
+
+        // The poll is over, but its closure has not yet been processed.
+        let ends_at = 2 + signup_period + voting_period;
+        run_to_block(ends_at - 1);
+        System::set_block_number(ends_at);
+        assert!(Infimum::polls(0).unwrap().is_over());
+        assert!(!Infimum::polls(0).unwrap().is_sealed());
+        assert_err!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)), Error::<Test>::PollNotSealed);
+        assert!(Infimum::polls(0).unwrap().state.interactions.root.is_none());
+
+        // The closure is processed before any extrinsic of the block, so merges within it are deterministic.
+        Infimum::on_initialize(ends_at);
+        assert!(Infimum::polls(0).unwrap().is_sealed());
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+        assert!(Infimum::polls(0).unwrap().state.interactions.root.is_some());
+        assert_ok!(Infimum::do_try_state());
+    })
+}
+
+/// A stored frontier which does not describe a tree should fail to merge with a dedicated error, and leave the
+/// poll unmerged.
+#[test]
+fn merge_malformed_frontier()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
+
+        let (pk, _, _) = get_participant();
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, pk));
+
+        // Three leaves at the bottom of a binary tree would have been hashed upon insertion.
+        PollRegistrationFrontier::<Test>::insert(0, vec::Vec::from([ (0, [1u8; 32]), (0, [2u8; 32]), (0, [3u8; 32]) ]));
+
+        run_to_block(1 + signup_period);
+        assert_err!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)), Error::<Test>::StateTreeMalformed);
+        assert_eq!(Infimum::polls(0).unwrap().state.registrations.root, None);
+    })
+}
+
+/// Polls whose batch sizes overflow should be rejected upon creation, and a stored poll with such a batch size should
+/// fail to merge rather than divide by zero.
+#[test]
+fn merge_invalid_batch_size()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+        let config = poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options);
+
+        register_coordinator(0, pk, vk);
+
+        // `5^14` interactions per proof, or `2^32` registrations per proof, do not fit in a `u32`.
+        let mut overflowing = config.clone();
+        overflowing.process_subtree_depth = 14;
+        assert_err!(Infimum::create_poll(RuntimeOrigin::signed(0), overflowing), Error::<Test>::InvalidBatchSize);
+
+        let mut overflowing = config.clone();
+        overflowing.tally_subtree_depth = 32;
+        assert_err!(Infimum::create_poll(RuntimeOrigin::signed(0), overflowing), Error::<Test>::InvalidBatchSize);
+
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), config));
+        Polls::<Test>::mutate(0, |poll| poll.as_mut().unwrap().config.process_subtree_depth = 14);
+
+        run_to_block(2);
+        let (participant_pk, shared_pk, message) = get_participant();
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, participant_pk));
+
+        run_to_block(1 + signup_period);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, interaction_cipher(&message)));
+
+        run_to_block(2 + signup_period + voting_period);
+        assert_err!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)), Error::<Test>::InvalidBatchSize);
+        assert_eq!(Infimum::polls(0).unwrap().state.interactions.root, None);
+    })
+}
+
+/// The registration tree should be able to be merged and produce the correct root and commitment value.
+#[test]
+fn merge_registration_state_success()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (alice_pk, alice_vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, alice_pk, alice_vk);
+        assert_ok!(
+            Infimum::create_poll(
+                RuntimeOrigin::signed(0),
+                poll_config(
+                    signup_period,
+                    voting_period,
+                    registration_depth,
+                    interaction_depth,
+                    process_subtree_depth,
+                    tally_subtree_depth,
+                    vote_option_tree_depth,
+                    vote_options
+                )
+            )
+        );
+
+        run_to_block(2);
+
+        for (origin, pk) in &get_participants()
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
+        }
+        
+        run_to_block(14);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        assert_eq!(
+            Infimum::polls(0).unwrap().state.registrations.root, 
+            Some([16, 44, 202, 10, 154, 154, 255, 162, 164, 69, 231, 62, 33, 104, 15, 112, 88, 216, 113, 111, 70, 122, 146, 189, 80, 94, 79, 213, 137, 73, 176, 205])
+        );
+        assert_eq!(
+            Infimum::polls(0).unwrap().state.commitment.process.current,
+            [42, 172, 65, 18, 133, 85, 171, 69, 236, 46, 172, 46, 31, 229, 218, 229, 163, 201, 108, 165, 174, 141, 40, 17, 128, 246, 71, 216, 46, 235, 135, 32]
+        );
+    })
+}
+
+/// Coordinators should be able to page through the stored registration leaves and rebuild the merged registration root.
+#[test]
+fn registration_leaves_rebuild()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
+
+        run_to_block(2);
+
+        let participants = get_participants();
+        for (origin, pk) in &participants
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
+        }
+
+        run_to_block(14);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        // Page through the leaves until a short page is returned.
+        let mut leaves = vec::Vec::<HashBytes>::new();
+        loop
+        {
+            let page = Infimum::registration_leaves(0, leaves.len() as u32, 2);
+            let done = page.len() < 2;
+            leaves.extend(page);
+            if done { break; }
+        }
+
+        assert_eq!(leaves.len(), participants.len());
+        assert_eq!(Infimum::registration_leaves(0, participants.len() as u32, 2), vec::Vec::<HashBytes>::new());
+        assert_eq!(Infimum::registration_leaves(1, 0, 2), vec::Vec::<HashBytes>::new());
+
+        // Rebuild the registration tree from the leaves alone.
+        let registrations = Infimum::polls(0).unwrap().state.registrations;
+        let tree = leaves
+            .into_iter()
+            .fold(
+                PollStateTree::new(registrations.arity, registrations.full_depth, Some((0, get_merkle_zeroes(registrations.arity)[0]))),
+                |tree, leaf| tree.insert(leaf).unwrap()
+            )
+            .merge(false)
+            .unwrap();
+
+        assert!(registrations.root.is_some());
+        assert_eq!(tree.root, registrations.root);
+    })
+}
+
+/// Participants should be able to prove their registration included in the merged registration tree.
+#[test]
+fn registration_inclusion_verified()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
+
+        run_to_block(2);
+
+        let participants = get_participants();
+        for (origin, pk) in &participants
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
+        }
+
+        // The registrations follow the zero leaf, and the tree is merged to the depth of its first subtree.
+        let registrations = Infimum::poll_registration_tree(0).unwrap();
+        let leaves = Infimum::registration_leaves(0, 0, participants.len() as u32);
+        let tree_leaves: vec::Vec<HashBytes> = [ get_merkle_zeroes(registrations.arity)[0] ].into_iter().chain(leaves.clone()).collect();
+        let depth = (0..=registrations.full_depth)
+            .find(|depth| u32::from(registrations.arity).pow((*depth).into()) >= tree_leaves.len() as u32)
+            .unwrap();
+
+        // The tree is not proven against until it is merged.
+        let (_, path) = merkle_path(registrations.arity, depth, &tree_leaves, 1);
+        assert!(!Infimum::verify_registration_inclusion(0, 0, leaves[0], path));
+
+        run_to_block(14);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        for (index, leaf) in leaves.iter().enumerate()
+        {
+            let (root, path) = merkle_path(registrations.arity, depth, &tree_leaves, index as u32 + 1);
+            assert_eq!(Infimum::polls(0).unwrap().state.registrations.root, Some(root));
+            assert!(Infimum::verify_registration_inclusion(0, index as u32, *leaf, path.clone()));
+
+            // The leaf is not included at any other index, nor in another poll.
+            assert!(!Infimum::verify_registration_inclusion(0, index as u32 + 1, *leaf, path.clone()));
+            assert!(!Infimum::verify_registration_inclusion(1, index as u32, *leaf, path));
+        }
+    })
+}
+
+/// Registration leaves should not be written to storage unless configured.
+#[test]
+fn registration_leaves_not_stored()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        StoreRegistrationLeaves::set(false);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
+
+        let (pk, _, _) = get_participant();
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, pk));
+
+        assert_eq!(Infimum::registration_leaf(0, 0), None);
+        assert_eq!(RegistrationLeaves::<Test>::iter_prefix(0).count(), 0);
+        assert_eq!(Infimum::registration_leaves(0, 0, 2), vec::Vec::<HashBytes>::new());
+    })
+}
+
+/// The registration tree should be able to be merged and produce the correct root and expected number of proofs.
+#[test]
+fn merge_interaction_state_success()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (alice_pk, alice_vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, alice_pk, alice_vk);
+        assert_ok!(
+            Infimum::create_poll(
+                RuntimeOrigin::signed(0),
+                poll_config(
+                    signup_period,
+                    voting_period,
+                    registration_depth,
+                    interaction_depth,
+                    process_subtree_depth,
+                    tally_subtree_depth,
+                    vote_option_tree_depth,
+                    vote_options
+                )
+            )
+        );
+
+        run_to_block(2);
+
+        for (origin, pk) in &get_participants()
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
+        }
+
+        run_to_block(14);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        // The merge is only completed once both roots are available.
+        let merge_completed = || System::events().iter().filter(|record| matches!(record.event, RuntimeEvent::Infimum(Event::PollMergeCompleted { .. }))).count();
+        assert_eq!(merge_completed(), 0);
+
+        let (_pk, bob_shared_pk, message_data) = get_participant();
+
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, bob_shared_pk, interaction_cipher(&message_data)));
+
+        run_to_block(26);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let poll = Infimum::polls(0).unwrap();
+        System::assert_has_event(Event::PollMergeCompleted {
+            poll_id: 0,
+            registration_root: poll.state.registrations.root.unwrap(),
+            interaction_root: poll.state.interactions.root.unwrap(),
+            coord_pub_key_hash: [ 44, 10, 176, 169, 239, 152, 113, 116, 72, 51, 92, 220, 1, 237, 221, 179, 150, 219, 12, 214, 219, 163, 3, 162, 241, 224, 121, 170, 90, 116, 97, 79 ]
+        }.into());
+
+        // Merging an already merged poll does not emit the event again.
+        assert_err!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)), Error::<Test>::PollDataEmpty);
+        assert_eq!(merge_completed(), 1);
+
+        assert_eq!(
+            Infimum::polls(0).unwrap().state.interactions.root, 
+            Some([31, 254, 7, 234, 211, 75, 174, 138, 104, 42, 237, 212, 221, 158, 115, 172, 29, 63, 109, 91, 47, 88, 77, 75, 76, 5, 201, 65, 69, 119, 219, 182])
+        );
+
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment.process.expected, 1);
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment.tally.expected, 2);
+    })
+}
+
+/// Polls created with `PoseidonPacked` should publish the hash of the packed coordinator key once ready for proving.
+#[test]
+fn merge_completed_packed_coord_key_hash()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+        let mut config = poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options);
+        config.coord_key_hash = CoordKeyHash::PoseidonPacked;
+
+        register_coordinator(0, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), config));
+        assert_eq!(Infimum::poll_coord_key_hash(0), CoordKeyHash::PoseidonPacked);
+
+        run_to_block(2);
+        let (participant_pk, shared_pk, message) = get_participant();
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, participant_pk));
+
+        run_to_block(14);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, interaction_cipher(&message)));
+
+        run_to_block(26);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let poll = Infimum::polls(0).unwrap();
+        System::assert_has_event(Event::PollMergeCompleted {
+            poll_id: 0,
+            registration_root: poll.state.registrations.root.unwrap(),
+            interaction_root: poll.state.interactions.root.unwrap(),
+            coord_pub_key_hash: [ 35, 225, 120, 168, 161, 142, 250, 141, 51, 78, 24, 78, 26, 207, 107, 10, 234, 108, 107, 11, 145, 253, 185, 73, 97, 171, 226, 105, 65, 57, 7, 254 ]
+        }.into());
+    })
+}
+
+/// The correct public signals should be produced prior to proving.
+#[test]
+fn process_messages_public_signals()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (alice_pk, alice_vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, alice_pk, alice_vk);
+        assert_ok!(
+            Infimum::create_poll(
+                RuntimeOrigin::signed(0),
+                poll_config(
+                    signup_period,
+                    voting_period,
+                    registration_depth,
+                    interaction_depth,
+                    process_subtree_depth,
+                    tally_subtree_depth,
+                    vote_option_tree_depth,
+                    vote_options
+                )
+            )
+        );
+
+        run_to_block(2);
+
+        for (origin, pk) in &get_participants()
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
+        }
+
+        run_to_block(14);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let (_pk, bob_shared_pk, message_data) = get_participant();
+
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, bob_shared_pk, interaction_cipher(&message_data)));
+
+        run_to_block(26);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        // Assert validity of public proof inputs. Expected:
+        // [
+        //     "4",
+        //     "25",
+        //     "14470532103638942535012694444804587998397771818472334093141391431267230669750",
+        //     "2",
+        //     "1",
+        //     "0",
+        //     "19920653097131876015283340295735326298336825292385683485447270132525802217807",
+        //     "19301486448472428800803584456730803281486402183229406170295981014011957970720",
+        //     "15716693934388801634961548270365703551068883178466706335144577413121368892481"
+        // ]
+        assert_eq!(Infimum::polls(0).unwrap().state.registrations.count + 1, 4);
+        assert_eq!(Infimum::polls(0).unwrap().get_voting_period_end(), 25);
+        assert_eq!(Infimum::polls(0).unwrap().state.interactions.root, Some([31, 254, 7, 234, 211, 75, 174, 138, 104, 42, 237, 212, 221, 158, 115, 172, 29, 63, 109, 91, 47, 88, 77, 75, 76, 5, 201, 65, 69, 119, 219, 182]));
+        assert_eq!(Infimum::polls(0).unwrap().state.registrations.depth, 2);
+        let mut hasher = Poseidon::<Fr>::new_circom(2).unwrap();
+        let coord_pub_key_fr: vec::Vec<Fr> = vec::Vec::from([ alice_pk.x, alice_pk.y ])
+            .iter()
+            .map(|bytes| Fr::from_be_bytes_mod_order(bytes))
+            .collect();
+        let coord_pub_key_hash = hasher.hash(&coord_pub_key_fr).unwrap().into_bigint().to_string();
+        assert_eq!(coord_pub_key_hash, "19920653097131876015283340295735326298336825292385683485447270132525802217807");
+        assert_eq!(
+            Infimum::polls(0).unwrap().state.commitment.process.current,
+            [42, 172, 65, 18, 133, 85, 171, 69, 236, 46, 172, 46, 31, 229, 218, 229, 163, 201, 108, 165, 174, 141, 40, 17, 128, 246, 71, 216, 46, 235, 135, 32]
+        );
+    })
+}
+
+/// A single valid message processing proof should be successfully verifiable.
+#[test]
+fn commit_outcome_single_batch()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (alice_pk, alice_vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, alice_pk, alice_vk);
+        assert_ok!(
+            Infimum::create_poll(
+                RuntimeOrigin::signed(0),
+                poll_config(
+                    signup_period,
+                    voting_period,
+                    registration_depth,
+                    interaction_depth,
+                    process_subtree_depth,
+                    tally_subtree_depth,
+                    vote_option_tree_depth,
+                    vote_options
+                )
+            )
+        );
+
+        run_to_block(2);
+
+        for (origin, pk) in &get_participants()
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
+        }
+
+        run_to_block(14);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let (_pk, bob_shared_pk, message_data) = get_participant();
+
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, bob_shared_pk, interaction_cipher(&message_data)));
+
+        run_to_block(26);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let (proof_data, new_proof_commitment, _tpf, _tc) = get_proof();
+        let proof_batches: vec::Vec<(ProofData, CommitmentData)> = vec::Vec::from([(proof_data, new_proof_commitment)]);
+    
+        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), 0, proof_batches.try_into().unwrap(), None));
+    
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment.process.completed, 1);
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment.process.current, new_proof_commitment);
+    })
+}
+
+/// The verifying keys of a poll should be replaceable once voting has closed, until a proof has been verified.
+#[test]
+fn replace_poll_verify_key()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        // The coordinator registered a process key of the right size, but from the wrong setup.
+        let (alice_pk, alice_vk) = get_coordinator_data();
+        let (_, malformed_vk) = get_coordinator_data_malformed();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        let mut wrong_vk = alice_vk.clone();
+        wrong_vk.process.delta_g2 = alice_vk.process.gamma_g2.clone();
+
+        let mut swapped_vk = alice_vk.clone();
+        swapped_vk.process = alice_vk.tally.clone();
+        swapped_vk.tally = alice_vk.process.clone();
+
+        register_coordinator(0, alice_pk, wrong_vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
+
+        run_to_block(2);
+        for (origin, pk) in &get_participants()
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
+        }
+
+        run_to_block(14);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let (_pk, bob_shared_pk, message_data) = get_participant();
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, bob_shared_pk, interaction_cipher(&message_data)));
+
+        // The keys may not be replaced while voting is in progress.
+        assert_err!(Infimum::replace_poll_verify_key(RuntimeOrigin::signed(0), 0, alice_vk.clone()), Error::<Test>::PollVotingInProgress);
+
+        run_to_block(26);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let (proof_data, new_proof_commitment, _tpf, _tc) = get_proof();
+        let proof_batches: vec::Vec<(ProofData, CommitmentData)> = vec::Vec::from([(proof_data, new_proof_commitment)]);
+        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), 0, proof_batches.clone().try_into().unwrap(), None));
+        System::assert_last_event(Event::ProofRejected { poll_id: 0, kind: ProofKind::Process, attempt: 1 }.into());
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment.process.completed, 0);
+
+        // Only the coordinator may replace the keys, and only with deserializable keys of the expected size.
+        assert_err!(Infimum::replace_poll_verify_key(RuntimeOrigin::signed(1), 0, alice_vk.clone()), Error::<Test>::NotPollCoordinator);
+        assert_err!(Infimum::replace_poll_verify_key(RuntimeOrigin::signed(0), 0, malformed_vk), Error::<Test>::MalformedKeys);
+        assert_err!(Infimum::replace_poll_verify_key(RuntimeOrigin::signed(0), 0, swapped_vk), Error::<Test>::VerifyKeySizeMismatch);
+
+        assert_ok!(Infimum::replace_poll_verify_key(RuntimeOrigin::signed(0), 0, alice_vk.clone()));
+        System::assert_has_event(Event::PollVerifyKeyReplaced { poll_id: 0, verify_key: alice_vk.clone() }.into());
+        assert_eq!(Infimum::poll_verify_key(0), Some(alice_vk.clone()));
+
+        // The proof verifies against the replaced keys, after which the keys may no longer be replaced.
+        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), 0, proof_batches.try_into().unwrap(), None));
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment.process.completed, 1);
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment.process.current, new_proof_commitment);
+        assert_err!(Infimum::replace_poll_verify_key(RuntimeOrigin::signed(0), 0, alice_vk), Error::<Test>::ProvingAlreadyStarted);
+    })
+}
+
+/// Only the circuit origin should register and remove circuits, whose keys are immutable once registered.
+#[test]
+fn circuit_registry_governance()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (_, alice_vk) = get_coordinator_data();
+        let (_, malformed_vk) = get_coordinator_data_malformed();
+
+        assert_err!(Infimum::register_circuit(RuntimeOrigin::signed(0), 1, alice_vk.clone()), error::BadOrigin);
+        assert_err!(Infimum::register_circuit(RuntimeOrigin::root(), 1, malformed_vk), Error::<Test>::MalformedKeys);
+
+        assert_ok!(Infimum::register_circuit(RuntimeOrigin::root(), 1, alice_vk.clone()));
+        System::assert_last_event(Event::CircuitRegistered { circuit_id: 1, verify_key: alice_vk.clone() }.into());
+        assert_eq!(Infimum::circuit(1), Some(alice_vk.clone()));
+        assert_err!(Infimum::register_circuit(RuntimeOrigin::root(), 1, alice_vk.clone()), Error::<Test>::CircuitAlreadyRegistered);
+
+        assert_err!(Infimum::remove_circuit(RuntimeOrigin::signed(0), 1), error::BadOrigin);
+        assert_err!(Infimum::remove_circuit(RuntimeOrigin::root(), 2), Error::<Test>::CircuitNotFound);
+
+        assert_ok!(Infimum::remove_circuit(RuntimeOrigin::root(), 1));
+        System::assert_last_event(Event::CircuitRemoved { circuit_id: 1 }.into());
+        assert_eq!(Infimum::circuit(1), None);
+    })
+}
+
+/// The proofs of a poll which references a circuit should be verified against the registered keys of the circuit,
+/// rather than those of its coordinator, and the circuit should not be removed while the poll is unfinished.
+#[test]
+fn poll_references_circuit()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        // The coordinator registered a process key of the right size, but from the wrong setup.
+        let (alice_pk, alice_vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        let mut wrong_vk = alice_vk.clone();
+        wrong_vk.process.delta_g2 = alice_vk.process.gamma_g2.clone();
+        register_coordinator(0, alice_pk, wrong_vk);
+
+        let mut config = poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options);
+        config.circuit_id = Some(1);
+
+        // A poll may not reference a circuit which is not registered.
+        assert_err!(Infimum::create_poll(RuntimeOrigin::signed(0), config.clone()), Error::<Test>::CircuitNotFound);
+
+        assert_ok!(Infimum::register_circuit(RuntimeOrigin::root(), 1, alice_vk.clone()));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), config));
+        assert_eq!(Infimum::poll_circuit(0), Some(1));
+        assert_eq!(Infimum::poll_verify_key(0), None);
+
+        run_to_block(2);
+        for (origin, pk) in &get_participants()
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
+        }
+
+        run_to_block(14);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let (_pk, bob_shared_pk, message_data) = get_participant();
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, bob_shared_pk, interaction_cipher(&message_data)));
+
+        run_to_block(26);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        // The circuit may not be removed while the unfinished poll verifies its proofs against it.
+        assert_eq!(Infimum::circuit_references(1), 1);
+        assert_err!(Infimum::remove_circuit(RuntimeOrigin::root(), 1), Error::<Test>::CircuitInUse);
+        assert_eq!(Infimum::circuit(1), Some(alice_vk));
+
+        // The proof verifies against the keys of the circuit.
+        let (proof_data, new_proof_commitment, _tpf, _tc) = get_proof();
+        let proof_batches: vec::Vec<(ProofData, CommitmentData)> = vec::Vec::from([(proof_data, new_proof_commitment)]);
+        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), 0, proof_batches.try_into().unwrap(), None));
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment.process.completed, 1);
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment.process.current, new_proof_commitment);
+    })
+}
+
+/// A poll should release its reference to a circuit once its keys are replaced or it is fulfilled, after which the
+/// circuit may be removed.
+#[test]
+fn circuit_references_released()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+        let mut config = poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options);
+        config.circuit_id = Some(1);
+
+        assert_ok!(Infimum::register_circuit(RuntimeOrigin::root(), 1, vk.clone()));
+        register_coordinator(0, pk, vk.clone());
+        register_coordinator(1, pk, vk.clone());
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), config.clone()));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(1), config));
+        assert_eq!(Infimum::circuit_references(1), 2);
+        assert_ok!(Infimum::do_try_state());
+
+        // Replacing the keys of a poll releases its circuit.
+        run_to_block(2 + signup_period + voting_period);
+        assert_ok!(Infimum::replace_poll_verify_key(RuntimeOrigin::signed(0), 0, vk));
+        assert_eq!(Infimum::circuit_references(1), 1);
+        assert_err!(Infimum::remove_circuit(RuntimeOrigin::root(), 1), Error::<Test>::CircuitInUse);
+
+        // As does nullifying a poll.
+        assert_ok!(Infimum::nullify_poll(RuntimeOrigin::signed(1)));
+        assert_eq!(Infimum::circuit_references(1), 0);
+        assert_eq!(Infimum::poll_circuit(1), None);
+        assert_ok!(Infimum::do_try_state());
+
+        assert_ok!(Infimum::remove_circuit(RuntimeOrigin::root(), 1));
+        assert_eq!(Infimum::circuit(1), None);
+    })
+}
+
+/// A nullified poll should not be nullified again, which would release its circuit and record its abandonment
+/// once more.
+#[test]
+fn nullify_poll_twice()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+        let mut config = poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options);
+        config.circuit_id = Some(1);
+
+        assert_ok!(Infimum::register_circuit(RuntimeOrigin::root(), 1, vk.clone()));
+        register_coordinator(0, pk, vk.clone());
+        register_coordinator(1, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), config.clone()));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(1), config));
+
+        run_to_block(2 + signup_period + voting_period);
+        assert_ok!(Infimum::nullify_poll(RuntimeOrigin::signed(1)));
+        assert_eq!(Infimum::circuit_references(1), 1);
+        assert_eq!(Infimum::coordinator_stats(1).polls_abandoned, 1);
+
+        // The poll of coordinator 0 still references the circuit, which may not be removed.
+        assert_err!(Infimum::nullify_poll(RuntimeOrigin::signed(1)), Error::<Test>::PollAlreadyNullified);
+        assert_eq!(Infimum::circuit_references(1), 1);
+        assert_eq!(Infimum::coordinator_stats(1).polls_abandoned, 1);
+        assert_err!(Infimum::remove_circuit(RuntimeOrigin::root(), 1), Error::<Test>::CircuitInUse);
+        assert_ok!(Infimum::do_try_state());
+    })
+}
+
+/// An invalid message processing proof should be rejected.
+#[test]
+fn commit_outcome_invalid_proof()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (alice_pk, alice_vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, alice_pk, alice_vk);
+        assert_ok!(
+            Infimum::create_poll(
+                RuntimeOrigin::signed(0),
+                poll_config(
+                    signup_period,
+                    voting_period,
+                    registration_depth,
+                    interaction_depth,
+                    process_subtree_depth,
+                    tally_subtree_depth,
+                    vote_option_tree_depth,
+                    vote_options
+                )
+            )
+        );
+
+        run_to_block(2);
+
+        for (origin, pk) in &get_participants()
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
+        }
+
+        run_to_block(14);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let (_pk, bob_shared_pk, message_data) = get_participant();
+
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, bob_shared_pk, interaction_cipher(&message_data)));
+
+        run_to_block(26);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let (_pf, new_proof_commitment, _tpf, _tc) = get_proof();
+        let proof_data = ProofData {
+            pi_a: vec::Vec::from([ 1, 90, 132, 178, 53, 72, 162, 190, 174, 234, 202, 225, 124, 15, 203, 241, 24, 166, 28, 140, 33, 166, 32, 142, 98, 204, 176, 252, 230, 140, 192, 20, 139, 39, 230, 152, 184, 129, 60, 181, 238, 20, 200, 162, 172, 120, 43, 154, 8, 140, 169, 102, 4, 146, 94, 64, 88, 220, 77, 63, 11, 46, 20, 23 ]),
+            pi_b: vec::Vec::from([ 84, 30, 183, 52, 30, 16, 193, 22, 207, 118, 249, 89, 64, 160, 107, 10, 205, 244, 52, 202, 249, 228, 234, 172, 175, 156, 23, 220, 186, 234, 66, 12, 83, 150, 12, 48, 176, 8, 107, 225, 135, 4, 133, 97, 30, 180, 200, 113, 196, 162, 63, 247, 68, 183, 181, 125, 165, 1, 27, 178, 151, 4, 100, 27, 235, 67, 144, 49, 36, 228, 17, 171, 138, 32, 78, 235, 17, 96, 110, 90, 181, 238, 134, 153, 143, 241, 126, 140, 110, 231, 89, 76, 11, 204, 229, 24, 29, 255, 158, 244, 198, 108, 64, 92, 228, 96, 63, 226, 6, 159, 93, 250, 157, 181, 97, 183, 8, 78, 34, 241, 253, 29, 119, 62, 9, 19, 207, 164 ]),
+            pi_c: vec::Vec::from([ 182, 96, 48, 82, 178, 199, 89, 110, 195, 62, 134, 21, 179, 247, 238, 14, 188, 181, 110, 68, 123, 104, 180, 13, 224, 126, 126, 197, 175, 15, 10, 21, 13, 52, 132, 172, 241, 121, 20, 152, 135, 139, 30, 106, 85, 16, 123, 212, 179, 189, 37, 237, 139, 45, 248, 83, 70, 14, 234, 82, 234, 229, 157, 8 ])
+        };
+        let proof_batches: vec::Vec<(ProofData, CommitmentData)> = vec::Vec::from([(proof_data, new_proof_commitment)]);
+    
+        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), 0, proof_batches.try_into().unwrap(), None));
+        System::assert_last_event(Event::ProofRejected { poll_id: 0, kind: ProofKind::Process, attempt: 1 }.into());
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment.process.completed, 0);
+    })
+}
+
+/// An valid message processing proof with an invalid commitment should be rejected.
+#[test]
+fn commit_outcome_invalid_commitment()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (alice_pk, alice_vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, alice_pk, alice_vk);
+        assert_ok!(
+            Infimum::create_poll(
+                RuntimeOrigin::signed(0),
+                poll_config(
+                    signup_period,
+                    voting_period,
+                    registration_depth,
+                    interaction_depth,
+                    process_subtree_depth,
+                    tally_subtree_depth,
+                    vote_option_tree_depth,
+                    vote_options
+                )
+            )
+        );
+
+        run_to_block(2);
+
+        for (origin, pk) in &get_participants()
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
+        }
+
+        run_to_block(14);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let (_pk, bob_shared_pk, message_data) = get_participant();
+
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, bob_shared_pk, interaction_cipher(&message_data)));
+
+        run_to_block(26);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let (proof_data, _c, _tpf, _tc) = get_proof();
+        let new_proof_commitment: HashBytes = [1, 191, 85, 98, 25, 92, 104, 227, 66, 252, 50, 63, 42, 27, 108, 81, 67, 38, 115, 38, 128, 126, 14, 99, 203, 194, 61, 124, 1, 119, 164, 65];
+        let proof_batches: vec::Vec<(ProofData, CommitmentData)> = vec::Vec::from([(proof_data, new_proof_commitment)]);
+    
+        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), 0, proof_batches.try_into().unwrap(), None));
+        System::assert_last_event(Event::ProofRejected { poll_id: 0, kind: ProofKind::Process, attempt: 1 }.into());
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment.process.completed, 0);
+    })
+}
+
+/// Once a poll exceeds the maximum number of failed proofs, further proofs should be throttled by the cooldown.
+#[test]
+fn commit_outcome_failure_cooldown()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (alice_pk, alice_vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, alice_pk, alice_vk);
+        assert_ok!(
+            Infimum::create_poll(
+                RuntimeOrigin::signed(0),
+                poll_config(
+                    signup_period,
+                    voting_period,
+                    registration_depth,
+                    interaction_depth,
+                    process_subtree_depth,
+                    tally_subtree_depth,
+                    vote_option_tree_depth,
+                    vote_options
+                )
+            )
+        );
+
+        run_to_block(2);
+
+        for (origin, pk) in &get_participants()
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
+        }
+
+        run_to_block(14);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let (_pk, bob_shared_pk, message_data) = get_participant();
+
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, bob_shared_pk, interaction_cipher(&message_data)));
+
+        run_to_block(26);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let (proof_data, new_proof_commitment, _tpf, _tc) = get_proof();
+        let invalid_commitment: HashBytes = [1, 191, 85, 98, 25, 92, 104, 227, 66, 252, 50, 63, 42, 27, 108, 81, 67, 38, 115, 38, 128, 126, 14, 99, 203, 194, 61, 124, 1, 119, 164, 65];
+        let invalid_batches: vec::Vec<(ProofData, CommitmentData)> = vec::Vec::from([(proof_data.clone(), invalid_commitment)]);
+        let proof_batches: vec::Vec<(ProofData, CommitmentData)> = vec::Vec::from([(proof_data, new_proof_commitment)]);
+
+        // Failures up to and including the one which exceeds the maximum are not throttled.
+        for attempt in 1..=(MaxProofFailures::get() + 1)
+        {
+            assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), 0, invalid_batches.clone().try_into().unwrap(), None));
+            System::assert_last_event(Event::ProofRejected { poll_id: 0, kind: ProofKind::Process, attempt }.into());
+        }
+        assert_eq!(Infimum::failed_proof_attempts(0), ProofFailures { attempts: 3, last_failed_at: 26 });
+
+        // Further proofs, valid or not, are rejected until the cooldown has passed.
+        assert_err!(Infimum::commit_outcome(RuntimeOrigin::signed(0), 0, proof_batches.clone().try_into().unwrap(), None), Error::<Test>::ProofCooldownActive);
+        run_to_block(35);
+        assert_err!(Infimum::commit_outcome(RuntimeOrigin::signed(0), 0, proof_batches.clone().try_into().unwrap(), None), Error::<Test>::ProofCooldownActive);
+
+        // A failure after the cooldown restarts it.
+        run_to_block(36);
+        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), 0, invalid_batches.try_into().unwrap(), None));
+        System::assert_last_event(Event::ProofRejected { poll_id: 0, kind: ProofKind::Process, attempt: 4 }.into());
+        assert_err!(Infimum::commit_outcome(RuntimeOrigin::signed(0), 0, proof_batches.clone().try_into().unwrap(), None), Error::<Test>::ProofCooldownActive);
+
+        run_to_block(46);
+        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), 0, proof_batches.try_into().unwrap(), None));
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment.process.completed, 1);
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment.process.current, new_proof_commitment);
+    })
+}
+
+/// Commitments which are not elements of the scalar field should be rejected.
+#[test]
+fn commit_outcome_non_canonical_commitment()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (alice_pk, alice_vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, alice_pk, alice_vk);
+        assert_ok!(
+            Infimum::create_poll(
+                RuntimeOrigin::signed(0),
+                poll_config(
+                    signup_period,
+                    voting_period,
+                    registration_depth,
+                    interaction_depth,
+                    process_subtree_depth,
+                    tally_subtree_depth,
+                    vote_option_tree_depth,
+                    vote_options
+                )
+            )
+        );
+
+        run_to_block(2);
+
+        for (origin, pk) in &get_participants()
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
+        }
+
+        run_to_block(14);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let (_pk, bob_shared_pk, message_data) = get_participant();
+
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, bob_shared_pk, interaction_cipher(&message_data)));
+
+        run_to_block(26);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        // The BN254 scalar field modulus is not itself a field element.
+        let (proof_data, _c, _tpf, _tc) = get_proof();
+        let modulus: HashBytes = [48, 100, 78, 114, 225, 49, 160, 41, 184, 80, 69, 182, 129, 129, 88, 93, 40, 51, 232, 72, 121, 185, 112, 145, 67, 225, 245, 147, 240, 0, 0, 1];
+        assert_err!(Infimum::commit_outcome(RuntimeOrigin::signed(0), 0, vec::Vec::from([(proof_data.clone(), modulus)]).try_into().unwrap(), None), Error::<Test>::InvalidCommitment);
+        assert_err!(Infimum::commit_outcome(RuntimeOrigin::signed(0), 0, vec::Vec::from([(proof_data, [255u8; 32])]).try_into().unwrap(), None), Error::<Test>::InvalidCommitment);
+    })
+}
+
+/// Proofs should be rejected with a dedicated error if the key which the poll circuits are parameterized with has
+/// a coordinate which is not a canonical field element.
+#[test]
+fn commit_outcome_malformed_coordinator_key()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
+
+        run_to_block(2);
+        for (origin, participant) in &get_participants()
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *participant));
+        }
+
+        run_to_block(14);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+        let (_, shared_pk, message) = get_participant();
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, interaction_cipher(&message)));
+
+        run_to_block(26);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        // Snapshot a key which predates validation, whose x-coordinate exceeds the BN254 scalar field modulus.
+        PollCoordinatorPubKey::<Test>::insert(0, PublicKey { x: [255u8; 32], y: pk.y });
+
+        let (proof_data, _c, _tpf, _tc) = get_proof();
+        assert_err!(
+            Infimum::commit_outcome(RuntimeOrigin::signed(0), 0, vec::Vec::from([(proof_data, [1u8; 32])]).try_into().unwrap(), None),
+            Error::<Test>::CoordinatorKeyMalformed
+        );
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment.process.completed, 0);
+    })
+}
+
+/// A valid message processing proof with mismatched data should be rejected.
+#[test]
+fn commit_outcome_mismatched_state()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (alice_pk, alice_vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, alice_pk, alice_vk);
+        assert_ok!(
+            Infimum::create_poll(
+                RuntimeOrigin::signed(0),
+                poll_config(
+                    signup_period,
+                    voting_period,
+                    registration_depth,
+                    interaction_depth,
+                    process_subtree_depth,
+                    tally_subtree_depth,
+                    vote_option_tree_depth,
+                    vote_options
+                )
+            )
+        );
+
+        run_to_block(2);
+
+        let bob_pk = PublicKey {
+            x: [ 28, 180, 134, 110, 83, 137, 190, 108, 12, 120, 74, 223, 227, 28, 194, 38, 100, 222, 10, 95, 61, 101, 146, 56, 59, 36, 137, 42, 211, 200, 100, 29 ],
+            y: [ 43, 40, 45, 216, 59, 166, 46, 82, 190, 189, 134, 128, 122, 184, 159, 189, 137, 38, 201, 82, 182, 73, 21, 141, 173, 52, 228, 175, 136, 119, 114, 32 ]
+        };
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, bob_pk));
+
+        run_to_block(14);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let (_pk, bob_shared_pk, message_data) = get_participant();
+
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, bob_shared_pk, interaction_cipher(&message_data)));
+
+        run_to_block(26);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let (proof_data, new_proof_commitment, _tpf, _tc) = get_proof();
+        let proof_batches: vec::Vec<(ProofData, CommitmentData)> = vec::Vec::from([(proof_data, new_proof_commitment)]);
+    
+        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), 0, proof_batches.try_into().unwrap(), None));
+        System::assert_last_event(Event::ProofRejected { poll_id: 0, kind: ProofKind::Process, attempt: 1 }.into());
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment.process.completed, 0);
+    })
+}
+
+/// A valid tally proof should successfully chain to a valid message processing proof.
+#[test]
+fn commit_outcome_full_batch()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (alice_pk, alice_vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, alice_pk, alice_vk);
+        assert_ok!(
+            Infimum::create_poll(
+                RuntimeOrigin::signed(0),
+                poll_config(
+                    signup_period,
+                    voting_period,
+                    registration_depth,
+                    interaction_depth,
+                    process_subtree_depth,
+                    tally_subtree_depth,
+                    vote_option_tree_depth,
+                    vote_options
+                )
+            )
         );
 
         run_to_block(2);
@@ -559,24 +4002,348 @@ fn merge_interaction_state_success()
 
         let (_pk, bob_shared_pk, message_data) = get_participant();
 
-        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, bob_shared_pk, message_data));
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, bob_shared_pk, interaction_cipher(&message_data)));
 
         run_to_block(26);
         assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
 
-        assert_eq!(
-            Infimum::polls(0).unwrap().state.interactions.root, 
-            Some([31, 254, 7, 234, 211, 75, 174, 138, 104, 42, 237, 212, 221, 158, 115, 172, 29, 63, 109, 91, 47, 88, 77, 75, 76, 5, 201, 65, 69, 119, 219, 182])
+        let (process_proof_data, process_commitment, tally_proof_data, tally_commitment) = get_proof();
+        let proof_batches: vec::Vec<(ProofData, CommitmentData)> = vec::Vec::from([(process_proof_data, process_commitment), (tally_proof_data, tally_commitment)]);
+
+        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), 0, proof_batches.try_into().unwrap(), None));
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment.process.completed, 1);
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment.process.current, process_commitment);
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment.tally.completed, 1);
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment.tally.current, tally_commitment);
+    })
+}
+
+/// A partial chain of valid proofs should be successfully verified, but not produce an outcome.
+#[test]
+fn commit_outcome_partial_success()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (alice_pk, alice_vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, alice_pk, alice_vk);
+        assert_ok!(
+            Infimum::create_poll(
+                RuntimeOrigin::signed(0),
+                poll_config(
+                    signup_period,
+                    voting_period,
+                    registration_depth,
+                    interaction_depth,
+                    process_subtree_depth,
+                    tally_subtree_depth,
+                    vote_option_tree_depth,
+                    vote_options
+                )
+            )
         );
 
-        assert_eq!(Infimum::polls(0).unwrap().state.commitment.expected_process, 1);
-        assert_eq!(Infimum::polls(0).unwrap().state.commitment.expected_tally, 2);
+        run_to_block(2);
+
+        for (origin, pk) in &get_participants()
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
+        }
+
+        run_to_block(14);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let (_pk, bob_shared_pk, message_data) = get_participant();
+
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, bob_shared_pk, interaction_cipher(&message_data)));
+
+        run_to_block(26);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let (process_proof_data, process_commitment, tally_proof_data, tally_commitment) = get_proof();
+        let proof_batches: vec::Vec<(ProofData, CommitmentData)> = vec::Vec::from([(process_proof_data, process_commitment), (tally_proof_data, tally_commitment)]);
+        let scenario = get_poll_scenario(0);
+
+        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), 0, proof_batches.try_into().unwrap(), scenario.outcome));
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment.process.completed, 1);
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment.process.current, process_commitment);
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment.tally.completed, 1);
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment.tally.current, tally_commitment);
+        assert_eq!(Infimum::polls(0).unwrap().state.outcome, scenario.expected);
     })
 }
 
-/// The correct public signals should be produced prior to proving.
+/// The finalization handler should be called with the winning vote option once the outcome is verified.
 #[test]
-fn process_messages_public_signals()
+fn commit_outcome_notifies_finalization()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        FinalizedPolls::set(vec::Vec::new());
+
+        let (alice_pk, alice_vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, alice_pk, alice_vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
+
+        for (origin, pk) in &get_participants()
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
+        }
+
+        run_to_block(1 + signup_period);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let scenario = get_poll_scenario(1);
+        for (pk, data) in &scenario.interactions
+        {
+            assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, *pk, interaction_cipher(data)));
+        }
+
+        run_to_block(2 + signup_period + voting_period);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        // The handler is not called for proofs alone.
+        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), 0, scenario.proof_batches.try_into().unwrap(), None));
+        assert_eq!(FinalizedPolls::get(), vec::Vec::new());
+
+        let outcome_index = scenario.expected.unwrap();
+        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), 0, Default::default(), scenario.outcome));
+        assert_eq!(Infimum::polls(0).unwrap().state.outcome, Some(outcome_index));
+        assert_eq!(FinalizedPolls::get(), vec::Vec::from([(0, outcome_index)]));
+    })
+}
+
+/// The call attached to the winning vote option should be dispatched once the outcome is verified.
+#[test]
+fn commit_outcome_executes_option_call()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (alice_pk, alice_vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+        let mut config = poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options);
+        config.option_calls = vec::Vec::from([
+            (4, RuntimeCall::Balances(pallet_balances::Call::force_set_balance { who: 2, new_free: 555 }).encode().try_into().unwrap()),
+            (5, RuntimeCall::Balances(pallet_balances::Call::force_set_balance { who: 3, new_free: 777 }).encode().try_into().unwrap())
+        ]).try_into().unwrap();
+
+        register_coordinator(0, alice_pk, alice_vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), config));
+
+        for (origin, pk) in &get_participants()
+        {{
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
+        }}
+
+        run_to_block(1 + signup_period);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let scenario = get_poll_scenario(1);
+        for (pk, data) in &scenario.interactions
+        {{
+            assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, *pk, interaction_cipher(data)));
+        }}
+
+        run_to_block(2 + signup_period + voting_period);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let outcome_index = scenario.expected.unwrap();
+        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), 0, scenario.proof_batches.try_into().unwrap(), scenario.outcome));
+        assert_eq!(Infimum::polls(0).unwrap().state.outcome, Some(outcome_index));
+
+        System::assert_last_event(Event::OutcomeExecuted { poll_id: 0, outcome_index }.into());
+        assert_eq!(Balances::free_balance(3), 777);
+
+        // Only the call of the winning option is dispatched, and the calls of the poll are pruned.
+        assert_eq!(Balances::free_balance(2), 1_000);
+        run_to_block(System::block_number() + 1);
+        assert_eq!(Infimum::poll_option_call(0, 4), None);
+        assert_eq!(Infimum::poll_option_call(0, 5), None);
+    })
+}
+
+/// A winning option whose call fails should be reported, without reverting the outcome of the poll.
+#[test]
+fn commit_outcome_option_call_fails()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (alice_pk, alice_vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+        let mut config = poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options);
+        config.option_calls = vec::Vec::from([
+            (4, RuntimeCall::Balances(pallet_balances::Call::force_set_balance { who: 2, new_free: 555 }).encode().try_into().unwrap()),
+            (5, RuntimeCall::System(frame_system::Call::remark_with_event { remark: vec::Vec::from([1]) }).encode().try_into().unwrap())
+        ]).try_into().unwrap();
+
+        register_coordinator(0, alice_pk, alice_vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), config));
+
+        for (origin, pk) in &get_participants()
+        {{
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
+        }}
+
+        run_to_block(1 + signup_period);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let scenario = get_poll_scenario(1);
+        for (pk, data) in &scenario.interactions
+        {{
+            assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, *pk, interaction_cipher(data)));
+        }}
+
+        run_to_block(2 + signup_period + voting_period);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let outcome_index = scenario.expected.unwrap();
+        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), 0, scenario.proof_batches.try_into().unwrap(), scenario.outcome));
+        assert_eq!(Infimum::polls(0).unwrap().state.outcome, Some(outcome_index));
+
+        System::assert_last_event(
+            Event::OutcomeExecutionFailed { poll_id: 0, outcome_index, error: sp_runtime::DispatchError::BadOrigin }.into()
+        );
+        assert_eq!(Balances::free_balance(2), 1_000);
+        assert!(Infimum::poll_outcomes(0).is_some());
+    })
+}
+
+/// Calls attached to vote options should name distinct options, decode, and be within the maximum weight.
+#[test]
+fn create_poll_invalid_option_calls()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+        let config = poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options);
+        let call: OptionCall<Test> = RuntimeCall::Balances(pallet_balances::Call::force_set_balance { who: 3, new_free: 777 })
+            .encode()
+            .try_into()
+            .unwrap();
+        let with_calls = |calls: vec::Vec<(u32, OptionCall<Test>)>| {
+            let mut config = config.clone();
+            config.option_calls = calls.try_into().unwrap();
+            config
+        };
+
+        register_coordinator(0, pk, vk);
+        assert_err!(Infimum::create_poll(RuntimeOrigin::signed(0), with_calls(vec::Vec::from([(25, call.clone())]))), Error::<Test>::OptionCallInvalid);
+        assert_err!(Infimum::create_poll(RuntimeOrigin::signed(0), with_calls(vec::Vec::from([(1, call.clone()), (1, call.clone())]))), Error::<Test>::OptionCallInvalid);
+        assert_err!(Infimum::create_poll(RuntimeOrigin::signed(0), with_calls(vec::Vec::from([(1, vec::Vec::from([255, 255]).try_into().unwrap())]))), Error::<Test>::OptionCallInvalid);
+
+        MaxOptionCallWeight::set(Weight::zero());
+        assert_err!(Infimum::create_poll(RuntimeOrigin::signed(0), with_calls(vec::Vec::from([(1, call.clone())]))), Error::<Test>::OptionCallTooHeavy);
+
+        MaxOptionCallWeight::set(Weight::from_parts(1_000_000_000_000, 1_000_000));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), with_calls(vec::Vec::from([(1, call.clone())]))));
+        assert_eq!(Infimum::poll_option_call(0, 1), Some(call));
+    })
+}
+
+/// Only `OptionCallOrigin` should be able to attach calls to vote options, as they are dispatched with `OutcomeOrigin`.
+#[test]
+fn create_poll_option_calls_privileged()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+        let config = poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options);
+        let mut with_call = config.clone();
+        with_call.option_calls = vec::Vec::from([
+            (1, RuntimeCall::Balances(pallet_balances::Call::force_set_balance { who: 2, new_free: 1_000_000 }).encode().try_into().unwrap())
+        ]).try_into().unwrap();
+
+        // A plain signed coordinator, who controls the outcome through their verifying keys, may not attach a call.
+        register_coordinator(2, pk, vk.clone());
+        assert_err!(Infimum::create_poll(RuntimeOrigin::signed(2), with_call.clone()), error::BadOrigin);
+        assert_eq!(Infimum::next_poll_id(), 0);
+        assert_eq!(Infimum::poll_option_call(0, 1), None);
+
+        // They may still create a poll without calls.
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(2), config));
+        assert_eq!(Infimum::poll_option_call(0, 1), None);
+
+        // The coordinator approved by `OptionCallOrigin` may attach it.
+        register_coordinator(0, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), with_call));
+        assert!(Infimum::poll_option_call(1, 1).is_some());
+    })
+}
+
+/// Resubmitted proofs should be recognised by their index and commitment, rather than verified as the next proof.
+#[test]
+fn commit_outcome_resubmission()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (alice_pk, alice_vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, alice_pk, alice_vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
+
+        run_to_block(2);
+        for (origin, pk) in &get_participants()
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
+        }
+
+        run_to_block(14);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let (_pk, bob_shared_pk, message_data) = get_participant();
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, bob_shared_pk, interaction_cipher(&message_data)));
+
+        run_to_block(26);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let (process_proof_data, process_commitment, tally_proof_data, tally_commitment) = get_proof();
+        let process_batch = (process_proof_data, process_commitment);
+        let tally_batch = (tally_proof_data, tally_commitment);
+
+        // The first proof may not be skipped.
+        assert_err!(Infimum::commit_outcome(RuntimeOrigin::signed(0), 1, vec::Vec::from([tally_batch.clone()]).try_into().unwrap(), None), Error::<Test>::ProofIndexMismatch);
+        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), 0, vec::Vec::from([process_batch.clone()]).try_into().unwrap(), None));
+        assert_eq!(Infimum::proof_commitment(0, 0), Some(process_commitment));
+
+        // An exact resubmission is benign and free of charge.
+        let error = Infimum::commit_outcome(RuntimeOrigin::signed(0), 0, vec::Vec::from([process_batch.clone()]).try_into().unwrap(), None).unwrap_err();
+        assert_eq!(error.error, Error::<Test>::ProofAlreadyAccepted.into());
+        assert_eq!(error.post_info.pays_fee, Pays::No);
+
+        // A stale index which extends past the verified proofs is rejected.
+        assert_err!(Infimum::commit_outcome(RuntimeOrigin::signed(0), 0, vec::Vec::from([process_batch.clone(), tally_batch.clone()]).try_into().unwrap(), None), Error::<Test>::ProofIndexMismatch);
+
+        // A resubmission which conflicts with the verified commitment is rejected.
+        assert_err!(Infimum::commit_outcome(RuntimeOrigin::signed(0), 0, vec::Vec::from([tally_batch.clone()]).try_into().unwrap(), None), Error::<Test>::ConflictingProofCommitment);
+
+        // None of the above advanced the proof chains.
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment.completed(), 1);
+
+        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), 1, vec::Vec::from([tally_batch.clone()]).try_into().unwrap(), None));
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment.tally.current, tally_commitment);
+        assert_eq!(Infimum::proof_commitment(0, 1), Some(tally_commitment));
+
+        // Resubmitting the verified proofs together is equally benign.
+        let error = Infimum::commit_outcome(RuntimeOrigin::signed(0), 0, vec::Vec::from([process_batch, tally_batch]).try_into().unwrap(), None).unwrap_err();
+        assert_eq!(error.error, Error::<Test>::ProofAlreadyAccepted.into());
+    })
+}
+
+/// An out of order chain of proofs should be rejected.
+#[test]
+fn commit_outcome_permuted()
 {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
@@ -584,18 +4351,20 @@ fn process_messages_public_signals()
         let (alice_pk, alice_vk) = get_coordinator_data();
         let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
 
-        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), alice_pk, alice_vk));
+        register_coordinator(0, alice_pk, alice_vk);
         assert_ok!(
             Infimum::create_poll(
                 RuntimeOrigin::signed(0),
-                signup_period,
-                voting_period,
-                registration_depth,
-                interaction_depth,
-                process_subtree_depth,
-                tally_subtree_depth,
-                vote_option_tree_depth,
-                vote_options
+                poll_config(
+                    signup_period,
+                    voting_period,
+                    registration_depth,
+                    interaction_depth,
+                    process_subtree_depth,
+                    tally_subtree_depth,
+                    vote_option_tree_depth,
+                    vote_options
+                )
             )
         );
 
@@ -611,95 +4380,157 @@ fn process_messages_public_signals()
 
         let (_pk, bob_shared_pk, message_data) = get_participant();
 
-        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, bob_shared_pk, message_data));
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, bob_shared_pk, interaction_cipher(&message_data)));
 
         run_to_block(26);
         assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
 
-        // Assert validity of public proof inputs. Expected:
-        // [
-        //     "4",
-        //     "25",
-        //     "14470532103638942535012694444804587998397771818472334093141391431267230669750",
-        //     "2",
-        //     "1",
-        //     "0",
-        //     "19920653097131876015283340295735326298336825292385683485447270132525802217807",
-        //     "19301486448472428800803584456730803281486402183229406170295981014011957970720",
-        //     "15716693934388801634961548270365703551068883178466706335144577413121368892481"
-        // ]
-        assert_eq!(Infimum::polls(0).unwrap().state.registrations.count + 1, 4);
-        assert_eq!(Infimum::polls(0).unwrap().get_voting_period_end(), 25);
-        assert_eq!(Infimum::polls(0).unwrap().state.interactions.root, Some([31, 254, 7, 234, 211, 75, 174, 138, 104, 42, 237, 212, 221, 158, 115, 172, 29, 63, 109, 91, 47, 88, 77, 75, 76, 5, 201, 65, 69, 119, 219, 182]));
-        assert_eq!(Infimum::polls(0).unwrap().state.registrations.depth, 2);
-        let mut hasher = Poseidon::<Fr>::new_circom(2).unwrap();
-        let coord_pub_key_fr: vec::Vec<Fr> = vec::Vec::from([ alice_pk.x, alice_pk.y ])
-            .iter()
-            .map(|bytes| Fr::from_be_bytes_mod_order(bytes))
-            .collect();
-        let coord_pub_key_hash = hasher.hash(&coord_pub_key_fr).unwrap().into_bigint().to_string();
-        assert_eq!(coord_pub_key_hash, "19920653097131876015283340295735326298336825292385683485447270132525802217807");
-        assert_eq!(
-            Infimum::polls(0).unwrap().state.commitment.process,
-            (0, [42, 172, 65, 18, 133, 85, 171, 69, 236, 46, 172, 46, 31, 229, 218, 229, 163, 201, 108, 165, 174, 141, 40, 17, 128, 246, 71, 216, 46, 235, 135, 32])
-        );
-    })
+        let (process_proof_data, process_commitment, tally_proof_data, tally_commitment) = get_proof();
+        let proof_batches: vec::Vec<(ProofData, CommitmentData)> = vec::Vec::from([(tally_proof_data, tally_commitment), (process_proof_data, process_commitment)]);
+
+        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), 0, proof_batches.try_into().unwrap(), None));
+        System::assert_last_event(Event::ProofRejected { poll_id: 0, kind: ProofKind::Process, attempt: 1 }.into());
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment.process.completed, 0);
+    })
+}
+
+macro_rules! invoke_test_poll_scenario {
+    ($test_name:ident, $scenario_index:expr) =>
+    {
+        #[test]
+        fn $test_name() {
+            new_test_ext().execute_with(|| {
+                System::set_block_number(1);
+
+                let (alice_pk, alice_vk) = get_coordinator_data();
+                let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+                register_coordinator(0, alice_pk, alice_vk);
+                assert_ok!(
+                    Infimum::create_poll(
+                        RuntimeOrigin::signed(0),
+                        poll_config(
+                            signup_period,
+                            voting_period,
+                            registration_depth,
+                            interaction_depth,
+                            process_subtree_depth,
+                            tally_subtree_depth,
+                            vote_option_tree_depth,
+                            vote_options
+                        )
+                    )
+                );
+
+                for (origin, pk) in &get_participants()
+                {
+                    assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
+                }
+
+                run_to_block(1 + signup_period);
+                assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+                let scenario = get_poll_scenario($scenario_index);
+                for (pk, data) in &scenario.interactions
+                {
+                    assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, *pk, interaction_cipher(data)));
+                }
+
+                if scenario.interactions.len() > 0
+                {
+                    run_to_block(2 + signup_period + voting_period);
+                    assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+                    if scenario.proof_batches.len() > 0
+                    {
+                        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), 0, scenario.proof_batches.try_into().unwrap(), scenario.outcome));
+                    }
+                }
+
+                assert_eq!(Infimum::polls(0).unwrap().state.outcome, scenario.expected);
+            })
+        }
+    };
 }
 
-/// A single valid message processing proof should be successfully verifiable.
+// A full chain of valid proofs should produce the correct outcome.
+invoke_test_poll_scenario!(commit_outcome_success, 1);
+
+// Correct outcome after every participant interacts with the poll.
+invoke_test_poll_scenario!(commit_outcome_full_round, 2);
+
+/// Stored interaction data and registration leaves should be pruned once the poll outcome is determined.
 #[test]
-fn commit_outcome_single_batch()
+fn commit_outcome_prunes_messages()
 {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
+        EmitFullInteractionData::set(false);
 
         let (alice_pk, alice_vk) = get_coordinator_data();
         let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
 
-        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), alice_pk, alice_vk));
+        register_coordinator(0, alice_pk, alice_vk);
         assert_ok!(
             Infimum::create_poll(
                 RuntimeOrigin::signed(0),
-                signup_period,
-                voting_period,
-                registration_depth,
-                interaction_depth,
-                process_subtree_depth,
-                tally_subtree_depth,
-                vote_option_tree_depth,
-                vote_options
+                poll_config(
+                    signup_period,
+                    voting_period,
+                    registration_depth,
+                    interaction_depth,
+                    process_subtree_depth,
+                    tally_subtree_depth,
+                    vote_option_tree_depth,
+                    vote_options
+                )
             )
         );
 
-        run_to_block(2);
-
         for (origin, pk) in &get_participants()
         {
             assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
         }
 
-        run_to_block(14);
+        run_to_block(1 + signup_period);
         assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
 
-        let (_pk, bob_shared_pk, message_data) = get_participant();
+        let scenario = get_poll_scenario(1);
+        for (pk, data) in &scenario.interactions
+        {
+            assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, *pk, interaction_cipher(data)));
+        }
+        assert_eq!(Messages::<Test>::iter_prefix(0).count(), scenario.interactions.len());
+        assert_eq!(RegistrationLeaves::<Test>::iter_prefix(0).count(), get_participants().len());
 
-        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, bob_shared_pk, message_data));
+        // The stored messages may be paged through in order of interaction.
+        let (pk, data) = scenario.interactions[1];
+        assert_eq!(Infimum::interaction_messages(0, 1, 1), vec::Vec::from([(pk, interaction_data(&data))]));
+        assert_eq!(Infimum::interaction_messages(0, 0, u32::MAX).len(), scenario.interactions.len());
 
-        run_to_block(26);
+        run_to_block(2 + signup_period + voting_period);
         assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), 0, scenario.proof_batches.try_into().unwrap(), scenario.outcome));
+        assert_eq!(Infimum::polls(0).unwrap().state.outcome, scenario.expected);
 
-        let (proof_data, new_proof_commitment, _tpf, _tc) = get_proof();
-        let proof_batches: vec::Vec<(ProofData, CommitmentData)> = vec::Vec::from([(proof_data, new_proof_commitment)]);
-    
-        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), proof_batches, None));
-    
-        assert_eq!(Infimum::polls(0).unwrap().state.commitment.process, (1, new_proof_commitment));
+        // The data is pruned in the following blocks, at most `MaxPruneKeys` keys per block.
+        MaxPruneKeys::set(2);
+        run_to_block(System::block_number() + 1);
+        assert_eq!(Messages::<Test>::iter_prefix(0).count(), scenario.interactions.len() - 2);
+        assert_eq!(RegistrationLeaves::<Test>::iter_prefix(0).count(), get_participants().len());
+        assert!(Infimum::poll_prune_cursor(0).is_some());
+
+        run_to_block(System::block_number() + 16);
+        assert_eq!(Messages::<Test>::iter_prefix(0).count(), 0);
+        assert_eq!(RegistrationLeaves::<Test>::iter_prefix(0).count(), 0);
+        assert!(Infimum::interaction_messages(0, 0, u32::MAX).is_empty());
+        assert_eq!(Infimum::poll_prune_cursor(0), None);
     })
 }
 
-/// An invalid message processing proof should be rejected.
+/// Batch sizes should be derived from the stored poll configuration.
 #[test]
-fn commit_outcome_invalid_proof()
+fn poll_batch_sizes()
 {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
@@ -707,53 +4538,44 @@ fn commit_outcome_invalid_proof()
         let (alice_pk, alice_vk) = get_coordinator_data();
         let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
 
-        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), alice_pk, alice_vk));
+        // Poll does not exist.
+        assert_eq!(Infimum::interaction_batch_size(0), None);
+        assert_eq!(Infimum::tally_batch_size(0), None);
+
+        register_coordinator(0, alice_pk, alice_vk);
         assert_ok!(
             Infimum::create_poll(
                 RuntimeOrigin::signed(0),
-                signup_period,
-                voting_period,
-                registration_depth,
-                interaction_depth,
-                process_subtree_depth,
-                tally_subtree_depth,
-                vote_option_tree_depth,
-                vote_options
+                poll_config(
+                    signup_period,
+                    voting_period,
+                    registration_depth,
+                    interaction_depth,
+                    process_subtree_depth,
+                    tally_subtree_depth,
+                    vote_option_tree_depth,
+                    vote_options
+                )
             )
         );
 
-        run_to_block(2);
-
-        for (origin, pk) in &get_participants()
-        {
-            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
-        }
-
-        run_to_block(14);
-        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
-
-        let (_pk, bob_shared_pk, message_data) = get_participant();
-
-        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, bob_shared_pk, message_data));
-
-        run_to_block(26);
-        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
-
-        let (_pf, new_proof_commitment, _tpf, _tc) = get_proof();
-        let proof_data = ProofData {
-            pi_a: vec::Vec::from([ 1, 90, 132, 178, 53, 72, 162, 190, 174, 234, 202, 225, 124, 15, 203, 241, 24, 166, 28, 140, 33, 166, 32, 142, 98, 204, 176, 252, 230, 140, 192, 20, 139, 39, 230, 152, 184, 129, 60, 181, 238, 20, 200, 162, 172, 120, 43, 154, 8, 140, 169, 102, 4, 146, 94, 64, 88, 220, 77, 63, 11, 46, 20, 23 ]),
-            pi_b: vec::Vec::from([ 84, 30, 183, 52, 30, 16, 193, 22, 207, 118, 249, 89, 64, 160, 107, 10, 205, 244, 52, 202, 249, 228, 234, 172, 175, 156, 23, 220, 186, 234, 66, 12, 83, 150, 12, 48, 176, 8, 107, 225, 135, 4, 133, 97, 30, 180, 200, 113, 196, 162, 63, 247, 68, 183, 181, 125, 165, 1, 27, 178, 151, 4, 100, 27, 235, 67, 144, 49, 36, 228, 17, 171, 138, 32, 78, 235, 17, 96, 110, 90, 181, 238, 134, 153, 143, 241, 126, 140, 110, 231, 89, 76, 11, 204, 229, 24, 29, 255, 158, 244, 198, 108, 64, 92, 228, 96, 63, 226, 6, 159, 93, 250, 157, 181, 97, 183, 8, 78, 34, 241, 253, 29, 119, 62, 9, 19, 207, 164 ]),
-            pi_c: vec::Vec::from([ 182, 96, 48, 82, 178, 199, 89, 110, 195, 62, 134, 21, 179, 247, 238, 14, 188, 181, 110, 68, 123, 104, 180, 13, 224, 126, 126, 197, 175, 15, 10, 21, 13, 52, 132, 172, 241, 121, 20, 152, 135, 139, 30, 106, 85, 16, 123, 212, 179, 189, 37, 237, 139, 45, 248, 83, 70, 14, 234, 82, 234, 229, 157, 8 ])
-        };
-        let proof_batches: vec::Vec<(ProofData, CommitmentData)> = vec::Vec::from([(proof_data, new_proof_commitment)]);
-    
-        assert_err!(Infimum::commit_outcome(RuntimeOrigin::signed(0), proof_batches, None), Error::<Test>::MalformedProof);
+        assert_eq!(Infimum::interaction_batch_size(0), Some(5));
+        assert_eq!(Infimum::tally_batch_size(0), Some(2));
+
+        // Batch sizes which overflow are not reported.
+        Polls::<Test>::mutate(0, |poll| {
+            let poll = poll.as_mut().unwrap();
+            poll.config.process_subtree_depth = 14;
+            poll.config.tally_subtree_depth = 32;
+        });
+        assert_eq!(Infimum::interaction_batch_size(0), None);
+        assert_eq!(Infimum::tally_batch_size(0), None);
     })
 }
 
-/// An valid message processing proof with an invalid commitment should be rejected.
+/// The verification of a stored outcome should be replayable.
 #[test]
-fn commit_outcome_invalid_commitment()
+fn commit_outcome_replay()
 {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
@@ -761,49 +4583,56 @@ fn commit_outcome_invalid_commitment()
         let (alice_pk, alice_vk) = get_coordinator_data();
         let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
 
-        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), alice_pk, alice_vk));
+        register_coordinator(0, alice_pk, alice_vk);
         assert_ok!(
             Infimum::create_poll(
                 RuntimeOrigin::signed(0),
-                signup_period,
-                voting_period,
-                registration_depth,
-                interaction_depth,
-                process_subtree_depth,
-                tally_subtree_depth,
-                vote_option_tree_depth,
-                vote_options
+                poll_config(
+                    signup_period,
+                    voting_period,
+                    registration_depth,
+                    interaction_depth,
+                    process_subtree_depth,
+                    tally_subtree_depth,
+                    vote_option_tree_depth,
+                    vote_options
+                )
             )
         );
 
-        run_to_block(2);
-
         for (origin, pk) in &get_participants()
         {
             assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
         }
 
-        run_to_block(14);
+        run_to_block(1 + signup_period);
         assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
 
-        let (_pk, bob_shared_pk, message_data) = get_participant();
-
-        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, bob_shared_pk, message_data));
+        let scenario = get_poll_scenario(1);
+        for (pk, data) in &scenario.interactions
+        {
+            assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, *pk, interaction_cipher(data)));
+        }
 
-        run_to_block(26);
+        run_to_block(2 + signup_period + voting_period);
         assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+        assert!(!Infimum::replay_poll_outcome(0));
 
-        let (proof_data, _c, _tpf, _tc) = get_proof();
-        let new_proof_commitment: HashBytes = [1, 191, 85, 98, 25, 92, 104, 227, 66, 252, 50, 63, 42, 27, 108, 81, 67, 38, 115, 38, 128, 126, 14, 99, 203, 194, 61, 124, 1, 119, 164, 65];
-        let proof_batches: vec::Vec<(ProofData, CommitmentData)> = vec::Vec::from([(proof_data, new_proof_commitment)]);
-    
-        assert_err!(Infimum::commit_outcome(RuntimeOrigin::signed(0), proof_batches, None), Error::<Test>::MalformedProof);
+        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), 0, scenario.proof_batches.try_into().unwrap(), scenario.outcome));
+        assert!(Infimum::replay_poll_outcome(0));
+
+        // Tampering with the stored outcome data should be detected.
+        PollOutcomes::<Test>::mutate(0, |outcome| {
+            let outcome = outcome.as_mut().unwrap();
+            outcome.tally_results[0] += 1;
+        });
+        assert!(!Infimum::replay_poll_outcome(0));
     })
 }
 
-/// A valid message processing proof with mismatched data should be rejected.
+/// Progress events should be emitted for every verified proof.
 #[test]
-fn commit_outcome_mismatched_state()
+fn commit_outcome_progress_events()
 {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
@@ -811,49 +4640,56 @@ fn commit_outcome_mismatched_state()
         let (alice_pk, alice_vk) = get_coordinator_data();
         let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
 
-        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), alice_pk, alice_vk));
+        register_coordinator(0, alice_pk, alice_vk);
         assert_ok!(
             Infimum::create_poll(
                 RuntimeOrigin::signed(0),
-                signup_period,
-                voting_period,
-                registration_depth,
-                interaction_depth,
-                process_subtree_depth,
-                tally_subtree_depth,
-                vote_option_tree_depth,
-                vote_options
+                poll_config(
+                    signup_period,
+                    voting_period,
+                    registration_depth,
+                    interaction_depth,
+                    process_subtree_depth,
+                    tally_subtree_depth,
+                    vote_option_tree_depth,
+                    vote_options
+                )
             )
         );
 
-        run_to_block(2);
-
-        let bob_pk = PublicKey {
-            x: [ 1, 65, 89, 247, 81, 66, 57, 66, 160, 59, 9, 185, 3, 52, 188, 122, 132, 221, 26, 200, 129, 243, 234, 120, 128, 23, 19, 96, 94, 154, 207, 196 ],
-            y: [ 38, 38, 57, 70, 162, 8, 198, 245, 211, 231, 101, 158, 63, 226, 172, 117, 156, 26, 3, 50, 0, 241, 20, 66, 227, 150, 160, 78, 249, 106, 140, 69 ]
-        };
-        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(1), 0, bob_pk));
+        for (origin, pk) in &get_participants()
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
+        }
 
-        run_to_block(14);
+        run_to_block(1 + signup_period);
         assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
 
-        let (_pk, bob_shared_pk, message_data) = get_participant();
-
-        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, bob_shared_pk, message_data));
+        let scenario = get_poll_scenario(1);
+        for (pk, data) in &scenario.interactions
+        {
+            assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, *pk, interaction_cipher(data)));
+        }
 
-        run_to_block(26);
+        run_to_block(2 + signup_period + voting_period);
         assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
 
-        let (proof_data, new_proof_commitment, _tpf, _tc) = get_proof();
-        let proof_batches: vec::Vec<(ProofData, CommitmentData)> = vec::Vec::from([(proof_data, new_proof_commitment)]);
-    
-        assert_err!(Infimum::commit_outcome(RuntimeOrigin::signed(0), proof_batches, None), Error::<Test>::MalformedProof);
+        // Commit each of the proofs separately.
+        for (index, batch) in scenario.proof_batches.iter().enumerate()
+        {
+            assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), index as u32, vec::Vec::from([batch.clone()]).try_into().unwrap(), None));
+        }
+
+        System::assert_has_event(Event::ProcessProgress { poll_id: 0, batches_done: 1, batches_total: 1 }.into());
+        System::assert_has_event(Event::TallyProgress { poll_id: 0, batches_done: 1, batches_total: 2 }.into());
+        System::assert_has_event(Event::TallyProgress { poll_id: 0, batches_done: 2, batches_total: 2 }.into());
     })
 }
 
-/// A valid tally proof should successfully chain to a valid message processing proof.
+/// A full set of valid tally proofs should be verified in a single batch, and a batch holding an invalid proof
+/// should be rejected without advancing the commitment.
 #[test]
-fn commit_outcome_full_batch()
+fn commit_tally_proofs_batched()
 {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
@@ -861,208 +4697,238 @@ fn commit_outcome_full_batch()
         let (alice_pk, alice_vk) = get_coordinator_data();
         let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
 
-        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), alice_pk, alice_vk));
-        assert_ok!(
-            Infimum::create_poll(
-                RuntimeOrigin::signed(0),
-                signup_period,
-                voting_period,
-                registration_depth,
-                interaction_depth,
-                process_subtree_depth,
-                tally_subtree_depth,
-                vote_option_tree_depth,
-                vote_options
-            )
-        );
-
-        run_to_block(2);
+        register_coordinator(0, alice_pk, alice_vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
 
         for (origin, pk) in &get_participants()
         {
             assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
         }
 
-        run_to_block(14);
+        run_to_block(1 + signup_period);
         assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
 
-        let (_pk, bob_shared_pk, message_data) = get_participant();
-
-        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, bob_shared_pk, message_data));
+        let scenario = get_poll_scenario(1);
+        for (pk, data) in &scenario.interactions
+        {
+            assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, *pk, interaction_cipher(data)));
+        }
 
-        run_to_block(26);
+        run_to_block(2 + signup_period + voting_period);
         assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
 
-        let (process_proof_data, process_commitment, tally_proof_data, tally_commitment) = get_proof();
-        let proof_batches: vec::Vec<(ProofData, CommitmentData)> = vec::Vec::from([(process_proof_data, process_commitment), (tally_proof_data, tally_commitment)]);
-
-        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), proof_batches, None));
-        assert_eq!(Infimum::polls(0).unwrap().state.commitment.process, (1, process_commitment));
-        assert_eq!(Infimum::polls(0).unwrap().state.commitment.tally, (1, tally_commitment));
+        let process_batch = scenario.proof_batches[0].clone();
+        let tally_batches = scenario.proof_batches[1..].to_vec();
+
+        // Tally proofs are only accepted once every process proof is verified, and only from the coordinator.
+        assert_err!(Infimum::commit_tally_proofs(RuntimeOrigin::signed(0), 0, 0, vec::Vec::from([process_batch.clone()]).try_into().unwrap()), Error::<Test>::ProcessProofsIncomplete);
+        assert_err!(Infimum::commit_tally_proofs(RuntimeOrigin::signed(0), 0, 0, tally_batches.clone().try_into().unwrap()), Error::<Test>::ProcessProofsIncomplete);
+        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), 0, vec::Vec::from([process_batch]).try_into().unwrap(), None));
+        assert_err!(Infimum::commit_tally_proofs(RuntimeOrigin::signed(1), 0, 1, tally_batches.clone().try_into().unwrap()), Error::<Test>::CoordinatorNotRegistered);
+        assert_err!(Infimum::commit_tally_proofs(RuntimeOrigin::signed(0), 0, 1, Default::default()), Error::<Test>::MalformedInput);
+
+        // A batch in which a single proof is invalid is rejected as a whole.
+        let commitment = Infimum::polls(0).unwrap().state.commitment;
+        let mut mutated = tally_batches.clone();
+        mutated[1].0 = tally_batches[0].0.clone();
+        assert_ok!(Infimum::commit_tally_proofs(RuntimeOrigin::signed(0), 0, 1, mutated.try_into().unwrap()));
+        System::assert_last_event(Event::ProofRejected { poll_id: 0, kind: ProofKind::Tally, attempt: 1 }.into());
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment, commitment);
+
+        // The full set of tally proofs advances the commitment at once.
+        assert_ok!(Infimum::commit_tally_proofs(RuntimeOrigin::signed(0), 0, 1, tally_batches.clone().try_into().unwrap()));
+        System::assert_has_event(Event::TallyProgress { poll_id: 0, batches_done: 1, batches_total: 2 }.into());
+        System::assert_has_event(Event::TallyProgress { poll_id: 0, batches_done: 2, batches_total: 2 }.into());
+        assert!(Infimum::polls(0).unwrap().is_proven());
+
+        // Resubmitting the verified proofs is benign and free of charge, as with `commit_outcome`.
+        let error = Infimum::commit_tally_proofs(RuntimeOrigin::signed(0), 0, 1, tally_batches.try_into().unwrap()).unwrap_err();
+        assert_eq!(error.error, Error::<Test>::ProofAlreadyAccepted.into());
+        assert_eq!(error.post_info.pays_fee, Pays::No);
+
+        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), 3, Default::default(), scenario.outcome));
+        assert_eq!(Infimum::polls(0).unwrap().state.outcome, scenario.expected);
     })
 }
 
-/// A partial chain of valid proofs should be successfully verified, but not produce an outcome.
+/// A poll should be enqueued for a merge at each phase transition which permits one, and drained once neither of
+/// its state trees may be merged.
 #[test]
-fn commit_outcome_partial_success()
+fn keeper_queue_merge_lifecycle()
 {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
 
-        let (alice_pk, alice_vk) = get_coordinator_data();
+        let (pk, vk) = get_coordinator_data();
         let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
 
-        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), alice_pk, alice_vk));
-        assert_ok!(
-            Infimum::create_poll(
-                RuntimeOrigin::signed(0),
-                signup_period,
-                voting_period,
-                registration_depth,
-                interaction_depth,
-                process_subtree_depth,
-                tally_subtree_depth,
-                vote_option_tree_depth,
-                vote_options
-            )
-        );
-
-        run_to_block(2);
+        register_coordinator(0, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
 
-        for (origin, pk) in &get_participants()
+        for (origin, participant) in &get_participants()
         {
-            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *participant));
         }
+        assert!(Infimum::needs_merge().is_empty());
 
-        run_to_block(14);
-        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
-
-        let (_pk, bob_shared_pk, message_data) = get_participant();
+        // The registration tree may be merged once the signup period ends.
+        run_to_block(1 + signup_period);
+        assert_eq!(Infimum::needs_merge().into_inner(), vec::Vec::from([0]));
+        assert!(Infimum::needs_nullification_check().is_empty());
 
-        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, bob_shared_pk, message_data));
+        // A failed call leaves the poll enqueued.
+        assert_err!(Infimum::merge_poll_state(RuntimeOrigin::signed(1)), Error::<Test>::CoordinatorNotRegistered);
+        assert_eq!(Infimum::needs_merge().into_inner(), vec::Vec::from([0]));
 
-        run_to_block(26);
         assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+        assert!(Infimum::needs_merge().is_empty());
 
-        let (process_proof_data, process_commitment, tally_proof_data, tally_commitment) = get_proof();
-        let proof_batches: vec::Vec<(ProofData, CommitmentData)> = vec::Vec::from([(process_proof_data, process_commitment), (tally_proof_data, tally_commitment)]);
-        let scenario = get_poll_scenario(0);
+        // The interaction tree may be merged once the poll is sealed.
+        let (_, shared_pk, message_data) = get_participant();
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, interaction_cipher(&message_data)));
 
-        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), proof_batches, scenario.outcome));
-        assert_eq!(Infimum::polls(0).unwrap().state.commitment.process, (1, process_commitment));
-        assert_eq!(Infimum::polls(0).unwrap().state.commitment.tally, (1, tally_commitment));
-        assert_eq!(Infimum::polls(0).unwrap().state.outcome, scenario.expected);
+        run_to_block(2 + signup_period + voting_period);
+        assert_eq!(Infimum::needs_merge().into_inner(), vec::Vec::from([0]));
+        assert!(Infimum::needs_nullification_check().is_empty());
+
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+        assert!(Infimum::needs_merge().is_empty());
+        assert_ok!(Infimum::do_try_state());
     })
 }
 
-/// An out of order chain of proofs should be rejected.
+/// A poll should be enqueued for nullification once it ends a period without participation, and for reaping once
+/// its activation timeout elapses, and be drained by the call which acts on it.
 #[test]
-fn commit_outcome_permuted()
+fn keeper_queue_nullification_and_reaping()
 {
     new_test_ext().execute_with(|| {
         System::set_block_number(1);
 
-        let (alice_pk, alice_vk) = get_coordinator_data();
+        let (pk, vk) = get_coordinator_data();
         let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+        let config = poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options);
 
-        assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), alice_pk, alice_vk));
-        assert_ok!(
-            Infimum::create_poll(
-                RuntimeOrigin::signed(0),
-                signup_period,
-                voting_period,
-                registration_depth,
-                interaction_depth,
-                process_subtree_depth,
-                tally_subtree_depth,
-                vote_option_tree_depth,
-                vote_options
-            )
-        );
-
-        run_to_block(2);
+        register_coordinator(0, pk, vk.clone());
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), config.clone()));
 
-        for (origin, pk) in &get_participants()
+        for coordinator in [100, 101]
         {
-            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
+            let mut pending = config.clone();
+            pending.pending = true;
+            register_coordinator(coordinator, pk, vk.clone());
+            assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(coordinator), pending));
         }
 
-        run_to_block(14);
-        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+        // The poll without registrations may be nullified once its signup period ends.
+        run_to_block(1 + signup_period);
+        assert_eq!(Infimum::needs_nullification_check().into_inner(), vec::Vec::from([0]));
+        assert!(Infimum::needs_merge().is_empty());
 
-        let (_pk, bob_shared_pk, message_data) = get_participant();
+        assert_ok!(Infimum::nullify_poll(RuntimeOrigin::signed(0)));
+        assert!(Infimum::needs_nullification_check().is_empty());
 
-        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, bob_shared_pk, message_data));
+        // The pending polls may be reaped once their activation timeout elapses, unless they are activated.
+        let timeout = <Test as crate::Config>::ActivationTimeout::get();
+        run_to_block(timeout);
+        assert!(Infimum::reapable().is_empty());
 
-        run_to_block(26);
-        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+        run_to_block(1 + timeout);
+        assert_eq!(Infimum::reapable().into_inner(), vec::Vec::from([1, 2]));
 
-        let (process_proof_data, process_commitment, tally_proof_data, tally_commitment) = get_proof();
-        let proof_batches: vec::Vec<(ProofData, CommitmentData)> = vec::Vec::from([(tally_proof_data, tally_commitment), (process_proof_data, process_commitment)]);
+        assert_ok!(Infimum::activate_poll(RuntimeOrigin::signed(101), 2));
+        assert_eq!(Infimum::reapable().into_inner(), vec::Vec::from([1]));
 
-        assert_err!(Infimum::commit_outcome(RuntimeOrigin::signed(0), proof_batches, None), Error::<Test>::MalformedProof);
+        assert_err!(Infimum::reap_poll(RuntimeOrigin::signed(3), 2), Error::<Test>::PollNotPending);
+        assert_ok!(Infimum::reap_poll(RuntimeOrigin::signed(3), 1));
+        assert!(Infimum::reapable().is_empty());
+
+        // The activated poll is enqueued anew once it ends its signup period without registrations.
+        run_to_block(1 + timeout + signup_period);
+        assert_eq!(Infimum::needs_nullification_check().into_inner(), vec::Vec::from([2]));
+        assert_ok!(Infimum::do_try_state());
     })
 }
 
-macro_rules! invoke_test_poll_scenario {
-    ($test_name:ident, $scenario_index:expr) =>
-    {
-        #[test]
-        fn $test_name() {
-            new_test_ext().execute_with(|| {
-                System::set_block_number(1);
+/// Polls enqueued beyond `MaxKeeperQueueLen` in a single block should be held in the backlog, and moved into the
+/// queue as it is drained, without ever being enqueued twice.
+#[test]
+fn keeper_queue_bounded()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        MaxKeeperQueueLen::set(2);
 
-                let (alice_pk, alice_vk) = get_coordinator_data();
-                let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+        let config = poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options);
 
-                assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(0), alice_pk, alice_vk));
-                assert_ok!(
-                    Infimum::create_poll(
-                        RuntimeOrigin::signed(0),
-                        signup_period,
-                        voting_period,
-                        registration_depth,
-                        interaction_depth,
-                        process_subtree_depth,
-                        tally_subtree_depth,
-                        vote_option_tree_depth,
-                        vote_options
-                    )
-                );
+        for coordinator in [0, 100, 101]
+        {
+            register_coordinator(coordinator, pk, vk.clone());
+            assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(coordinator), config.clone()));
+        }
 
-                for (origin, pk) in &get_participants()
-                {
-                    assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *pk));
-                }
+        // All three polls end their signup period without registrations in the same block.
+        run_to_block(1 + signup_period);
+        assert_eq!(Infimum::needs_nullification_check().into_inner(), vec::Vec::from([0, 1]));
+        assert!(KeeperBacklog::<Test>::contains_key(KeeperAction::NullificationCheck, 2));
+        assert_ok!(Infimum::do_try_state());
 
-                run_to_block(1 + signup_period);
-                assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+        // Closing the polls without interactions enqueues none of them twice.
+        run_to_block(2 + signup_period + voting_period);
+        assert_eq!(Infimum::needs_nullification_check().into_inner(), vec::Vec::from([0, 1]));
+        assert_eq!(KeeperBacklog::<Test>::iter_prefix(KeeperAction::NullificationCheck).count(), 1);
 
-                let scenario = get_poll_scenario($scenario_index);
-                for (pk, data) in &scenario.interactions
-                {
-                    assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, *pk, *data));
-                }
+        // Draining the queue moves the backlog into it.
+        assert_ok!(Infimum::nullify_poll(RuntimeOrigin::signed(0)));
+        assert_eq!(Infimum::needs_nullification_check().into_inner(), vec::Vec::from([1, 2]));
+        assert_eq!(KeeperBacklog::<Test>::iter_prefix(KeeperAction::NullificationCheck).count(), 0);
 
-                if scenario.interactions.len() > 0
-                {
-                    run_to_block(2 + signup_period + voting_period);
-                    assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+        assert_ok!(Infimum::nullify_poll(RuntimeOrigin::signed(100)));
+        assert_ok!(Infimum::nullify_poll(RuntimeOrigin::signed(101)));
+        assert!(Infimum::needs_nullification_check().is_empty());
+        assert_ok!(Infimum::do_try_state());
+    })
+}
 
-                    if scenario.proof_batches.len() > 0
-                    {
-                        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), scenario.proof_batches, scenario.outcome));
-                    }
-                }
+/// Poll transitions beyond `MaxInitializeWeight` should be deferred to the following blocks, in order.
+#[test]
+fn poll_transitions_deferred_beyond_initialize_weight()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
 
-                assert_eq!(Infimum::polls(0).unwrap().state.outcome, scenario.expected);
-            })
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+        let config = poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options);
+        let closes_at = 2 + signup_period + voting_period;
+
+        for coordinator in [0, 1]
+        {
+            register_coordinator(coordinator, pk, vk.clone());
+            assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(coordinator), config.clone()));
         }
-    };
+        run_to_block(closes_at - 1);
+
+        // The cursor costs 2, the schedules of a block 6, and sealing a poll 9: one poll is sealed per block.
+        DbWeight::set(RuntimeDbWeight { read: 1, write: 1 });
+        MaxInitializeWeight::set(Weight::from_parts(17, 0));
+
+        run_to_block(closes_at);
+        assert!(Infimum::polls(0).unwrap().is_sealed());
+        assert!(!Infimum::polls(1).unwrap().is_sealed());
+        assert_eq!(Infimum::poll_closures(closes_at), vec::Vec::from([1]));
+        assert_eq!(Infimum::transitions_deferred_from(), Some(closes_at));
+
+        // The deferred poll is sealed first, leaving no weight for the schedules of the current block.
+        run_to_block(closes_at + 1);
+        assert!(Infimum::polls(1).unwrap().is_sealed());
+        System::assert_has_event(Event::PollClosed { poll_id: 1, registrations: 0, interactions: 0 }.into());
+        assert_eq!(Infimum::transitions_deferred_from(), Some(closes_at + 1));
+
+        run_to_block(closes_at + 2);
+        assert_eq!(Infimum::transitions_deferred_from(), None);
+        assert_ok!(Infimum::do_try_state());
+    })
 }
-
-// A full chain of valid proofs should produce the correct outcome.
-invoke_test_poll_scenario!(commit_outcome_success, 1);
-
-// Correct outcome after every participant interacts with the poll.
-invoke_test_poll_scenario!(commit_outcome_full_round, 2);
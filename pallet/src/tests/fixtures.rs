@@ -0,0 +1,131 @@
+//! End-to-end tests which run the proof fixtures of the test circuits through the full pipeline of a poll. The
+//! verification keys, registrations, interactions, proofs and outcome are those of `get_poll_scenario(2)`, which
+//! were generated by the MACI-compatible circuits at the depths of `get_poll_config`.
+//!
+//! Enabled by the `proof-fixtures` feature, and the regression gate for any change to the preparation of the public
+//! inputs or the verification of a poll outcome.
+
+use frame_support::{assert_ok, traits::Get};
+use crate::{mock::*, Event};
+use crate::poll::{ProofBatches, ProofKind};
+use crate::tests::{
+    get_coordinator_data,
+    get_participants,
+    get_poll_config,
+    get_poll_scenario,
+    interaction_cipher,
+    poll_config,
+    register_coordinator,
+    run_to_block,
+    PollScenario
+};
+
+/// Register the fixture coordinator and participants, cast the fixture interactions and merge the state trees of
+/// the poll, returning the scenario and the number of expected message processing proofs.
+fn fixture_poll() -> (PollScenario, usize)
+{
+    System::set_block_number(1);
+
+    let (pk, vk) = get_coordinator_data();
+    let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+    register_coordinator(0, pk, vk);
+    assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
+
+    for (origin, participant) in &get_participants()
+    {
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *participant));
+    }
+
+    run_to_block(1 + signup_period);
+    assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+    let scenario = get_poll_scenario(2);
+    for (pk, data) in &scenario.interactions
+    {
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, *pk, interaction_cipher(data)));
+    }
+
+    run_to_block(2 + signup_period + voting_period);
+    assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+    let commitment = Infimum::polls(0).unwrap().state.commitment;
+    assert_eq!(commitment.process.expected + commitment.tally.expected, scenario.proof_batches.len() as u32);
+
+    (scenario, commitment.process.expected as usize)
+}
+
+/// Every fixture proof should verify in turn, and the fixture outcome should conclude the poll with its winner.
+#[test]
+fn fixture_full_pipeline()
+{
+    new_test_ext().execute_with(|| {
+        let (scenario, process_proofs) = fixture_poll();
+        let total = scenario.proof_batches.len();
+
+        for (index, batch) in scenario.proof_batches.iter().enumerate()
+        {
+            let batches: ProofBatches<Test> = sp_std::vec![batch.clone()].try_into().unwrap();
+            assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), index as u32, batches, None));
+
+            let commitment = Infimum::polls(0).unwrap().state.commitment;
+            if index < process_proofs
+            {
+                System::assert_has_event(Event::ProcessProgress { poll_id: 0, batches_done: index as u32 + 1, batches_total: process_proofs as u32 }.into());
+                assert_eq!(commitment.process.current, batch.1);
+            }
+            else
+            {
+                System::assert_has_event(Event::TallyProgress { poll_id: 0, batches_done: (index - process_proofs) as u32 + 1, batches_total: (total - process_proofs) as u32 }.into());
+                assert_eq!(commitment.tally.current, batch.1);
+            }
+        }
+
+        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), total as u32, Default::default(), scenario.outcome));
+        assert_eq!(Infimum::polls(0).unwrap().state.outcome, scenario.expected);
+        assert!(System::events().iter().any(|record| matches!(
+            record.event,
+            RuntimeEvent::Infimum(Event::PollOutcome { poll_id: 0, outcome_index, .. }) if Some(outcome_index) == scenario.expected
+        )));
+        assert_ok!(Infimum::do_try_state());
+    })
+}
+
+/// A fixture proof whose points were tampered with should be rejected, without disturbing the verified proofs.
+#[test]
+fn fixture_mutated_proof_rejected()
+{
+    new_test_ext().execute_with(|| {
+        let (scenario, process_proofs) = fixture_poll();
+
+        // Graft the final point of the last proof onto each of the others, such that every point remains on the
+        // curve but no proof verifies.
+        let last = scenario.proof_batches.len() - 1;
+        for (index, batch) in scenario.proof_batches.iter().enumerate().take(last)
+        {
+            let mut mutated = batch.clone();
+            mutated.0.pi_c = scenario.proof_batches[last].0.pi_c.clone();
+
+            let kind = if index < process_proofs { ProofKind::Process } else { ProofKind::Tally };
+            assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), index as u32, sp_std::vec![mutated].try_into().unwrap(), None));
+            System::assert_last_event(Event::ProofRejected { poll_id: 0, kind, attempt: index as u32 + 1 }.into());
+
+            let commitment = Infimum::polls(0).unwrap().state.commitment;
+            assert_eq!(commitment.process.completed + commitment.tally.completed, index as u32);
+
+            // The genuine proof is accepted in place of the mutated one.
+            assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), index as u32, sp_std::vec![batch.clone()].try_into().unwrap(), None));
+        }
+
+        let mut mutated = scenario.proof_batches[last].clone();
+        mutated.0.pi_c = scenario.proof_batches[0].0.pi_c.clone();
+        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), last as u32, sp_std::vec![mutated].try_into().unwrap(), scenario.outcome.clone()));
+        System::assert_last_event(Event::ProofRejected { poll_id: 0, kind: ProofKind::Tally, attempt: last as u32 + 1 }.into());
+        assert_eq!(Infimum::polls(0).unwrap().state.outcome, None);
+
+        // Having exceeded `MaxProofFailures`, the poll cools off before the genuine proof is accepted.
+        run_to_block(System::block_number() + <Test as crate::Config>::ProofFailureCooldown::get());
+        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), last as u32, sp_std::vec![scenario.proof_batches[last].clone()].try_into().unwrap(), scenario.outcome));
+        assert_eq!(Infimum::polls(0).unwrap().state.outcome, scenario.expected);
+    })
+}
@@ -0,0 +1,145 @@
+use sp_runtime::traits::Zero;
+use frame_support::{assert_ok, assert_err, instances::Instance1, traits::Hooks};
+use crate::{
+    mock_instances::*,
+    Config,
+    Coordinators,
+    Error,
+    Event,
+    NextPollId,
+    Polls
+};
+use crate::poll::{CoordKeyHash, PollConfigOf};
+use crate::tests::{
+    get_coordinator_signed_data,
+    get_poll_config
+};
+
+/// The poll creation parameters of the test data, for the given instance.
+fn poll_config<I: 'static>() -> PollConfigOf<TestInstances, I>
+where
+    TestInstances: Config<I>
+{
+    let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+    PollConfigOf {
+        signup_period,
+        voting_period,
+        registration_depth,
+        interaction_depth,
+        process_subtree_depth,
+        tally_subtree_depth,
+        vote_option_tree_depth,
+        vote_options: vote_options.try_into().unwrap(),
+        fee_allowance: Zero::zero(),
+        reject_coordinator_key: false,
+        allow_early_interactions: false,
+        message_words: 10,
+        pending: false,
+        collect_intents: false,
+        unique_keys: false,
+        compact_interactions: false,
+        coord_key_hash: CoordKeyHash::Poseidon2Coords,
+        circuit_id: None,
+        option_calls: Default::default()
+    }
+}
+
+/// The state of each instance should be isolated, such that a coordinator or poll of one instance does not
+/// exist in the other.
+#[test]
+fn instances_isolated()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk, signature) = get_coordinator_signed_data(0);
+
+        // Registering with one instance does not register with the other.
+        assert_ok!(Community::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk.clone(), signature));
+        assert!(Coordinators::<TestInstances>::contains_key(0));
+        assert!(!Coordinators::<TestInstances, Instance1>::contains_key(0));
+        assert_err!(
+            Council::create_poll(RuntimeOrigin::signed(0), poll_config()),
+            Error::<TestInstances, Instance1>::CoordinatorNotRegistered
+        );
+
+        // A poll created in one instance does not exist in the other.
+        assert_ok!(Community::create_poll(RuntimeOrigin::signed(0), poll_config()));
+        assert!(Community::polls(0).is_some());
+        assert!(Council::polls(0).is_none());
+        assert_eq!(Polls::<TestInstances, Instance1>::count(), 0);
+
+        // The same account may coordinate in both instances, whose poll ids are assigned independently.
+        assert_ok!(Council::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk.clone(), signature));
+        assert_ok!(Council::create_poll(RuntimeOrigin::signed(0), poll_config()));
+        assert_eq!(Council::polls(0).map(|poll| poll.coordinator), Some(0));
+        assert_eq!(Polls::<TestInstances>::count(), 1);
+        assert_eq!(Polls::<TestInstances, Instance1>::count(), 1);
+
+        // Events are deposited by the instance which emitted them.
+        System::assert_has_event(Event::<TestInstances, Instance1>::CoordinatorRegistered { who: 0, public_key: pk, verify_key: vk.clone() }.into());
+        System::assert_has_event(Event::<TestInstances>::CoordinatorRegistered { who: 0, public_key: pk, verify_key: vk }.into());
+
+        // Closing the poll of one instance does not affect the other.
+        Community::on_initialize(26);
+        assert!(Community::polls(0).map_or(false, |poll| poll.state.sealed));
+        assert!(Council::polls(0).map_or(false, |poll| !poll.state.sealed));
+    })
+}
+
+/// Each instance should be bounded by its own constants.
+#[test]
+fn instances_configured_independently()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk, signature) = get_coordinator_signed_data(0);
+        assert_ok!(Community::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk.clone(), signature));
+        assert_ok!(Council::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk, signature));
+
+        // The vote options of the test data exceed neither instance's `MaxVoteOptions`, but the council
+        // admits no more.
+        let mut config = poll_config::<Instance1>();
+        assert!(config.vote_options.try_push(25).is_err());
+
+        // A registration tree deeper than `MaxPollRegistrations` is only permitted by the community instance.
+        let mut community_config = poll_config();
+        community_config.registration_depth = 11;
+        config.registration_depth = 11;
+        assert_err!(
+            Council::create_poll(RuntimeOrigin::signed(0), config),
+            Error::<TestInstances, Instance1>::PollConfigInvalid
+        );
+        assert_ok!(Community::create_poll(RuntimeOrigin::signed(0), community_config));
+        assert_eq!(Polls::<TestInstances, Instance1>::count(), 0);
+    })
+}
+
+/// An instance which indexes polls with `u64` should assign and look up ids beyond `u32::MAX`.
+#[test]
+fn instances_wide_poll_ids()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk, signature) = get_coordinator_signed_data(0);
+        assert_ok!(Council::register_as_coordinator(RuntimeOrigin::signed(0), pk, vk, signature));
+
+        // Assume the council has exhausted the ids which fit in a `u32`.
+        let poll_id = u64::from(u32::MAX) + 1;
+        NextPollId::<TestInstances, Instance1>::put(poll_id);
+
+        assert_ok!(Council::create_poll(RuntimeOrigin::signed(0), poll_config::<Instance1>()));
+        assert_eq!(Council::polls(poll_id).map(|poll| poll.index), Some(poll_id));
+        assert_eq!(Council::next_poll_id(), poll_id + 1);
+        assert_eq!(Council::coordinators(0).and_then(|coordinator| coordinator.last_poll), Some(poll_id));
+        assert_eq!(Council::get_coordinator_poll_ids(0), vec![ poll_id ]);
+        assert_eq!(Council::active_polls(poll_id - 1, 8), vec![ poll_id ]);
+        assert!(Council::polls(0).is_none());
+
+        // The id space of the community instance is unaffected.
+        assert_eq!(Community::next_poll_id(), 0);
+    })
+}
@@ -0,0 +1,199 @@
+use sp_std::vec;
+use ark_bn254::{Fr, G1Affine, G2Affine};
+use ark_ec::{AffineRepr, CurveGroup};
+use ark_serialize::CanonicalSerialize;
+use frame_support::{assert_ok, assert_err};
+use crate::{
+    mock::*,
+    pallet::{verify_proof, verify_proofs_batched},
+    Error
+};
+use crate::tests::{
+    get_coordinator_data,
+    get_coordinator_data_malformed,
+    get_coordinator_signed_data
+};
+use crate::poll::{
+    MAX_PUBLIC_INPUTS,
+    PROCESS_PUBLIC_INPUTS,
+    TALLY_PUBLIC_INPUTS,
+    ProofData,
+    PublicKey,
+    VerifyKey
+};
+
+/// The point (0, -1) lies on the curve, but has order two.
+const SMALL_ORDER_PK: PublicKey = PublicKey {
+    x: [0u8; 32],
+    y: [ 48, 100, 78, 114, 225, 49, 160, 41, 184, 80, 69, 182, 129, 129, 88, 93, 40, 51, 232, 72, 121, 185, 112, 145, 67, 225, 245, 147, 240, 0, 0, 0 ]
+};
+
+const OFF_CURVE_PK: PublicKey = PublicKey {
+    x: [ 1, 65, 89, 247, 81, 66, 57, 66, 160, 59, 9, 185, 3, 52, 188, 122, 132, 221, 26, 200, 129, 243, 234, 120, 128, 23, 19, 96, 94, 154, 207, 196 ],
+    y: [ 38, 38, 57, 70, 162, 8, 198, 245, 211, 231, 101, 158, 63, 226, 172, 117, 156, 26, 3, 50, 0, 241, 20, 66, 227, 150, 160, 78, 249, 106, 140, 69 ]
+};
+
+/// Well formed coordinator keys should be accepted.
+#[test]
+fn coordinator_keys_valid()
+{
+    let (pk, vk) = get_coordinator_data();
+    assert_ok!(Infimum::validate_coordinator_keys(&pk, &vk));
+
+    let (pk, vk, _) = get_coordinator_signed_data(0);
+    assert_ok!(Infimum::validate_coordinator_keys(&pk, &vk));
+}
+
+/// Public keys which are not points in the prime order subgroup should be rejected.
+#[test]
+fn coordinator_keys_invalid_public_key()
+{
+    let (_, vk) = get_coordinator_data();
+
+    assert_err!(Infimum::validate_coordinator_keys(&OFF_CURVE_PK, &vk), Error::<Test>::MalformedKeys);
+    assert_err!(Infimum::validate_coordinator_keys(&SMALL_ORDER_PK, &vk), Error::<Test>::PublicKeyNotInSubgroup);
+
+    // Coordinates which are not canonical field elements.
+    let non_canonical_pk = PublicKey { x: [255u8; 32], y: [255u8; 32] };
+    assert_err!(Infimum::validate_coordinator_keys(&non_canonical_pk, &vk), Error::<Test>::MalformedKeys);
+}
+
+/// Verifying keys which are not deserializable, or exceed their bounds, should be rejected.
+#[test]
+fn coordinator_keys_invalid_verify_key()
+{
+    let (pk, vk) = get_coordinator_data();
+
+    // Empty keys.
+    let (_, empty) = get_coordinator_data_malformed();
+    assert_err!(Infimum::validate_coordinator_keys(&pk, &empty), Error::<Test>::MalformedKeys);
+
+    // A malformed tally key alongside a valid process key.
+    let mut malformed = vk.clone();
+    malformed.tally = empty.tally.clone();
+    assert_err!(Infimum::validate_coordinator_keys(&pk, &malformed), Error::<Test>::MalformedKeys);
+
+    // A truncated point.
+    let mut truncated = vk.clone();
+    truncated.process.beta_g2.pop();
+    assert_err!(Infimum::validate_coordinator_keys(&pk, &truncated), Error::<Test>::MalformedKeys);
+
+    // A point which is not on the curve.
+    let mut off_curve = vk.clone();
+    off_curve.process.alpha_g1[0] ^= 1;
+    assert_err!(Infimum::validate_coordinator_keys(&pk, &off_curve), Error::<Test>::MalformedKeys);
+
+    // Trailing bytes.
+    let mut padded = vk.clone();
+    padded.tally.delta_g2.push(0);
+    assert_err!(Infimum::validate_coordinator_keys(&pk, &padded), Error::<Test>::MalformedKeys);
+
+    // Too many public inputs.
+    let mut excessive = vk;
+    let point = excessive.process.gamma_abc_g1[0].clone();
+    excessive.process.gamma_abc_g1.resize(MAX_PUBLIC_INPUTS + 2, point);
+    assert_err!(Infimum::validate_coordinator_keys(&pk, &excessive), Error::<Test>::MalformedKeys);
+
+    // No public inputs.
+    let mut missing = get_coordinator_data().1;
+    missing.tally.gamma_abc_g1 = vec::Vec::new();
+    assert_err!(Infimum::validate_coordinator_keys(&pk, &missing), Error::<Test>::MalformedKeys);
+}
+
+/// The public key is validated before the verifying keys, such that its error takes precedence.
+#[test]
+fn coordinator_keys_first_error()
+{
+    let (_, empty) = get_coordinator_data_malformed();
+
+    assert_err!(Infimum::validate_coordinator_keys(&SMALL_ORDER_PK, &empty), Error::<Test>::PublicKeyNotInSubgroup);
+}
+
+/// Verifying keys whose number of public inputs does not match their circuit should be rejected.
+#[test]
+fn coordinator_keys_size_mismatch()
+{
+    let (pk, vk) = get_coordinator_data();
+
+    // Process and tally keys the wrong way round.
+    let mut swapped = vk.clone();
+    swapped.process = vk.tally.clone();
+    swapped.tally = vk.process.clone();
+    assert_err!(Infimum::validate_coordinator_keys(&pk, &swapped), Error::<Test>::VerifyKeySizeMismatch);
+
+    // A tally key with an additional public input, which is nevertheless bounded.
+    let mut extended = vk.clone();
+    let point = extended.tally.gamma_abc_g1[0].clone();
+    extended.tally.gamma_abc_g1.push(point);
+    assert_err!(Infimum::validate_coordinator_keys(&pk, &extended), Error::<Test>::VerifyKeySizeMismatch);
+
+    assert_eq!(vk.process.byte_len(), VerifyKey::expected_byte_len(PROCESS_PUBLIC_INPUTS));
+    assert_eq!(vk.tally.byte_len(), VerifyKey::expected_byte_len(TALLY_PUBLIC_INPUTS));
+}
+
+fn serialize_point<P: CanonicalSerialize>(point: P) -> vec::Vec<u8>
+{
+    let mut bytes = vec::Vec::new();
+    point.serialize_uncompressed(&mut bytes).expect("serialization into a vector is infallible; qed");
+    bytes
+}
+
+/// A verifying key of which every point is a curve generator, such that its trapdoor is known.
+fn generator_key(public_inputs: usize) -> VerifyKey
+{
+    let g1 = serialize_point(G1Affine::generator());
+    let g2 = serialize_point(G2Affine::generator());
+
+    VerifyKey {
+        alpha_g1: g1.clone(),
+        beta_g2: g2.clone(),
+        gamma_g2: g2.clone(),
+        delta_g2: g2,
+        gamma_abc_g1: vec::Vec::from_iter((0..=public_inputs).map(|_| g1.clone()))
+    }
+}
+
+/// A proof of the public inputs against `generator_key`, simulated with its trapdoor. With `B` the generator, the
+/// pairing equation `e(A, B) = e(alpha, beta) * e(inputs, gamma) * e(C, delta)` reduces to `a = 1 + l + c`, where
+/// `l` is one plus the sum of the public inputs.
+fn simulated_proof(a: Fr, public_inputs: &[Fr]) -> ProofData
+{
+    let l = public_inputs.iter().fold(Fr::from(1u64), |sum, input| sum + input);
+    let c = a - Fr::from(1u64) - l;
+
+    ProofData {
+        pi_a: serialize_point((G1Affine::generator() * a).into_affine()),
+        pi_b: serialize_point(G2Affine::generator()),
+        pi_c: serialize_point((G1Affine::generator() * c).into_affine())
+    }
+}
+
+/// A batch of valid proofs should verify, while a batch in which a single proof is invalid should be rejected as a
+/// whole, wherever the invalid proof lies among the valid ones.
+#[test]
+fn batched_verification_rejects_invalid_proof()
+{
+    let verify_key = generator_key(TALLY_PUBLIC_INPUTS);
+    let statements: vec::Vec<(vec::Vec<Fr>, ProofData)> = (0..4u64)
+        .map(|index| {
+            let public_inputs: vec::Vec<Fr> = (0..TALLY_PUBLIC_INPUTS as u64).map(|input| Fr::from(index * 16 + input)).collect();
+            let proof = simulated_proof(Fr::from(index + 2), &public_inputs);
+            (public_inputs, proof)
+        })
+        .collect();
+
+    for (public_inputs, proof) in statements.iter()
+    {
+        assert!(verify_proof(verify_key.clone(), public_inputs.clone(), proof.clone()));
+    }
+    assert!(verify_proofs_batched(verify_key.clone(), statements.clone()));
+
+    for invalid in 0..statements.len()
+    {
+        // The proof of the next statement, which attests to other public inputs.
+        let mut mixed = statements.clone();
+        mixed[invalid].1 = statements[(invalid + 1) % statements.len()].1.clone();
+        assert!(!verify_proof(verify_key.clone(), mixed[invalid].0.clone(), mixed[invalid].1.clone()));
+        assert!(!verify_proofs_batched(verify_key.clone(), mixed));
+    }
+}
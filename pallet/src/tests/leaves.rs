@@ -0,0 +1,51 @@
+use crate::poll::{compute_coordinator_key_hash, compute_message_leaf, compute_state_leaf, CoordKeyHash, HashBytes, PublicKey};
+use crate::tests::{get_coordinator_data, get_participant, interaction_cipher};
+
+/// State leaves should match the circomlibjs reference, i.e. `H4(x, y, credits, timestamp)`.
+#[test]
+fn state_leaf_reference()
+{
+    let (pk, _, _) = get_participant();
+
+    let expected_leaf: HashBytes = [ 35, 34, 96, 176, 245, 174, 151, 43, 202, 252, 131, 178, 78, 33, 178, 80, 254, 69, 201, 134, 108, 10, 227, 224, 160, 132, 115, 73, 238, 46, 191, 80 ];
+    assert_eq!(compute_state_leaf(&pk, 1, 1), Some(expected_leaf));
+
+    let expected_leaf: HashBytes = [ 40, 255, 184, 70, 68, 172, 25, 203, 196, 19, 153, 126, 212, 139, 42, 81, 208, 153, 136, 22, 187, 237, 9, 130, 125, 69, 190, 206, 153, 36, 149, 228 ];
+    assert_eq!(compute_state_leaf(&pk, 100, 1_700_000_000), Some(expected_leaf));
+}
+
+/// Message leaves should match the circomlibjs reference, i.e. `H4(H5(m[0..5]), H5(m[5..10]), x, y)`.
+#[test]
+fn message_leaf_reference()
+{
+    let (_, shared_pk, message) = get_participant();
+
+    let expected_leaf: HashBytes = [ 17, 227, 242, 122, 28, 83, 74, 247, 98, 202, 135, 226, 42, 94, 247, 78, 190, 144, 142, 101, 232, 193, 55, 62, 162, 125, 60, 140, 88, 92, 250, 160 ];
+    assert_eq!(compute_message_leaf(&shared_pk, &interaction_cipher(&message)), Some(expected_leaf));
+}
+
+/// Coordinator key hashes should match the circomlibjs reference for both conventions, i.e. `H2(x, y)` and `H1` of
+/// the key packed as by `packPoint`.
+#[test]
+fn coordinator_key_hash_reference()
+{
+    let mut one = [0u8; 32];
+    one[31] = 1;
+    let key = PublicKey { x: one, y: one };
+
+    let expected_hash: HashBytes = [ 0, 122, 243, 70, 226, 211, 4, 39, 158, 121, 224, 169, 243, 2, 63, 119, 18, 148, 167, 138, 203, 112, 231, 63, 144, 175, 226, 124, 173, 64, 30, 129 ];
+    assert_eq!(compute_coordinator_key_hash(&key, CoordKeyHash::Poseidon2Coords), Some(expected_hash));
+
+    // The sign of `x` is clear, such that the packed key is `y`.
+    let expected_hash: HashBytes = [ 41, 23, 97, 0, 234, 169, 98, 189, 193, 254, 108, 101, 77, 106, 60, 19, 14, 150, 164, 209, 22, 139, 51, 132, 139, 137, 125, 197, 2, 130, 1, 51 ];
+    assert_eq!(compute_coordinator_key_hash(&key, CoordKeyHash::PoseidonPacked), Some(expected_hash));
+
+    let (pk, _) = get_coordinator_data();
+
+    let expected_hash: HashBytes = [ 44, 10, 176, 169, 239, 152, 113, 116, 72, 51, 92, 220, 1, 237, 221, 179, 150, 219, 12, 214, 219, 163, 3, 162, 241, 224, 121, 170, 90, 116, 97, 79 ];
+    assert_eq!(compute_coordinator_key_hash(&pk, CoordKeyHash::Poseidon2Coords), Some(expected_hash));
+
+    // The sign of `x` is set, such that the packed key is `y + 2^255` reduced modulo `r`.
+    let expected_hash: HashBytes = [ 35, 225, 120, 168, 161, 142, 250, 141, 51, 78, 24, 78, 26, 207, 107, 10, 234, 108, 107, 11, 145, 253, 185, 73, 97, 171, 226, 105, 65, 57, 7, 254 ];
+    assert_eq!(compute_coordinator_key_hash(&pk, CoordKeyHash::PoseidonPacked), Some(expected_hash));
+}
@@ -0,0 +1,336 @@
+use frame_support::{
+    assert_ok,
+    storage::unhashed,
+    traits::{GetStorageVersion, NamedReservableCurrency, OnRuntimeUpgrade, ReservableCurrency, StorageVersion}
+};
+use crate::{
+    mock::*,
+    CircuitReferences,
+    NextPollId,
+    PollInteractionFrontier,
+    PollInteractionTree,
+    PollPhases,
+    PollRegistrationFrontier,
+    PollRegistrationTree,
+    Polls
+};
+use crate::migrations::{
+    v1::{v0, MigrateToV1},
+    v2::MigrateToV2,
+    v3::MigrateToV3,
+    v4::MigrateToV4,
+    v5::MigrateToV5,
+    v6::{v5, MigrateToV6},
+    v7::MigrateToV7,
+    v8::MigrateToV8,
+    v9::MigrateToV9,
+    v10::MigrateToV10,
+    v11::MigrateToV11
+};
+use crate::poll::{Poll, POLL_SCHEMA_VERSION};
+use crate::tests::{
+    get_coordinator_data,
+    get_participants,
+    get_poll_config,
+    get_poll_scenario,
+    interaction_cipher,
+    poll_config,
+    register_coordinator,
+    run_to_block,
+    PollScenario
+};
+
+/// The stage of its lifecycle at which a poll is migrated.
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum Stage
+{
+    /// The voting period is under way, and the interaction tree is partially filled.
+    Voting,
+
+    /// The state trees are merged, and the first of the proofs is verified.
+    Proving
+}
+
+/// Advance a poll over the interactions of scenario 2 to the given stage, returning the scenario.
+fn poll_in_flight(stage: Stage) -> PollScenario
+{
+    System::set_block_number(1);
+
+    let (pk, vk) = get_coordinator_data();
+    let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+    register_coordinator(0, pk, vk);
+    assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
+
+    for (origin, participant) in &get_participants()
+    {
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *participant));
+    }
+
+    run_to_block(1 + signup_period);
+    assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+    let scenario = get_poll_scenario(2);
+    let interactions = if stage == Stage::Voting { 1 } else { scenario.interactions.len() };
+    for (pk, data) in scenario.interactions.iter().take(interactions)
+    {
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, *pk, interaction_cipher(data)));
+    }
+
+    if stage == Stage::Proving
+    {
+        run_to_block(2 + signup_period + voting_period);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), 0, scenario.proof_batches[..1].to_vec().try_into().unwrap(), None));
+        assert_eq!(Infimum::polls(0).unwrap().state.commitment.completed(), 1);
+    }
+
+    scenario
+}
+
+/// Complete the lifecycle of a poll advanced by `poll_in_flight`, and check that it concludes with the expected
+/// outcome.
+fn complete_poll(stage: Stage, scenario: PollScenario)
+{
+    let (signup_period, voting_period, ..) = get_poll_config();
+
+    let mut proof_index = 0;
+    let mut proof_batches = scenario.proof_batches;
+    if stage == Stage::Voting
+    {
+        for (pk, data) in scenario.interactions.iter().skip(1)
+        {
+            assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, *pk, interaction_cipher(data)));
+        }
+
+        run_to_block(2 + signup_period + voting_period);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+    }
+    else
+    {
+        proof_index = 1;
+        proof_batches.remove(0);
+    }
+
+    assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), proof_index, proof_batches.try_into().unwrap(), scenario.outcome));
+    assert_eq!(Infimum::polls(0).unwrap().state.outcome, scenario.expected);
+    assert_ok!(Infimum::do_try_state());
+}
+
+/// Store the poll, and the storage which was split off from it, in the layout of the given storage version.
+fn downgrade(poll_id: u32, version: u16)
+{
+    // Version 2 and prior embed the frontiers of the state trees, and version 3 and prior the trees themselves.
+    let poll = if version <= 2 { Infimum::get_poll_with_frontiers(poll_id) } else { Infimum::polls(poll_id) }.unwrap();
+    if version <= 3
+    {
+        PollRegistrationTree::<Test>::remove(poll_id);
+        PollInteractionTree::<Test>::remove(poll_id);
+        PollPhases::<Test>::remove(poll_id);
+    }
+    if version <= 2
+    {
+        PollRegistrationFrontier::<Test>::remove(poll_id);
+        PollInteractionFrontier::<Test>::remove(poll_id);
+    }
+    if version <= 4 { NextPollId::<Test>::kill(); }
+
+    let key = Polls::<Test>::hashed_key_for(poll_id);
+    if version == 0 { unhashed::put(&key, &v0_poll(poll)); } else { unhashed::put(&key, &legacy_poll(poll)); }
+
+    StorageVersion::new(version).put::<Infimum>();
+}
+
+/// The poll in the layout of version 0, i.e. with positional proof commitments.
+fn v0_poll(poll: Poll<Test>) -> v0::Poll<Test>
+{
+    let commitment = poll.state.commitment;
+
+    v0::Poll {
+        index: poll.index,
+        coordinator: poll.coordinator,
+        created_at: poll.created_at,
+        state: v0::PollState {
+            registrations: poll.state.registrations,
+            interactions: poll.state.interactions,
+            commitment: v0::Commitment {
+                process: (commitment.process.completed, commitment.process.current),
+                tally: (commitment.tally.completed, commitment.tally.current),
+                expected_process: commitment.process.expected,
+                expected_tally: commitment.tally.expected
+            },
+            outcome: poll.state.outcome,
+            tombstone: poll.state.tombstone,
+            pending: poll.state.pending,
+            sealed: poll.state.sealed
+        },
+        config: poll.config
+    }
+}
+
+/// The poll in the layout of versions 1 to 5, i.e. without a schema version.
+fn legacy_poll(poll: Poll<Test>) -> v5::Poll<Test>
+{
+    v5::Poll {
+        index: poll.index,
+        coordinator: poll.coordinator,
+        created_at: poll.created_at,
+        state: poll.state,
+        config: poll.config
+    }
+}
+
+/// Apply every shipped migration in order, as the runtime does upon an upgrade.
+fn run_migrations()
+{
+    MigrateToV1::<Test>::on_runtime_upgrade();
+    MigrateToV2::<Test>::on_runtime_upgrade();
+    MigrateToV3::<Test>::on_runtime_upgrade();
+    MigrateToV4::<Test>::on_runtime_upgrade();
+    MigrateToV5::<Test>::on_runtime_upgrade();
+    MigrateToV6::<Test>::on_runtime_upgrade();
+    MigrateToV7::<Test>::on_runtime_upgrade();
+    MigrateToV8::<Test>::on_runtime_upgrade();
+    MigrateToV9::<Test>::on_runtime_upgrade();
+    MigrateToV10::<Test>::on_runtime_upgrade();
+    MigrateToV11::<Test>::on_runtime_upgrade();
+}
+
+/// Migrate a poll at the given stage from the layout of every prior storage version, and complete its lifecycle.
+fn migrate_in_flight(stage: Stage)
+{
+    for version in 0..POLL_SCHEMA_VERSION
+    {
+        new_test_ext().execute_with(|| {
+            let scenario = poll_in_flight(stage);
+            let poll = Infimum::polls(0).unwrap();
+            let phase = Infimum::poll_phase(0);
+
+            downgrade(0, version);
+            run_migrations();
+
+            assert_eq!(Infimum::on_chain_storage_version(), Infimum::current_storage_version());
+            assert_eq!(Infimum::polls(0), Some(poll));
+            assert_eq!(Infimum::poll_phase(0), phase);
+            assert_eq!(Infimum::next_poll_id(), 1);
+            assert_ok!(Infimum::do_try_state());
+
+            complete_poll(stage, scenario);
+        });
+    }
+}
+
+/// A poll whose voting period spans an upgrade should keep its partially filled interaction tree.
+#[test]
+fn migrate_poll_mid_voting()
+{
+    migrate_in_flight(Stage::Voting);
+}
+
+/// A poll whose proofs span an upgrade should keep its partial proof chain.
+#[test]
+fn migrate_poll_mid_proving()
+{
+    migrate_in_flight(Stage::Proving);
+}
+
+/// Upgrading to version 6 should store the schema version inside every poll, and skip polls which are current.
+#[test]
+fn migrate_schema_version_to_v6()
+{
+    new_test_ext().execute_with(|| {
+        poll_in_flight(Stage::Voting);
+        let poll = Infimum::polls(0).unwrap();
+        assert_eq!(poll.schema_version, POLL_SCHEMA_VERSION);
+
+        // A poll which a migration missed is detected.
+        let mut straggler = poll.clone();
+        straggler.schema_version = POLL_SCHEMA_VERSION - 1;
+        Polls::<Test>::insert(0, straggler);
+        assert!(Infimum::do_try_state().is_err());
+
+        downgrade(0, 5);
+        MigrateToV6::<Test>::on_runtime_upgrade();
+        assert_eq!(Infimum::polls(0), Some(poll.clone()));
+        assert_eq!(Infimum::on_chain_storage_version(), 6);
+        assert_ok!(Infimum::do_try_state());
+
+        // A poll which is already current is left untouched.
+        StorageVersion::new(5).put::<Infimum>();
+        MigrateToV6::<Test>::on_runtime_upgrade();
+        assert_eq!(Infimum::polls(0), Some(poll));
+        assert_eq!(Polls::<Test>::count(), 1);
+    })
+}
+
+/// Upgrading to version 8 should move the fee allowance of every poll into the named reserve of its coordinator.
+#[test]
+fn migrate_fee_allowance_to_v8()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+        let mut config = poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options);
+        config.fee_allowance = 25;
+
+        register_coordinator(0, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), config));
+
+        // Hold the allowance in the anonymous reserve, as in version 7.
+        let id = FeeAllowanceReserveId::get();
+        Balances::unreserve_named(&id, &0, 25);
+        assert_ok!(Balances::reserve(&0, 25));
+        StorageVersion::new(7).put::<Infimum>();
+
+        MigrateToV8::<Test>::on_runtime_upgrade();
+        assert_eq!(Balances::reserved_balance_named(&id, &0), 25);
+        assert_eq!(Balances::reserved_balance(0), 25);
+        assert_eq!(Infimum::fee_allowances(0), Some(25));
+        assert_eq!(Infimum::on_chain_storage_version(), 8);
+        assert_ok!(Infimum::do_try_state());
+
+        // The migration is not applied twice.
+        MigrateToV8::<Test>::on_runtime_upgrade();
+        assert_eq!(Balances::reserved_balance_named(&id, &0), 25);
+        assert_eq!(Balances::reserved_balance(0), 25);
+    })
+}
+
+/// Upgrading to version 9 should count the unfinished polls which reference each circuit.
+#[test]
+fn migrate_circuit_references_to_v9()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+        let mut config = poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options);
+        config.circuit_id = Some(1);
+
+        assert_ok!(Infimum::register_circuit(RuntimeOrigin::root(), 1, vk.clone()));
+        register_coordinator(0, pk, vk.clone());
+        register_coordinator(1, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), config.clone()));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(1), config));
+
+        // The nullified poll no longer references the circuit.
+        run_to_block(2 + signup_period + voting_period);
+        assert_ok!(Infimum::nullify_poll(RuntimeOrigin::signed(1)));
+
+        // Forget the references, as in version 8.
+        let _ = CircuitReferences::<Test>::clear(u32::MAX, None);
+        StorageVersion::new(8).put::<Infimum>();
+
+        MigrateToV9::<Test>::on_runtime_upgrade();
+        assert_eq!(Infimum::circuit_references(1), 1);
+        assert_eq!(Infimum::on_chain_storage_version(), 9);
+        assert_ok!(Infimum::do_try_state());
+
+        // The migration is not applied twice.
+        MigrateToV9::<Test>::on_runtime_upgrade();
+        assert_eq!(Infimum::circuit_references(1), 1);
+    })
+}
@@ -1,5 +1,15 @@
+pub mod commitment;
 pub mod extrinsics;
+#[cfg(feature = "proof-fixtures")]
+pub mod fixtures;
+pub mod instances;
+pub mod keys;
+pub mod leaves;
+pub mod migrations;
+pub mod outcome;
 pub mod poseidon;
+pub mod provider;
+pub mod state;
 pub mod data;
 pub mod utils;
 
@@ -0,0 +1,596 @@
+use sp_std::vec;
+use ark_bn254::Fr;
+use ark_ff::{BigInteger, PrimeField};
+use frame_support::{
+    assert_err,
+    assert_ok,
+    dispatch::GetDispatchInfo,
+    storage::unhashed,
+    traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+    weights::Weight
+};
+use sp_runtime::traits::Dispatchable;
+use crate::{
+    mock::*,
+    Error,
+    Event,
+    PollOutcomes,
+    StagedOutcomes
+};
+use crate::hash::{Poseidon, PoseidonHasher};
+use crate::migrations::{
+    v2::{v1, MigrateToV2},
+    v10::MigrateToV10,
+    v11::{v10, MigrateToV11}
+};
+use crate::poll::{
+    CoordKeyHash,
+    HashBytes,
+    OutcomeChunk,
+    OutcomeHeader,
+    PollOutcome,
+    ProofChain,
+    NO_VOTES,
+    provider::{encode_tally_result, PollProvider},
+    zeroes::get_merkle_zeroes
+};
+use crate::tests::{
+    get_coordinator_data,
+    get_participants,
+    get_poll_config,
+    poll_config,
+    register_coordinator,
+    run_to_block
+};
+
+fn hash(inputs: &[HashBytes]) -> HashBytes
+{
+    let mut hasher = Poseidon::<Fr>::new_circom(inputs.len()).unwrap();
+    let inputs: vec::Vec<Fr> = inputs.iter().map(|bytes| Fr::from_be_bytes_mod_order(bytes)).collect();
+    let bytes = hasher.hash(&inputs).unwrap().into_bigint().to_bytes_be();
+
+    let mut hash = [0u8; 32];
+    hash[..bytes.len()].copy_from_slice(&bytes);
+    hash
+}
+
+/// Build the quinary vote option tree of the given depth over the tally results, and return its root alongside
+/// the path of siblings of every vote option.
+fn vote_option_tree(tally_results: &[u128], depth: u8) -> (HashBytes, vec::Vec<vec::Vec<vec::Vec<HashBytes>>>)
+{
+    let mut level: vec::Vec<HashBytes> = (0..5usize.pow(depth.into()))
+        .map(|index| encode_tally_result(tally_results.get(index).copied().unwrap_or(0)).unwrap())
+        .collect();
+    let mut paths = vec::Vec::from_iter(tally_results.iter().map(|_| vec::Vec::new()));
+
+    for height in 0..u32::from(depth)
+    {
+        for (index, path) in paths.iter_mut().enumerate()
+        {
+            let node = index / 5usize.pow(height);
+            let first = node - node % 5;
+            path.push((first..first + 5).filter(|sibling| *sibling != node).map(|sibling| level[sibling]).collect());
+        }
+
+        level = level.chunks(5).map(hash).collect();
+    }
+
+    (level[0], paths)
+}
+
+/// Tally results should be encoded as 128 bit big-endian leaves.
+#[test]
+fn tally_result_encoding()
+{
+    let mut expected = [0u8; 32];
+    expected[16..].copy_from_slice(&u128::MAX.to_be_bytes());
+    assert_eq!(encode_tally_result(u128::MAX), Some(expected));
+
+    let mut expected = [0u8; 32];
+    expected[27] = 1;
+    assert_eq!(encode_tally_result(1u128 << 32), Some(expected));
+}
+
+/// Tally results which exceed `u32` should verify, and their truncation should not.
+#[test]
+fn verify_outcome_large_tally()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone())));
+
+        let large = (1u128 << 32) + 5;
+        let mut tally_results = vec::Vec::from_iter(vote_options.iter().map(|_| 0u128));
+        tally_results[1] = 9;
+        tally_results[3] = large;
+
+        let (root, tally_result_proofs) = vote_option_tree(&tally_results, vote_option_tree_depth);
+        let tally_result_salt = [3u8; 32];
+        let new_results_commitment = hash(&[root, tally_result_salt]);
+
+        let mut total_spent = [0u8; 32];
+        total_spent[16..].copy_from_slice(&(large + 9).to_be_bytes());
+        let total_spent_salt = [4u8; 32];
+        let spent_votes_hash = hash(&[total_spent, total_spent_salt]);
+
+        let mut poll = Infimum::polls(0).unwrap();
+        poll.state.commitment.process = ProofChain { completed: 1, expected: 1, current: [1u8; 32] };
+        poll.state.commitment.tally = ProofChain { completed: 1, expected: 1, current: hash(&[new_results_commitment, spent_votes_hash]) };
+
+        let outcome = PollOutcome {
+            tally_results,
+            tally_result_proofs,
+            total_spent,
+            total_spent_salt,
+            tally_result_salt,
+            new_results_commitment,
+            spent_votes_hash
+        };
+        assert_eq!(poll.clone().verify_outcome(Some(outcome.clone())), Some(3));
+
+        let mut truncated = outcome;
+        truncated.tally_results[3] = large as u32 as u128;
+        assert_eq!(poll.clone().verify_outcome(Some(truncated)), None);
+    })
+}
+
+/// A poll without interactions should merge an empty interaction tree, skip the message processing circuit, and
+/// conclude with `NO_VOTES` once the tally over its registrations is proven.
+#[test]
+fn empty_poll_concludes_without_votes()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        FinalizedPolls::set(vec::Vec::new());
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, pk, vk.clone());
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options.clone())));
+
+        for (origin, participant) in &get_participants()
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *participant));
+        }
+
+        run_to_block(1 + signup_period);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        // The empty interaction tree merges to the root of the zero tree, and no message processing proofs are expected.
+        run_to_block(2 + signup_period + voting_period);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let poll = Infimum::polls(0).unwrap();
+        assert!(poll.is_merged());
+        assert_eq!(poll.state.interactions.root, Some(get_merkle_zeroes(5)[usize::from(interaction_depth)]));
+        assert_eq!(poll.state.commitment.process.expected, 0);
+        assert!(poll.state.commitment.process.is_complete());
+        assert_eq!(poll.state.commitment.tally.expected, 2);
+
+        // The first proof is verified against the tally circuit.
+        let (verify_key, public_inputs, commitment) = poll.clone().prepare_public_inputs(pk, CoordKeyHash::Poseidon2Coords, vk.clone(), [1u8; 32]).unwrap();
+        assert_eq!(verify_key, vk.tally);
+        assert_eq!(public_inputs.len(), 5);
+        assert_eq!(commitment.process, poll.state.commitment.process);
+        assert_eq!(commitment.tally.completed, 1);
+
+        // The tally proofs over the blank ballots require the circuits, so the tally commitment is set directly.
+        let tally_results = vec::Vec::from_iter(vote_options.iter().map(|_| 0u128));
+        let (root, tally_result_proofs) = vote_option_tree(&tally_results, vote_option_tree_depth);
+        let new_results_commitment = hash(&[root, [0u8; 32]]);
+        let spent_votes_hash = hash(&[[0u8; 32], [0u8; 32]]);
+
+        let mut poll = poll;
+        poll.state.commitment.tally = ProofChain { completed: 2, expected: 2, current: hash(&[new_results_commitment, spent_votes_hash]) };
+        Infimum::insert_poll(0, poll);
+
+        let outcome = PollOutcome {
+            tally_results,
+            tally_result_proofs,
+            total_spent: [0u8; 32],
+            total_spent_salt: [0u8; 32],
+            tally_result_salt: [0u8; 32],
+            new_results_commitment,
+            spent_votes_hash
+        };
+        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), 0, Default::default(), Some(outcome)));
+
+        System::assert_last_event(Event::PollOutcome { poll_id: 0, outcome_index: NO_VOTES, weight_consumed: Infimum::poll_resource_usage(0) }.into());
+        assert_eq!(Infimum::polls(0).unwrap().state.outcome, Some(NO_VOTES));
+        assert_eq!(FinalizedPolls::get(), vec::Vec::from([(0, NO_VOTES)]));
+        assert!(Infimum::replay_poll_outcome(0));
+
+        // The poll counts towards the track record of the coordinator, alongside its participants.
+        let record = Infimum::coordinator_stats(0);
+        assert_eq!(record.polls_created, 1);
+        assert_eq!(record.polls_finalized, 1);
+        assert_eq!(record.polls_abandoned, 0);
+        assert_eq!(record.participants_served, get_participants().len() as u64);
+
+        // The export carries the stored outcome alongside the commitments and roots it was verified against.
+        let poll = Infimum::polls(0).unwrap();
+        let export = Infimum::outcome_export(0).unwrap();
+        let stored = Infimum::poll_outcomes(0).unwrap();
+        assert_eq!(export.outcome_index, NO_VOTES);
+        assert_eq!(export.header, stored.header);
+        assert_eq!(export.tally_results, stored.tally_results.into_inner());
+        assert_eq!(export.tally_commitment, poll.state.commitment.tally.current);
+        assert_eq!(export.process_commitment, poll.state.commitment.process.current);
+        assert_eq!(Some(export.registration_root), poll.state.registrations.root);
+        assert_eq!(Some(export.interaction_root), poll.state.interactions.root);
+        assert_eq!(Infimum::outcome_export(1), None);
+    })
+}
+
+/// Create a poll whose registrations are tallied without interactions, and whose tally commitment commits to the
+/// given tally results, returning the outcome which verifies against it.
+fn proven_poll(tally_results: &[u128]) -> PollOutcome
+{
+    System::set_block_number(1);
+
+    let (pk, vk) = get_coordinator_data();
+    let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+    register_coordinator(0, pk, vk);
+    assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
+
+    for (origin, participant) in &get_participants()
+    {
+        assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *participant));
+    }
+
+    run_to_block(1 + signup_period);
+    assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+    run_to_block(2 + signup_period + voting_period);
+    assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+    prove_tally(tally_results)
+}
+
+/// Set the tally commitment of the merged poll to commit to the given tally results, returning the outcome which
+/// verifies against it.
+fn prove_tally(tally_results: &[u128]) -> PollOutcome
+{
+    let (.., vote_option_tree_depth, _) = get_poll_config();
+    let (root, tally_result_proofs) = vote_option_tree(tally_results, vote_option_tree_depth);
+    let tally_result_salt = [3u8; 32];
+    let new_results_commitment = hash(&[root, tally_result_salt]);
+
+    let mut total_spent = [0u8; 32];
+    total_spent[16..].copy_from_slice(&tally_results.iter().sum::<u128>().to_be_bytes());
+    let total_spent_salt = [4u8; 32];
+    let spent_votes_hash = hash(&[total_spent, total_spent_salt]);
+
+    // The tally proofs require the circuits, so the tally commitment is set directly.
+    let mut poll = Infimum::polls(0).unwrap();
+    let expected = poll.state.commitment.tally.expected;
+    poll.state.commitment.tally = ProofChain { completed: expected, expected, current: hash(&[new_results_commitment, spent_votes_hash]) };
+    Infimum::insert_poll(0, poll);
+
+    PollOutcome {
+        tally_results: tally_results.to_vec(),
+        tally_result_proofs,
+        total_spent,
+        total_spent_salt,
+        tally_result_salt,
+        new_results_commitment,
+        spent_votes_hash
+    }
+}
+
+fn outcome_header(outcome: &PollOutcome) -> OutcomeHeader
+{
+    OutcomeHeader {
+        total_spent: outcome.total_spent,
+        total_spent_salt: outcome.total_spent_salt,
+        tally_result_salt: outcome.tally_result_salt,
+        new_results_commitment: outcome.new_results_commitment,
+        spent_votes_hash: outcome.spent_votes_hash,
+        option_count: outcome.tally_results.len() as u32
+    }
+}
+
+fn outcome_chunk(outcome: &PollOutcome, options: core::ops::Range<u32>) -> OutcomeChunk<Test>
+{
+    let chunk: vec::Vec<_> = options
+        .map(|index| {
+            let path = outcome.tally_result_proofs[index as usize]
+                .iter()
+                .map(|siblings| siblings.clone().try_into().unwrap())
+                .collect::<vec::Vec<_>>();
+
+            (index, outcome.tally_results[index as usize], path.try_into().unwrap())
+        })
+        .collect();
+
+    chunk.try_into().unwrap()
+}
+
+fn sample_tally_results() -> vec::Vec<u128>
+{
+    let mut tally_results = vec::Vec::from_iter((0..25).map(|_| 0u128));
+    tally_results[4] = 7;
+    tally_results[17] = 9;
+    tally_results[21] = 3;
+    tally_results
+}
+
+/// An outcome staged across multiple extrinsics should fulfill the poll exactly as a single `commit_outcome` would.
+#[test]
+fn staged_outcome_matches_single_shot()
+{
+    let single_shot = new_test_ext().execute_with(|| {
+        let outcome = proven_poll(&sample_tally_results());
+        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), 0, Default::default(), Some(outcome)));
+
+        (Infimum::polls(0).unwrap(), Infimum::poll_outcomes(0).unwrap(), Infimum::coordinator_stats(0))
+    });
+
+    let staged = new_test_ext().execute_with(|| {
+        FinalizedPolls::set(vec::Vec::new());
+
+        let outcome = proven_poll(&sample_tally_results());
+        assert_ok!(Infimum::begin_outcome(RuntimeOrigin::signed(0), 0, outcome_header(&outcome)));
+        System::assert_last_event(Event::OutcomeStagingBegun { poll_id: 0, expires_at: 46 }.into());
+
+        for range in [0..10, 10..20, 20..25]
+        {
+            let end = range.end;
+            assert_ok!(Infimum::submit_outcome_chunk(RuntimeOrigin::signed(0), 0, outcome_chunk(&outcome, range)));
+            System::assert_last_event(Event::OutcomeChunkAccepted { poll_id: 0, options_done: end, options_total: 25 }.into());
+        }
+
+        assert_ok!(Infimum::finalize_outcome(RuntimeOrigin::signed(0), 0));
+        System::assert_last_event(Event::PollOutcome { poll_id: 0, outcome_index: 17, weight_consumed: Infimum::poll_resource_usage(0) }.into());
+        assert_eq!(FinalizedPolls::get(), vec::Vec::from([(0, 17)]));
+        assert_eq!(Infimum::staged_outcome(0), None);
+        assert!(Infimum::replay_poll_outcome(0));
+
+        (Infimum::polls(0).unwrap(), Infimum::poll_outcomes(0).unwrap(), Infimum::coordinator_stats(0))
+    });
+
+    assert_eq!(staged.0.state.outcome, Some(17));
+    assert_eq!(staged, single_shot);
+}
+
+/// Chunks should be accepted strictly in order of vote option, and only once every proof has been verified.
+#[test]
+fn staged_outcome_interleaving()
+{
+    new_test_ext().execute_with(|| {
+        let outcome = proven_poll(&sample_tally_results());
+        let header = outcome_header(&outcome);
+
+        // Only the coordinator may stage the outcome, and only for every vote option of the poll.
+        assert_err!(Infimum::begin_outcome(RuntimeOrigin::signed(1), 0, header.clone()), Error::<Test>::NotPollCoordinator);
+        assert_err!(Infimum::begin_outcome(RuntimeOrigin::signed(0), 0, OutcomeHeader { option_count: 24, ..header.clone() }), Error::<Test>::MalformedInput);
+        assert_err!(Infimum::submit_outcome_chunk(RuntimeOrigin::signed(0), 0, outcome_chunk(&outcome, 0..10)), Error::<Test>::OutcomeNotStaged);
+
+        assert_ok!(Infimum::begin_outcome(RuntimeOrigin::signed(0), 0, header.clone()));
+
+        // Chunks which skip, or repeat, vote options are rejected.
+        assert_err!(Infimum::submit_outcome_chunk(RuntimeOrigin::signed(0), 0, outcome_chunk(&outcome, 10..20)), Error::<Test>::InvalidOutcomeChunk);
+        assert_ok!(Infimum::submit_outcome_chunk(RuntimeOrigin::signed(0), 0, outcome_chunk(&outcome, 0..10)));
+        assert_err!(Infimum::submit_outcome_chunk(RuntimeOrigin::signed(0), 0, outcome_chunk(&outcome, 5..15)), Error::<Test>::InvalidOutcomeChunk);
+        assert_err!(Infimum::submit_outcome_chunk(RuntimeOrigin::signed(0), 0, Default::default()), Error::<Test>::MalformedInput);
+
+        let mut reversed = outcome_chunk(&outcome, 10..20).into_inner();
+        reversed.reverse();
+        assert_err!(Infimum::submit_outcome_chunk(RuntimeOrigin::signed(0), 0, reversed.try_into().unwrap()), Error::<Test>::InvalidOutcomeChunk);
+
+        // The outcome may not be finalized before every tally result is staged.
+        assert_ok!(Infimum::submit_outcome_chunk(RuntimeOrigin::signed(0), 0, outcome_chunk(&outcome, 10..20)));
+        assert_err!(Infimum::finalize_outcome(RuntimeOrigin::signed(0), 0), Error::<Test>::OutcomeIncomplete);
+
+        // Staging anew discards the staged results.
+        assert_ok!(Infimum::begin_outcome(RuntimeOrigin::signed(0), 0, header));
+        assert_eq!(Infimum::staged_outcome(0).unwrap().next_option(), 0);
+        assert_ok!(Infimum::submit_outcome_chunk(RuntimeOrigin::signed(0), 0, outcome_chunk(&outcome, 0..25)));
+        assert_ok!(Infimum::finalize_outcome(RuntimeOrigin::signed(0), 0));
+
+        // The poll is fulfilled, such that its outcome may not be staged again.
+        assert_err!(Infimum::begin_outcome(RuntimeOrigin::signed(0), 0, outcome_header(&outcome)), Error::<Test>::PollOutcomeAlreadyDetermined);
+    })
+}
+
+/// A chunk with a tally result which does not match the tally commitment should be rejected as a whole, retaining the
+/// results staged before it, and a staged outcome should be abandoned once it expires.
+#[test]
+fn staged_outcome_bad_chunk()
+{
+    new_test_ext().execute_with(|| {
+        let outcome = proven_poll(&sample_tally_results());
+
+        assert_ok!(Infimum::begin_outcome(RuntimeOrigin::signed(0), 0, outcome_header(&outcome)));
+        assert_ok!(Infimum::submit_outcome_chunk(RuntimeOrigin::signed(0), 0, outcome_chunk(&outcome, 0..10)));
+
+        let mut tampered = outcome_chunk(&outcome, 10..20).into_inner();
+        tampered[7].1 += 1;
+        assert_err!(Infimum::submit_outcome_chunk(RuntimeOrigin::signed(0), 0, tampered.try_into().unwrap()), Error::<Test>::InvalidOutcomeChunk);
+        assert_eq!(Infimum::staged_outcome(0).unwrap().next_option(), 10);
+
+        // A total which does not match the tally commitment is rejected upon finalization.
+        assert_ok!(Infimum::begin_outcome(RuntimeOrigin::signed(0), 0, OutcomeHeader { total_spent: [1u8; 32], ..outcome_header(&outcome) }));
+        assert_ok!(Infimum::submit_outcome_chunk(RuntimeOrigin::signed(0), 0, outcome_chunk(&outcome, 0..25)));
+        assert_err!(Infimum::finalize_outcome(RuntimeOrigin::signed(0), 0), Error::<Test>::OutcomeVerificationFailed);
+        assert_eq!(Infimum::polls(0).unwrap().state.outcome, None);
+
+        // The abandoned outcome must be staged anew.
+        assert_ok!(Infimum::begin_outcome(RuntimeOrigin::signed(0), 0, outcome_header(&outcome)));
+        let expires_at = Infimum::staged_outcome(0).unwrap().expires_at;
+        run_to_block(expires_at + 1);
+        assert_err!(Infimum::submit_outcome_chunk(RuntimeOrigin::signed(0), 0, outcome_chunk(&outcome, 0..10)), Error::<Test>::OutcomeStagingExpired);
+        assert_err!(Infimum::finalize_outcome(RuntimeOrigin::signed(0), 0), Error::<Test>::OutcomeStagingExpired);
+
+        assert_ok!(Infimum::begin_outcome(RuntimeOrigin::signed(0), 0, outcome_header(&outcome)));
+        assert_ok!(Infimum::submit_outcome_chunk(RuntimeOrigin::signed(0), 0, outcome_chunk(&outcome, 0..25)));
+        assert_ok!(Infimum::finalize_outcome(RuntimeOrigin::signed(0), 0));
+        assert_eq!(Infimum::polls(0).unwrap().state.outcome, Some(17));
+    })
+}
+
+/// The outcome of a poll should not be staged before every proof has been verified.
+#[test]
+fn staged_outcome_unproven()
+{
+    new_test_ext().execute_with(|| {
+        let outcome = proven_poll(&sample_tally_results());
+
+        let mut poll = Infimum::polls(0).unwrap();
+        poll.state.commitment.tally.completed -= 1;
+        Infimum::insert_poll(0, poll);
+
+        assert_err!(Infimum::begin_outcome(RuntimeOrigin::signed(0), 0, outcome_header(&outcome)), Error::<Test>::PollNotProven);
+    })
+}
+
+/// The tally results of version 1 outcomes should be widened to `u128`.
+#[test]
+fn migrate_outcome_to_v2()
+{
+    new_test_ext().execute_with(|| {
+        let old = v1::PollOutcome {
+            tally_results: vec::Vec::from([ 0, u32::MAX, 2 ]),
+            tally_result_proofs: vec::Vec::from([ vec::Vec::from([ vec::Vec::from([ [1u8; 32] ]) ]) ]),
+            total_spent: [2u8; 32],
+            total_spent_salt: [3u8; 32],
+            tally_result_salt: [4u8; 32],
+            new_results_commitment: [5u8; 32],
+            spent_votes_hash: [6u8; 32]
+        };
+        unhashed::put(&PollOutcomes::<Test>::hashed_key_for(0), &old);
+        StorageVersion::new(1).put::<Infimum>();
+
+        MigrateToV2::<Test>::on_runtime_upgrade();
+
+        let migrated: PollOutcome = unhashed::get(&PollOutcomes::<Test>::hashed_key_for(0)).unwrap();
+        assert_eq!(migrated.tally_results, vec::Vec::from([ 0, u32::MAX as u128, 2 ]));
+        assert_eq!(migrated.tally_result_proofs, old.tally_result_proofs);
+        assert_eq!(migrated.spent_votes_hash, old.spent_votes_hash);
+        assert_eq!(Infimum::on_chain_storage_version(), 2);
+
+        // The migration is not applied twice.
+        MigrateToV2::<Test>::on_runtime_upgrade();
+        assert_eq!(unhashed::get(&PollOutcomes::<Test>::hashed_key_for(0)), Some(migrated));
+    })
+}
+
+/// Upgrading to version 10 should retain only the tally results of the vote options and the commitments of each
+/// stored outcome, which still replays.
+#[test]
+fn migrate_outcome_to_v10()
+{
+    new_test_ext().execute_with(|| {
+        let outcome = proven_poll(&sample_tally_results());
+        assert_ok!(Infimum::commit_outcome(RuntimeOrigin::signed(0), 0, Default::default(), Some(outcome.clone())));
+        let record = Infimum::poll_outcomes(0).unwrap();
+
+        // Store the outcome alongside its paths and a surplus tally result, as in version 9.
+        let mut old = outcome;
+        old.tally_results.push(0);
+        unhashed::put(&PollOutcomes::<Test>::hashed_key_for(0), &old);
+        StorageVersion::new(9).put::<Infimum>();
+
+        MigrateToV10::<Test>::on_runtime_upgrade();
+        assert_eq!(Infimum::poll_outcomes(0), Some(record.clone()));
+        assert_eq!(record.tally_results.into_inner(), sample_tally_results());
+        assert_eq!(record.header.option_count, 25);
+        assert_eq!(Infimum::on_chain_storage_version(), 10);
+        assert!(Infimum::replay_poll_outcome(0));
+
+        // The migration is not applied twice.
+        MigrateToV10::<Test>::on_runtime_upgrade();
+        assert_eq!(Infimum::poll_outcomes(0), Some(record));
+    })
+}
+
+/// The tally results staged before an upgrade should be retained without their vote option tree paths, such that
+/// staging may resume where it left off.
+#[test]
+fn migrate_staged_outcome_to_v11()
+{
+    new_test_ext().execute_with(|| {
+        let outcome = proven_poll(&sample_tally_results());
+        assert_ok!(Infimum::begin_outcome(RuntimeOrigin::signed(0), 0, outcome_header(&outcome)));
+        assert_ok!(Infimum::submit_outcome_chunk(RuntimeOrigin::signed(0), 0, outcome_chunk(&outcome, 0..10)));
+        let staged = Infimum::staged_outcome(0).unwrap();
+
+        // Store the staged outcome alongside its paths, as in version 10.
+        let old = v10::StagedOutcome {
+            header: staged.header.clone(),
+            tally_results: staged.tally_results.clone().into_inner(),
+            tally_result_proofs: outcome.tally_result_proofs[..10].to_vec(),
+            expires_at: staged.expires_at
+        };
+        unhashed::put(&StagedOutcomes::<Test>::hashed_key_for(0), &old);
+        StorageVersion::new(10).put::<Infimum>();
+
+        MigrateToV11::<Test>::on_runtime_upgrade();
+        assert_eq!(Infimum::staged_outcome(0), Some(staged.clone()));
+        assert_eq!(Infimum::on_chain_storage_version(), 11);
+
+        // The migration is not applied twice.
+        MigrateToV11::<Test>::on_runtime_upgrade();
+        assert_eq!(Infimum::staged_outcome(0), Some(staged));
+
+        assert_ok!(Infimum::submit_outcome_chunk(RuntimeOrigin::signed(0), 0, outcome_chunk(&outcome, 10..25)));
+        assert_ok!(Infimum::finalize_outcome(RuntimeOrigin::signed(0), 0));
+        assert_eq!(Infimum::polls(0).unwrap().state.outcome, Some(17));
+    })
+}
+
+/// The resource usage of a poll should equal the sum of the actual weights of the extrinsics scoped to it, and be
+/// published once the poll is fulfilled.
+#[test]
+fn poll_resource_usage_sums_actual_weights()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let mut consumed = Weight::zero();
+        let mut dispatch = |who: u64, call: crate::Call<Test>| {
+            let call = RuntimeCall::Infimum(call);
+            let info = call.get_dispatch_info();
+            let post = call.dispatch(RuntimeOrigin::signed(who)).unwrap();
+            consumed.saturating_accrue(post.calc_actual_weight(&info));
+        };
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        // Registering the coordinator is not scoped to a poll.
+        register_coordinator(0, pk, vk);
+        dispatch(0, crate::Call::create_poll {
+            config: poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)
+        });
+
+        for (origin, participant) in &get_participants()
+        {
+            dispatch(*origin, crate::Call::register_as_participant { poll_id: 0, public_key: *participant });
+        }
+
+        run_to_block(1 + signup_period);
+        dispatch(0, crate::Call::merge_poll_state {});
+        run_to_block(2 + signup_period + voting_period);
+        dispatch(0, crate::Call::merge_poll_state {});
+
+        // A failed extrinsic does not accrue.
+        assert!(RuntimeCall::Infimum(crate::Call::finalize_outcome { poll_id: 0 }).dispatch(RuntimeOrigin::signed(0)).is_err());
+
+        let outcome = prove_tally(&sample_tally_results());
+        dispatch(0, crate::Call::begin_outcome { poll_id: 0, header: outcome_header(&outcome) });
+        dispatch(0, crate::Call::submit_outcome_chunk { poll_id: 0, options: outcome_chunk(&outcome, 0..25) });
+        dispatch(0, crate::Call::finalize_outcome { poll_id: 0 });
+
+        assert_eq!(Infimum::poll_resource_usage(0), consumed);
+        assert!(consumed.any_gt(Weight::zero()));
+        System::assert_last_event(Event::PollOutcome { poll_id: 0, outcome_index: 17, weight_consumed: consumed }.into());
+        assert_eq!(Infimum::poll_resource_usage(1), Weight::zero());
+    })
+}
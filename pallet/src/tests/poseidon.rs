@@ -9,7 +9,11 @@ use crate::hash::{
     Poseidon,
     PoseidonError,
     PoseidonHasher,
-    PoseidonBytesHasher
+    PoseidonBytesHasher,
+    poseidon2,
+    poseidon3,
+    poseidon4,
+    poseidon5
 };
 
 /// Check the hash of `1` as a prime field element.
@@ -249,3 +253,30 @@ fn circomlibjs_compat_1_to_12_inputs()
         assert!(hash != CIRCOMLIBJS_TEST_CASES[i - 1]);
     }
 }
+
+/// The typed wrappers should agree with circomlibjs.
+#[test]
+fn typed_wrappers_circomlibjs_compat()
+{
+    let mut one = [0u8; 32];
+    one[31] = 1;
+
+    assert_eq!(poseidon2(one, one), Some(CIRCOMLIBJS_TEST_CASES[1]));
+    assert_eq!(poseidon3(one, one, one), Some(CIRCOMLIBJS_TEST_CASES[2]));
+    assert_eq!(poseidon4([one; 4]), Some(CIRCOMLIBJS_TEST_CASES[3]));
+    assert_eq!(poseidon5([one; 5]), Some(CIRCOMLIBJS_TEST_CASES[4]));
+}
+
+/// Unsupported arities should be reported as such.
+#[test]
+fn unsupported_arity()
+{
+    assert_eq!(
+        Poseidon::<Fr>::new_circom(0).err(),
+        Some(PoseidonError::UnsupportedArity { nr_inputs: 0, max_limit: 12 })
+    );
+    assert_eq!(
+        Poseidon::<Fr>::new_circom(13).err(),
+        Some(PoseidonError::UnsupportedArity { nr_inputs: 13, max_limit: 12 })
+    );
+}
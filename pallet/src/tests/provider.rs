@@ -0,0 +1,335 @@
+use sp_std::vec;
+use codec::Encode;
+use ark_bn254::Fr;
+use frame_support::{assert_err, assert_ok};
+use crate::{mock::*, Error, Polls};
+use crate::poll::{
+    provider::{
+        compute_merkle_root_from_path,
+        effective_state_size,
+        select_winner,
+        PollProvider,
+        BLANK_STATE_LEAVES,
+        MAX_PATH_DEPTH
+    },
+    zeroes::QUINARY_ZEROES,
+    BlockNumber,
+    CoordKeyHash,
+    HashBytes,
+    PollIdOf,
+    PollPhaseCache,
+    PublicKey,
+    NO_VOTES
+};
+use crate::tests::{
+    get_coordinator_data,
+    get_participant,
+    get_participants,
+    get_poll_config,
+    interaction_cipher,
+    poll_config,
+    register_coordinator,
+    run_to_block
+};
+
+/// The vote option with the largest tally should win, with ties won by the lowest option index. Polls without
+/// votes should elect no option.
+#[test]
+fn select_winner_tie_break()
+{
+    assert_eq!(select_winner(&[ 1, 4, 2, 3 ]), 1);
+    assert_eq!(select_winner(&[ 1, 4, 2, 4, 4 ]), 1);
+    assert_eq!(select_winner(&[ 5, 2, 5 ]), 0);
+    assert_eq!(select_winner(&[ 0, 0, 0 ]), NO_VOTES);
+    assert_eq!(select_winner(&[]), NO_VOTES);
+    assert_eq!(select_winner(&[ 0, 0, 1 ]), 2);
+}
+
+/// Period boundaries which overflow `BlockNumber` should fall back to the safe value rather than wrap: the poll is
+/// neither within its registration nor its voting period, and its voting period never ends.
+#[test]
+fn block_arithmetic_overflow()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
+
+        // A poll which was never inserted computes its period ends from its periods.
+        let mut poll = Infimum::polls(0).unwrap();
+        poll.index = 1;
+        run_to_block(5);
+
+        poll.config.signup_period = BlockNumber::MAX;
+        assert!(!poll.is_registration_period());
+        assert!(!poll.is_voting_period());
+        assert_eq!(poll.get_registration_period_end(), BlockNumber::MAX);
+        assert_eq!(poll.get_voting_period_end(), BlockNumber::MAX);
+        assert!(!poll.is_over());
+
+        poll.config.signup_period = 2;
+        poll.config.voting_period = BlockNumber::MAX;
+        assert!(!poll.is_registration_period());
+        assert!(!poll.is_voting_period());
+        assert_eq!(poll.get_registration_period_end(), 3);
+        assert_eq!(poll.get_voting_period_end(), BlockNumber::MAX);
+        assert!(!poll.is_over());
+
+        poll.created_at = BlockNumber::MAX;
+        poll.config.signup_period = 1;
+        poll.config.voting_period = 1;
+        assert!(!poll.is_registration_period());
+        assert!(!poll.is_voting_period());
+        assert_eq!(poll.get_registration_period_end(), BlockNumber::MAX);
+        assert_eq!(poll.get_voting_period_end(), BlockNumber::MAX);
+    })
+}
+
+/// The expected number of proofs should be `ceil(interactions / process_batch_size)` and
+/// `1 + registrations / tally_batch_size`, whether or not the counts fill their last batch.
+#[test]
+fn expected_batches_at_boundaries()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, pk, vk);
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
+
+        let mut poll = Infimum::polls(0).unwrap();
+        let process_batch_size = poll.interaction_batch_size().unwrap();
+        let tally_batch_size = poll.tally_batch_size().unwrap();
+        assert_eq!((process_batch_size, tally_batch_size), (5, 2));
+
+        // (interactions, registrations, expected process proofs, expected tally proofs)
+        let cases: [(u32, u32, u32, u32); 7] = [
+            (0, 0, 0, 1),
+            (1, 1, 1, 1),
+            (4, 3, 1, 2),
+            (5, 4, 1, 3),
+            (6, 5, 2, 3),
+            (10, 9, 2, 5),
+            (11, 10, 3, 6)
+        ];
+        for (interactions, registrations, expected_process, expected_tally) in cases
+        {
+            poll.state.interactions.count = interactions;
+            poll.state.registrations.count = registrations;
+
+            assert_eq!(poll.expected_batches(), Some((expected_process, expected_tally)));
+            assert_eq!(expected_process, (interactions + process_batch_size - 1) / process_batch_size);
+            assert_eq!(expected_tally, 1 + registrations / tally_batch_size);
+        }
+    })
+}
+
+/// The blank state leaf should be accounted for once: every public input which carries the number of state leaves,
+/// and the number of expected tally proofs, should be derived from the same effective size.
+#[test]
+fn effective_state_size_consistent()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, pk, vk.clone());
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
+
+        let participants = get_participants();
+        for (origin, participant) in &participants
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *participant));
+        }
+
+        run_to_block(1 + signup_period);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+        let (_, shared_pk, message) = get_participant();
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, interaction_cipher(&message)));
+
+        run_to_block(2 + signup_period + voting_period);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let poll = Infimum::polls(0).unwrap();
+        let state_size = effective_state_size(participants.len() as u32);
+        assert_eq!(state_size, participants.len() as u32 + BLANK_STATE_LEAVES);
+
+        // The tally proofs cover the effective state size in batches.
+        let tally_batch_size = poll.tally_batch_size().unwrap();
+        let expected_tally = poll.state.commitment.tally.expected;
+        assert!((expected_tally - 1) * tally_batch_size < state_size);
+        assert!(expected_tally * tally_batch_size >= state_size);
+
+        // The message processing circuit takes the effective state size as its first input.
+        let (_, public_inputs, _) = poll.clone().prepare_public_inputs(pk, CoordKeyHash::Poseidon2Coords, vk.clone(), [1u8; 32]).unwrap();
+        assert_eq!(public_inputs[0], Fr::from(state_size));
+
+        // The tally circuit takes the effective state size as its last input.
+        let mut poll = poll;
+        poll.state.commitment.process.completed = poll.state.commitment.process.expected;
+        let (_, public_inputs, _) = poll.clone().prepare_public_inputs(pk, CoordKeyHash::Poseidon2Coords, vk.clone(), [1u8; 32]).unwrap();
+        assert_eq!(public_inputs[4], Fr::from(state_size));
+
+        // No tally proof is accepted beyond the effective state size.
+        poll.state.commitment.tally.completed = expected_tally;
+        assert!(poll.prepare_public_inputs(pk, CoordKeyHash::Poseidon2Coords, vk, [1u8; 32]).is_none());
+    })
+}
+
+/// Public inputs should not be derived from a coordinator key whose coordinates are not canonical field elements.
+#[test]
+fn public_inputs_non_canonical_key()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, pk, vk.clone());
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options)));
+
+        for (origin, participant) in &get_participants()
+        {
+            assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(*origin), 0, *participant));
+        }
+
+        run_to_block(1 + signup_period);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+        let (_, shared_pk, message) = get_participant();
+        assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(1), 0, shared_pk, interaction_cipher(&message)));
+
+        run_to_block(2 + signup_period + voting_period);
+        assert_ok!(Infimum::merge_poll_state(RuntimeOrigin::signed(0)));
+
+        let poll = Infimum::polls(0).unwrap();
+        assert!(poll.clone().prepare_public_inputs(pk, CoordKeyHash::Poseidon2Coords, vk.clone(), [1u8; 32]).is_some());
+
+        // The BN254 scalar field modulus, and any larger value, would be reduced rather than rejected.
+        let modulus: HashBytes = [48, 100, 78, 114, 225, 49, 160, 41, 184, 80, 69, 182, 129, 129, 88, 93, 40, 51, 232, 72, 121, 185, 112, 145, 67, 225, 245, 147, 240, 0, 0, 1];
+        let key = PublicKey { x: modulus, y: pk.y };
+        assert!(poll.clone().prepare_public_inputs(key, CoordKeyHash::Poseidon2Coords, vk.clone(), [1u8; 32]).is_none());
+        let key = PublicKey { x: pk.x, y: [255u8; 32] };
+        assert!(poll.prepare_public_inputs(key, CoordKeyHash::Poseidon2Coords, vk, [1u8; 32]).is_none());
+    })
+}
+
+/// Vote option tree paths should be verified up to `MAX_PATH_DEPTH`, and rejected beyond it or when malformed, such
+/// that the number of hashes per tally result is bounded.
+#[test]
+fn merkle_path_depth_limit()
+{
+    let zero_path = |depth: u8| -> vec::Vec<vec::Vec<HashBytes>> {
+        (0..usize::from(depth)).map(|level| vec::Vec::from([QUINARY_ZEROES[level]; 4])).collect()
+    };
+
+    let root = compute_merkle_root_from_path(MAX_PATH_DEPTH, 0, QUINARY_ZEROES[0], zero_path(MAX_PATH_DEPTH));
+    assert_eq!(root, Some(QUINARY_ZEROES[usize::from(MAX_PATH_DEPTH)]));
+
+    assert_eq!(compute_merkle_root_from_path(MAX_PATH_DEPTH + 1, 0, QUINARY_ZEROES[0], zero_path(MAX_PATH_DEPTH + 1)), None);
+
+    // The path must hold four siblings at each of `depth` levels.
+    assert_eq!(compute_merkle_root_from_path(2, 0, QUINARY_ZEROES[0], zero_path(1)), None);
+    let mut short_level = zero_path(2);
+    short_level[1].pop();
+    assert_eq!(compute_merkle_root_from_path(2, 0, QUINARY_ZEROES[0], short_level), None);
+}
+
+/// Polls whose vote option tree is deeper than `MAX_PATH_DEPTH` should be rejected upon creation.
+#[test]
+fn vote_option_tree_depth_limit()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, _, vote_options) = get_poll_config();
+
+        register_coordinator(0, pk, vk);
+        assert_err!(
+            Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, MAX_PATH_DEPTH + 1, vote_options.clone())),
+            Error::<Test>::PollConfigInvalid
+        );
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, MAX_PATH_DEPTH, vote_options)));
+    })
+}
+
+/// Asserts that the phase cache of the poll is that of the stored poll, and that each of its checks agrees with the
+/// corresponding check of the poll at the current block.
+fn assert_phase_matches(poll_id: PollIdOf<Test>)
+{
+    let poll = Infimum::polls(poll_id).unwrap();
+    let phase = Infimum::poll_phase(poll_id).unwrap();
+    let now = System::block_number();
+
+    assert_eq!(phase, PollPhaseCache::from(&poll));
+    assert_eq!(phase.is_pending(), poll.is_pending());
+    assert_eq!(phase.is_registration_period(now), poll.is_registration_period());
+    assert_eq!(phase.is_over(now), poll.is_over());
+    assert_eq!(phase.registration_limit_reached(poll.state.registrations.count), poll.registration_limit_reached());
+    assert_eq!(phase.interaction_limit_reached(poll.state.interactions.count), poll.interaction_limit_reached());
+}
+
+/// The phase cache read by the hot extrinsics should agree with the poll at every block of its lifecycle, including
+/// the activation of a pending poll, and the trees stored apart from the poll should be those of the poll.
+#[test]
+fn phase_cache_matches_poll()
+{
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let (pk, vk) = get_coordinator_data();
+        let (signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options) = get_poll_config();
+
+        register_coordinator(0, pk, vk.clone());
+        register_coordinator(1, pk, vk);
+        let config = poll_config(signup_period, voting_period, registration_depth, interaction_depth, process_subtree_depth, tally_subtree_depth, vote_option_tree_depth, vote_options);
+        let mut pending = config.clone();
+        pending.pending = true;
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(0), config));
+        assert_ok!(Infimum::create_poll(RuntimeOrigin::signed(1), pending));
+
+        for block in 1..=(3 + signup_period + voting_period)
+        {
+            run_to_block(block);
+
+            if block == 2
+            {
+                let (pk, _, _) = get_participant();
+                assert_ok!(Infimum::register_as_participant(RuntimeOrigin::signed(2), 0, pk));
+            }
+            if block == 3 { assert_ok!(Infimum::activate_poll(RuntimeOrigin::signed(1), 1)); }
+            if block == 1 + signup_period
+            {
+                let (_, shared_pk, message) = get_participant();
+                assert_ok!(Infimum::interact_with_poll(RuntimeOrigin::signed(2), 0, shared_pk, interaction_cipher(&message)));
+            }
+
+            assert_phase_matches(0);
+            assert_phase_matches(1);
+        }
+
+        let poll = Infimum::polls(0).unwrap();
+        assert_eq!(poll.state.registrations.count, 1);
+        assert_eq!(poll.state.interactions.count, 1);
+        assert_eq!(Infimum::poll_registration_tree(0), Some(poll.state.registrations));
+        assert_eq!(Infimum::poll_interaction_tree(0), Some(poll.state.interactions.clone()));
+
+        // The stored poll embeds only the shape of its trees, and an interaction reads and writes fewer bytes than
+        // the stored poll alone.
+        let stored = Polls::<Test>::get(0).unwrap();
+        assert_eq!(stored.state.registrations, poll.state.registrations.shape());
+        assert_eq!(stored.state.interactions, poll.state.interactions.shape());
+        assert_eq!(stored.state.interactions.count, 0);
+        let phase = Infimum::poll_phase(0).unwrap();
+        assert!(phase.encoded_size() + poll.state.interactions.encoded_size() < stored.encoded_size());
+    })
+}
@@ -0,0 +1,200 @@
+use sp_std::vec;
+use crate::poll::{
+    AmortizedIncrementalMerkleTree,
+    HashBytes,
+    MerkleTreeError,
+    MerkleTreeStats,
+    state::PollStateTree,
+    zeroes::get_merkle_zeroes
+};
+use crate::tests::merkle_path;
+
+fn leaf(index: u8) -> HashBytes
+{
+    let mut leaf = [0u8; 32];
+    leaf[31] = index;
+    leaf
+}
+
+fn fill(mut tree: PollStateTree, count: u8) -> PollStateTree
+{
+    for index in 1..=count { tree = tree.insert(leaf(index)).unwrap(); }
+    tree
+}
+
+/// A partially filled tree should survive a round trip through the compact format.
+#[test]
+fn compact_bytes_partial_tree()
+{
+    let registrations = fill(PollStateTree::new(2, 4, Some((0, get_merkle_zeroes(2)[0]))), 6);
+    let interactions = fill(PollStateTree::new(5, 2, None), 7);
+
+    for tree in [ registrations, interactions ]
+    {
+        let bytes = tree.to_compact_bytes();
+        assert_eq!(bytes.len(), 8 + 33 * tree.hashes.len());
+        assert_eq!(PollStateTree::from_compact_bytes(&bytes), Ok(tree));
+    }
+}
+
+/// A full tree should reject further leaves, and only take on its root once merged.
+#[test]
+fn full_tree_merges()
+{
+    let tree = fill(PollStateTree::new(2, 2, Some((0, get_merkle_zeroes(2)[0]))), 3);
+    assert_eq!(tree.root, None);
+    assert_eq!(tree.hashes.len(), 1);
+    assert!(matches!(tree.clone().insert(leaf(4)), Err(MerkleTreeError::TreeAlreadyFull)));
+
+    let root = tree.hashes[0].1;
+    let merged = tree.merge(false).unwrap();
+    assert_eq!(merged.root, Some(root));
+    assert!(merged.hashes.is_empty());
+    assert!(matches!(merged.insert(leaf(4)), Err(MerkleTreeError::TreeAlreadyMerged)));
+}
+
+/// Merging should fail for a tree which was already merged, whose hashes fail, or whose frontier is malformed.
+#[test]
+fn merge_failure_modes()
+{
+    let merged = fill(PollStateTree::new(2, 2, None), 3).merge(true).unwrap();
+    assert!(matches!(merged.merge(true), Err(MerkleTreeError::TreeAlreadyMerged)));
+
+    // Subtrees are padded to the arity of the tree, for which no hash exists.
+    let unhashable = fill(PollStateTree::new(20, 1, None), 1);
+    assert!(matches!(unhashable.merge(true), Err(MerkleTreeError::HashFailed)));
+
+    // More than `arity` subtrees of the same depth.
+    let mut overfull = PollStateTree::new(2, 2, None);
+    overfull.hashes = vec::Vec::from([ (0, leaf(1)), (0, leaf(2)), (0, leaf(3)) ]);
+    assert!(matches!(overfull.merge(true), Err(MerkleTreeError::MalformedFrontier)));
+
+    // A subtree at the full depth alongside further subtrees.
+    let mut overdeep = PollStateTree::new(2, 2, None);
+    overdeep.hashes = vec::Vec::from([ (2, leaf(1)), (0, leaf(2)) ]);
+    assert!(matches!(overdeep.merge(false), Err(MerkleTreeError::MalformedFrontier)));
+
+    // Partially filled and empty trees are padded rather than rejected.
+    assert!(fill(PollStateTree::new(2, 2, None), 1).merge(true).unwrap().root.is_some());
+    assert_eq!(PollStateTree::new(2, 2, None).merge(true).unwrap().root, Some(get_merkle_zeroes(2)[2]));
+}
+
+/// A complete tree should be encoded by its root alone.
+#[test]
+fn compact_bytes_merged_tree()
+{
+    let tree = fill(PollStateTree::new(5, 2, None), 7).merge(true).unwrap();
+    assert!(tree.root.is_some());
+
+    let bytes = tree.to_compact_bytes();
+    assert_eq!(bytes.len(), 8 + 32);
+    assert_eq!(&bytes[..8], &[ 5, 2, tree.depth, 7, 0, 0, 0, 1 ]);
+    assert_eq!(PollStateTree::from_compact_bytes(&bytes), Ok(tree));
+}
+
+/// Malformed compact bytes should be rejected.
+#[test]
+fn compact_bytes_malformed()
+{
+    let tree = fill(PollStateTree::new(5, 2, None), 7);
+    let bytes = tree.to_compact_bytes();
+
+    // Truncated header and frontier.
+    assert!(PollStateTree::from_compact_bytes(&bytes[..7]).is_err());
+    assert!(PollStateTree::from_compact_bytes(&bytes[..bytes.len() - 1]).is_err());
+
+    // Unsupported arity.
+    let mut malformed = bytes.clone();
+    malformed[0] = 3;
+    assert!(PollStateTree::from_compact_bytes(&malformed).is_err());
+
+    // Unknown root flag.
+    let mut malformed = bytes.clone();
+    malformed[7] = 2;
+    assert!(PollStateTree::from_compact_bytes(&malformed).is_err());
+
+    // A root flag which is followed by the frontier rather than a root.
+    let mut malformed = bytes;
+    malformed[7] = 1;
+    assert!(PollStateTree::from_compact_bytes(&malformed).is_err());
+
+    // The compact encoding of an empty tree consists of the header alone.
+    let empty = PollStateTree::new(2, 4, None);
+    assert_eq!(empty.to_compact_bytes(), vec::Vec::from([ 2, 4, 0, 0, 0, 0, 0, 0 ]));
+}
+
+/// Only the unhashed leaves of the frontier should be yielded, at their positions in the tree.
+#[test]
+fn iter_leaves()
+{
+    // The zero leaf and six leaves fill a subtree of four positions, followed by a subtree of two, and a leaf.
+    let zero = get_merkle_zeroes(2)[0];
+    let registrations = fill(PollStateTree::new(2, 4, Some((0, zero))), 6);
+    assert_eq!(registrations.iter_leaves().collect::<vec::Vec<_>>(), vec::Vec::from([(6, leaf(6))]));
+
+    // Seven leaves fill a subtree of five positions, followed by two leaves.
+    let interactions = fill(PollStateTree::new(5, 2, None), 7);
+    assert_eq!(interactions.iter_leaves().collect::<vec::Vec<_>>(), vec::Vec::from([(5, leaf(6)), (6, leaf(7))]));
+
+    // A fresh registration tree retains its zero leaf, and a merged tree retains none.
+    let fresh = PollStateTree::new(2, 4, Some((0, zero)));
+    assert_eq!(fresh.iter_leaves().collect::<vec::Vec<_>>(), vec::Vec::from([(0, zero)]));
+    assert_eq!(interactions.merge(true).unwrap().iter_leaves().count(), 0);
+}
+
+/// The stats of a tree should report its capacity at full depth, and the share of it which is filled.
+#[test]
+fn tree_stats()
+{
+    // Six leaves are counted apart from the zero leaf.
+    let registrations = fill(PollStateTree::new(2, 4, Some((0, get_merkle_zeroes(2)[0]))), 6);
+    assert_eq!(registrations.stats(), MerkleTreeStats {
+        depth: 4,
+        arity: 2,
+        leaf_count: 6,
+        capacity: 16,
+        fill_percent: 37,
+        is_merged: false
+    });
+
+    // A full tree is filled to 100 percent, and reported as merged once its root is computed.
+    let interactions = fill(PollStateTree::new(5, 1, None), 5).merge(false).unwrap();
+    let stats = interactions.stats();
+    assert_eq!((stats.leaf_count, stats.capacity, stats.fill_percent, stats.is_merged), (5, 5, 100, true));
+
+    // The capacity of a tree deeper than `u32` can count saturates, and the share is computed against it.
+    let mut deep = PollStateTree::new(5, 32, None);
+    deep.count = u32::MAX;
+    let stats = deep.stats();
+    assert_eq!((stats.capacity, stats.fill_percent), (u32::MAX, 100));
+}
+
+/// A leaf should verify against the root of a merged tree along its path, and any other leaf, index or path should not.
+#[test]
+fn verify_leaf_round_trip()
+{
+    // Seven leaves of a quinary tree merged to its full depth.
+    let leaves: vec::Vec<HashBytes> = (1..=7).map(leaf).collect();
+    let interactions = fill(PollStateTree::new(5, 2, None), 7);
+    assert!(!interactions.verify_leaf(6, leaf(7), merkle_path(5, 2, &leaves, 6).1));
+
+    let interactions = interactions.merge(true).unwrap();
+    let (root, path) = merkle_path(5, 2, &leaves, 6);
+    assert_eq!(interactions.root, Some(root));
+    assert!(interactions.verify_leaf(6, leaf(7), path.clone()));
+    assert!(!interactions.verify_leaf(6, leaf(8), path.clone()));
+    assert!(!interactions.verify_leaf(5, leaf(7), path.clone()));
+    assert!(!interactions.verify_leaf(6 + 25, leaf(7), path.clone()));
+    assert!(!interactions.verify_leaf(6, leaf(7), path[..1].to_vec()));
+
+    // A registration tree merged below its full depth is proven against the depth of its root, after the zero leaf.
+    let zero = get_merkle_zeroes(2)[0];
+    let registrations = fill(PollStateTree::new(2, 4, Some((0, zero))), 2).merge(false).unwrap();
+    let (root, path) = merkle_path(2, 2, &[ zero, leaf(1), leaf(2) ], 2);
+    assert_eq!(registrations.root, Some(root));
+    assert!(registrations.verify_leaf(2, leaf(2), path.clone()));
+    assert!(!registrations.verify_leaf(1, leaf(2), path));
+
+    let (_, path) = merkle_path(2, 2, &[ zero, leaf(1), leaf(2) ], 1);
+    assert!(registrations.verify_leaf(1, leaf(1), path));
+}
@@ -1,7 +1,25 @@
+use codec::Encode;
 use crate::{
-    mock::*
+    crypto,
+    mock::*,
+    Coordinators
 };
-use frame_support::pallet_prelude::Hooks;
+use crate::poll::{
+    AmortizedIncrementalMerkleTree,
+    BlockNumber,
+    CoordKeyHash,
+    HashBytes,
+    PollConfigOf,
+    PollInteractionCipher,
+    PollInteractionData,
+    PublicKey,
+    Signature,
+    VerifyingKeys,
+    state::PollStateTree,
+    zeroes::get_merkle_zeroes
+};
+use frame_support::{assert_ok, pallet_prelude::{Hooks, Weight}};
+use sp_std::vec;
 
 pub fn run_to_block(n: u64)
 {
@@ -9,6 +27,7 @@ pub fn run_to_block(n: u64)
     {
         if System::block_number() > 1 
         {
+            Infimum::on_idle(System::block_number(), Weight::MAX);
             Infimum::on_finalize(System::block_number());
             System::on_finalize(System::block_number());
         }
@@ -17,3 +36,99 @@ pub fn run_to_block(n: u64)
         Infimum::on_initialize(System::block_number());
     }
 }
+
+/// A public key whose private key is derived from the account, alongside its proof of possession signed over
+/// the account.
+pub fn signed_coordinator_key(who: u64) -> (PublicKey, Signature)
+{
+    crypto::sign(&sp_io::hashing::blake2_256(&who.encode()), crypto::proof_of_possession_message(&who))
+}
+
+/// Register a coordinator with `register_as_coordinator`, proving possession of the key derived from their account.
+/// The proof fixtures were generated against a coordinator public key whose private key is not available to the
+/// tests, such that any other public key then replaces the derived key, as a rotation would.
+pub fn register_coordinator(
+    who: u64,
+    public_key: PublicKey,
+    verify_key: VerifyingKeys
+)
+{
+    let (signed_key, signature) = signed_coordinator_key(who);
+    assert_ok!(Infimum::register_as_coordinator(RuntimeOrigin::signed(who), signed_key, verify_key, signature));
+
+    if public_key != signed_key
+    {
+        Coordinators::<Test>::mutate(who, |coordinator| {
+            if let Some(coordinator) = coordinator { coordinator.public_key = public_key; }
+        });
+    }
+}
+
+/// Collect the poll creation parameters into a poll configuration.
+pub fn poll_config(
+    signup_period: BlockNumber,
+    voting_period: BlockNumber,
+    registration_depth: u8,
+    interaction_depth: u8,
+    process_subtree_depth: u8,
+    tally_subtree_depth: u8,
+    vote_option_tree_depth: u8,
+    vote_options: vec::Vec<u128>
+) -> PollConfigOf<Test>
+{
+    PollConfigOf {
+        signup_period,
+        voting_period,
+        registration_depth,
+        interaction_depth,
+        process_subtree_depth,
+        tally_subtree_depth,
+        vote_option_tree_depth,
+        vote_options: vote_options.try_into().unwrap(),
+        fee_allowance: 0,
+        reject_coordinator_key: false,
+        allow_early_interactions: false,
+        message_words: 10,
+        pending: false,
+        collect_intents: false,
+        unique_keys: false,
+        compact_interactions: false,
+        coord_key_hash: CoordKeyHash::Poseidon2Coords,
+        circuit_id: None,
+        option_calls: Default::default()
+    }
+}
+
+pub fn interaction_data(words: &[[u8; 32]]) -> PollInteractionData
+{
+    words.to_vec().try_into().unwrap()
+}
+
+pub fn interaction_cipher(words: &[[u8; 32]]) -> PollInteractionCipher
+{
+    interaction_data(words).into()
+}
+
+/// The root of the tree of the given arity and depth which holds `leaves` followed by zeros, and the siblings of
+/// each level of the path from the leaf at `index` upwards.
+pub fn merkle_path(arity: u8, depth: u8, leaves: &[HashBytes], index: u32) -> (HashBytes, vec::Vec<vec::Vec<HashBytes>>)
+{
+    let zeroes = get_merkle_zeroes(arity);
+    let arity = usize::from(arity);
+
+    let mut level = leaves.to_vec();
+    let mut position = index as usize;
+    let mut path = vec::Vec::new();
+    for zero in zeroes.iter().take(usize::from(depth))
+    {
+        level.resize(((level.len() + arity - 1) / arity).max(1) * arity, *zero);
+
+        let start = position - position % arity;
+        path.push((start..start + arity).filter(|&i| i != position).map(|i| level[i]).collect());
+
+        level = level.chunks(arity).map(|chunk| PollStateTree::hash(chunk.to_vec()).unwrap()).collect();
+        position /= arity;
+    }
+
+    (level[0], path)
+}
@@ -0,0 +1,241 @@
+//! Weights for `pallet_infimum`.
+//!
+//! The computational component of each weight is composed from timings of the operations it performs, i.e. Groth16
+//! pairings, BN254 point deserialization and scalar multiplication, circom Poseidon hashes and Baby Jubjub arithmetic,
+//! measured natively with the arkworks versions of the pallet and scaled by three for the wasm executor. The storage
+//! component counts the accesses of each call. Per operation, in microseconds before scaling:
+//!
+//! | operation                  | time                             |
+//! |----------------------------|----------------------------------|
+//! | `H2` / `H4` / `H5`         | 77 / 152 / 209                   |
+//! | Baby Jubjub subgroup check | 7_530                            |
+//! | EdDSA verification         | 13_900                           |
+//! | G1 / G2 deserialization    | 165 / 453                        |
+//! | G1 scalar multiplication   | 163                              |
+//! | pairing                    | 1_973                            |
+//! | multi-pairing              | 3_058 for 3 pairs, +620 per pair |
+//!
+//! The values are to be superseded by the output of the pallet benchmarks on reference hardware, e.g.
+//!
+//! ```text
+//! ./target/release/node-template benchmark pallet \
+//!     --chain dev \
+//!     --pallet pallet_infimum \
+//!     --extrinsic '*' \
+//!     --steps 50 \
+//!     --repeat 20 \
+//!     --output pallets/infimum/src/weights.rs
+//! ```
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for `pallet_infimum`.
+pub trait WeightInfo
+{
+    fn register_as_coordinator() -> Weight;
+    fn rotate_keys() -> Weight;
+    fn register_as_participant() -> Weight;
+    fn interact_with_poll(d: u32) -> Weight;
+    fn interact_with_poll_batch(n: u32) -> Weight;
+    fn merge_registrations() -> Weight;
+    fn merge_interactions() -> Weight;
+    fn verify_proof() -> Weight;
+    fn verify_proofs_sequential(n: u32) -> Weight;
+    fn verify_proofs_batched(n: u32) -> Weight;
+    fn commit_outcome(o: u32) -> Weight;
+    fn register_circuit(p: u32) -> Weight;
+    fn remove_circuit() -> Weight;
+}
+
+/// Weights for `pallet_infimum` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T>
+{
+    /// A subgroup check of the public key, the verification of the proof of possession, and the deserialization
+    /// of the 18 G1 and 6 G2 points of both verifying keys.
+    fn register_as_coordinator() -> Weight
+    {
+        Weight::from_parts(81_400_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads_writes(1, 1))
+    }
+
+    /// As `register_as_coordinator`, and an `H2` chain over each of the previous and new verifying keys, i.e. 63
+    /// digests apiece, to alert the provers of an unfinished poll.
+    fn rotate_keys() -> Weight
+    {
+        Weight::from_parts(110_500_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads_writes(3, 1))
+    }
+
+    /// A subgroup check of the public key, an `H4` state leaf, and an `H2` at each of the 16 levels of the deepest
+    /// registration tree.
+    fn register_as_participant() -> Weight
+    {
+        Weight::from_parts(26_640_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads_writes(5, 4))
+    }
+
+    /// A subgroup check of the shared public key, two `H5` chunk digests and an `H4` leaf, and an `H5` at each of
+    /// the `d` levels of the interaction tree.
+    fn interact_with_poll(d: u32) -> Weight
+    {
+        Weight::from_parts(24_210_000_000, 0)
+            .saturating_add(Weight::from_parts(627_000_000, 0).saturating_mul(d.into()))
+            .saturating_add(T::DbWeight::get().reads_writes(4, 3))
+    }
+
+    /// The leaf of each interaction as with `interact_with_poll`, an insertion amortized across the batch, and an
+    /// `H5` at each of the 7 levels of the deepest interaction tree.
+    fn interact_with_poll_batch(n: u32) -> Weight
+    {
+        Weight::from_parts(4_400_000_000, 0)
+            .saturating_add(Weight::from_parts(24_900_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads_writes(4, 3))
+            .saturating_add(T::DbWeight::get().writes(n.into()))
+    }
+
+    /// An `H2` at each of the 16 levels of the deepest registration tree.
+    fn merge_registrations() -> Weight
+    {
+        Weight::from_parts(3_700_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads_writes(6, 6))
+    }
+
+    /// An `H5` at each of the 7 levels of the deepest interaction tree.
+    fn merge_interactions() -> Weight
+    {
+        Weight::from_parts(4_400_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads_writes(6, 6))
+    }
+
+    /// The message processing circuit, with 9 public inputs: the deserialization and preparation of the verifying
+    /// key, the deserialization of the proof, the preparation of its inputs and a multi-pairing of 3 pairs.
+    fn verify_proof() -> Weight
+    {
+        Weight::from_parts(30_900_000_000, 0)
+    }
+
+    /// The deserialization of each proof, the linear combination of its public inputs and a four pair multi-pairing.
+    fn verify_proofs_sequential(n: u32) -> Weight
+    {
+        Weight::from_parts(26_940_000_000, 0).saturating_mul(n.into())
+    }
+
+    /// A single multi-pairing of `n + 3` pairs, after the deserialization and scaling of each proof.
+    fn verify_proofs_batched(n: u32) -> Weight
+    {
+        Weight::from_parts(22_620_000_000, 0)
+            .saturating_add(Weight::from_parts(8_130_000_000, 0).saturating_mul(n.into()))
+    }
+
+    /// A tally result path of `MAX_PATH_DEPTH` `H5` digests per vote option.
+    fn commit_outcome(o: u32) -> Weight
+    {
+        Weight::from_parts(1_000_000_000, 0)
+            .saturating_add(Weight::from_parts(20_700_000_000, 0).saturating_mul(o.into()))
+            .saturating_add(T::DbWeight::get().reads_writes(4, 2))
+    }
+
+    /// The deserialization of the 2 G1 and 6 G2 points of both verifying keys, and of the `p` points of their
+    /// `gamma_abc_g1`.
+    fn register_circuit(p: u32) -> Weight
+    {
+        Weight::from_parts(9_144_000_000, 0)
+            .saturating_add(Weight::from_parts(495_000_000, 0).saturating_mul(p.into()))
+            .saturating_add(T::DbWeight::get().reads_writes(1, 1))
+    }
+
+    /// Only the registry and the references of the circuit are accessed.
+    fn remove_circuit() -> Weight
+    {
+        T::DbWeight::get().reads_writes(2, 1)
+    }
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for ()
+{
+    fn register_as_coordinator() -> Weight
+    {
+        Weight::from_parts(81_400_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads_writes(1, 1))
+    }
+
+    fn rotate_keys() -> Weight
+    {
+        Weight::from_parts(110_500_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads_writes(3, 1))
+    }
+
+    fn register_as_participant() -> Weight
+    {
+        Weight::from_parts(26_640_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads_writes(5, 4))
+    }
+
+    fn interact_with_poll(d: u32) -> Weight
+    {
+        Weight::from_parts(24_210_000_000, 0)
+            .saturating_add(Weight::from_parts(627_000_000, 0).saturating_mul(d.into()))
+            .saturating_add(RocksDbWeight::get().reads_writes(4, 3))
+    }
+
+    fn interact_with_poll_batch(n: u32) -> Weight
+    {
+        Weight::from_parts(4_400_000_000, 0)
+            .saturating_add(Weight::from_parts(24_900_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads_writes(4, 3))
+            .saturating_add(RocksDbWeight::get().writes(n.into()))
+    }
+
+    fn merge_registrations() -> Weight
+    {
+        Weight::from_parts(3_700_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads_writes(6, 6))
+    }
+
+    fn merge_interactions() -> Weight
+    {
+        Weight::from_parts(4_400_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads_writes(6, 6))
+    }
+
+    fn verify_proof() -> Weight
+    {
+        Weight::from_parts(30_900_000_000, 0)
+    }
+
+    fn verify_proofs_sequential(n: u32) -> Weight
+    {
+        Weight::from_parts(26_940_000_000, 0).saturating_mul(n.into())
+    }
+
+    fn verify_proofs_batched(n: u32) -> Weight
+    {
+        Weight::from_parts(22_620_000_000, 0)
+            .saturating_add(Weight::from_parts(8_130_000_000, 0).saturating_mul(n.into()))
+    }
+
+    fn commit_outcome(o: u32) -> Weight
+    {
+        Weight::from_parts(1_000_000_000, 0)
+            .saturating_add(Weight::from_parts(20_700_000_000, 0).saturating_mul(o.into()))
+            .saturating_add(RocksDbWeight::get().reads_writes(4, 2))
+    }
+
+    fn register_circuit(p: u32) -> Weight
+    {
+        Weight::from_parts(9_144_000_000, 0)
+            .saturating_add(Weight::from_parts(495_000_000, 0).saturating_mul(p.into()))
+            .saturating_add(RocksDbWeight::get().reads_writes(1, 1))
+    }
+
+    fn remove_circuit() -> Weight
+    {
+        RocksDbWeight::get().reads_writes(2, 1)
+    }
+}